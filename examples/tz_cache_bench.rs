@@ -0,0 +1,36 @@
+//! Compares `TzCache` with uncached `TimeZone::to_local` over a day-sorted
+//! stream of timestamps. Run with `cargo run --release --example tz_cache_bench`.
+
+use std::hint::black_box;
+
+use nanotime::tz::{TimeZone, TzCache};
+use nanotime::{Elapsed, NanoTime};
+
+fn main() {
+    let zone = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    let start = NanoTime::new(2026, 1, 1, 0, 0, 0, 0)
+        .unwrap()
+        .to_epoch_secs();
+    // Ten million timestamps, 3 seconds apart: about a year of events.
+    let times: Vec<NanoTime> = (0..10_000_000u64)
+        .map(|i| NanoTime::from_epoch(start + i * 3))
+        .collect();
+
+    let timer = Elapsed::start();
+    for t in &times {
+        black_box(zone.offset_at(t));
+    }
+    let uncached = timer.elapsed_nanos();
+
+    let mut cache = TzCache::new(zone.clone());
+    let timer = Elapsed::start();
+    for t in &times {
+        black_box(cache.offset_at(t));
+    }
+    let cached = timer.elapsed_nanos();
+
+    let per = |nanos: u128| nanos as f64 / times.len() as f64;
+    println!("offset_at uncached: {:.1} ns/op", per(uncached));
+    println!("offset_at TzCache:  {:.1} ns/op", per(cached));
+    println!("speedup: {:.1}x", uncached as f64 / cached as f64);
+}
@@ -0,0 +1,69 @@
+#!/bin/sh
+# Regenerates src/tz/bundled.rs and src/tz/tzdata.bin from a compiled IANA
+# zoneinfo directory.
+#
+# Usage: scripts/gen-bundled-tz.sh [ZONEINFO_DIR]
+#
+# ZONEINFO_DIR defaults to /usr/share/zoneinfo and must contain TZif v2+
+# files plus tzdata.zi (for the version line). To bundle a specific release,
+# build it from https://www.iana.org/time-zones with `make install
+# TOPDIR=/tmp/tz` and pass /tmp/tz/usr/share/zoneinfo.
+#
+# tzdata.bin is every zone's TZif file concatenated, with identical files
+# (links and backward-compatible names) stored once. bundled.rs indexes it.
+set -eu
+
+dir="${1:-/usr/share/zoneinfo}"
+out="$(cd "$(dirname "$0")/.." && pwd)/src/tz"
+version=$(sed -n '1s/^# version //p' "$dir/tzdata.zi")
+bin="$out/tzdata.bin"
+seen=$(mktemp)
+trap 'rm -f "$seen"' EXIT
+: >"$bin"
+
+{
+cat <<EOF
+//! Compiled IANA time zone data for the \`bundled-tz\` feature.
+//!
+//! Generated by \`scripts/gen-bundled-tz.sh\` from tzdata $version. Each zone
+//! is its full TZif file, historical transitions included, stored in
+//! \`tzdata.bin\`. Do not edit by hand.
+
+/// The IANA tzdata release the bundled zones were generated from.
+pub const TZDATA_VERSION: &str = "$version";
+
+/// Every bundled zone's TZif file, concatenated.
+pub(super) static TZDATA: &[u8] = include_bytes!("tzdata.bin");
+
+/// Zone name and the byte range of its TZif file in [\`TZDATA\`], sorted
+/// bytewise by name.
+#[rustfmt::skip]
+pub(super) const ZONES: &[(&str, usize, usize)] = &[
+EOF
+
+cd "$dir"
+find . \( -path ./posix -o -path ./right \) -prune -o \( -type f -o -type l \) -print |
+    sed 's|^\./||' | LC_ALL=C sort |
+    while read -r name; do
+        case "$name" in localtime | posixrules | Factory) continue ;; esac
+        case "$(head -c 5 "$name")" in TZif[2-9]) ;; *) continue ;; esac
+        sum=$(cksum <"$name")
+        start=
+        # Reuse an earlier copy if the checksum matches and the bytes do too.
+        while read -r s_crc s_len s_start; do
+            [ "$s_crc $s_len" = "$sum" ] || continue
+            if tail -c +$((s_start + 1)) "$bin" | head -c "$s_len" | cmp -s - "$name"; then
+                start=$s_start
+                break
+            fi
+        done <"$seen"
+        if [ -z "$start" ]; then
+            start=$(wc -c <"$bin")
+            cat "$name" >>"$bin"
+            echo "$sum $start" >>"$seen"
+        fi
+        printf '    ("%s", %d, %d),\n' "$name" "$start" $((start + $(wc -c <"$name")))
+    done
+
+echo "];"
+} >"$out/bundled.rs"
@@ -0,0 +1,154 @@
+//! Conversions to and from Apache Arrow timestamp arrays. Requires the
+//! `arrow` feature.
+//!
+//! Each conversion is a single pass that builds the output buffer
+//! directly. Arrow timestamps count from the Unix epoch, and a
+//! [`NanoTime`] holding an instant is UTC, so produced arrays carry no
+//! time zone. Call `.with_timezone_utc()` on the result to label them.
+//!
+//! ```rust
+//! use nanotime::NanoTime;
+//! use nanotime::arrow::{from_nanosecond_array, to_nanosecond_array};
+//!
+//! let times = [NanoTime::from_epoch(0), NanoTime::from_epoch_ms(1_500)];
+//! let array = to_nanosecond_array(&times);
+//! assert_eq!(array.value(1), 1_500_000_000);
+//!
+//! let back = from_nanosecond_array(&array);
+//! assert_eq!(back, vec![Some(times[0]), Some(times[1])]);
+//! ```
+
+use arrow_array::{TimestampMillisecondArray, TimestampNanosecondArray};
+
+use crate::NanoTime;
+
+/// Epoch nanoseconds, or `None` outside the `i64` range (about 1677–2262).
+fn epoch_nanos(t: &NanoTime) -> Option<i64> {
+    i64::try_from(t.unix_nanos()).ok()
+}
+
+/// Epoch milliseconds, truncating sub-millisecond precision.
+fn epoch_millis(t: &NanoTime) -> i64 {
+    t.unix_secs() * 1_000 + t.millisecond() as i64
+}
+
+fn from_nanos(nanos: i64) -> Option<NanoTime> {
+    NanoTime::checked_from_unix_nanos(nanos as i128)
+}
+
+fn from_millis(millis: i64) -> Option<NanoTime> {
+    NanoTime::checked_from_unix(
+        millis.div_euclid(1_000),
+        millis.rem_euclid(1_000) as u32 * 1_000_000,
+    )
+}
+
+/// Builds a `TimestampNanosecondArray`. Times outside the `i64` nanosecond
+/// range (about years 1677–2262) become null.
+pub fn to_nanosecond_array(times: &[NanoTime]) -> TimestampNanosecondArray {
+    times.iter().map(epoch_nanos).collect()
+}
+
+/// Builds a `TimestampNanosecondArray` with `None` entries as nulls.
+pub fn to_nanosecond_array_opt(times: &[Option<NanoTime>]) -> TimestampNanosecondArray {
+    times
+        .iter()
+        .map(|t| t.as_ref().and_then(epoch_nanos))
+        .collect()
+}
+
+/// Builds a `TimestampMillisecondArray`, truncating to whole milliseconds.
+pub fn to_millisecond_array(times: &[NanoTime]) -> TimestampMillisecondArray {
+    times.iter().map(|t| Some(epoch_millis(t))).collect()
+}
+
+/// Builds a `TimestampMillisecondArray` with `None` entries as nulls.
+pub fn to_millisecond_array_opt(times: &[Option<NanoTime>]) -> TimestampMillisecondArray {
+    times.iter().map(|t| t.as_ref().map(epoch_millis)).collect()
+}
+
+/// Reads a `TimestampNanosecondArray`, with nulls as `None`.
+pub fn from_nanosecond_array(array: &TimestampNanosecondArray) -> Vec<Option<NanoTime>> {
+    array.iter().map(|v| v.and_then(from_nanos)).collect()
+}
+
+/// Reads a `TimestampMillisecondArray`, with nulls as `None`. Values whose
+/// year falls outside `0..=65535` are also `None`.
+pub fn from_millisecond_array(array: &TimestampMillisecondArray) -> Vec<Option<NanoTime>> {
+    array.iter().map(|v| v.and_then(from_millis)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+
+    fn sample() -> Vec<NanoTime> {
+        vec![
+            NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap(),
+            NanoTime::from_epoch(0),
+            NanoTime::new(1969, 12, 31, 23, 59, 59, 999_999_999).unwrap(),
+            NanoTime::new(1900, 1, 1, 0, 0, 0, 1).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_nanosecond_round_trip() {
+        let times = sample();
+        let array = to_nanosecond_array(&times);
+        assert_eq!(array.len(), 4);
+        assert_eq!(array.null_count(), 0);
+        assert_eq!(array.value(0), 1_771_770_605_123_456_789);
+        assert_eq!(array.value(2), -1);
+        let back: Vec<NanoTime> = from_nanosecond_array(&array)
+            .into_iter()
+            .map(Option::unwrap)
+            .collect();
+        assert_eq!(back, times);
+    }
+
+    #[test]
+    fn test_nanosecond_out_of_range_is_null() {
+        let far = NanoTime::new(2300, 1, 1, 0, 0, 0, 0).unwrap();
+        let array = to_nanosecond_array(&[far]);
+        assert!(array.is_null(0));
+    }
+
+    #[test]
+    fn test_millisecond_truncates() {
+        let times = sample();
+        let array = to_millisecond_array(&times);
+        assert_eq!(array.value(0), 1_771_770_605_123);
+        assert_eq!(array.value(2), -1);
+        let back = from_millisecond_array(&array);
+        assert_eq!(
+            back[0],
+            Some(NanoTime::new(2026, 2, 22, 14, 30, 5, 123_000_000).unwrap())
+        );
+        assert_eq!(
+            back[2],
+            Some(NanoTime::new(1969, 12, 31, 23, 59, 59, 999_000_000).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_nulls_both_directions() {
+        let times = [Some(NanoTime::from_epoch(1)), None];
+        let nanos = to_nanosecond_array_opt(&times);
+        assert_eq!(nanos.null_count(), 1);
+        assert_eq!(from_nanosecond_array(&nanos), times);
+
+        let millis = to_millisecond_array_opt(&times);
+        assert!(millis.is_null(1));
+        assert_eq!(from_millisecond_array(&millis), times);
+    }
+
+    #[test]
+    fn test_millisecond_out_of_range_reads_as_none() {
+        let array = TimestampMillisecondArray::from(vec![i64::MIN, i64::MAX, 0]);
+        assert_eq!(
+            from_millisecond_array(&array),
+            vec![None, None, Some(NanoTime::from_epoch(0))]
+        );
+    }
+}
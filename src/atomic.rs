@@ -0,0 +1,150 @@
+//! A timestamp that can be shared and updated across threads without a lock.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::{system_nanos, NanoTime};
+
+/// Nanoseconds since the Unix epoch, clamped to what a `u64` holds.
+pub(crate) fn to_nanos(t: &NanoTime) -> u64 {
+    let nanos = t.unix_nanos();
+    nanos.clamp(0, u64::MAX as i128) as u64
+}
+
+pub(crate) fn from_nanos(nanos: u64) -> NanoTime {
+    NanoTime::from_epoch_nanos(nanos as u128)
+}
+
+/// A UTC timestamp stored in an atomic integer, for "last seen" and
+/// "last heartbeat" tracking shared between threads.
+///
+/// The value is kept as nanoseconds since the Unix epoch in a `u64`, which
+/// covers 1970 through 2554. Times outside that range are clamped to it.
+/// Stores use release ordering and loads use acquire ordering, so data
+/// written before a store is visible to a thread that loads the new value.
+///
+/// ```rust
+/// use nanotime::AtomicNanoTime;
+///
+/// let last_heartbeat = AtomicNanoTime::now();
+/// // ... on another thread ...
+/// last_heartbeat.store_now();
+/// assert!(last_heartbeat.elapsed_since_store().as_secs() < 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicNanoTime {
+    nanos: AtomicU64,
+}
+
+impl AtomicNanoTime {
+    /// Creates an atomic timestamp holding `t`.
+    pub fn new(t: NanoTime) -> Self {
+        AtomicNanoTime {
+            nanos: AtomicU64::new(to_nanos(&t)),
+        }
+    }
+
+    /// Creates an atomic timestamp holding the current UTC time.
+    pub fn now() -> Self {
+        AtomicNanoTime {
+            nanos: AtomicU64::new(system_nanos()),
+        }
+    }
+
+    /// Returns the stored time.
+    pub fn load(&self) -> NanoTime {
+        from_nanos(self.nanos.load(Ordering::Acquire))
+    }
+
+    /// Replaces the stored time with `t`.
+    pub fn store(&self, t: NanoTime) {
+        self.nanos.store(to_nanos(&t), Ordering::Release);
+    }
+
+    /// Replaces the stored time with the current UTC time.
+    pub fn store_now(&self) {
+        self.nanos.store(system_nanos(), Ordering::Release);
+    }
+
+    /// Stores `t` if it is later than the stored time, and returns the
+    /// previous value. Concurrent writers reporting out of order can never
+    /// move the timestamp backwards.
+    pub fn fetch_max(&self, t: NanoTime) -> NanoTime {
+        from_nanos(self.nanos.fetch_max(to_nanos(&t), Ordering::AcqRel))
+    }
+
+    /// Returns how long ago the stored time was, by the system clock. Zero
+    /// if the stored time is in the future.
+    pub fn elapsed_since_store(&self) -> Duration {
+        let stored = self.nanos.load(Ordering::Acquire);
+        Duration::from_nanos(system_nanos().saturating_sub(stored))
+    }
+
+    /// Consumes the atomic and returns the stored time.
+    pub fn into_inner(self) -> NanoTime {
+        from_nanos(self.nanos.into_inner())
+    }
+}
+
+impl From<NanoTime> for AtomicNanoTime {
+    fn from(t: NanoTime) -> Self {
+        Self::new(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_load_store_round_trip() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+        let a = AtomicNanoTime::default();
+        assert_eq!(a.load(), NanoTime::from_epoch(0));
+        a.store(t);
+        assert_eq!(a.load(), t);
+        assert_eq!(a.into_inner(), t);
+    }
+
+    #[test]
+    fn test_out_of_range_clamps() {
+        let a = AtomicNanoTime::new(NanoTime::new(1969, 12, 31, 23, 59, 59, 0).unwrap());
+        assert_eq!(a.load(), NanoTime::from_epoch(0));
+        a.store(NanoTime::new(3000, 1, 1, 0, 0, 0, 0).unwrap());
+        assert_eq!(a.load().year(), 2554);
+    }
+
+    #[test]
+    fn test_fetch_max_never_goes_backwards() {
+        let early = NanoTime::new(2026, 1, 1, 0, 0, 0, 0).unwrap();
+        let late = NanoTime::new(2026, 6, 1, 0, 0, 0, 0).unwrap();
+        let a = AtomicNanoTime::new(late);
+        assert_eq!(a.fetch_max(early), late);
+        assert_eq!(a.load(), late);
+
+        let shared = Arc::new(AtomicNanoTime::new(early));
+        let handles: Vec<_> = (0..8u64)
+            .map(|i| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || {
+                    shared.fetch_max(NanoTime::from_epoch(1_800_000_000 + i));
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert_eq!(shared.load(), NanoTime::from_epoch(1_800_000_007));
+    }
+
+    #[test]
+    fn test_elapsed_since_store() {
+        let a = AtomicNanoTime::now();
+        thread::sleep(Duration::from_millis(5));
+        assert!(a.elapsed_since_store() >= Duration::from_millis(5));
+        a.store(NanoTime::new(3000, 1, 1, 0, 0, 0, 0).unwrap());
+        assert_eq!(a.elapsed_since_store(), Duration::ZERO);
+    }
+}
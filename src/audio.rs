@@ -0,0 +1,178 @@
+//! Conversions between audio sample positions and time.
+//!
+//! A sample period is rarely a whole number of nanoseconds (1/44100 s is
+//! 22675.73... ns), so stepping a clock by a rounded period drifts by
+//! milliseconds an hour. Everything here is computed from the sample
+//! count in integer arithmetic instead, and rounds once.
+
+use std::time::Duration;
+
+use crate::{NanoTime, NANOS_PER_SEC};
+
+fn check_rate(sample_rate: u32) {
+    assert!(sample_rate > 0, "sample rate must be non-zero");
+}
+
+/// Returns the time `n` samples take at `sample_rate` Hz, rounded down to
+/// the nanosecond.
+///
+/// # Panics
+///
+/// Panics if `sample_rate` is zero.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::samples_to_duration;
+///
+/// // One hour at 44.1 kHz, exactly, with no accumulated rounding.
+/// assert_eq!(samples_to_duration(158_760_000, 44_100), Duration::from_secs(3600));
+/// ```
+pub fn samples_to_duration(n: u64, sample_rate: u32) -> Duration {
+    check_rate(sample_rate);
+    let nanos = n as u128 * NANOS_PER_SEC as u128 / sample_rate as u128;
+    Duration::new(
+        (nanos / NANOS_PER_SEC as u128) as u64,
+        (nanos % NANOS_PER_SEC as u128) as u32,
+    )
+}
+
+/// Returns the number of whole samples that fit in `d` at `sample_rate`
+/// Hz, saturating at `u64::MAX`.
+///
+/// # Panics
+///
+/// Panics if `sample_rate` is zero.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::duration_to_samples;
+///
+/// assert_eq!(duration_to_samples(Duration::from_millis(10), 48_000), 480);
+/// ```
+pub fn duration_to_samples(d: Duration, sample_rate: u32) -> u64 {
+    check_rate(sample_rate);
+    let samples = d.as_nanos() * sample_rate as u128 / NANOS_PER_SEC as u128;
+    u64::try_from(samples).unwrap_or(u64::MAX)
+}
+
+/// Maps sample positions in a stream to wall-clock times, given the time
+/// one sample was played or captured.
+///
+/// Each conversion works from the anchor, so positions hours into a
+/// stream are as exact as the first. Re-anchor with
+/// [`resync`](Self::resync) when a fresh hardware timestamp arrives, to
+/// follow the device clock's drift against the system clock.
+///
+/// ```rust
+/// use nanotime::{NanoTime, SampleClock};
+///
+/// let start = NanoTime::new(2026, 2, 22, 14, 30, 0, 0).unwrap();
+/// let clock = SampleClock::new(start, 48_000);
+/// assert_eq!(clock.time_at(72_000), NanoTime::new(2026, 2, 22, 14, 30, 1, 500_000_000));
+///
+/// let t = NanoTime::new(2026, 2, 22, 14, 30, 1, 0).unwrap();
+/// assert_eq!(clock.sample_at(&t), Some(48_000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SampleClock {
+    anchor: NanoTime,
+    anchor_sample: u64,
+    sample_rate: u32,
+}
+
+impl SampleClock {
+    /// Creates a clock on which sample 0 falls at `anchor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_rate` is zero.
+    pub fn new(anchor: NanoTime, sample_rate: u32) -> Self {
+        check_rate(sample_rate);
+        SampleClock {
+            anchor,
+            anchor_sample: 0,
+            sample_rate,
+        }
+    }
+
+    /// Re-anchors the clock so that `sample` falls at `time`, keeping the
+    /// sample rate.
+    pub fn resync(&mut self, sample: u64, time: NanoTime) {
+        self.anchor = time;
+        self.anchor_sample = sample;
+    }
+
+    /// Returns the sample rate in Hz.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Returns the anchor sample and its time.
+    pub fn anchor(&self) -> (u64, NanoTime) {
+        (self.anchor_sample, self.anchor)
+    }
+
+    /// Returns the time of `sample`, rounded down to the nanosecond.
+    /// Samples before the anchor are extrapolated backward. Returns `None`
+    /// if the year would fall outside `0..=65535`.
+    pub fn time_at(&self, sample: u64) -> Option<NanoTime> {
+        let delta = sample as i128 - self.anchor_sample as i128;
+        let nanos = (delta * NANOS_PER_SEC).div_euclid(self.sample_rate as i128);
+        self.anchor.checked_add_nanos(nanos)
+    }
+
+    /// Returns the sample playing at `t`: the last one that starts at or
+    /// before it. Returns `None` if that would be before sample 0.
+    pub fn sample_at(&self, t: &NanoTime) -> Option<u64> {
+        let nanos = t.diff_nanos(&self.anchor);
+        // Sample starts are rounded down, so sample n starts at or before
+        // t exactly when n * 1e9 < (t + 1) * rate.
+        let delta = ((nanos + 1) * self.sample_rate as i128 - 1).div_euclid(NANOS_PER_SEC);
+        u64::try_from(self.anchor_sample as i128 + delta).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_free_functions_round_down() {
+        // One sample at 44.1 kHz is 22675.7 ns.
+        assert_eq!(samples_to_duration(1, 44_100), Duration::from_nanos(22_675));
+        assert_eq!(duration_to_samples(Duration::from_nanos(22_675), 44_100), 0);
+        assert_eq!(duration_to_samples(Duration::from_nanos(22_676), 44_100), 1);
+        assert_eq!(duration_to_samples(Duration::MAX, 192_000), u64::MAX);
+    }
+
+    #[test]
+    fn test_no_drift_over_a_day() {
+        let start = NanoTime::new(2026, 2, 22, 0, 0, 0, 0).unwrap();
+        let clock = SampleClock::new(start, 44_100);
+        let day = 44_100 * 86_400;
+        assert_eq!(clock.time_at(day), NanoTime::new(2026, 2, 23, 0, 0, 0, 0));
+        assert_eq!(clock.time_at(day + 1).unwrap().nanosecond(), 22_675);
+        for sample in [0, 1, 12_345_678, day - 1] {
+            let t = clock.time_at(sample).unwrap();
+            assert_eq!(clock.sample_at(&t), Some(sample));
+        }
+    }
+
+    #[test]
+    fn test_resync_and_before_anchor() {
+        let t0 = NanoTime::new(2026, 2, 22, 0, 0, 10, 0).unwrap();
+        let mut clock = SampleClock::new(t0, 48_000);
+        clock.resync(480_000, t0);
+        assert_eq!(clock.anchor(), (480_000, t0));
+        assert_eq!(clock.time_at(0), NanoTime::new(2026, 2, 22, 0, 0, 0, 0));
+        let early = NanoTime::new(2026, 2, 22, 0, 0, 0, 0).unwrap();
+        assert_eq!(clock.sample_at(&early), Some(0));
+        assert_eq!(clock.sample_at(&early.add_nanos(-1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "sample rate must be non-zero")]
+    fn test_zero_rate_panics() {
+        SampleClock::new(NanoTime::from_epoch(0), 0);
+    }
+}
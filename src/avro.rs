@@ -0,0 +1,225 @@
+//! Avro logical date and time types.
+//!
+//! Avro stores `timestamp-*` as a `long` count of epoch units in UTC and
+//! `local-timestamp-*` as the same count measured from 1970-01-01T00:00 on
+//! the local clock, with no zone. `time-millis` is an `int` and
+//! `time-micros` a `long` counting from midnight, with no date.
+
+use crate::NanoTime;
+
+/// An Avro timestamp logical type.
+///
+/// The UTC and local variants encode identically; they differ only in
+/// whether the [`NanoTime`] holds a UTC instant or a local wall-clock time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AvroTimestamp {
+    /// `timestamp-millis`.
+    Millis,
+    /// `timestamp-micros`.
+    Micros,
+    /// `timestamp-nanos`, added in Avro 1.12.
+    Nanos,
+    /// `local-timestamp-millis`.
+    LocalMillis,
+    /// `local-timestamp-micros`.
+    LocalMicros,
+    /// `local-timestamp-nanos`, added in Avro 1.12.
+    LocalNanos,
+}
+
+impl AvroTimestamp {
+    /// Looks up a schema's `logicalType` attribute. Returns `None` for
+    /// names that are not timestamp types.
+    ///
+    /// ```rust
+    /// use nanotime::AvroTimestamp;
+    ///
+    /// assert_eq!(AvroTimestamp::from_logical_type("timestamp-micros"), Some(AvroTimestamp::Micros));
+    /// assert_eq!(AvroTimestamp::from_logical_type("date"), None);
+    /// ```
+    pub fn from_logical_type(name: &str) -> Option<Self> {
+        Some(match name {
+            "timestamp-millis" => AvroTimestamp::Millis,
+            "timestamp-micros" => AvroTimestamp::Micros,
+            "timestamp-nanos" => AvroTimestamp::Nanos,
+            "local-timestamp-millis" => AvroTimestamp::LocalMillis,
+            "local-timestamp-micros" => AvroTimestamp::LocalMicros,
+            "local-timestamp-nanos" => AvroTimestamp::LocalNanos,
+            _ => return None,
+        })
+    }
+
+    /// Returns the `logicalType` attribute value.
+    pub fn logical_type(&self) -> &'static str {
+        match self {
+            AvroTimestamp::Millis => "timestamp-millis",
+            AvroTimestamp::Micros => "timestamp-micros",
+            AvroTimestamp::Nanos => "timestamp-nanos",
+            AvroTimestamp::LocalMillis => "local-timestamp-millis",
+            AvroTimestamp::LocalMicros => "local-timestamp-micros",
+            AvroTimestamp::LocalNanos => "local-timestamp-nanos",
+        }
+    }
+
+    /// Returns `true` for the `local-timestamp-*` types.
+    pub fn is_local(&self) -> bool {
+        matches!(
+            self,
+            AvroTimestamp::LocalMillis | AvroTimestamp::LocalMicros | AvroTimestamp::LocalNanos
+        )
+    }
+
+    fn nanos_per_unit(&self) -> i128 {
+        match self {
+            AvroTimestamp::Millis | AvroTimestamp::LocalMillis => 1_000_000,
+            AvroTimestamp::Micros | AvroTimestamp::LocalMicros => 1_000,
+            AvroTimestamp::Nanos | AvroTimestamp::LocalNanos => 1,
+        }
+    }
+}
+
+impl NanoTime {
+    /// Returns this time as an Avro timestamp `long` of type `ty`,
+    /// truncating toward the past. Returns `None` outside the `i64`
+    /// range, which only the nanosecond types reach, before 1677 and after
+    /// 2262.
+    ///
+    /// ```rust
+    /// use nanotime::{AvroTimestamp, NanoTime};
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+    /// assert_eq!(t.to_avro_timestamp(AvroTimestamp::Micros), Some(1_771_770_605_123_456));
+    ///
+    /// let back = NanoTime::from_avro_timestamp(1_771_770_605_123, AvroTimestamp::Millis).unwrap();
+    /// assert_eq!(back.millisecond(), 123);
+    /// ```
+    pub fn to_avro_timestamp(&self, ty: AvroTimestamp) -> Option<i64> {
+        let nanos = self.unix_nanos();
+        i64::try_from(nanos.div_euclid(ty.nanos_per_unit())).ok()
+    }
+
+    /// Converts an Avro timestamp `long` of type `ty`. Returns `None` if
+    /// the year would fall outside `0..=65535`.
+    pub fn from_avro_timestamp(value: i64, ty: AvroTimestamp) -> Option<Self> {
+        let nanos = value as i128 * ty.nanos_per_unit();
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+        Self::checked_from_unix(secs, nanos.rem_euclid(1_000_000_000) as u32)
+    }
+
+    /// Returns the time of day as an Avro `time-millis` value, truncating
+    /// to whole milliseconds.
+    pub fn to_avro_time_millis(&self) -> i32 {
+        (self.to_avro_time_micros() / 1_000) as i32
+    }
+
+    /// Returns the time of day as an Avro `time-micros` value, truncating
+    /// to whole microseconds.
+    pub fn to_avro_time_micros(&self) -> i64 {
+        let secs = self.hour() as i64 * 3600 + self.minute() as i64 * 60 + self.second() as i64;
+        secs * 1_000_000 + self.microsecond() as i64
+    }
+
+    /// Converts an Avro `time-millis` value to a time on the date of
+    /// `date`. Returns `None` unless it falls within one day.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let day = NanoTime::new(2026, 2, 22, 0, 0, 0, 0).unwrap();
+    /// let t = NanoTime::from_avro_time_millis(52_205_123, &day).unwrap();
+    /// assert_eq!(t, NanoTime::new(2026, 2, 22, 14, 30, 5, 123_000_000).unwrap());
+    /// assert_eq!(t.to_avro_time_millis(), 52_205_123);
+    /// ```
+    pub fn from_avro_time_millis(ms: i32, date: &NanoTime) -> Option<Self> {
+        Self::from_avro_time_micros(ms as i64 * 1_000, date)
+    }
+
+    /// Converts an Avro `time-micros` value to a time on the date of
+    /// `date`. Returns `None` unless it falls within one day.
+    pub fn from_avro_time_micros(us: i64, date: &NanoTime) -> Option<Self> {
+        if !(0..86_400_000_000).contains(&us) {
+            return None;
+        }
+        let secs = us / 1_000_000;
+        NanoTime::new(
+            date.year(),
+            date.month(),
+            date.day(),
+            (secs / 3600) as u8,
+            (secs % 3600 / 60) as u8,
+            (secs % 60) as u8,
+            (us % 1_000_000) as u32 * 1_000,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [AvroTimestamp; 6] = [
+        AvroTimestamp::Millis,
+        AvroTimestamp::Micros,
+        AvroTimestamp::Nanos,
+        AvroTimestamp::LocalMillis,
+        AvroTimestamp::LocalMicros,
+        AvroTimestamp::LocalNanos,
+    ];
+
+    #[test]
+    fn test_logical_type_names_round_trip() {
+        for ty in ALL {
+            assert_eq!(
+                AvroTimestamp::from_logical_type(ty.logical_type()),
+                Some(ty)
+            );
+        }
+        assert!(AvroTimestamp::LocalNanos.is_local());
+        assert!(!AvroTimestamp::Nanos.is_local());
+    }
+
+    #[test]
+    fn test_timestamps_truncate_toward_past() {
+        let before = NanoTime::new(1969, 12, 31, 23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(before.to_avro_timestamp(AvroTimestamp::Millis), Some(-1));
+        assert_eq!(
+            NanoTime::from_avro_timestamp(-1, AvroTimestamp::Micros),
+            NanoTime::new(1969, 12, 31, 23, 59, 59, 999_999_000)
+        );
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+        for ty in ALL {
+            let value = t.to_avro_timestamp(ty).unwrap();
+            let back = NanoTime::from_avro_timestamp(value, ty).unwrap();
+            assert_eq!(back.to_avro_timestamp(ty), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_timestamp_ranges() {
+        let far = NanoTime::new(2300, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(far.to_avro_timestamp(AvroTimestamp::Nanos), None);
+        let last = NanoTime::new(65535, 12, 31, 23, 59, 59, 999_999_000).unwrap();
+        let us = last.to_avro_timestamp(AvroTimestamp::Micros).unwrap();
+        assert_eq!(
+            NanoTime::from_avro_timestamp(us, AvroTimestamp::Micros),
+            Some(last)
+        );
+        assert_eq!(
+            NanoTime::from_avro_timestamp(i64::MAX, AvroTimestamp::Millis),
+            None
+        );
+    }
+
+    #[test]
+    fn test_time_of_day() {
+        let day = NanoTime::new(2026, 2, 22, 9, 0, 0, 0).unwrap();
+        let t = NanoTime::from_avro_time_micros(86_399_999_999, &day).unwrap();
+        assert_eq!(
+            t,
+            NanoTime::new(2026, 2, 22, 23, 59, 59, 999_999_000).unwrap()
+        );
+        assert_eq!(t.to_avro_time_micros(), 86_399_999_999);
+        assert_eq!(NanoTime::from_avro_time_micros(86_400_000_000, &day), None);
+        assert_eq!(NanoTime::from_avro_time_millis(-1, &day), None);
+    }
+}
@@ -0,0 +1,80 @@
+//! Conversions to and from BSON datetimes, as used by the MongoDB driver.
+//! Requires the `bson` feature.
+//!
+//! A BSON datetime is signed milliseconds since the Unix epoch, so
+//! converting a [`NanoTime`] drops everything below the millisecond,
+//! rounding toward the past. Converting back always succeeds for values a
+//! `NanoTime` produced, and fails only for datetimes outside years 0 to
+//! 65535.
+//!
+//! ```rust
+//! use nanotime::NanoTime;
+//!
+//! let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+//! let dt = bson::DateTime::from(t);
+//! assert_eq!(dt.timestamp_millis(), 1_771_770_605_123);
+//! assert_eq!(NanoTime::try_from(dt).unwrap().nanosecond(), 123_000_000);
+//! ```
+
+use std::fmt;
+
+use ::bson::DateTime;
+
+use crate::NanoTime;
+
+/// A BSON datetime outside the years a [`NanoTime`] can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfRange;
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BSON datetime out of range for NanoTime")
+    }
+}
+
+impl std::error::Error for OutOfRange {}
+
+/// Truncates to whole milliseconds, toward the past.
+impl From<NanoTime> for DateTime {
+    fn from(t: NanoTime) -> Self {
+        DateTime::from_millis(t.unix_secs() * 1_000 + t.millisecond() as i64)
+    }
+}
+
+/// The result is UTC.
+impl TryFrom<DateTime> for NanoTime {
+    type Error = OutOfRange;
+
+    fn try_from(dt: DateTime) -> Result<Self, Self::Error> {
+        let ms = dt.timestamp_millis();
+        NanoTime::checked_from_unix(
+            ms.div_euclid(1_000),
+            ms.rem_euclid(1_000) as u32 * 1_000_000,
+        )
+        .ok_or(OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncates_toward_past() {
+        let before = NanoTime::new(1969, 12, 31, 23, 59, 59, 999_999_999).unwrap();
+        let dt = DateTime::from(before);
+        assert_eq!(dt.timestamp_millis(), -1);
+        assert_eq!(
+            NanoTime::try_from(dt),
+            Ok(NanoTime::new(1969, 12, 31, 23, 59, 59, 999_000_000).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(NanoTime::try_from(DateTime::MAX), Err(OutOfRange));
+        assert_eq!(NanoTime::try_from(DateTime::MIN), Err(OutOfRange));
+        let last = NanoTime::new(65535, 12, 31, 23, 59, 59, 999_000_000).unwrap();
+        assert_eq!(NanoTime::try_from(DateTime::from(last)), Ok(last));
+    }
+}
@@ -0,0 +1,249 @@
+//! Per-stage latency allowances.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::checkpoint::human;
+
+/// Time measured for one pipeline stage against its allowance.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StageBudget {
+    name: String,
+    allowance: Option<Duration>,
+    elapsed: Duration,
+}
+
+impl StageBudget {
+    /// Returns the stage name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the configured allowance, or `None` for a stage that was
+    /// recorded without one.
+    pub fn allowance(&self) -> Option<Duration> {
+        self.allowance
+    }
+
+    /// Returns the total time recorded for the stage.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns how far the stage went over its allowance, or `None` if it
+    /// stayed within it or has none.
+    pub fn overrun(&self) -> Option<Duration> {
+        let allowance = self.allowance?;
+        (self.elapsed > allowance).then(|| self.elapsed - allowance)
+    }
+
+    /// Returns `true` if the stage went over its allowance.
+    pub fn is_over(&self) -> bool {
+        self.overrun().is_some()
+    }
+}
+
+/// Tracks the time each pipeline stage takes against a configured
+/// allowance.
+///
+/// Time recorded for a stage accumulates, so a stage that runs several
+/// times is checked on its total. Stages recorded without an allowance are
+/// reported but never flagged.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::Budget;
+///
+/// let mut budget = Budget::new()
+///     .with_stage("decode", Duration::from_millis(5))
+///     .with_stage("query", Duration::from_millis(20));
+///
+/// budget.record("decode", Duration::from_millis(3));
+/// budget.record("query", Duration::from_millis(26));
+///
+/// let report = budget.report();
+/// assert!(!report.is_within_budget());
+/// let over: Vec<&str> = report.over_budget().map(|s| s.name()).collect();
+/// assert_eq!(over, ["query"]);
+/// assert_eq!(report.stages()[1].overrun(), Some(Duration::from_millis(6)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Budget {
+    stages: Vec<StageBudget>,
+}
+
+impl Budget {
+    /// Creates a budget with no stages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a stage with an allowance, or replaces the allowance of an
+    /// existing one.
+    pub fn with_stage(mut self, name: impl Into<String>, allowance: Duration) -> Self {
+        let name = name.into();
+        self.stage_mut(&name).allowance = Some(allowance);
+        self
+    }
+
+    fn stage_mut(&mut self, name: &str) -> &mut StageBudget {
+        let i = match self.stages.iter().position(|s| s.name == name) {
+            Some(i) => i,
+            None => {
+                self.stages.push(StageBudget {
+                    name: name.to_string(),
+                    allowance: None,
+                    elapsed: Duration::ZERO,
+                });
+                self.stages.len() - 1
+            }
+        };
+        &mut self.stages[i]
+    }
+
+    /// Adds `elapsed` to the time recorded for `stage`.
+    pub fn record(&mut self, stage: &str, elapsed: Duration) {
+        let s = self.stage_mut(stage);
+        s.elapsed = s.elapsed.saturating_add(elapsed);
+    }
+
+    /// Runs `f`, records how long it took against `stage`, and returns its
+    /// result.
+    pub fn time<R>(&mut self, stage: &str, f: impl FnOnce() -> R) -> R {
+        let start = Instant::now();
+        let result = f();
+        self.record(stage, start.elapsed());
+        result
+    }
+
+    /// Clears the recorded times, keeping the stages and allowances, so the
+    /// budget can be reused for the next run of the pipeline.
+    pub fn reset(&mut self) {
+        for s in &mut self.stages {
+            s.elapsed = Duration::ZERO;
+        }
+    }
+
+    /// Returns the recorded time of each stage against its allowance.
+    pub fn report(&self) -> BudgetReport {
+        BudgetReport {
+            stages: self.stages.clone(),
+        }
+    }
+}
+
+/// The result of a [`Budget`], one entry per stage in the order they were
+/// added.
+///
+/// Displays as an aligned table that marks overruns:
+///
+/// ```text
+/// decode     3.0ms     5.0ms
+/// query     26.0ms    20.0ms  over by 6.0ms
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BudgetReport {
+    stages: Vec<StageBudget>,
+}
+
+impl BudgetReport {
+    /// Returns every stage.
+    pub fn stages(&self) -> &[StageBudget] {
+        &self.stages
+    }
+
+    /// Returns the stages that went over their allowance.
+    pub fn over_budget(&self) -> impl Iterator<Item = &StageBudget> {
+        self.stages.iter().filter(|s| s.is_over())
+    }
+
+    /// Returns `true` if no stage went over its allowance.
+    pub fn is_within_budget(&self) -> bool {
+        self.over_budget().next().is_none()
+    }
+
+    /// Returns the time recorded across all stages.
+    pub fn total(&self) -> Duration {
+        self.stages.iter().map(|s| s.elapsed).sum()
+    }
+}
+
+impl fmt::Display for BudgetReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .stages
+            .iter()
+            .map(|s| s.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (i, s) in self.stages.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let allowance = s.allowance.map_or_else(|| "-".to_string(), human);
+            write!(
+                f,
+                "{:<width$}  {:>8}  {:>8}",
+                s.name,
+                human(s.elapsed),
+                allowance,
+                width = width
+            )?;
+            if let Some(over) = s.overrun() {
+                write!(f, "  over by {}", human(over))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_accumulates() {
+        let mut budget = Budget::new()
+            .with_stage("a", Duration::from_millis(10))
+            .with_stage("b", Duration::from_millis(10));
+        budget.record("a", Duration::from_millis(6));
+        budget.record("a", Duration::from_millis(6));
+        budget.record("b", Duration::from_millis(10));
+        budget.record("extra", Duration::from_secs(1));
+        let report = budget.report();
+        let names: Vec<&str> = report.stages().iter().map(StageBudget::name).collect();
+        assert_eq!(names, ["a", "b", "extra"]);
+        assert_eq!(report.stages()[0].overrun(), Some(Duration::from_millis(2)));
+        // Exactly on budget and unbudgeted stages are not flagged.
+        assert!(!report.stages()[1].is_over() && !report.stages()[2].is_over());
+        assert_eq!(report.total(), Duration::from_millis(1022));
+
+        budget.reset();
+        assert!(budget.report().is_within_budget());
+        assert_eq!(budget.report().stages().len(), 3);
+    }
+
+    #[test]
+    fn test_time_and_display() {
+        let mut budget = Budget::new().with_stage("sleep", Duration::from_nanos(1));
+        let value = budget.time("sleep", || {
+            std::thread::sleep(Duration::from_millis(1));
+            7
+        });
+        assert_eq!(value, 7);
+        assert!(budget.report().stages()[0].elapsed() >= Duration::from_millis(1));
+
+        let mut budget = Budget::new()
+            .with_stage("decode", Duration::from_millis(5))
+            .with_stage("query", Duration::from_millis(20));
+        budget.record("decode", Duration::from_millis(3));
+        budget.record("query", Duration::from_millis(26));
+        budget.record("log", Duration::from_micros(40));
+        assert_eq!(
+            budget.report().to_string(),
+            "decode     3.0ms     5.0ms\n\
+             query     26.0ms    20.0ms  over by 6.0ms\n\
+             log       40.0µs         -"
+        );
+    }
+}
@@ -0,0 +1,250 @@
+//! Business hours and deadlines that only advance while open.
+
+use std::time::Duration;
+
+use crate::{days_from_civil, NanoDuration, NanoTime, Weekday, NANOS_PER_DAY};
+
+/// Weekly opening hours and holidays, for computing deadlines such as
+/// "respond within 8 business hours".
+///
+/// Each weekday has at most one open window, given as offsets from
+/// midnight. Times are wall-clock times in whatever zone the schedule is
+/// kept in; convert UTC times with [`TimeZone::to_local`] first.
+///
+/// [`TimeZone::to_local`]: crate::tz::TimeZone::to_local
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{BusinessSchedule, NanoTime};
+///
+/// let hours = |h: u64| Duration::from_secs(h * 3600);
+/// let schedule = BusinessSchedule::new()
+///     .with_weekdays(hours(9), hours(17))
+///     .with_holiday(&NanoTime::new(2026, 12, 25, 0, 0, 0, 0).unwrap());
+///
+/// // Thursday Dec 24th is a working day; Friday the 25th is closed.
+/// let t = NanoTime::new(2026, 12, 24, 15, 0, 0, 0).unwrap();
+/// assert!(schedule.is_open(&t));
+/// assert!(!schedule.is_open(&NanoTime::new(2026, 12, 25, 10, 0, 0, 0).unwrap()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BusinessSchedule {
+    /// Open window per weekday, Sunday first, in nanoseconds after midnight.
+    hours: [Option<(i128, i128)>; 7],
+    /// Sorted, deduplicated day numbers since the epoch.
+    holidays: Vec<i64>,
+}
+
+impl BusinessSchedule {
+    /// Creates a schedule that is never open.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens `day` from `open` to `close` after midnight, replacing any
+    /// hours it had.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `open` is before `close` and `close` is at most 24
+    /// hours.
+    pub fn with_hours(mut self, day: Weekday, open: Duration, close: Duration) -> Self {
+        let (open, close) = (open.as_nanos() as i128, close.as_nanos() as i128);
+        assert!(
+            open < close && close <= NANOS_PER_DAY,
+            "business hours must open before they close, within one day"
+        );
+        self.hours[day as usize] = Some((open, close));
+        self
+    }
+
+    /// Opens Monday to Friday from `open` to `close` after midnight.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `open` is before `close` and `close` is at most 24
+    /// hours.
+    pub fn with_weekdays(self, open: Duration, close: Duration) -> Self {
+        [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+        ]
+        .into_iter()
+        .fold(self, |s, day| s.with_hours(day, open, close))
+    }
+
+    /// Closes the whole day holding `date`.
+    pub fn with_holiday(mut self, date: &NanoTime) -> Self {
+        let day = day_number(date);
+        if let Err(i) = self.holidays.binary_search(&day) {
+            self.holidays.insert(i, day);
+        }
+        self
+    }
+
+    /// Returns the open window on day number `day`, in epoch nanoseconds.
+    fn window(&self, day: i64) -> Option<(i128, i128)> {
+        if self.holidays.binary_search(&day).is_ok() {
+            return None;
+        }
+        let weekday = (day + 4).rem_euclid(7) as usize;
+        let (open, close) = self.hours[weekday]?;
+        let midnight = day as i128 * NANOS_PER_DAY;
+        Some((midnight + open, midnight + close))
+    }
+
+    /// Returns `true` if `t` falls within opening hours. Hours include
+    /// the opening time and exclude the closing time.
+    pub fn is_open(&self, t: &NanoTime) -> bool {
+        let now = t.unix_nanos();
+        self.window(day_number(t))
+            .is_some_and(|(open, close)| (open..close).contains(&now))
+    }
+}
+
+fn day_number(t: &NanoTime) -> i64 {
+    days_from_civil(t.year() as i64, t.month(), t.day())
+}
+
+/// Returns the time `d` of open business hours after `start`, skipping
+/// closed hours, weekends, and holidays.
+///
+/// Work started outside hours begins at the next opening. A deadline that
+/// uses up a day's hours exactly falls at that day's closing time rather
+/// than the next opening. A zero duration returns `start`. Returns `None`
+/// for a negative duration, a schedule with no open hours, or a result
+/// past the end of year 65535.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{add_business_duration, BusinessSchedule, NanoDuration, NanoTime};
+///
+/// let hours = |h: u64| Duration::from_secs(h * 3600);
+/// let schedule = BusinessSchedule::new().with_weekdays(hours(9), hours(17));
+///
+/// // Friday 4pm plus 8 business hours is Monday 4pm.
+/// let ticket = NanoTime::new(2026, 2, 27, 16, 0, 0, 0).unwrap();
+/// let sla = NanoDuration::from_nanos(8 * 3_600_000_000_000).unwrap();
+/// let due = add_business_duration(&ticket, sla, &schedule).unwrap();
+/// assert_eq!(due, NanoTime::new(2026, 3, 2, 16, 0, 0, 0).unwrap());
+/// ```
+pub fn add_business_duration(
+    start: &NanoTime,
+    d: NanoDuration,
+    schedule: &BusinessSchedule,
+) -> Option<NanoTime> {
+    if d.is_negative() || schedule.hours.iter().all(Option::is_none) {
+        return None;
+    }
+    let mut remaining = d.as_nanos();
+    if remaining == 0 {
+        return Some(*start);
+    }
+    let begin = start.unix_nanos();
+    let mut day = day_number(start);
+    loop {
+        if let Some((open, close)) = schedule.window(day) {
+            let from = begin.max(open);
+            if from < close {
+                if remaining <= close - from {
+                    return start.checked_add_nanos(from + remaining - begin);
+                }
+                remaining -= close - from;
+            }
+        }
+        day += 1;
+        // Stop once past the latest representable day.
+        NanoTime::checked_from_unix(day * 86_400, 0)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hours(h: u64) -> Duration {
+        Duration::from_secs(h * 3600)
+    }
+
+    fn business_hours(h: i128) -> NanoDuration {
+        NanoDuration::from_nanos(h * 3_600_000_000_000).unwrap()
+    }
+
+    fn at(d: u8, h: u8) -> NanoTime {
+        // February 2026: the 23rd is a Monday.
+        NanoTime::new(2026, 2, d, h, 0, 0, 0).unwrap()
+    }
+
+    fn schedule() -> BusinessSchedule {
+        BusinessSchedule::new().with_weekdays(hours(9), hours(17))
+    }
+
+    #[test]
+    fn test_day_boundaries() {
+        let s = schedule();
+        // Ends exactly at closing time, not the next morning.
+        assert_eq!(
+            add_business_duration(&at(23, 9), business_hours(8), &s),
+            Some(at(23, 17))
+        );
+        // Starting before opening or after closing waits for the next open.
+        assert_eq!(
+            add_business_duration(&at(23, 6), business_hours(1), &s),
+            Some(at(23, 10))
+        );
+        assert_eq!(
+            add_business_duration(&at(23, 20), business_hours(1), &s),
+            Some(at(24, 10))
+        );
+        assert_eq!(
+            add_business_duration(&at(23, 20), NanoDuration::ZERO, &s),
+            Some(at(23, 20))
+        );
+        assert!(s.is_open(&at(23, 9)) && !s.is_open(&at(23, 17)));
+    }
+
+    #[test]
+    fn test_weekends_and_holidays_are_skipped() {
+        let s = schedule()
+            .with_holiday(&at(23, 0))
+            .with_holiday(&at(24, 12));
+        // Friday 4pm: 1 hour Friday, Monday and Tuesday are holidays.
+        assert_eq!(
+            add_business_duration(&at(20, 16), business_hours(3), &s),
+            Some(at(25, 11))
+        );
+        let saturday = BusinessSchedule::new().with_hours(Weekday::Saturday, hours(10), hours(14));
+        assert_eq!(
+            add_business_duration(&at(23, 9), business_hours(5), &saturday),
+            NanoTime::new(2026, 3, 7, 11, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_unusable_inputs() {
+        let negative = NanoDuration::from_nanos(-1).unwrap();
+        assert_eq!(
+            add_business_duration(&at(23, 9), negative, &schedule()),
+            None
+        );
+        let never = BusinessSchedule::new();
+        assert_eq!(
+            add_business_duration(&at(23, 9), business_hours(1), &never),
+            None
+        );
+        let last = NanoTime::new(65535, 12, 31, 16, 0, 0, 0).unwrap();
+        assert_eq!(
+            add_business_duration(&last, business_hours(2), &schedule()),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "business hours must open before they close")]
+    fn test_inverted_hours_panic() {
+        BusinessSchedule::new().with_hours(Weekday::Monday, hours(17), hours(9));
+    }
+}
@@ -0,0 +1,195 @@
+//! Named intermediate marks on an [`Elapsed`] timer.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::Elapsed;
+
+/// Formats a duration in ns, µs, ms, or s, whichever keeps it below 1000.
+pub(crate) fn human(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+/// One labeled mark in a [`CheckpointReport`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Checkpoint {
+    label: String,
+    segment: Duration,
+    cumulative: Duration,
+}
+
+impl Checkpoint {
+    /// Returns the label passed to [`Elapsed::checkpoint`].
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the time since the previous checkpoint, or since the start
+    /// for the first one.
+    pub fn segment(&self) -> Duration {
+        self.segment
+    }
+
+    /// Returns the time since the timer started.
+    pub fn cumulative(&self) -> Duration {
+        self.cumulative
+    }
+}
+
+/// Per-segment and cumulative durations for each checkpoint of an
+/// [`Elapsed`] timer.
+///
+/// Displays as an aligned table, one checkpoint per line:
+///
+/// ```text
+/// load        12.4ms    12.4ms
+/// parse      201.3ms   213.7ms
+/// validate     3.2ms   216.9ms
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CheckpointReport {
+    checkpoints: Vec<Checkpoint>,
+}
+
+impl CheckpointReport {
+    /// Returns the checkpoints in the order they were recorded.
+    pub fn checkpoints(&self) -> &[Checkpoint] {
+        &self.checkpoints
+    }
+
+    /// Returns the time from the start to the last checkpoint.
+    pub fn total(&self) -> Duration {
+        self.checkpoints
+            .last()
+            .map_or(Duration::ZERO, Checkpoint::cumulative)
+    }
+
+    /// Returns the checkpoint with the longest segment, the phase that took
+    /// the most time.
+    pub fn slowest(&self) -> Option<&Checkpoint> {
+        self.checkpoints.iter().max_by_key(|c| c.segment)
+    }
+}
+
+impl fmt::Display for CheckpointReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .checkpoints
+            .iter()
+            .map(|c| c.label.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (i, c) in self.checkpoints.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{:<width$}  {:>8}  {:>8}",
+                c.label,
+                human(c.segment),
+                human(c.cumulative),
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Elapsed {
+    /// Records a named mark at the current instant.
+    ///
+    /// ```rust
+    /// use nanotime::Elapsed;
+    ///
+    /// let mut timer = Elapsed::start();
+    /// // ... load ...
+    /// timer.checkpoint("load");
+    /// // ... parse ...
+    /// timer.checkpoint("parse");
+    ///
+    /// let report = timer.report();
+    /// assert_eq!(report.checkpoints()[1].label(), "parse");
+    /// println!("{}", report);
+    /// ```
+    pub fn checkpoint(&mut self, label: impl Into<String>) {
+        self.checkpoints.push((label.into(), Instant::now()));
+    }
+
+    /// Returns the durations between the start and each checkpoint so far.
+    pub fn report(&self) -> CheckpointReport {
+        let mut previous = self.start;
+        let checkpoints = self
+            .checkpoints
+            .iter()
+            .map(|(label, at)| {
+                let c = Checkpoint {
+                    label: label.clone(),
+                    segment: at.duration_since(previous),
+                    cumulative: at.duration_since(self.start),
+                };
+                previous = *at;
+                c
+            })
+            .collect();
+        CheckpointReport { checkpoints }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkpoint(label: &str, segment_ms: u64, cumulative_ms: u64) -> Checkpoint {
+        Checkpoint {
+            label: label.to_string(),
+            segment: Duration::from_millis(segment_ms),
+            cumulative: Duration::from_millis(cumulative_ms),
+        }
+    }
+
+    #[test]
+    fn test_segments_add_up() {
+        let mut timer = Elapsed::start();
+        assert_eq!(timer.report().total(), Duration::ZERO);
+        for label in ["a", "b", "c"] {
+            std::thread::sleep(Duration::from_millis(2));
+            timer.checkpoint(label);
+        }
+        let report = timer.report();
+        let labels: Vec<&str> = report.checkpoints().iter().map(Checkpoint::label).collect();
+        assert_eq!(labels, ["a", "b", "c"]);
+        let sum: Duration = report.checkpoints().iter().map(Checkpoint::segment).sum();
+        assert_eq!(sum, report.total());
+        assert!(report
+            .checkpoints()
+            .iter()
+            .all(|c| c.segment() >= Duration::from_millis(2)));
+    }
+
+    #[test]
+    fn test_display_table() {
+        let report = CheckpointReport {
+            checkpoints: vec![
+                checkpoint("load", 12, 12),
+                checkpoint("validate", 1500, 1512),
+            ],
+        };
+        assert_eq!(
+            report.to_string(),
+            "load        12.0ms    12.0ms\nvalidate     1.50s     1.51s"
+        );
+        assert_eq!(report.slowest().unwrap().label(), "validate");
+        assert_eq!(human(Duration::from_nanos(999)), "999ns");
+        assert_eq!(human(Duration::from_nanos(1_500)), "1.5µs");
+    }
+}
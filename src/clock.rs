@@ -0,0 +1,182 @@
+//! A common interface over sources of the current time.
+
+use std::sync::Arc;
+
+use crate::{CoarseClock, NanoDuration, NanoTime};
+
+/// A source of the current UTC time.
+///
+/// Code that takes a `Clock` instead of calling [`NanoTime::now_utc`]
+/// directly can be run against virtual, cached, or corrected time without
+/// changes. Combine it with the `_at(now)` methods found throughout the
+/// crate, such as [`Deadline::is_expired_at`](crate::Deadline::is_expired_at):
+///
+/// ```rust
+/// use nanotime::{Clock, Deadline, SystemClock};
+///
+/// fn expired<C: Clock>(clock: &C, deadline: &Deadline) -> bool {
+///     deadline.is_expired_at(clock.now())
+/// }
+///
+/// let deadline = Deadline::new(nanotime::NanoTime::from_epoch(0));
+/// assert!(expired(&SystemClock, &deadline));
+/// ```
+pub trait Clock {
+    /// Returns the current time in UTC.
+    fn now(&self) -> NanoTime;
+}
+
+/// The system's wall clock, read on every call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NanoTime {
+        NanoTime::now_utc()
+    }
+}
+
+impl Clock for CoarseClock {
+    fn now(&self) -> NanoTime {
+        CoarseClock::now(self)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> NanoTime {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> NanoTime {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now(&self) -> NanoTime {
+        (**self).now()
+    }
+}
+
+/// A [`Clock`] corrected by a fixed offset and, optionally, a rate skew.
+///
+/// On a host whose clock is known to be off, reading the local clock and
+/// correcting it is far cheaper than asking a time server on every read.
+/// The offset can come from configuration or from one SNTP exchange via
+/// [`from_sntp`](Self::from_sntp). If the local clock also runs fast or
+/// slow, [`with_skew`](Self::with_skew) corrects the drift that builds up
+/// after the offset was measured.
+///
+/// ```rust
+/// use nanotime::{Clock, NanoDuration, NanoTime, SimClock, SkewedClock};
+///
+/// let local = SimClock::paused(NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap());
+/// let behind = NanoDuration::from_nanos(1_500_000_000).unwrap();
+/// let clock = SkewedClock::new(local, behind);
+/// assert_eq!(clock.now(), NanoTime::new(2026, 2, 22, 14, 30, 6, 500_000_000).unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SkewedClock<C> {
+    inner: C,
+    offset: i128,
+    /// Parts per million the inner clock gains, and the inner time at which
+    /// `offset` was exact.
+    skew: Option<(f64, NanoTime)>,
+}
+
+impl<C: Clock> SkewedClock<C> {
+    /// Wraps `inner`, adding `offset` to every reading.
+    pub fn new(inner: C, offset: NanoDuration) -> Self {
+        SkewedClock {
+            inner,
+            offset: offset.as_nanos(),
+            skew: None,
+        }
+    }
+
+    /// Wraps `inner` with the offset measured by one SNTP exchange.
+    ///
+    /// `originate` and `destination` are the local times the request was
+    /// sent and the response received, read from `inner`; `receive` and
+    /// `transmit` are the server's timestamps from the response. The offset
+    /// is the standard `((receive - originate) + (transmit - destination)) / 2`,
+    /// which assumes the network delay was the same in both directions.
+    pub fn from_sntp(
+        inner: C,
+        originate: NanoTime,
+        receive: NanoTime,
+        transmit: NanoTime,
+        destination: NanoTime,
+    ) -> Self {
+        let offset = (receive.diff_nanos(&originate) + transmit.diff_nanos(&destination)) / 2;
+        SkewedClock {
+            inner,
+            offset,
+            skew: None,
+        }
+    }
+
+    /// Also corrects for the inner clock gaining `ppm` parts per million
+    /// (negative if it loses time), measured from the inner time
+    /// `reference` at which the offset was exact.
+    pub fn with_skew(mut self, ppm: f64, reference: NanoTime) -> Self {
+        self.skew = Some((ppm, reference));
+        self
+    }
+
+    /// Returns the fixed offset added to the inner clock.
+    pub fn offset(&self) -> NanoDuration {
+        NanoDuration::from_nanos(self.offset).unwrap_or_default()
+    }
+
+    /// Returns the wrapped clock.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: Clock> Clock for SkewedClock<C> {
+    fn now(&self) -> NanoTime {
+        let local = self.inner.now();
+        let drift = match self.skew {
+            Some((ppm, reference)) => (local.diff_nanos(&reference) as f64 * ppm / 1e6) as i128,
+            None => 0,
+        };
+        local.add_nanos(self.offset - drift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimClock;
+    use std::time::Duration;
+
+    fn base() -> NanoTime {
+        NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap()
+    }
+
+    #[test]
+    fn test_sntp_offset() {
+        let ms = |n: i128| base().add_nanos(n * 1_000_000);
+        // Sent at 0, server stamped 110 and 112, received at 20: the server
+        // is 101ms ahead, with 9ms of delay each way.
+        let clock = SkewedClock::from_sntp(SystemClock, ms(0), ms(110), ms(112), ms(20));
+        assert_eq!(clock.offset().as_nanos(), 101_000_000);
+        let before = NanoTime::now_utc();
+        assert!(clock.now().diff_nanos(&before) >= 101_000_000);
+    }
+
+    #[test]
+    fn test_skew() {
+        let local = SimClock::paused(base());
+        let clock = SkewedClock::new(&local, NanoDuration::ZERO).with_skew(50.0, base());
+        assert_eq!(clock.now(), base());
+        // Gaining 50ppm, the local clock is 5ms ahead after 100s.
+        local.advance(Duration::from_secs(100));
+        assert_eq!(clock.now(), base().add_nanos(99_995_000_000));
+        assert_eq!(clock.inner().now(), base().add_nanos(100_000_000_000));
+    }
+}
@@ -0,0 +1,114 @@
+//! A cached wall clock that is cheap to read.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::thread;
+use std::time::Duration;
+
+use crate::{system_nanos, NanoTime};
+
+/// A wall clock that is read from a cached value instead of the system.
+///
+/// Reading is a single relaxed atomic load, so code that timestamps every
+/// request or packet avoids a system call each time. The cached value is
+/// only as fresh as its last refresh: either call [`update`](Self::update)
+/// from a loop you already run, or let [`spawn`](Self::spawn) start a
+/// thread that refreshes it at a fixed interval.
+///
+/// Clones share the same cached value. A spawned updater thread exits once
+/// every clone has been dropped.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::CoarseClock;
+///
+/// let clock = CoarseClock::spawn(Duration::from_millis(1));
+/// let t = clock.now(); // within about 1ms of NanoTime::now_utc()
+/// ```
+#[derive(Debug, Clone)]
+pub struct CoarseClock {
+    nanos: Arc<AtomicU64>,
+}
+
+impl CoarseClock {
+    /// Creates a clock holding the current time, refreshed only by
+    /// [`update`](Self::update).
+    pub fn new() -> Self {
+        CoarseClock {
+            nanos: Arc::new(AtomicU64::new(system_nanos())),
+        }
+    }
+
+    /// Creates a clock and a background thread that refreshes it every
+    /// `interval`.
+    pub fn spawn(interval: Duration) -> Self {
+        let clock = Self::new();
+        let weak: Weak<AtomicU64> = Arc::downgrade(&clock.nanos);
+        thread::Builder::new()
+            .name("nanotime-coarse-clock".into())
+            .spawn(move || loop {
+                thread::sleep(interval);
+                match weak.upgrade() {
+                    Some(nanos) => nanos.store(system_nanos(), Ordering::Relaxed),
+                    None => break,
+                }
+            })
+            .expect("failed to spawn coarse clock thread");
+        clock
+    }
+
+    /// Refreshes the cached value from the system clock.
+    pub fn update(&self) {
+        self.nanos.store(system_nanos(), Ordering::Relaxed);
+    }
+
+    /// Returns the cached time in UTC.
+    pub fn now(&self) -> NanoTime {
+        NanoTime::from_epoch_nanos(self.epoch_nanos() as u128)
+    }
+
+    /// Returns the cached time as nanoseconds since the Unix epoch, without
+    /// converting it to a calendar date.
+    pub fn epoch_nanos(&self) -> u64 {
+        self.nanos.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for CoarseClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_refreshes() {
+        let clock = CoarseClock::new();
+        let before = clock.epoch_nanos();
+        thread::sleep(Duration::from_millis(2));
+        assert_eq!(clock.epoch_nanos(), before);
+        clock.update();
+        assert!(clock.epoch_nanos() > before);
+        assert!(clock.now().diff_secs(&NanoTime::now_utc()).abs() <= 1);
+    }
+
+    #[test]
+    fn test_spawned_clock_advances() {
+        let clock = CoarseClock::spawn(Duration::from_millis(1));
+        let before = clock.epoch_nanos();
+        thread::sleep(Duration::from_millis(50));
+        assert!(clock.epoch_nanos() > before);
+    }
+
+    #[test]
+    fn test_clones_share_value() {
+        let a = CoarseClock::new();
+        let b = a.clone();
+        thread::sleep(Duration::from_millis(2));
+        a.update();
+        assert_eq!(a.epoch_nanos(), b.epoch_nanos());
+    }
+}
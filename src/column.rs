@@ -0,0 +1,277 @@
+//! Columnar storage for large sets of timestamps.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::{BulkFormatter, NanoTime};
+
+/// A struct-of-arrays column of timestamps.
+///
+/// Values are stored as signed Unix epoch nanoseconds, 8 bytes each, so
+/// scans such as [`min`](Self::min), [`max`](Self::max), and bucketing
+/// run over a flat `i64` slice. The civil [`NanoTime`] view is decoded
+/// only when first asked for, then cached.
+///
+/// The representable range is about 1677-09-21 to 2262-04-11.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{NanoTime, NanoTimeColumn};
+///
+/// let col = NanoTimeColumn::from_epoch_nanos(vec![
+///     1_771_770_605_000_000_000,
+///     1_771_770_000_000_000_000,
+///     1_771_773_000_000_000_000,
+/// ]);
+/// assert_eq!(col.min().unwrap().datetime(), "2026-02-22 14:20:00.000");
+///
+/// let hourly = col.bucket_counts(Duration::from_secs(3600));
+/// assert_eq!(hourly.len(), 2);
+/// assert_eq!(hourly[0].1, 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NanoTimeColumn {
+    nanos: Vec<i64>,
+    civil: OnceLock<Vec<NanoTime>>,
+}
+
+impl PartialEq for NanoTimeColumn {
+    fn eq(&self, other: &Self) -> bool {
+        self.nanos == other.nanos
+    }
+}
+
+impl Eq for NanoTimeColumn {}
+
+fn to_nanos(t: &NanoTime) -> Option<i64> {
+    i64::try_from(t.unix_nanos()).ok()
+}
+
+fn from_nanos(nanos: i64) -> NanoTime {
+    NanoTime::from_unix_nanos(nanos as i128)
+}
+
+/// Converts a bucket width to nanoseconds, panicking if it is zero.
+fn width_nanos(width: Duration) -> i64 {
+    let nanos = i64::try_from(width.as_nanos()).unwrap_or(i64::MAX);
+    assert!(nanos > 0, "bucket width must be nonzero");
+    nanos
+}
+
+impl NanoTimeColumn {
+    /// Wraps a vector of signed Unix epoch nanoseconds without copying.
+    pub fn from_epoch_nanos(nanos: Vec<i64>) -> Self {
+        NanoTimeColumn {
+            nanos,
+            civil: OnceLock::new(),
+        }
+    }
+
+    /// Builds a column from timestamps, treated as UTC. Returns `None` if
+    /// any falls outside the `i64` nanosecond range.
+    pub fn from_times(times: &[NanoTime]) -> Option<Self> {
+        let nanos = times.iter().map(to_nanos).collect::<Option<Vec<_>>>()?;
+        Some(Self::from_epoch_nanos(nanos))
+    }
+
+    /// Number of values.
+    pub fn len(&self) -> usize {
+        self.nanos.len()
+    }
+
+    /// Returns `true` if the column holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.nanos.is_empty()
+    }
+
+    /// The raw epoch nanoseconds.
+    pub fn epoch_nanos(&self) -> &[i64] {
+        &self.nanos
+    }
+
+    /// Consumes the column, returning the raw epoch nanoseconds.
+    pub fn into_epoch_nanos(self) -> Vec<i64> {
+        self.nanos
+    }
+
+    /// Decodes one value.
+    pub fn get(&self, index: usize) -> Option<NanoTime> {
+        self.nanos.get(index).map(|&n| from_nanos(n))
+    }
+
+    /// All values as civil timestamps, decoded on first call and cached.
+    pub fn civil(&self) -> &[NanoTime] {
+        self.civil
+            .get_or_init(|| self.nanos.iter().map(|&n| from_nanos(n)).collect())
+    }
+
+    /// Appends a timestamp. Returns `false`, leaving the column unchanged,
+    /// if it falls outside the `i64` nanosecond range.
+    pub fn push(&mut self, t: &NanoTime) -> bool {
+        let Some(nanos) = to_nanos(t) else {
+            return false;
+        };
+        self.nanos.push(nanos);
+        if let Some(civil) = self.civil.get_mut() {
+            civil.push(*t);
+        }
+        true
+    }
+
+    /// The earliest value.
+    pub fn min(&self) -> Option<NanoTime> {
+        self.nanos.iter().copied().min().map(from_nanos)
+    }
+
+    /// The latest value.
+    pub fn max(&self) -> Option<NanoTime> {
+        self.nanos.iter().copied().max().map(from_nanos)
+    }
+
+    /// Returns a new column with every value rounded down to a multiple of
+    /// `width` since the Unix epoch, such as the start of its minute or hour.
+    /// A value whose multiple falls before the `i64` range saturates to
+    /// `i64::MIN`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero.
+    pub fn floor(&self, width: Duration) -> NanoTimeColumn {
+        let w = width_nanos(width);
+        Self::from_epoch_nanos(
+            self.nanos
+                .iter()
+                .map(|&n| n.saturating_sub(n.rem_euclid(w)))
+                .collect(),
+        )
+    }
+
+    /// Counts values per bucket of `width`, returning each non-empty
+    /// bucket's start and count in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero.
+    pub fn bucket_counts(&self, width: Duration) -> Vec<(NanoTime, usize)> {
+        let mut floored = self.floor(width).nanos;
+        floored.sort_unstable();
+        let mut counts: Vec<(i64, usize)> = Vec::new();
+        for start in floored {
+            match counts.last_mut() {
+                Some((last, count)) if *last == start => *count += 1,
+                _ => counts.push((start, 1)),
+            }
+        }
+        counts
+            .into_iter()
+            .map(|(start, count)| (from_nanos(start), count))
+            .collect()
+    }
+
+    /// Formats every value through `bulk` into `out`.
+    pub fn format_into(&self, bulk: &BulkFormatter, out: &mut String) {
+        bulk.format_column(self.civil(), out);
+    }
+}
+
+impl From<Vec<i64>> for NanoTimeColumn {
+    fn from(nanos: Vec<i64>) -> Self {
+        Self::from_epoch_nanos(nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Format;
+
+    fn times() -> Vec<NanoTime> {
+        vec![
+            NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap(),
+            NanoTime::new(1969, 7, 20, 20, 17, 40, 0).unwrap(),
+            NanoTime::new(2026, 2, 22, 14, 59, 59, 999_999_999).unwrap(),
+            NanoTime::new(2026, 2, 22, 15, 0, 0, 0).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_round_trip_and_lazy_civil() {
+        let col = NanoTimeColumn::from_times(&times()).unwrap();
+        assert_eq!(col.len(), 4);
+        assert!(col.civil.get().is_none());
+        assert_eq!(col.get(1), Some(times()[1]));
+        assert!(col.civil.get().is_none());
+        assert_eq!(col.civil(), times().as_slice());
+        assert!(col.civil.get().is_some());
+        assert_eq!(col.get(4), None);
+    }
+
+    #[test]
+    fn test_from_times_out_of_range() {
+        let far = NanoTime::new(2300, 1, 1, 0, 0, 0, 0).unwrap();
+        assert!(NanoTimeColumn::from_times(&[far]).is_none());
+        let mut col = NanoTimeColumn::default();
+        assert!(!col.push(&far));
+        assert!(col.is_empty());
+    }
+
+    #[test]
+    fn test_push_keeps_cache_in_sync() {
+        let mut col = NanoTimeColumn::from_times(&times()[..2]).unwrap();
+        let _ = col.civil();
+        assert!(col.push(&times()[2]));
+        assert_eq!(col.civil(), &times()[..3]);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let col = NanoTimeColumn::from_times(&times()).unwrap();
+        assert_eq!(col.min(), Some(times()[1]));
+        assert_eq!(col.max(), Some(times()[3]));
+        assert_eq!(NanoTimeColumn::default().min(), None);
+    }
+
+    #[test]
+    fn test_floor_and_buckets() {
+        let col = NanoTimeColumn::from_times(&times()).unwrap();
+        let hour = Duration::from_secs(3600);
+        let floored = col.floor(hour);
+        assert_eq!(floored.get(0), NanoTime::new(2026, 2, 22, 14, 0, 0, 0));
+        // Pre-epoch values round down, not toward zero.
+        assert_eq!(floored.get(1), NanoTime::new(1969, 7, 20, 20, 0, 0, 0));
+
+        let buckets = col.bucket_counts(hour);
+        assert_eq!(
+            buckets,
+            vec![
+                (NanoTime::new(1969, 7, 20, 20, 0, 0, 0).unwrap(), 1),
+                (NanoTime::new(2026, 2, 22, 14, 0, 0, 0).unwrap(), 2),
+                (NanoTime::new(2026, 2, 22, 15, 0, 0, 0).unwrap(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_floor_saturates_at_range_start() {
+        let col = NanoTimeColumn::from_epoch_nanos(vec![i64::MIN, i64::MIN + 1, i64::MAX]);
+        let floored = col.floor(Duration::from_secs(3600)).into_epoch_nanos();
+        assert_eq!(floored[..2], [i64::MIN, i64::MIN]);
+        assert_eq!(floored[2], i64::MAX - i64::MAX % 3_600_000_000_000);
+        assert_eq!(col.bucket_counts(Duration::from_secs(3600))[0].1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "bucket width must be nonzero")]
+    fn test_zero_width_panics() {
+        NanoTimeColumn::default().floor(Duration::ZERO);
+    }
+
+    #[test]
+    fn test_format_into() {
+        let col = NanoTimeColumn::from_times(&times()[..2]).unwrap();
+        let bulk = BulkFormatter::new(Format::new("%F %T"));
+        let mut out = String::new();
+        col.format_into(&bulk, &mut out);
+        assert_eq!(out, "2026-02-22 14:30:05\n1969-07-20 20:17:40\n");
+    }
+}
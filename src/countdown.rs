@@ -0,0 +1,181 @@
+//! Time-remaining displays such as `02:15:33` and `3d 02:15`.
+
+use std::time::Duration;
+
+use crate::round::round_nanos;
+use crate::{Deadline, NanoTime, RoundMode, NANOS_PER_DAY};
+
+/// The smallest unit a countdown shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CountdownUnit {
+    /// `HH:MM`.
+    Minutes,
+    /// `HH:MM:SS`.
+    Seconds,
+    /// `HH:MM:SS.mmm`.
+    Millis,
+}
+
+impl CountdownUnit {
+    fn nanos(&self) -> u64 {
+        match self {
+            CountdownUnit::Minutes => 60_000_000_000,
+            CountdownUnit::Seconds => 1_000_000_000,
+            CountdownUnit::Millis => 1_000_000,
+        }
+    }
+}
+
+/// Formats time remaining as a clock-style countdown.
+///
+/// By default shows `HH:MM:SS`, splits whole days out as a `3d ` prefix,
+/// and truncates to the displayed unit, so it reads `00:00:00` only in the
+/// last second. Round up with [`RoundMode::Ceil`] instead for a countdown
+/// that reaches zero exactly when time runs out.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{CountdownFormat, CountdownUnit, RoundMode};
+///
+/// let left = Duration::from_secs(3 * 86_400 + 2 * 3600 + 15 * 60 + 33);
+/// assert_eq!(CountdownFormat::new().format(left), "3d 02:15:33");
+///
+/// let fmt = CountdownFormat::new()
+///     .with_unit(CountdownUnit::Minutes)
+///     .with_rounding(RoundMode::Ceil)
+///     .with_days(false);
+/// assert_eq!(fmt.format(left), "74:16");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CountdownFormat {
+    unit: CountdownUnit,
+    rounding: RoundMode,
+    days: bool,
+}
+
+impl Default for CountdownFormat {
+    fn default() -> Self {
+        CountdownFormat {
+            unit: CountdownUnit::Seconds,
+            rounding: RoundMode::Floor,
+            days: true,
+        }
+    }
+}
+
+impl CountdownFormat {
+    /// Creates the default `3d 02:15:33` format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the smallest unit shown.
+    pub fn with_unit(mut self, unit: CountdownUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Sets how the remaining time rounds to the smallest unit shown.
+    pub fn with_rounding(mut self, rounding: RoundMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets whether whole days appear as a `3d ` prefix. Without it, hours
+    /// keep counting past 24.
+    pub fn with_days(mut self, days: bool) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Formats `remaining`.
+    pub fn format(&self, remaining: Duration) -> String {
+        let unit = Duration::from_nanos(self.unit.nanos());
+        let nanos = round_nanos(remaining.as_nanos() as i128, unit, self.rounding);
+        let (days, rest) = if self.days {
+            (nanos / NANOS_PER_DAY, nanos % NANOS_PER_DAY)
+        } else {
+            (0, nanos)
+        };
+        let secs = rest / 1_000_000_000;
+        let mut out = String::with_capacity(16);
+        if days > 0 {
+            out.push_str(&format!("{}d ", days));
+        }
+        out.push_str(&format!("{:02}:{:02}", secs / 3600, secs / 60 % 60));
+        if self.unit != CountdownUnit::Minutes {
+            out.push_str(&format!(":{:02}", secs % 60));
+        }
+        if self.unit == CountdownUnit::Millis {
+            out.push_str(&format!(".{:03}", rest / 1_000_000 % 1_000));
+        }
+        out
+    }
+}
+
+/// Formats `remaining` as `HH:MM:SS`, with a `3d ` prefix once it reaches
+/// a day, truncating to whole seconds. See [`CountdownFormat`] for other
+/// layouts.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::format_countdown;
+///
+/// assert_eq!(format_countdown(Duration::from_secs(8133)), "02:15:33");
+/// assert_eq!(format_countdown(Duration::from_millis(999)), "00:00:00");
+/// ```
+pub fn format_countdown(remaining: Duration) -> String {
+    CountdownFormat::new().format(remaining)
+}
+
+impl Deadline {
+    /// Formats the time left before the deadline with
+    /// [`format_countdown`], showing `00:00:00` once it has passed.
+    pub fn display_countdown(&self) -> String {
+        self.display_countdown_at(NanoTime::now_utc())
+    }
+
+    /// Formats the time left before the deadline as of `now`.
+    pub fn display_countdown_at(&self, now: NanoTime) -> String {
+        format_countdown(self.remaining_at(now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units_and_days() {
+        let left = Duration::from_nanos(90_061_250_000_000); // 1d 01:01:01.25
+        let fmt = CountdownFormat::new();
+        assert_eq!(fmt.format(left), "1d 01:01:01");
+        assert_eq!(
+            fmt.with_unit(CountdownUnit::Millis).format(left),
+            "1d 01:01:01.250"
+        );
+        assert_eq!(
+            fmt.with_unit(CountdownUnit::Minutes).format(left),
+            "1d 01:01"
+        );
+        assert_eq!(fmt.with_days(false).format(left), "25:01:01");
+        assert_eq!(fmt.format(Duration::ZERO), "00:00:00");
+    }
+
+    #[test]
+    fn test_rounding_carries_into_days() {
+        let fmt = CountdownFormat::new().with_rounding(RoundMode::Ceil);
+        assert_eq!(fmt.format(Duration::from_nanos(1)), "00:00:01");
+        let almost_a_day = Duration::from_nanos(86_399_500_000_000);
+        assert_eq!(fmt.format(almost_a_day), "1d 00:00:00");
+        assert_eq!(format_countdown(almost_a_day), "23:59:59");
+    }
+
+    #[test]
+    fn test_deadline_countdown() {
+        let d = Deadline::new(NanoTime::new(2026, 2, 22, 14, 30, 0, 0).unwrap());
+        let now = NanoTime::new(2026, 2, 22, 12, 14, 26, 500_000_000).unwrap();
+        assert_eq!(d.display_countdown_at(now), "02:15:33");
+        assert_eq!(d.display_countdown_at(d.time().add_nanos(1)), "00:00:00");
+    }
+}
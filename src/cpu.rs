@@ -0,0 +1,435 @@
+//! CPU time used by the process and by individual threads.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+#[cfg(unix)]
+mod sys {
+    use std::time::Duration;
+
+    #[repr(C)]
+    struct Timeval {
+        tv_sec: i64,
+        #[cfg(target_vendor = "apple")]
+        tv_usec: i32,
+        #[cfg(not(target_vendor = "apple"))]
+        tv_usec: i64,
+    }
+
+    #[repr(C)]
+    struct Rusage {
+        ru_utime: Timeval,
+        ru_stime: Timeval,
+        _rest: [i64; 14],
+    }
+
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: i64,
+        tv_nsec: i64,
+    }
+
+    const RUSAGE_SELF: i32 = 0;
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    const CLOCK_THREAD_CPUTIME_ID: i32 = 3;
+    #[cfg(target_vendor = "apple")]
+    const CLOCK_THREAD_CPUTIME_ID: i32 = 16;
+    #[cfg(target_os = "freebsd")]
+    const CLOCK_THREAD_CPUTIME_ID: i32 = 14;
+
+    extern "C" {
+        fn getrusage(who: i32, usage: *mut Rusage) -> i32;
+        fn clock_gettime(clk_id: i32, tp: *mut Timespec) -> i32;
+    }
+
+    fn duration(tv: &Timeval) -> Duration {
+        Duration::new(tv.tv_sec.max(0) as u64, 0) + Duration::from_micros(tv.tv_usec.max(0) as u64)
+    }
+
+    /// Returns the user and system CPU time of the process.
+    #[cfg(not(tarpaulin_include))]
+    pub fn process_times() -> (Duration, Duration) {
+        unsafe {
+            let mut usage = std::mem::zeroed::<Rusage>();
+            if getrusage(RUSAGE_SELF, &mut usage) != 0 {
+                return (Duration::ZERO, Duration::ZERO);
+            }
+            (duration(&usage.ru_utime), duration(&usage.ru_stime))
+        }
+    }
+
+    /// Returns the CPU time of the calling thread.
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_vendor = "apple",
+        target_os = "freebsd"
+    ))]
+    #[cfg(not(tarpaulin_include))]
+    pub fn thread_time() -> Duration {
+        unsafe {
+            let mut ts = std::mem::zeroed::<Timespec>();
+            if clock_gettime(CLOCK_THREAD_CPUTIME_ID, &mut ts) != 0 {
+                return Duration::ZERO;
+            }
+            Duration::new(
+                ts.tv_sec.max(0) as u64,
+                ts.tv_nsec.clamp(0, 999_999_999) as u32,
+            )
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_vendor = "apple",
+        target_os = "freebsd"
+    )))]
+    pub fn thread_time() -> Duration {
+        Duration::ZERO
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::ffi::c_void;
+    use std::time::Duration;
+
+    #[repr(C)]
+    struct FILETIME {
+        low: u32,
+        high: u32,
+    }
+
+    extern "system" {
+        fn GetCurrentProcess() -> *mut c_void;
+        fn GetCurrentThread() -> *mut c_void;
+        fn GetProcessTimes(
+            process: *mut c_void,
+            creation: *mut FILETIME,
+            exit: *mut FILETIME,
+            kernel: *mut FILETIME,
+            user: *mut FILETIME,
+        ) -> i32;
+        fn GetThreadTimes(
+            thread: *mut c_void,
+            creation: *mut FILETIME,
+            exit: *mut FILETIME,
+            kernel: *mut FILETIME,
+            user: *mut FILETIME,
+        ) -> i32;
+    }
+
+    /// Converts a FILETIME interval, in 100ns units, to a duration.
+    fn duration(ft: &FILETIME) -> Duration {
+        let ticks = ((ft.high as u64) << 32) | ft.low as u64;
+        Duration::from_nanos(ticks.saturating_mul(100))
+    }
+
+    pub fn process_times() -> (Duration, Duration) {
+        unsafe {
+            let mut creation = std::mem::zeroed::<FILETIME>();
+            let mut exit = std::mem::zeroed::<FILETIME>();
+            let mut kernel = std::mem::zeroed::<FILETIME>();
+            let mut user = std::mem::zeroed::<FILETIME>();
+            let ok = GetProcessTimes(
+                GetCurrentProcess(),
+                &mut creation,
+                &mut exit,
+                &mut kernel,
+                &mut user,
+            );
+            if ok == 0 {
+                return (Duration::ZERO, Duration::ZERO);
+            }
+            (duration(&user), duration(&kernel))
+        }
+    }
+
+    pub fn thread_time() -> Duration {
+        unsafe {
+            let mut creation = std::mem::zeroed::<FILETIME>();
+            let mut exit = std::mem::zeroed::<FILETIME>();
+            let mut kernel = std::mem::zeroed::<FILETIME>();
+            let mut user = std::mem::zeroed::<FILETIME>();
+            let ok = GetThreadTimes(
+                GetCurrentThread(),
+                &mut creation,
+                &mut exit,
+                &mut kernel,
+                &mut user,
+            );
+            if ok == 0 {
+                return Duration::ZERO;
+            }
+            duration(&user) + duration(&kernel)
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod sys {
+    use std::time::Duration;
+
+    pub fn process_times() -> (Duration, Duration) {
+        (Duration::ZERO, Duration::ZERO)
+    }
+
+    pub fn thread_time() -> Duration {
+        Duration::ZERO
+    }
+}
+
+/// Formats like [`Elapsed`](crate::Elapsed): `Xms` under a second,
+/// otherwise `X.XXs`.
+fn short(d: Duration) -> String {
+    if d.as_millis() < 1000 {
+        format!("{}ms", d.as_millis())
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+/// CPU time consumed by the process, split into user and system time.
+///
+/// ```rust
+/// use nanotime::CpuTime;
+///
+/// let used = CpuTime::process();
+/// assert!(used.total() >= used.user());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CpuTime {
+    user: Duration,
+    system: Duration,
+}
+
+impl CpuTime {
+    /// Returns the CPU time used by all threads of the process so far.
+    ///
+    /// Uses `getrusage` on Unix and `GetProcessTimes` on Windows. Returns
+    /// zero if the platform has no such counter.
+    pub fn process() -> Self {
+        let (user, system) = sys::process_times();
+        CpuTime { user, system }
+    }
+
+    /// Returns the time spent running the program's own code.
+    pub fn user(&self) -> Duration {
+        self.user
+    }
+
+    /// Returns the time the kernel spent working on the program's behalf,
+    /// such as in system calls.
+    pub fn system(&self) -> Duration {
+        self.system
+    }
+
+    /// Returns user plus system time.
+    pub fn total(&self) -> Duration {
+        self.user + self.system
+    }
+
+    fn saturating_sub(&self, earlier: &CpuTime) -> CpuTime {
+        CpuTime {
+            user: self.user.saturating_sub(earlier.user),
+            system: self.system.saturating_sub(earlier.system),
+        }
+    }
+}
+
+/// Measures wall-clock time alongside the process CPU time used over the
+/// same span.
+///
+/// Comparing the two shows whether a region was compute-bound (CPU close to
+/// wall, or above it when several threads run) or spent its time waiting on
+/// I/O, locks, or sleeps (CPU well below wall). CPU time counts every
+/// thread of the process, not just the one holding the timer.
+///
+/// ```rust
+/// use nanotime::CpuTimer;
+///
+/// let timer = CpuTimer::start();
+/// // ... do work ...
+/// let usage = timer.elapsed();
+/// println!("{}", usage); // "350ms wall, 120ms user, 15ms sys"
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CpuTimer {
+    wall: Instant,
+    cpu: CpuTime,
+}
+
+impl CpuTimer {
+    /// Captures the current instant and process CPU time.
+    pub fn start() -> Self {
+        CpuTimer {
+            wall: Instant::now(),
+            cpu: CpuTime::process(),
+        }
+    }
+
+    /// Returns the wall-clock and CPU time used since the timer started.
+    pub fn elapsed(&self) -> CpuUsage {
+        CpuUsage {
+            wall: self.wall.elapsed(),
+            cpu: CpuTime::process().saturating_sub(&self.cpu),
+        }
+    }
+}
+
+/// Wall-clock and CPU time measured by a [`CpuTimer`].
+///
+/// Displays as `350ms wall, 120ms user, 15ms sys`, each part formatted
+/// like [`Elapsed`](crate::Elapsed).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CpuUsage {
+    wall: Duration,
+    cpu: CpuTime,
+}
+
+impl CpuUsage {
+    /// Returns the wall-clock time.
+    pub fn wall(&self) -> Duration {
+        self.wall
+    }
+
+    /// Returns the user CPU time.
+    pub fn user(&self) -> Duration {
+        self.cpu.user
+    }
+
+    /// Returns the system CPU time.
+    pub fn system(&self) -> Duration {
+        self.cpu.system
+    }
+
+    /// Returns the user and system CPU time.
+    pub fn cpu(&self) -> CpuTime {
+        self.cpu
+    }
+
+    /// Returns CPU time as a fraction of wall-clock time: near `1.0` for a
+    /// single busy thread, near `0.0` when waiting, and above `1.0` when
+    /// several threads are busy. Returns `0.0` if no wall time has passed.
+    pub fn utilization(&self) -> f64 {
+        if self.wall.is_zero() {
+            return 0.0;
+        }
+        self.cpu.total().as_secs_f64() / self.wall.as_secs_f64()
+    }
+}
+
+impl fmt::Display for CpuUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} wall, {} user, {} sys",
+            short(self.wall),
+            short(self.cpu.user),
+            short(self.cpu.system)
+        )
+    }
+}
+
+/// Measures the CPU time used by the current thread.
+///
+/// Unlike [`Elapsed`](crate::Elapsed), time the thread spends descheduled,
+/// blocked, or sleeping does not count, so the result reflects the cost of
+/// the thread's own work regardless of load on the machine. Uses
+/// `CLOCK_THREAD_CPUTIME_ID` on Linux, macOS, and FreeBSD, and
+/// `GetThreadTimes` on Windows; elsewhere it always reads zero.
+///
+/// The timer is tied to the thread that started it, so it is neither `Send`
+/// nor `Sync`.
+///
+/// ```rust
+/// use nanotime::ThreadCpuTimer;
+///
+/// let timer = ThreadCpuTimer::start();
+/// let sum: u64 = (0..1_000_000u64).sum();
+/// println!("sum {} took {:?} of CPU", sum, timer.elapsed());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThreadCpuTimer {
+    start: Duration,
+    _thread: PhantomData<*const ()>,
+}
+
+impl ThreadCpuTimer {
+    /// Captures the current thread's CPU time.
+    pub fn start() -> Self {
+        ThreadCpuTimer {
+            start: sys::thread_time(),
+            _thread: PhantomData,
+        }
+    }
+
+    /// Returns the CPU time the thread has used since the timer started.
+    pub fn elapsed(&self) -> Duration {
+        sys::thread_time().saturating_sub(self.start)
+    }
+}
+
+impl fmt::Display for ThreadCpuTimer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&short(self.elapsed()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_busy_loop_uses_cpu() {
+        let timer = CpuTimer::start();
+        let mut x = 0u64;
+        while timer.wall.elapsed() < Duration::from_millis(50) {
+            x = std::hint::black_box(x.wrapping_mul(31).wrapping_add(7));
+        }
+        let usage = timer.elapsed();
+        assert!(usage.wall() >= Duration::from_millis(50));
+        assert!(usage.cpu().total() > Duration::ZERO);
+        assert!(usage.user() <= usage.cpu().total());
+    }
+
+    #[test]
+    fn test_process_time_is_monotonic() {
+        let before = CpuTime::process();
+        std::thread::sleep(Duration::from_millis(10));
+        let after = CpuTime::process();
+        assert!(after.user() >= before.user() && after.system() >= before.system());
+    }
+
+    #[test]
+    fn test_thread_cpu_excludes_sleep() {
+        let timer = ThreadCpuTimer::start();
+        std::thread::sleep(Duration::from_millis(50));
+        let slept = timer.elapsed();
+        assert!(slept < Duration::from_millis(25), "{:?}", slept);
+
+        let wall = Instant::now();
+        let mut x = 0u64;
+        while wall.elapsed() < Duration::from_millis(30) {
+            x = std::hint::black_box(x.wrapping_mul(31).wrapping_add(7));
+        }
+        assert!(timer.elapsed() > slept);
+    }
+
+    #[test]
+    fn test_display() {
+        let usage = CpuUsage {
+            wall: Duration::from_millis(350),
+            cpu: CpuTime {
+                user: Duration::from_millis(120),
+                system: Duration::from_millis(1500),
+            },
+        };
+        assert_eq!(usage.to_string(), "350ms wall, 120ms user, 1.50s sys");
+        assert!((usage.utilization() - 1620.0 / 350.0).abs() < 1e-9);
+        assert_eq!(CpuUsage::default().utilization(), 0.0);
+    }
+}
@@ -0,0 +1,215 @@
+//! Absolute points in time to wait for or finish by.
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::{sleep_until, NanoTime};
+
+/// A UTC time by which something should happen.
+///
+/// Unlike a timeout, a deadline is absolute: it can be logged, persisted,
+/// or handed to another thread and still mean the same moment.
+///
+/// Displays as RFC 3339.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::Deadline;
+///
+/// let deadline = Deadline::after(Duration::from_secs(30));
+/// assert!(!deadline.is_expired());
+/// assert!(deadline.remaining() <= Duration::from_secs(30));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Deadline {
+    time: NanoTime,
+}
+
+impl Deadline {
+    /// Creates a deadline at the UTC time `time`.
+    pub fn new(time: NanoTime) -> Self {
+        Deadline { time }
+    }
+
+    /// Creates a deadline `timeout` from now.
+    pub fn after(timeout: Duration) -> Self {
+        Self::new(NanoTime::now_utc().add_nanos(timeout.as_nanos() as i128))
+    }
+
+    /// Returns the UTC time of the deadline.
+    pub fn time(&self) -> NanoTime {
+        self.time
+    }
+
+    /// Returns the time left before the deadline as of `now`, zero once it
+    /// has passed.
+    pub fn remaining_at(&self, now: NanoTime) -> Duration {
+        let nanos = self.time.diff_nanos(&now).max(0);
+        Duration::from_nanos(nanos.min(u64::MAX as i128) as u64)
+    }
+
+    /// Returns the time left before the deadline, zero once it has passed.
+    pub fn remaining(&self) -> Duration {
+        self.remaining_at(NanoTime::now_utc())
+    }
+
+    /// Returns `true` if the deadline has been reached as of `now`.
+    pub fn is_expired_at(&self, now: NanoTime) -> bool {
+        now >= self.time
+    }
+
+    /// Returns `true` if the deadline has been reached.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(NanoTime::now_utc())
+    }
+
+    /// Sleeps until the deadline. See [`sleep_until`].
+    pub fn sleep_until(&self) {
+        sleep_until(self.time)
+    }
+
+    /// Returns an error if the deadline has passed, for use with `?` at
+    /// checkpoints in long-running work.
+    pub fn check(&self) -> Result<(), DeadlineExceeded> {
+        if self.is_expired() {
+            Err(DeadlineExceeded { deadline: *self })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The error returned when work does not finish before its [`Deadline`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeadlineExceeded {
+    deadline: Deadline,
+}
+
+impl DeadlineExceeded {
+    /// Returns the deadline that was missed.
+    pub fn deadline(&self) -> Deadline {
+        self.deadline
+    }
+}
+
+impl fmt::Display for DeadlineExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deadline {} exceeded", self.deadline)
+    }
+}
+
+impl std::error::Error for DeadlineExceeded {}
+
+/// Runs `f` against `deadline` and returns its result only if it finishes
+/// in time.
+///
+/// Sync code cannot be interrupted, so the deadline is cooperative: `f`
+/// receives the deadline and should call [`Deadline::check`] between steps
+/// or size its work by [`Deadline::remaining`]. `f` is not run at all if
+/// the deadline has already passed, and a result produced after the
+/// deadline is replaced by [`DeadlineExceeded`], so `Ok` always means the
+/// work finished in time.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{run_with_deadline, Deadline, DeadlineExceeded};
+///
+/// let deadline = Deadline::after(Duration::from_secs(5));
+/// let sum = run_with_deadline(deadline, |d| -> Result<u64, DeadlineExceeded> {
+///     let mut sum = 0;
+///     for chunk in 0..10 {
+///         d.check()?;
+///         sum += chunk;
+///     }
+///     Ok(sum)
+/// });
+/// assert_eq!(sum, Ok(45));
+/// ```
+pub fn run_with_deadline<T, E, F>(deadline: Deadline, f: F) -> Result<T, E>
+where
+    F: FnOnce(&Deadline) -> Result<T, E>,
+    E: From<DeadlineExceeded>,
+{
+    deadline.check()?;
+    let value = f(&deadline)?;
+    deadline.check()?;
+    Ok(value)
+}
+
+impl From<NanoTime> for Deadline {
+    fn from(time: NanoTime) -> Self {
+        Self::new(time)
+    }
+}
+
+impl fmt::Display for Deadline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.time.to_rfc3339())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_remaining_and_expiry() {
+        let d = Deadline::new(NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap());
+        let before = NanoTime::new(2026, 2, 22, 14, 30, 3, 500_000_000).unwrap();
+        assert_eq!(d.remaining_at(before), Duration::from_millis(1500));
+        assert!(!d.is_expired_at(before));
+        assert!(d.is_expired_at(d.time()));
+        assert_eq!(d.remaining_at(d.time().add_nanos(1)), Duration::ZERO);
+        assert_eq!(d.to_string(), "2026-02-22T14:30:05Z");
+    }
+
+    #[test]
+    fn test_run_with_deadline() {
+        let passed = Deadline::new(NanoTime::from_epoch(0));
+        let err = passed.check().unwrap_err();
+        assert_eq!(err.deadline(), passed);
+        assert_eq!(err.to_string(), "deadline 1970-01-01T00:00:00Z exceeded");
+
+        let mut ran = false;
+        let result: Result<(), DeadlineExceeded> = run_with_deadline(passed, |_| {
+            ran = true;
+            Ok(())
+        });
+        assert!(result.is_err() && !ran);
+
+        // Late results are discarded.
+        let soon = Deadline::after(Duration::from_millis(5));
+        let result = run_with_deadline(soon, |d| {
+            d.sleep_until();
+            Ok::<_, DeadlineExceeded>(1)
+        });
+        assert_eq!(result, Err(DeadlineExceeded { deadline: soon }));
+
+        // Errors from `f` pass through in the caller's error type.
+        #[derive(Debug, PartialEq)]
+        enum JobError {
+            Timeout,
+            Failed,
+        }
+        impl From<DeadlineExceeded> for JobError {
+            fn from(_: DeadlineExceeded) -> Self {
+                JobError::Timeout
+            }
+        }
+        let later = Deadline::after(Duration::from_secs(60));
+        let result: Result<(), JobError> = run_with_deadline(later, |_| Err(JobError::Failed));
+        assert_eq!(result, Err(JobError::Failed));
+        let result: Result<(), JobError> = run_with_deadline(passed, |_| Ok(()));
+        assert_eq!(result, Err(JobError::Timeout));
+    }
+
+    #[test]
+    fn test_sleep_until() {
+        let start = Instant::now();
+        let d = Deadline::after(Duration::from_millis(20));
+        d.sleep_until();
+        assert!(d.is_expired());
+        assert!(start.elapsed() >= Duration::from_millis(15));
+    }
+}
@@ -0,0 +1,236 @@
+//! A report on the clocks and zone data in use, for "wrong time" bugs.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::checkpoint::human;
+use crate::{NanoTime, TimeZone, UtcOffset};
+
+/// Where the host's local zone comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalZoneSource {
+    /// The `TZ` environment variable.
+    TzVariable,
+    /// The `/etc/localtime` file.
+    LocaltimeFile,
+    /// The zone selected in Windows settings.
+    WindowsApi,
+    /// None found, or `TZ` is set but empty; local time is UTC.
+    Unavailable,
+}
+
+impl fmt::Display for LocalZoneSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LocalZoneSource::TzVariable => "TZ environment variable",
+            LocalZoneSource::LocaltimeFile => "/etc/localtime",
+            LocalZoneSource::WindowsApi => "Windows time zone API",
+            LocalZoneSource::Unavailable => "none (UTC)",
+        })
+    }
+}
+
+/// What [`diagnostics`] found about this host.
+///
+/// Displays as one `key: value` line per field, suitable for pasting into
+/// a bug report:
+///
+/// ```text
+/// backend: clock_gettime(CLOCK_REALTIME) + localtime_r
+/// wall clock resolution: 21ns
+/// monotonic resolution: 20ns
+/// local zone: Europe/Berlin (from TZ environment variable)
+/// local offset now: +01:00
+/// bundled tzdata: not compiled in
+/// sub-millisecond local time: yes
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics {
+    backend: &'static str,
+    wall_resolution: Duration,
+    monotonic_resolution: Duration,
+    local_zone: Option<String>,
+    local_zone_source: LocalZoneSource,
+    local_offset: Option<UtcOffset>,
+    tzdata_version: Option<&'static str>,
+    sub_millisecond_local: bool,
+}
+
+impl Diagnostics {
+    /// Returns the system calls behind [`NanoTime::now`] and
+    /// [`NanoTime::now_utc`].
+    pub fn backend(&self) -> &'static str {
+        self.backend
+    }
+
+    /// Returns the smallest step observed in the wall clock, or zero if it
+    /// did not advance while measured.
+    pub fn wall_resolution(&self) -> Duration {
+        self.wall_resolution
+    }
+
+    /// Returns the smallest step observed in the monotonic clock behind
+    /// [`Instant`], or zero if it did not advance while measured.
+    pub fn monotonic_resolution(&self) -> Duration {
+        self.monotonic_resolution
+    }
+
+    /// Returns the IANA name of the local zone, if known.
+    pub fn local_zone(&self) -> Option<&str> {
+        self.local_zone.as_deref()
+    }
+
+    /// Returns where the local zone was read from.
+    pub fn local_zone_source(&self) -> LocalZoneSource {
+        self.local_zone_source
+    }
+
+    /// Returns the local zone's offset from UTC when the report was made,
+    /// or `None` if no local zone could be loaded.
+    pub fn local_offset(&self) -> Option<UtcOffset> {
+        self.local_offset
+    }
+
+    /// Returns the version of the compiled-in tzdata, when built with the
+    /// `bundled-tz` feature.
+    pub fn tzdata_version(&self) -> Option<&'static str> {
+        self.tzdata_version
+    }
+
+    /// Returns `true` if [`NanoTime::now`] carries time below a
+    /// millisecond. On Windows local time is only to the millisecond.
+    pub fn sub_millisecond_local(&self) -> bool {
+        self.sub_millisecond_local
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "backend: {}", self.backend)?;
+        writeln!(f, "wall clock resolution: {}", human(self.wall_resolution))?;
+        writeln!(
+            f,
+            "monotonic resolution: {}",
+            human(self.monotonic_resolution)
+        )?;
+        writeln!(
+            f,
+            "local zone: {} (from {})",
+            self.local_zone.as_deref().unwrap_or("unnamed"),
+            self.local_zone_source
+        )?;
+        match self.local_offset {
+            Some(offset) => writeln!(f, "local offset now: {}", offset)?,
+            None => writeln!(f, "local offset now: unknown")?,
+        }
+        writeln!(
+            f,
+            "bundled tzdata: {}",
+            self.tzdata_version.unwrap_or("not compiled in")
+        )?;
+        let sub_ms = if self.sub_millisecond_local {
+            "yes"
+        } else {
+            "no"
+        };
+        write!(f, "sub-millisecond local time: {}", sub_ms)
+    }
+}
+
+/// Returns the smallest nonzero step between successive readings of
+/// `read`, in nanoseconds, over 100 steps or 50ms, whichever comes first.
+/// Returns zero if the reading never changed.
+fn smallest_step(mut read: impl FnMut() -> i128) -> Duration {
+    let deadline = Instant::now() + Duration::from_millis(50);
+    let (mut best, mut steps) = (i128::MAX, 0);
+    let mut prev = read();
+    while steps < 100 && Instant::now() < deadline {
+        let now = read();
+        if now > prev {
+            best = best.min(now - prev);
+            steps += 1;
+        }
+        prev = now;
+    }
+    if steps == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_nanos(best.min(u64::MAX as i128) as u64)
+}
+
+/// Reports which clocks and zone data this host uses: the platform
+/// backend, measured clock resolutions, the local zone and where it came
+/// from, the bundled tzdata version, and whether local time has
+/// sub-millisecond precision.
+///
+/// Resolutions are measured by sampling each clock for up to 50ms, so
+/// the call can take up to a tenth of a second on coarse clocks.
+///
+/// ```rust
+/// let report = nanotime::diagnostics();
+/// assert!(report.monotonic_resolution() < std::time::Duration::from_millis(50));
+/// eprintln!("{}", report);
+/// ```
+pub fn diagnostics() -> Diagnostics {
+    let backend = if cfg!(windows) {
+        "GetSystemTime + GetLocalTime"
+    } else {
+        "clock_gettime(CLOCK_REALTIME) + localtime_r"
+    };
+    let wall_resolution = smallest_step(|| NanoTime::now_utc().unix_nanos());
+    let origin = Instant::now();
+    let monotonic_resolution = smallest_step(|| origin.elapsed().as_nanos() as i128);
+    let (zone, local_zone_source) = match TimeZone::local_with_source() {
+        Some((zone, source)) => (Some(zone), source),
+        None => (None, LocalZoneSource::Unavailable),
+    };
+    let local_offset = zone.as_ref().map(|z| z.offset_at(&NanoTime::now_utc()));
+    #[cfg(feature = "bundled-tz")]
+    let tzdata_version = Some(crate::tz::TZDATA_VERSION);
+    #[cfg(not(feature = "bundled-tz"))]
+    let tzdata_version = None;
+    Diagnostics {
+        backend,
+        wall_resolution,
+        monotonic_resolution,
+        local_zone: zone.as_ref().and_then(|z| z.name()).map(str::to_string),
+        local_zone_source,
+        local_offset,
+        tzdata_version,
+        sub_millisecond_local: !cfg!(windows),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_lines() {
+        let report = diagnostics();
+        let text = report.to_string();
+        assert_eq!(text.lines().count(), 7);
+        assert!(text.starts_with("backend: "));
+        assert!(report.wall_resolution() < Duration::from_millis(100));
+        assert_eq!(
+            report.tzdata_version().is_some(),
+            cfg!(feature = "bundled-tz")
+        );
+        let local = TimeZone::local();
+        assert_eq!(report.local_offset().is_some(), local.is_some());
+        if report.local_zone_source() == LocalZoneSource::Unavailable {
+            assert!(report.local_offset().is_none_or(|o| o.is_utc()));
+        }
+    }
+
+    #[test]
+    fn test_smallest_step() {
+        let mut n = 0;
+        let step = smallest_step(|| {
+            n += 1;
+            n / 2 * 10
+        });
+        assert_eq!(step, Duration::from_nanos(10));
+        assert_eq!(smallest_step(|| 7), Duration::ZERO);
+    }
+}
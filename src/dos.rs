@@ -0,0 +1,117 @@
+//! MS-DOS date and time, as stored in ZIP entries and FAT directory
+//! records.
+//!
+//! The packed value holds the date in its high 16 bits and the time in
+//! its low 16 bits:
+//!
+//! ```text
+//! bits 31-25  year - 1980 (0-127)     bits 15-11  hour (0-23)
+//! bits 24-21  month (1-12)            bits 10-5   minute (0-59)
+//! bits 20-16  day (1-31)              bits 4-0    second / 2 (0-29)
+//! ```
+//!
+//! The format has no time zone, so both directions are civil time.
+
+use crate::NanoTime;
+
+impl NanoTime {
+    /// Packs this time into the 32-bit MS-DOS date and time format used by
+    /// ZIP and FAT. The date is in the high 16 bits and the time in the
+    /// low 16 bits, matching ZIP's "last mod file date" and "last mod file
+    /// time" fields.
+    ///
+    /// The format has 2-second resolution: odd seconds and any fraction
+    /// are truncated. Returns `None` for years outside 1980–2107.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_000_000).unwrap();
+    /// let dos = t.to_dos_datetime().unwrap();
+    /// let back = NanoTime::from_dos_datetime(dos).unwrap();
+    /// assert_eq!(back.datetime(), "2026-02-22 14:30:04.000");
+    /// ```
+    pub fn to_dos_datetime(&self) -> Option<u32> {
+        if !(1980..=2107).contains(&self.year()) {
+            return None;
+        }
+        let date =
+            (self.year() as u32 - 1980) << 9 | (self.month() as u32) << 5 | self.day() as u32;
+        let time =
+            (self.hour() as u32) << 11 | (self.minute() as u32) << 5 | (self.second() as u32 / 2);
+        Some(date << 16 | time)
+    }
+
+    /// Unpacks a 32-bit MS-DOS date and time. Returns `None` if any field
+    /// is out of range, including the all-zero value some tools write for
+    /// "no date".
+    pub fn from_dos_datetime(dos: u32) -> Option<Self> {
+        let (date, time) = (dos >> 16, dos & 0xFFFF);
+        NanoTime::new(
+            1980 + (date >> 9) as u16,
+            ((date >> 5) & 0x0F) as u8,
+            (date & 0x1F) as u8,
+            (time >> 11) as u8,
+            ((time >> 5) & 0x3F) as u8,
+            (time & 0x1F) as u8 * 2,
+            0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_value() {
+        // Date 46<<9 | 2<<5 | 22, time 14<<11 | 30<<5 | 4/2.
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 4, 0).unwrap();
+        assert_eq!(t.to_dos_datetime(), Some(0x5C56_73C2));
+        assert_eq!(NanoTime::from_dos_datetime(0x5C56_73C2), Some(t));
+    }
+
+    #[test]
+    fn test_range_limits() {
+        let first = NanoTime::new(1980, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(first.to_dos_datetime(), Some(0x0021_0000));
+        let last = NanoTime::new(2107, 12, 31, 23, 59, 58, 0).unwrap();
+        assert_eq!(
+            NanoTime::from_dos_datetime(last.to_dos_datetime().unwrap()),
+            Some(last)
+        );
+        assert_eq!(
+            NanoTime::new(1979, 12, 31, 0, 0, 0, 0)
+                .unwrap()
+                .to_dos_datetime(),
+            None
+        );
+        assert_eq!(
+            NanoTime::new(2108, 1, 1, 0, 0, 0, 0)
+                .unwrap()
+                .to_dos_datetime(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_truncates_to_even_seconds() {
+        let t = NanoTime::new(2000, 6, 15, 12, 0, 59, 999_999_999).unwrap();
+        let back = NanoTime::from_dos_datetime(t.to_dos_datetime().unwrap()).unwrap();
+        assert_eq!(back, NanoTime::new(2000, 6, 15, 12, 0, 58, 0).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_fields() {
+        assert_eq!(NanoTime::from_dos_datetime(0), None);
+        // February 30th.
+        assert_eq!(
+            NanoTime::from_dos_datetime((46 << 9 | 2 << 5 | 30) << 16),
+            None
+        );
+        // Second field 30 decodes to 60.
+        assert_eq!(NanoTime::from_dos_datetime(0x0021_0000 | 30), None);
+        // Hour 24.
+        assert_eq!(NanoTime::from_dos_datetime(0x0021_0000 | 24 << 11), None);
+    }
+}
@@ -0,0 +1,467 @@
+//! Signed durations with nanosecond resolution.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::time::Duration;
+
+use crate::{NanoTime, NANOS_PER_SEC};
+
+/// A signed span of time with nanosecond resolution.
+///
+/// Stored as whole seconds plus a non-negative sub-second part, so
+/// `-1.5s` is `-2` seconds and `500_000_000` nanoseconds. Ordering
+/// follows the signed value.
+///
+/// ```rust
+/// use nanotime::NanoDuration;
+///
+/// let d = NanoDuration::from_nanos(-1_500_000_000).unwrap();
+/// assert_eq!(d.as_nanos(), -1_500_000_000);
+/// assert_eq!(d.secs_floor(), -2);
+/// assert_eq!(d.subsec_nanos(), 500_000_000);
+/// assert_eq!(d.to_string(), "-1.5s");
+///
+/// let shift = NanoDuration::from_hms(8, 30, 0).unwrap();
+/// assert_eq!(shift.as_secs_f64(), 30_600.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NanoDuration {
+    secs: i64,
+    nanos: u32,
+}
+
+impl NanoDuration {
+    /// The zero-length duration.
+    pub const ZERO: NanoDuration = NanoDuration { secs: 0, nanos: 0 };
+
+    /// Builds a duration of whole seconds.
+    pub fn from_secs(secs: i64) -> Self {
+        NanoDuration { secs, nanos: 0 }
+    }
+
+    /// Builds a duration of whole milliseconds.
+    pub fn from_millis(millis: i64) -> Self {
+        NanoDuration {
+            secs: millis.div_euclid(1_000),
+            nanos: millis.rem_euclid(1_000) as u32 * 1_000_000,
+        }
+    }
+
+    /// Builds a duration of whole microseconds.
+    pub fn from_micros(micros: i64) -> Self {
+        NanoDuration {
+            secs: micros.div_euclid(1_000_000),
+            nanos: micros.rem_euclid(1_000_000) as u32 * 1_000,
+        }
+    }
+
+    /// Builds a duration from hours, minutes, and seconds, each of which
+    /// may be negative or exceed its usual range. Returns `None` beyond
+    /// the `i64` seconds range.
+    pub fn from_hms(hours: i64, minutes: i64, seconds: i64) -> Option<Self> {
+        let secs = hours
+            .checked_mul(3_600)?
+            .checked_add(minutes.checked_mul(60)?)?
+            .checked_add(seconds)?;
+        Some(Self::from_secs(secs))
+    }
+
+    /// Builds a duration from a total nanosecond count. Returns `None`
+    /// beyond the `i64` seconds range.
+    pub fn from_nanos(nanos: i128) -> Option<Self> {
+        let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?;
+        Some(NanoDuration {
+            secs,
+            nanos: nanos.rem_euclid(NANOS_PER_SEC) as u32,
+        })
+    }
+
+    /// Total length in nanoseconds.
+    pub fn as_nanos(&self) -> i128 {
+        self.secs as i128 * NANOS_PER_SEC + self.nanos as i128
+    }
+
+    /// Total length in microseconds, truncated toward zero.
+    pub fn as_micros(&self) -> i128 {
+        self.as_nanos() / 1_000
+    }
+
+    /// Total length in milliseconds, truncated toward zero.
+    pub fn as_millis(&self) -> i128 {
+        self.as_nanos() / 1_000_000
+    }
+
+    /// Total length in seconds as a float.
+    pub fn as_secs_f64(&self) -> f64 {
+        self.secs as f64 + self.nanos as f64 / 1e9
+    }
+
+    /// Whole seconds, rounded toward negative infinity.
+    pub fn secs_floor(&self) -> i64 {
+        self.secs
+    }
+
+    /// Nanoseconds past [`secs_floor`](Self::secs_floor), always in
+    /// `0..1_000_000_000`.
+    pub fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Returns `true` if the duration is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.secs < 0
+    }
+}
+
+/// Writes seconds with trailing fractional zeros trimmed: `90s`, `-1.5s`,
+/// `0.000000001s`.
+impl fmt::Display for NanoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.as_nanos();
+        let abs = nanos.unsigned_abs();
+        let sign = if nanos < 0 { "-" } else { "" };
+        let (secs, frac) = (abs / NANOS_PER_SEC as u128, abs % NANOS_PER_SEC as u128);
+        if frac == 0 {
+            return write!(f, "{}{}s", sign, secs);
+        }
+        let digits = format!("{:09}", frac);
+        write!(f, "{}{}.{}s", sign, secs, digits.trim_end_matches('0'))
+    }
+}
+
+impl NanoTime {
+    /// Returns the signed time from `other` to `self`, positive when `self`
+    /// is later.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoDuration, NanoTime};
+    ///
+    /// let a = NanoTime::new(2026, 2, 22, 14, 30, 0, 0).unwrap();
+    /// let b = NanoTime::new(2026, 2, 22, 14, 31, 30, 500_000_000).unwrap();
+    /// assert_eq!(b.diff(&a), NanoDuration::from_millis(90_500));
+    /// assert_eq!(a.diff(&b).to_string(), "-90.5s");
+    /// ```
+    pub fn diff(&self, other: &NanoTime) -> NanoDuration {
+        let nanos = (self.unix_secs() as i128 - other.unix_secs() as i128) * NANOS_PER_SEC
+            + self.nanosecond() as i128
+            - other.nanosecond() as i128;
+        NanoDuration::from_nanos(nanos).expect("years 0-65535 span well under i64 seconds")
+    }
+
+    /// Subtracts a signed number of nanoseconds, returning `None` if the
+    /// year would fall outside `0..=65535`.
+    pub fn checked_sub_nanos(&self, nanos: i128) -> Option<NanoTime> {
+        self.checked_add_nanos(nanos.checked_neg()?)
+    }
+
+    /// Adds a signed number of milliseconds, returning `None` if the year
+    /// would fall outside `0..=65535`.
+    pub fn checked_add_millis(&self, millis: i64) -> Option<NanoTime> {
+        self.checked_add_nanos(millis as i128 * 1_000_000)
+    }
+
+    /// Subtracts a signed number of milliseconds, returning `None` if the
+    /// year would fall outside `0..=65535`.
+    pub fn checked_sub_millis(&self, millis: i64) -> Option<NanoTime> {
+        self.checked_add_nanos(-(millis as i128) * 1_000_000)
+    }
+
+    /// Adds a signed number of seconds, returning `None` if the year would
+    /// fall outside `0..=65535`.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+    /// assert_eq!(t.checked_add_secs(-3_600).unwrap().datetime(), "2026-02-22 13:30:05.000");
+    /// assert_eq!(t.checked_add_secs(i64::MAX), None);
+    /// assert_eq!(t.checked_sub_secs(i64::MIN), None);
+    /// ```
+    pub fn checked_add_secs(&self, secs: i64) -> Option<NanoTime> {
+        self.checked_add_nanos(secs as i128 * NANOS_PER_SEC)
+    }
+
+    /// Subtracts a signed number of seconds, returning `None` if the year
+    /// would fall outside `0..=65535`.
+    pub fn checked_sub_secs(&self, secs: i64) -> Option<NanoTime> {
+        self.checked_add_nanos(-(secs as i128) * NANOS_PER_SEC)
+    }
+
+    /// Adds a duration, returning `None` if the year would fall outside
+    /// `0..=65535`. The non-panicking form of `self + d`.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoDuration, NanoTime};
+    ///
+    /// let t = NanoTime::new(65535, 12, 31, 23, 59, 59, 0).unwrap();
+    /// assert_eq!(t.checked_add(NanoDuration::from_secs(1)), None);
+    /// assert_eq!(t.checked_sub(NanoDuration::from_secs(1)), Some(t - NanoDuration::from_secs(1)));
+    /// ```
+    pub fn checked_add(&self, d: NanoDuration) -> Option<NanoTime> {
+        self.checked_add_nanos(d.as_nanos())
+    }
+
+    /// Subtracts a duration, returning `None` if the year would fall outside
+    /// `0..=65535`. The non-panicking form of `self - d`.
+    pub fn checked_sub(&self, d: NanoDuration) -> Option<NanoTime> {
+        self.checked_add_nanos(-d.as_nanos())
+    }
+}
+
+impl Neg for NanoDuration {
+    type Output = NanoDuration;
+
+    /// # Panics
+    ///
+    /// Panics when negating the most negative duration.
+    fn neg(self) -> NanoDuration {
+        NanoDuration::from_nanos(-self.as_nanos()).expect("overflow when negating duration")
+    }
+}
+
+impl Add for NanoDuration {
+    type Output = NanoDuration;
+
+    /// # Panics
+    ///
+    /// Panics if the sum overflows the `i64` seconds range.
+    fn add(self, rhs: NanoDuration) -> NanoDuration {
+        NanoDuration::from_nanos(self.as_nanos() + rhs.as_nanos())
+            .expect("overflow when adding durations")
+    }
+}
+
+impl Sub for NanoDuration {
+    type Output = NanoDuration;
+
+    /// # Panics
+    ///
+    /// Panics if the difference overflows the `i64` seconds range.
+    fn sub(self, rhs: NanoDuration) -> NanoDuration {
+        NanoDuration::from_nanos(self.as_nanos() - rhs.as_nanos())
+            .expect("overflow when subtracting durations")
+    }
+}
+
+/// Shifts a time by a signed duration.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{NanoDuration, NanoTime};
+///
+/// let now = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+/// let deadline = now + NanoDuration::from_secs(30);
+/// assert_eq!(deadline.datetime(), "2026-02-22 14:30:35.000");
+/// assert_eq!(deadline - now, NanoDuration::from_secs(30));
+/// assert_eq!((now - Duration::from_millis(5_500)).datetime(), "2026-02-22 14:29:59.500");
+/// ```
+///
+/// # Panics
+///
+/// Panics if the result falls outside years 0–65535. Use
+/// [`checked_add`](NanoTime::checked_add) to handle that case.
+impl Add<NanoDuration> for NanoTime {
+    type Output = NanoTime;
+
+    fn add(self, rhs: NanoDuration) -> NanoTime {
+        self.checked_add_nanos(rhs.as_nanos())
+            .expect("overflow when adding duration to NanoTime")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result falls outside years 0–65535.
+impl Sub<NanoDuration> for NanoTime {
+    type Output = NanoTime;
+
+    fn sub(self, rhs: NanoDuration) -> NanoTime {
+        self.checked_add_nanos(-rhs.as_nanos())
+            .expect("overflow when subtracting duration from NanoTime")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result falls outside years 0–65535.
+impl Add<Duration> for NanoTime {
+    type Output = NanoTime;
+
+    fn add(self, rhs: Duration) -> NanoTime {
+        self.checked_add_nanos(rhs.as_nanos() as i128)
+            .expect("overflow when adding duration to NanoTime")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result falls outside years 0–65535.
+impl Sub<Duration> for NanoTime {
+    type Output = NanoTime;
+
+    fn sub(self, rhs: Duration) -> NanoTime {
+        self.checked_add_nanos(-(rhs.as_nanos() as i128))
+            .expect("overflow when subtracting duration from NanoTime")
+    }
+}
+
+impl AddAssign<NanoDuration> for NanoTime {
+    fn add_assign(&mut self, rhs: NanoDuration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<NanoDuration> for NanoTime {
+    fn sub_assign(&mut self, rhs: NanoDuration) {
+        *self = *self - rhs;
+    }
+}
+
+impl AddAssign<Duration> for NanoTime {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Duration> for NanoTime {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+/// The signed time from `rhs` to `self`, the same as
+/// [`diff`](NanoTime::diff).
+impl Sub for NanoTime {
+    type Output = NanoDuration;
+
+    fn sub(self, rhs: NanoTime) -> NanoDuration {
+        self.diff(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_nanos_floors() {
+        let d = NanoDuration::from_nanos(-1).unwrap();
+        assert_eq!((d.secs_floor(), d.subsec_nanos()), (-1, 999_999_999));
+        assert!(d.is_negative());
+        assert!(d < NanoDuration::ZERO);
+        assert!(!NanoDuration::ZERO.is_negative());
+        assert_eq!(NanoDuration::from_nanos(i128::MAX), None);
+    }
+
+    #[test]
+    fn test_as_nanos_round_trip() {
+        for n in [0, 1, -1, 999_999_999, -1_000_000_000, 86_400_123_456_789] {
+            assert_eq!(NanoDuration::from_nanos(n).unwrap().as_nanos(), n);
+        }
+    }
+
+    #[test]
+    fn test_constructors_agree() {
+        let d = NanoDuration::from_hms(1, -30, 5).unwrap();
+        assert_eq!(d, NanoDuration::from_secs(1_805));
+        assert_eq!(NanoDuration::from_millis(-1_500).as_nanos(), -1_500_000_000);
+        assert_eq!(NanoDuration::from_micros(-1).as_nanos(), -1_000);
+        assert_eq!(
+            NanoDuration::from_millis(i64::MIN).as_millis(),
+            i64::MIN as i128
+        );
+        assert_eq!(NanoDuration::from_hms(i64::MAX / 3_600 + 1, 0, 0), None);
+        assert_eq!(NanoDuration::from_millis(-1_999).as_secs_f64(), -1.999);
+        assert_eq!(NanoDuration::from_micros(-1_999).as_millis(), -1);
+    }
+
+    #[test]
+    fn test_display() {
+        let show = |nanos: i128| NanoDuration::from_nanos(nanos).unwrap().to_string();
+        assert_eq!(show(0), "0s");
+        assert_eq!(show(90_000_000_000), "90s");
+        assert_eq!(show(-1), "-0.000000001s");
+        assert_eq!(show(-1_250_000_000), "-1.25s");
+        assert_eq!(
+            NanoDuration::from_secs(i64::MIN).to_string(),
+            "-9223372036854775808s"
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let a = NanoTime::from_epoch(1_000_000_000);
+        let b = NanoTime::new(2026, 2, 22, 14, 30, 5, 999_999_999).unwrap();
+        assert_eq!(b.diff(&a).as_nanos(), b.diff_nanos(&a));
+        assert_eq!(a.diff(&b).as_nanos(), -b.diff_nanos(&a));
+        assert_eq!(a.diff(&a), NanoDuration::ZERO);
+        // Before 1970 and across the whole year range.
+        let first = NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap();
+        let last = NanoTime::new(65535, 12, 31, 23, 59, 59, 0).unwrap();
+        let span = last.diff(&first);
+        assert_eq!(span.secs_floor(), (last.unix_secs() - first.unix_secs()));
+        assert_eq!(
+            first.diff(&last),
+            NanoDuration::from_secs(-span.secs_floor())
+        );
+    }
+
+    #[test]
+    fn test_operators() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 250_000_000).unwrap();
+        let d = NanoDuration::from_millis(-1_500);
+        assert_eq!((t + d).datetime(), "2026-02-22 14:30:03.750");
+        assert_eq!((t - d).datetime(), "2026-02-22 14:30:06.750");
+        assert_eq!(t + d - t, d);
+        assert_eq!(
+            t + Duration::from_secs(86_400) - t,
+            NanoDuration::from_secs(86_400)
+        );
+        let mut u = t;
+        u += d;
+        u -= Duration::from_millis(500);
+        u += Duration::from_secs(2);
+        u -= d;
+        assert_eq!(u, t + NanoDuration::from_millis(1_500));
+        assert_eq!(-d + d, NanoDuration::ZERO);
+        assert_eq!(d - d - d, NanoDuration::from_millis(1_500));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when adding duration to NanoTime")]
+    fn test_add_past_year_range_panics() {
+        let _ = NanoTime::new(65535, 12, 31, 23, 59, 59, 0).unwrap() + NanoDuration::from_secs(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when negating duration")]
+    fn test_neg_min_panics() {
+        let _ = -NanoDuration::from_secs(i64::MIN);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let first = NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap();
+        let last = NanoTime::new(65535, 12, 31, 23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(first.checked_sub_nanos(1), None);
+        assert_eq!(last.checked_add_nanos(1), None);
+        assert_eq!(first.checked_sub_nanos(i128::MIN), None);
+        assert_eq!(last.checked_add_millis(1), None);
+        assert_eq!(first.checked_sub_millis(1), None);
+        assert_eq!(first.checked_add_secs(i64::MIN), None);
+        assert_eq!(last.checked_sub_secs(i64::MAX), None);
+        assert_eq!(
+            first.checked_sub(NanoDuration::from_nanos(1).unwrap()),
+            None
+        );
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+        assert_eq!(
+            t.checked_add_millis(-1_500),
+            Some(t + NanoDuration::from_millis(-1_500))
+        );
+        assert_eq!(t.checked_sub_millis(1_500), t.checked_add_millis(-1_500));
+        assert_eq!(t.checked_sub_secs(-60), t.checked_add_secs(60));
+        assert_eq!(t.checked_sub_nanos(-5), t.checked_add_nanos(5));
+        assert_eq!(last.checked_sub(last.diff(&first)), Some(first));
+    }
+}
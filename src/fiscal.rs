@@ -0,0 +1,297 @@
+//! Fiscal years, quarters, and periods for finance reporting.
+
+use std::ops::Range;
+
+use crate::{civil_from_days, days_from_civil, NanoTime, Weekday};
+
+/// How a 13-week quarter splits into three periods under a week-based
+/// fiscal calendar, by weeks in each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeekPattern {
+    /// 4, 4, then 5 weeks.
+    FourFourFive,
+    /// 4, 5, then 4 weeks.
+    FourFiveFour,
+    /// 5, 4, then 4 weeks.
+    FiveFourFour,
+}
+
+impl WeekPattern {
+    fn weeks(&self) -> [i64; 3] {
+        match self {
+            WeekPattern::FourFourFive => [4, 4, 5],
+            WeekPattern::FourFiveFour => [4, 5, 4],
+            WeekPattern::FiveFourFour => [5, 4, 4],
+        }
+    }
+}
+
+/// A fiscal calendar: which month the year starts in, how it is named,
+/// and whether its periods follow calendar months or whole weeks.
+///
+/// By default each fiscal year starts on the first of `start_month` and
+/// is named for the calendar year it ends in, so with an October start,
+/// FY2027 runs from 2026-10-01 to 2027-09-30, as for the US federal
+/// government. Quarters are three periods, and periods are months.
+///
+/// With [`with_weeks`](Self::with_weeks), the year instead ends on the
+/// last given weekday of the month before `start_month`, as in retail
+/// 4-4-5 calendars. Years are then 52 weeks, or 53 when the weekday
+/// falls late enough, with the extra week in the last period.
+///
+/// ```rust
+/// use nanotime::{FiscalCalendar, NanoTime};
+///
+/// let federal = FiscalCalendar::new(10).unwrap();
+/// let t = NanoTime::new(2026, 11, 15, 0, 0, 0, 0).unwrap();
+/// assert_eq!(federal.fiscal_year(&t), 2027);
+/// assert_eq!(federal.fiscal_quarter(&t), 1);
+///
+/// let q2 = federal.quarter_range(2027, 2).unwrap();
+/// assert_eq!(q2.start, NanoTime::new(2027, 1, 1, 0, 0, 0, 0).unwrap());
+/// assert_eq!(q2.end, NanoTime::new(2027, 4, 1, 0, 0, 0, 0).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    start_month: u8,
+    named_by_start: bool,
+    weeks: Option<(Weekday, WeekPattern)>,
+}
+
+impl FiscalCalendar {
+    /// Creates a month-based calendar whose years start on the first of
+    /// `start_month`. Returns `None` unless it is 1 to 12.
+    pub fn new(start_month: u8) -> Option<Self> {
+        if !(1..=12).contains(&start_month) {
+            return None;
+        }
+        Some(FiscalCalendar {
+            start_month,
+            named_by_start: false,
+            weeks: None,
+        })
+    }
+
+    /// Names each fiscal year for the calendar year it starts in, so that
+    /// with an April start FY2026 runs from April 2026 to March 2027, as
+    /// in India and Japan. Has no effect when years start in January.
+    pub fn with_year_named_by_start(mut self) -> Self {
+        self.named_by_start = true;
+        self
+    }
+
+    /// Switches to whole weeks: each year ends on the last `year_end` of
+    /// the month before the start month, and quarters split into periods
+    /// by `pattern`.
+    ///
+    /// ```rust
+    /// use nanotime::{FiscalCalendar, NanoTime, WeekPattern, Weekday};
+    ///
+    /// // Years end on the last Saturday of January.
+    /// let retail = FiscalCalendar::new(2)
+    ///     .unwrap()
+    ///     .with_weeks(Weekday::Saturday, WeekPattern::FourFourFive);
+    /// let year = retail.year_range(2026).unwrap();
+    /// assert_eq!(year.start, NanoTime::new(2025, 1, 26, 0, 0, 0, 0).unwrap());
+    /// assert_eq!(year.end, NanoTime::new(2026, 2, 1, 0, 0, 0, 0).unwrap());
+    ///
+    /// let t = NanoTime::new(2025, 3, 30, 0, 0, 0, 0).unwrap(); // week 10
+    /// assert_eq!(retail.fiscal_period(&t), 3);
+    /// ```
+    pub fn with_weeks(mut self, year_end: Weekday, pattern: WeekPattern) -> Self {
+        self.weeks = Some((year_end, pattern));
+        self
+    }
+
+    /// Returns the fiscal year holding `t`.
+    pub fn fiscal_year(&self, t: &NanoTime) -> i32 {
+        self.name(self.end_year(day_number(t)))
+    }
+
+    /// Returns the fiscal quarter holding `t`, 1 to 4.
+    pub fn fiscal_quarter(&self, t: &NanoTime) -> u8 {
+        (self.fiscal_period(t) - 1) / 3 + 1
+    }
+
+    /// Returns the fiscal period holding `t`, 1 to 12: the month of the
+    /// fiscal year, or under [`with_weeks`](Self::with_weeks) the 4- or
+    /// 5-week block.
+    pub fn fiscal_period(&self, t: &NanoTime) -> u8 {
+        let day = day_number(t);
+        match self.weeks {
+            None => (t.month() + 12 - self.start_month) % 12 + 1,
+            Some((_, pattern)) => {
+                let begin = self.year_start(self.end_year(day));
+                let week = (day - begin) / 7;
+                let quarter = (week / 13).min(3);
+                let mut left = week - quarter * 13;
+                let mut period = 0;
+                for weeks in pattern.weeks().into_iter().take(2) {
+                    if left < weeks {
+                        break;
+                    }
+                    left -= weeks;
+                    period += 1;
+                }
+                (quarter * 3 + period + 1) as u8
+            }
+        }
+    }
+
+    /// Returns midnight starting fiscal year `year` up to midnight
+    /// starting the next. Returns `None` outside years 0 to 65535.
+    pub fn year_range(&self, year: i32) -> Option<Range<NanoTime>> {
+        let end_year = self.unname(year);
+        let start = self.year_start(end_year);
+        let end = self.year_start(end_year + 1);
+        Some(at_day(start)?..at_day(end)?)
+    }
+
+    /// Returns midnight starting quarter `quarter` of fiscal year `year`
+    /// up to midnight starting the next. Returns `None` unless `quarter`
+    /// is 1 to 4, or outside years 0 to 65535.
+    pub fn quarter_range(&self, year: i32, quarter: u8) -> Option<Range<NanoTime>> {
+        if !(1..=4).contains(&quarter) {
+            return None;
+        }
+        let end_year = self.unname(year);
+        let q = quarter as i64 - 1;
+        let (start, end) = match self.weeks {
+            None => {
+                let base = if self.start_month == 1 {
+                    end_year
+                } else {
+                    end_year - 1
+                };
+                let month = |n: i64| {
+                    let months = self.start_month as i64 - 1 + 3 * n;
+                    days_from_civil(base + months / 12, (months % 12) as u8 + 1, 1)
+                };
+                (month(q), month(q + 1))
+            }
+            Some(_) => {
+                let begin = self.year_start(end_year);
+                let end = if quarter == 4 {
+                    self.year_start(end_year + 1)
+                } else {
+                    begin + (q + 1) * 91
+                };
+                (begin + q * 91, end)
+            }
+        };
+        Some(at_day(start)?..at_day(end)?)
+    }
+
+    /// The calendar year in which the fiscal year holding `day` ends.
+    fn end_year(&self, day: i64) -> i64 {
+        let (year, month, _) = civil_from_days(day);
+        match self.weeks {
+            None if self.start_month > 1 && month >= self.start_month => year + 1,
+            None => year,
+            Some(_) if day >= self.year_start(year + 1) => year + 1,
+            Some(_) if day < self.year_start(year) => year - 1,
+            Some(_) => year,
+        }
+    }
+
+    /// First day of the fiscal year ending in `end_year`.
+    fn year_start(&self, end_year: i64) -> i64 {
+        match self.weeks {
+            None if self.start_month == 1 => days_from_civil(end_year, 1, 1),
+            None => days_from_civil(end_year - 1, self.start_month, 1),
+            Some((weekday, _)) => {
+                // The day after the last `weekday` of the month before.
+                let (year, month) = if self.start_month == 1 {
+                    (end_year, 1)
+                } else {
+                    (end_year - 1, self.start_month)
+                };
+                let last_day = days_from_civil(year, month, 1) - 1;
+                let back = (last_day + 4 - weekday as i64).rem_euclid(7);
+                last_day - back + 1
+            }
+        }
+    }
+
+    fn name(&self, end_year: i64) -> i32 {
+        let by_start = self.named_by_start && self.start_month > 1;
+        (end_year - by_start as i64) as i32
+    }
+
+    fn unname(&self, year: i32) -> i64 {
+        let by_start = self.named_by_start && self.start_month > 1;
+        year as i64 + by_start as i64
+    }
+}
+
+fn day_number(t: &NanoTime) -> i64 {
+    days_from_civil(t.year() as i64, t.month(), t.day())
+}
+
+fn at_day(day: i64) -> Option<NanoTime> {
+    NanoTime::checked_from_unix(day.checked_mul(86_400)?, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: u16, m: u8, d: u8) -> NanoTime {
+        NanoTime::new(y, m, d, 0, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_month_based_naming() {
+        let uk = FiscalCalendar::new(4).unwrap();
+        let t = date(2026, 3, 31);
+        assert_eq!((uk.fiscal_year(&t), uk.fiscal_quarter(&t)), (2026, 4));
+        assert_eq!(uk.fiscal_period(&t), 12);
+        let india = uk.with_year_named_by_start();
+        assert_eq!(india.fiscal_year(&t), 2025);
+        assert_eq!(india.fiscal_year(&date(2026, 4, 1)), 2026);
+        assert_eq!(
+            india.year_range(2026).unwrap(),
+            date(2026, 4, 1)..date(2027, 4, 1)
+        );
+        assert_eq!(
+            india.quarter_range(2026, 4).unwrap(),
+            date(2027, 1, 1)..date(2027, 4, 1)
+        );
+    }
+
+    #[test]
+    fn test_calendar_year() {
+        let cal = FiscalCalendar::new(1).unwrap().with_year_named_by_start();
+        let t = date(2026, 8, 1);
+        assert_eq!((cal.fiscal_year(&t), cal.fiscal_quarter(&t)), (2026, 3));
+        assert_eq!(
+            cal.quarter_range(2026, 3).unwrap(),
+            date(2026, 7, 1)..date(2026, 10, 1)
+        );
+        assert_eq!(cal.quarter_range(2026, 5), None);
+        assert_eq!(FiscalCalendar::new(13), None);
+    }
+
+    #[test]
+    fn test_weeks_53_week_year() {
+        // Years end on the last Saturday of December: 2021-12-25, then
+        // 2022-12-31, making FY2022 a 53-week year.
+        let cal = FiscalCalendar::new(1)
+            .unwrap()
+            .with_weeks(Weekday::Saturday, WeekPattern::FiveFourFour);
+        let fy2022 = cal.year_range(2022).unwrap();
+        assert_eq!(fy2022, date(2021, 12, 26)..date(2023, 1, 1));
+        assert_eq!(fy2022.end.diff_secs(&fy2022.start), 53 * 7 * 86_400);
+        let last = date(2022, 12, 31);
+        assert_eq!(cal.fiscal_year(&last), 2022);
+        assert_eq!(cal.fiscal_period(&last), 12);
+        assert_eq!(cal.fiscal_year(&date(2023, 1, 1)), 2023);
+        assert_eq!(cal.fiscal_year(&date(2021, 12, 26)), 2022);
+        // Period lengths follow the pattern: 5, 4, 4 weeks.
+        assert_eq!(cal.fiscal_period(&date(2022, 1, 29)), 1);
+        assert_eq!(cal.fiscal_period(&date(2022, 1, 30)), 2);
+        let q4 = cal.quarter_range(2022, 4).unwrap();
+        assert_eq!(q4.start, date(2022, 9, 25));
+        assert_eq!(q4.end, fy2022.end);
+    }
+}
@@ -0,0 +1,199 @@
+//! FIX protocol `UTCTimestamp` and `UTCTimeOnly` fields.
+//!
+//! ```text
+//! UTCTimestamp   20260222-14:30:05.123456789
+//! UTCTimeOnly    14:30:05.123
+//! ```
+//!
+//! Both are always UTC. The fraction is optional, and FIX defines it with
+//! 3, 6, or 9 digits; 12-digit picoseconds from FIX 5.0 SP2 are accepted
+//! and truncated.
+
+use std::fmt::Write;
+
+use crate::parse::{civil, ParseError, Scanner};
+use crate::{days_in_month, NanoTime, Precision};
+
+/// Returns the fraction digits to write: for [`Precision::Auto`], the
+/// fewest of 0, 3, 6, or 9 that hold `nanos` exactly.
+fn fix_digits(precision: Precision, nanos: u32) -> u8 {
+    match precision {
+        Precision::Auto => Precision::Auto.digits_for(nanos).div_ceil(3) as u8 * 3,
+        p => p.digits_for(nanos) as u8,
+    }
+}
+
+impl NanoTime {
+    /// Formats as a FIX `UTCTimestamp`: `"20260222-14:30:05.123"`.
+    ///
+    /// Pass [`Precision::Millis`], [`Precision::Micros`], or
+    /// [`Precision::Nanos`] to match what the counterparty expects, or
+    /// [`Precision::Secs`] for none. [`Precision::Auto`] writes the
+    /// shortest of those that loses nothing. Other digit counts are written
+    /// as asked, though FIX does not define them.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, Precision};
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_450_000).unwrap();
+    /// assert_eq!(t.to_fix_timestamp(Precision::Millis), "20260222-14:30:05.123");
+    /// assert_eq!(t.to_fix_timestamp(Precision::Auto), "20260222-14:30:05.123450");
+    /// ```
+    pub fn to_fix_timestamp(&self, precision: Precision) -> String {
+        let mut out = String::with_capacity(27);
+        let _ = write!(
+            out,
+            "{:04}{:02}{:02}-",
+            self.year(),
+            self.month(),
+            self.day()
+        );
+        self.write_fix_time(&mut out, precision);
+        out
+    }
+
+    /// Formats the time of day as a FIX `UTCTimeOnly`: `"14:30:05.123"`.
+    /// `precision` works as in [`to_fix_timestamp`](Self::to_fix_timestamp).
+    pub fn to_fix_time_only(&self, precision: Precision) -> String {
+        let mut out = String::with_capacity(18);
+        self.write_fix_time(&mut out, precision);
+        out
+    }
+
+    fn write_fix_time(&self, out: &mut String, precision: Precision) {
+        let _ = write!(
+            out,
+            "{:02}:{:02}:{:02}",
+            self.hour(),
+            self.minute(),
+            self.second()
+        );
+        let digits = fix_digits(precision, self.nanosecond());
+        Precision::Digits(digits).write_fraction(out, self.nanosecond());
+    }
+
+    /// Parses a FIX `UTCTimestamp` such as `"20260222-14:30:05.123456789"`.
+    ///
+    /// A fraction of any length is accepted; digits beyond nanoseconds are
+    /// truncated. Leap seconds (`:60`) are rejected.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::parse_fix_timestamp("20260222-14:30:05.123456789").unwrap();
+    /// assert_eq!(t, NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap());
+    /// ```
+    pub fn parse_fix_timestamp(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let year = s.number(4, 0, 9999, "year")? as u16;
+        let month = s.number(2, 1, 12, "month")? as u8;
+        let day = s.number(2, 1, days_in_month(year, month) as u32, "day")? as u8;
+        s.literal('-')?;
+        let time = s.time()?;
+        s.finish()?;
+        Ok(civil((year, month, day), time))
+    }
+
+    /// Parses a FIX `UTCTimeOnly` such as `"14:30:05.123"` as a time on the
+    /// UTC date of `date`, typically the message's `UTCDateOnly` field or
+    /// its `SendingTime`.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let sent = NanoTime::new(2026, 2, 22, 14, 30, 6, 0).unwrap();
+    /// let t = NanoTime::parse_fix_time_only("14:30:05.5", &sent).unwrap();
+    /// assert_eq!(t, NanoTime::new(2026, 2, 22, 14, 30, 5, 500_000_000).unwrap());
+    /// ```
+    pub fn parse_fix_time_only(input: &str, date: &NanoTime) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let time = s.time()?;
+        s.finish()?;
+        Ok(civil((date.year(), date.month(), date.day()), time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t(nanos: u32) -> NanoTime {
+        NanoTime::new(2026, 2, 22, 14, 30, 5, nanos).unwrap()
+    }
+
+    #[test]
+    fn test_format_precisions() {
+        let t = t(123_456_789);
+        assert_eq!(t.to_fix_timestamp(Precision::Secs), "20260222-14:30:05");
+        assert_eq!(
+            t.to_fix_timestamp(Precision::Micros),
+            "20260222-14:30:05.123456"
+        );
+        assert_eq!(
+            t.to_fix_timestamp(Precision::Nanos),
+            "20260222-14:30:05.123456789"
+        );
+        assert_eq!(t.to_fix_time_only(Precision::Millis), "14:30:05.123");
+    }
+
+    #[test]
+    fn test_auto_rounds_up_to_fix_widths() {
+        assert_eq!(t(0).to_fix_time_only(Precision::Auto), "14:30:05");
+        assert_eq!(
+            t(500_000_000).to_fix_time_only(Precision::Auto),
+            "14:30:05.500"
+        );
+        assert_eq!(
+            t(1_000).to_fix_time_only(Precision::Auto),
+            "14:30:05.000001"
+        );
+        assert_eq!(
+            t(100).to_fix_time_only(Precision::Auto),
+            "14:30:05.000000100"
+        );
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for precision in [
+            Precision::Secs,
+            Precision::Millis,
+            Precision::Micros,
+            Precision::Nanos,
+        ] {
+            let expected =
+                NanoTime::parse_rfc3339(&t(123_456_789).to_rfc3339_opts(precision, None)).unwrap();
+            let text = t(123_456_789).to_fix_timestamp(precision);
+            assert_eq!(NanoTime::parse_fix_timestamp(&text).unwrap(), expected);
+        }
+        // Picoseconds are truncated.
+        assert_eq!(
+            NanoTime::parse_fix_timestamp("20260222-14:30:05.123456789012").unwrap(),
+            t(123_456_789)
+        );
+    }
+
+    #[test]
+    fn test_time_only_takes_date() {
+        let date = NanoTime::new(2026, 3, 1, 0, 0, 0, 0).unwrap();
+        let parsed = NanoTime::parse_fix_time_only("23:59:59.999", &date).unwrap();
+        assert_eq!(parsed.datetime(), "2026-03-01 23:59:59.999");
+    }
+
+    #[test]
+    fn test_rejections() {
+        let err = |input| NanoTime::parse_fix_timestamp(input).unwrap_err();
+        assert_eq!(err("2026-02-22-14:30:05").expected(), "2-digit month");
+        assert_eq!(err("20260230-14:30:05").expected(), "day 01-28");
+        assert_eq!(err("20260222T14:30:05").expected(), "'-'");
+        assert_eq!(err("20260222-14:30:60").expected(), "second 00-59");
+        assert_eq!(err("20260222-14:30:05Z").offset(), 17);
+        let date = t(0);
+        assert_eq!(
+            NanoTime::parse_fix_time_only("14:30", &date)
+                .unwrap_err()
+                .offset(),
+            5
+        );
+    }
+}
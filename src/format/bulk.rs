@@ -0,0 +1,136 @@
+//! Formatting whole columns of timestamps.
+
+use std::io;
+
+use super::Format;
+use crate::NanoTime;
+
+/// Bytes buffered before flushing to an `io::Write`.
+const CHUNK: usize = 64 * 1024;
+
+/// Writes many timestamps with one compiled [`Format`].
+///
+/// Output space is reserved once from the format's maximum length, and
+/// each value is written in place, instead of allocating a `String` per
+/// row as calling [`NanoTime::datetime`] or [`NanoTime::format`] in a loop
+/// does. Every value is followed by the separator, `"\n"` by default.
+///
+/// ```rust
+/// use nanotime::{BulkFormatter, Format, NanoTime};
+///
+/// let rows = [NanoTime::from_epoch(0), NanoTime::from_epoch(86_400)];
+/// let bulk = BulkFormatter::new(Format::new("%Y-%m-%d"));
+///
+/// let mut out = String::new();
+/// bulk.format_column(&rows, &mut out);
+/// assert_eq!(out, "1970-01-01\n1970-01-02\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct BulkFormatter {
+    format: Format,
+    separator: String,
+}
+
+impl BulkFormatter {
+    /// Creates a formatter that ends each value with a newline.
+    pub fn new(format: Format) -> Self {
+        BulkFormatter {
+            format,
+            separator: "\n".to_string(),
+        }
+    }
+
+    /// Sets the text written after each value.
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Returns the compiled format.
+    pub fn format(&self) -> &Format {
+        &self.format
+    }
+
+    fn row_len(&self) -> usize {
+        self.format.max_len() + self.separator.len()
+    }
+
+    /// Appends every timestamp in `times` to `out`, reserving space once.
+    pub fn format_column(&self, times: &[NanoTime], out: &mut String) {
+        out.reserve(times.len() * self.row_len());
+        for t in times {
+            self.format.write(out, t);
+            out.push_str(&self.separator);
+        }
+    }
+
+    /// Writes every timestamp in `times` to `w` through a single reused
+    /// buffer of about 64 KiB.
+    pub fn write_column<W: io::Write>(&self, times: &[NanoTime], w: &mut W) -> io::Result<()> {
+        let row_len = self.row_len();
+        let mut buf = String::with_capacity(CHUNK.max(row_len) + row_len);
+        for t in times {
+            self.format.write(&mut buf, t);
+            buf.push_str(&self.separator);
+            if buf.len() >= CHUNK {
+                w.write_all(buf.as_bytes())?;
+                buf.clear();
+            }
+        }
+        w.write_all(buf.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(n: u64) -> Vec<NanoTime> {
+        (0..n).map(|i| NanoTime::from_epoch(i * 3_661)).collect()
+    }
+
+    #[test]
+    fn test_format_column_matches_per_row() {
+        let times = rows(100);
+        let fmt = "%Y-%m-%dT%H:%M:%S";
+        let bulk = BulkFormatter::new(Format::new(fmt)).with_separator(",");
+        let mut out = String::new();
+        bulk.format_column(&times, &mut out);
+        let expected: String = times.iter().map(|t| t.format(fmt) + ",").collect();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_format_column_fits_reservation() {
+        let times = rows(1_000);
+        let bulk = BulkFormatter::new(Format::new("%A, %B %e %Y %r (%s)"));
+        let mut out = String::new();
+        bulk.format_column(&times, &mut out);
+        // Output within the up-front reservation never reallocates.
+        assert!(out.len() <= times.len() * bulk.row_len());
+        assert_eq!(out.lines().count(), 1_000);
+    }
+
+    #[test]
+    fn test_write_column_spans_chunks() {
+        let times = rows(10_000);
+        let bulk = BulkFormatter::new(Format::new("%s.%N"));
+        let mut bytes = Vec::new();
+        bulk.write_column(&times, &mut bytes).unwrap();
+        let mut expected = String::new();
+        bulk.format_column(&times, &mut expected);
+        assert!(bytes.len() > CHUNK);
+        assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_empty_column() {
+        let bulk = BulkFormatter::new(Format::new("%F"));
+        let mut out = String::new();
+        bulk.format_column(&[], &mut out);
+        assert!(out.is_empty());
+        let mut bytes = Vec::new();
+        bulk.write_column(&[], &mut bytes).unwrap();
+        assert!(bytes.is_empty());
+    }
+}
@@ -0,0 +1,511 @@
+//! Locale-aware date and time styles using CLDR patterns.
+//!
+//! Each locale carries the CLDR date, time, and date-time patterns for its
+//! styles along with month, weekday, and day-period names. Patterns use the
+//! CLDR field letters `y`, `M`, `d`, `E`, `H`, `h`, `m`, `s`, and `a`, with
+//! quoted literal text.
+
+use std::fmt::Write;
+
+use crate::parse::WEEKDAYS;
+use crate::NanoTime;
+
+/// How much of the date to show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateStyle {
+    /// Weekday and spelled-out month: "Sunday, February 22, 2026".
+    Full,
+    /// Spelled-out month: "February 22, 2026".
+    Long,
+    /// Abbreviated month: "Feb 22, 2026".
+    Medium,
+    /// Numeric: "2/22/26".
+    Short,
+    /// No date.
+    Omit,
+}
+
+/// How much of the time of day to show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeStyle {
+    /// Hours, minutes, and seconds: "2:30:05 PM".
+    Medium,
+    /// Hours and minutes: "2:30 PM".
+    Short,
+    /// No time.
+    Omit,
+}
+
+struct Locale {
+    tag: &'static str,
+    /// Full, long, medium, and short date patterns.
+    date: [&'static str; 4],
+    /// Medium and short time patterns.
+    time: [&'static str; 2],
+    /// Joins `{1}` date and `{0}` time for full/long and medium/short dates.
+    glue: [&'static str; 2],
+    months: [&'static str; 12],
+    months_abbr: [&'static str; 12],
+    weekdays: [&'static str; 7],
+    weekdays_abbr: [&'static str; 7],
+    am_pm: [&'static str; 2],
+}
+
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const EN_WEEKDAYS_ABBR: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Supported locales, sorted by tag. `en` is the fallback.
+const LOCALES: &[Locale] = &[
+    Locale {
+        tag: "de",
+        date: ["EEEE, d. MMMM y", "d. MMMM y", "dd.MM.y", "dd.MM.yy"],
+        time: ["HH:mm:ss", "HH:mm"],
+        glue: ["{1} 'um' {0}", "{1}, {0}"],
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        months_abbr: [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        weekdays: [
+            "Sonntag",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+        ],
+        weekdays_abbr: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+        am_pm: ["AM", "PM"],
+    },
+    Locale {
+        tag: "en",
+        date: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "M/d/yy"],
+        time: ["h:mm:ss a", "h:mm a"],
+        glue: ["{1} 'at' {0}", "{1}, {0}"],
+        months: EN_MONTHS,
+        months_abbr: crate::parse::MONTHS,
+        weekdays: WEEKDAYS,
+        weekdays_abbr: EN_WEEKDAYS_ABBR,
+        am_pm: ["AM", "PM"],
+    },
+    Locale {
+        tag: "en-GB",
+        date: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+        time: ["HH:mm:ss", "HH:mm"],
+        glue: ["{1} 'at' {0}", "{1}, {0}"],
+        months: EN_MONTHS,
+        months_abbr: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec",
+        ],
+        weekdays: WEEKDAYS,
+        weekdays_abbr: EN_WEEKDAYS_ABBR,
+        am_pm: ["am", "pm"],
+    },
+    Locale {
+        tag: "es",
+        date: [
+            "EEEE, d 'de' MMMM 'de' y",
+            "d 'de' MMMM 'de' y",
+            "d MMM y",
+            "d/M/yy",
+        ],
+        time: ["H:mm:ss", "H:mm"],
+        glue: ["{1}, {0}", "{1}, {0}"],
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        months_abbr: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        weekdays: [
+            "domingo",
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+        ],
+        weekdays_abbr: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+        am_pm: ["a. m.", "p. m."],
+    },
+    Locale {
+        tag: "fr",
+        date: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+        time: ["HH:mm:ss", "HH:mm"],
+        glue: ["{1} 'à' {0}", "{1} {0}"],
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        months_abbr: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: [
+            "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+        ],
+        weekdays_abbr: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+        am_pm: ["AM", "PM"],
+    },
+    Locale {
+        tag: "it",
+        date: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/yy"],
+        time: ["HH:mm:ss", "HH:mm"],
+        glue: ["{1} {0}", "{1}, {0}"],
+        months: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        months_abbr: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        weekdays: [
+            "domenica",
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+        ],
+        weekdays_abbr: ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+        am_pm: ["AM", "PM"],
+    },
+    Locale {
+        tag: "ja",
+        date: ["y年M月d日EEEE", "y年M月d日", "y/MM/dd", "y/MM/dd"],
+        time: ["H:mm:ss", "H:mm"],
+        glue: ["{1} {0}", "{1} {0}"],
+        months: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        months_abbr: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: [
+            "日曜日",
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+        ],
+        weekdays_abbr: ["日", "月", "火", "水", "木", "金", "土"],
+        am_pm: ["午前", "午後"],
+    },
+    Locale {
+        tag: "pt",
+        date: [
+            "EEEE, d 'de' MMMM 'de' y",
+            "d 'de' MMMM 'de' y",
+            "d 'de' MMM 'de' y",
+            "dd/MM/y",
+        ],
+        time: ["HH:mm:ss", "HH:mm"],
+        glue: ["{1} {0}", "{1} {0}"],
+        months: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        months_abbr: [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+            "dez.",
+        ],
+        weekdays: [
+            "domingo",
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+        ],
+        weekdays_abbr: ["dom.", "seg.", "ter.", "qua.", "qui.", "sex.", "sáb."],
+        am_pm: ["AM", "PM"],
+    },
+];
+
+/// Finds a locale by BCP 47 tag, falling back to its language, then `en`.
+fn lookup(tag: &str) -> &'static Locale {
+    let tag = tag.replace('_', "-");
+    let find = |t: &str| LOCALES.iter().find(|l| l.tag.eq_ignore_ascii_case(t));
+    find(&tag)
+        .or_else(|| find(tag.split('-').next().unwrap_or("")))
+        .or_else(|| find("en"))
+        .expect("en locale is always present")
+}
+
+impl Locale {
+    /// Expands a CLDR pattern for `t` into `out`.
+    fn expand(&self, out: &mut String, pattern: &str, t: &NanoTime) {
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                if chars.peek() == Some(&'\'') {
+                    chars.next();
+                    out.push('\'');
+                    continue;
+                }
+                while let Some(q) = chars.next() {
+                    if q == '\'' {
+                        if chars.peek() != Some(&'\'') {
+                            break;
+                        }
+                        chars.next();
+                    }
+                    out.push(q);
+                }
+                continue;
+            }
+            if !c.is_ascii_alphabetic() {
+                out.push(c);
+                continue;
+            }
+            let mut width = 1;
+            while chars.peek() == Some(&c) {
+                chars.next();
+                width += 1;
+            }
+            self.field(out, c, width, t);
+        }
+    }
+
+    fn field(&self, out: &mut String, letter: char, width: usize, t: &NanoTime) {
+        let month = t.month() as usize - 1;
+        let weekday = t.weekday_index();
+        let _ = match (letter, width) {
+            ('y', 2) => write!(out, "{:02}", t.year() % 100),
+            ('y', _) => write!(out, "{}", t.year()),
+            ('M' | 'L', 1) => write!(out, "{}", t.month()),
+            ('M' | 'L', 2) => write!(out, "{:02}", t.month()),
+            ('M' | 'L', 3) => write!(out, "{}", self.months_abbr[month]),
+            ('M' | 'L', _) => write!(out, "{}", self.months[month]),
+            ('d', 1) => write!(out, "{}", t.day()),
+            ('d', _) => write!(out, "{:02}", t.day()),
+            ('E', 1..=3) => write!(out, "{}", self.weekdays_abbr[weekday]),
+            ('E', _) => write!(out, "{}", self.weekdays[weekday]),
+            ('H', 1) => write!(out, "{}", t.hour()),
+            ('H', _) => write!(out, "{:02}", t.hour()),
+            ('h', _) => {
+                let h = match t.hour() % 12 {
+                    0 => 12,
+                    h => h,
+                };
+                write!(out, "{:0width$}", h, width = width.min(2))
+            }
+            ('m', 1) => write!(out, "{}", t.minute()),
+            ('m', _) => write!(out, "{:02}", t.minute()),
+            ('s', 1) => write!(out, "{}", t.second()),
+            ('s', _) => write!(out, "{:02}", t.second()),
+            ('a', _) => write!(out, "{}", self.am_pm[(t.hour() >= 12) as usize]),
+            _ => {
+                for _ in 0..width {
+                    out.push(letter);
+                }
+                Ok(())
+            }
+        };
+    }
+}
+
+impl NanoTime {
+    /// Formats with a locale's CLDR date and time patterns, so dates come
+    /// out in that locale's field order, separators, and names.
+    ///
+    /// `locale` is a BCP 47 tag such as `"en-US"`, `"de"`, or `"pt_BR"`.
+    /// Unknown regions fall back to the language, and unknown languages to
+    /// English. Requires the `locale` feature.
+    ///
+    /// ```rust
+    /// use nanotime::{DateStyle, NanoTime, TimeStyle};
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+    /// assert_eq!(
+    ///     t.format_localized(DateStyle::Medium, TimeStyle::Short, "en-US"),
+    ///     "Feb 22, 2026, 2:30 PM"
+    /// );
+    /// assert_eq!(
+    ///     t.format_localized(DateStyle::Medium, TimeStyle::Short, "de-DE"),
+    ///     "22.02.2026, 14:30"
+    /// );
+    /// ```
+    pub fn format_localized(&self, date: DateStyle, time: TimeStyle, locale: &str) -> String {
+        let locale = lookup(locale);
+        let date_pattern = match date {
+            DateStyle::Full => Some(locale.date[0]),
+            DateStyle::Long => Some(locale.date[1]),
+            DateStyle::Medium => Some(locale.date[2]),
+            DateStyle::Short => Some(locale.date[3]),
+            DateStyle::Omit => None,
+        };
+        let time_pattern = match time {
+            TimeStyle::Medium => Some(locale.time[0]),
+            TimeStyle::Short => Some(locale.time[1]),
+            TimeStyle::Omit => None,
+        };
+        let mut out = String::new();
+        match (date_pattern, time_pattern) {
+            (Some(d), Some(t)) => {
+                let glue = match date {
+                    DateStyle::Full | DateStyle::Long => locale.glue[0],
+                    _ => locale.glue[1],
+                };
+                // Substitute the expanded parts, not the raw patterns, so
+                // quoting in the glue stays independent of the pieces.
+                let mut parts = glue.split("{1}");
+                let before = parts.next().unwrap_or("");
+                let rest = parts.next().unwrap_or("");
+                let (between, after) = rest.split_once("{0}").unwrap_or((rest, ""));
+                locale.expand(&mut out, before, self);
+                locale.expand(&mut out, d, self);
+                locale.expand(&mut out, between, self);
+                locale.expand(&mut out, t, self);
+                locale.expand(&mut out, after, self);
+            }
+            (Some(p), None) | (None, Some(p)) => locale.expand(&mut out, p, self),
+            (None, None) => {}
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t() -> NanoTime {
+        NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap()
+    }
+
+    #[test]
+    fn test_locales_sorted_and_complete() {
+        assert!(LOCALES.windows(2).all(|w| w[0].tag < w[1].tag));
+        assert_eq!(lookup("xx").tag, "en");
+    }
+
+    #[test]
+    fn test_lookup_fallback() {
+        assert_eq!(lookup("en-US").tag, "en");
+        assert_eq!(lookup("en_GB").tag, "en-GB");
+        assert_eq!(lookup("EN-gb").tag, "en-GB");
+        assert_eq!(lookup("de-AT").tag, "de");
+        assert_eq!(lookup("pt-BR").tag, "pt");
+        assert_eq!(lookup("").tag, "en");
+    }
+
+    #[test]
+    fn test_en_styles() {
+        let f = |d, tm| t().format_localized(d, tm, "en-US");
+        assert_eq!(
+            f(DateStyle::Full, TimeStyle::Short),
+            "Sunday, February 22, 2026 at 2:30 PM"
+        );
+        assert_eq!(
+            f(DateStyle::Long, TimeStyle::Medium),
+            "February 22, 2026 at 2:30:05 PM"
+        );
+        assert_eq!(f(DateStyle::Medium, TimeStyle::Omit), "Feb 22, 2026");
+        assert_eq!(f(DateStyle::Short, TimeStyle::Short), "2/22/26, 2:30 PM");
+        assert_eq!(f(DateStyle::Omit, TimeStyle::Medium), "2:30:05 PM");
+        assert_eq!(f(DateStyle::Omit, TimeStyle::Omit), "");
+    }
+
+    #[test]
+    fn test_date_order_by_locale() {
+        let short = |loc| t().format_localized(DateStyle::Short, TimeStyle::Omit, loc);
+        assert_eq!(short("en-US"), "2/22/26");
+        assert_eq!(short("en-GB"), "22/02/2026");
+        assert_eq!(short("de"), "22.02.26");
+        assert_eq!(short("fr"), "22/02/2026");
+        assert_eq!(short("ja"), "2026/02/22");
+    }
+
+    #[test]
+    fn test_long_forms_with_literals() {
+        let full = |loc| t().format_localized(DateStyle::Full, TimeStyle::Short, loc);
+        assert_eq!(full("de"), "Sonntag, 22. Februar 2026 um 14:30");
+        assert_eq!(full("fr"), "dimanche 22 février 2026 à 14:30");
+        assert_eq!(full("es"), "domingo, 22 de febrero de 2026, 14:30");
+        assert_eq!(full("ja"), "2026年2月22日日曜日 14:30");
+        assert_eq!(full("en-GB"), "Sunday 22 February 2026 at 14:30");
+    }
+
+    #[test]
+    fn test_expand_quoting_and_padding() {
+        let en = lookup("en");
+        let morning = NanoTime::new(2026, 9, 5, 0, 7, 3, 0).unwrap();
+        let mut out = String::new();
+        en.expand(&mut out, "hh 'o''clock' h:m:s a, EEE MMM dd ''yy", &morning);
+        assert_eq!(out, "12 o'clock 12:7:3 AM, Sat Sep 05 '26");
+    }
+}
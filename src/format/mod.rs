@@ -0,0 +1,391 @@
+//! Formatting timestamps for interchange and display.
+
+use std::fmt::Write;
+
+mod bulk;
+#[cfg(feature = "locale")]
+mod locale;
+mod sql;
+mod strftime;
+
+pub use bulk::BulkFormatter;
+
+#[cfg(feature = "locale")]
+pub use locale::{DateStyle, TimeStyle};
+pub use sql::Dialect;
+#[doc(hidden)]
+pub use strftime::check_spec;
+pub use strftime::Format;
+pub(crate) use strftime::Item;
+
+use crate::parse::WEEKDAYS;
+use crate::{NanoTime, OffsetNanoTime, UtcOffset};
+
+/// English month names, January first.
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// How many fractional-second digits to emit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// As many digits as needed, trimming trailing zeros; none for whole seconds.
+    Auto,
+    /// Whole seconds, no fraction.
+    Secs,
+    /// Three digits.
+    Millis,
+    /// Six digits.
+    Micros,
+    /// Nine digits.
+    Nanos,
+    /// A fixed number of digits, clamped to 0–9.
+    Digits(u8),
+}
+
+impl Precision {
+    /// Returns the number of digits to write for `nanos`.
+    pub(crate) fn digits_for(&self, nanos: u32) -> usize {
+        match *self {
+            Precision::Auto => {
+                if nanos == 0 {
+                    0
+                } else {
+                    9 - trailing_zeros(nanos)
+                }
+            }
+            Precision::Secs => 0,
+            Precision::Millis => 3,
+            Precision::Micros => 6,
+            Precision::Nanos => 9,
+            Precision::Digits(d) => d.min(9) as usize,
+        }
+    }
+
+    /// Appends `.ddd` for `nanos` at this precision, or nothing for zero digits.
+    pub(crate) fn write_fraction(&self, out: &mut String, nanos: u32) {
+        let digits = self.digits_for(nanos);
+        if digits > 0 {
+            let _ = write!(out, ".{:09}", nanos);
+            out.truncate(out.len() - (9 - digits));
+        }
+    }
+}
+
+/// Counts trailing decimal zeros in a nonzero nanosecond value.
+fn trailing_zeros(mut nanos: u32) -> usize {
+    let mut n = 0;
+    while nanos.is_multiple_of(10) {
+        nanos /= 10;
+        n += 1;
+    }
+    n
+}
+
+/// Writes `±HH:MM`, or `Z` for UTC when `zulu` is set.
+pub(crate) fn write_rfc3339_offset(out: &mut String, offset: UtcOffset, zulu: bool) {
+    if zulu && offset.is_utc() {
+        out.push('Z');
+        return;
+    }
+    let secs = offset.as_secs();
+    let sign = if secs < 0 { '-' } else { '+' };
+    let abs = secs.unsigned_abs();
+    let _ = write!(out, "{}{:02}:{:02}", sign, abs / 3600, (abs % 3600) / 60);
+}
+
+/// Writes `YYYY-MM-DDTHH:MM:SS[.f]` with no offset.
+pub(crate) fn write_rfc3339_local(out: &mut String, t: &NanoTime, precision: Precision) {
+    let _ = write!(
+        out,
+        "{}T{:02}:{:02}:{:02}",
+        t.date(),
+        t.hour(),
+        t.minute(),
+        t.second()
+    );
+    precision.write_fraction(out, t.nanosecond());
+}
+
+impl NanoTime {
+    /// Formats this UTC time as RFC 3339 with a `Z` suffix, trimming
+    /// trailing fractional zeros: `"2026-02-22T14:30:05.12Z"`.
+    pub fn to_rfc3339(&self) -> String {
+        self.to_rfc3339_opts(Precision::Auto, None)
+    }
+
+    /// Formats this UTC time as RFC 3339.
+    ///
+    /// With `offset: None` the output ends in `Z`. With an offset, the time
+    /// is shifted to that offset and suffixed with it, e.g. `+05:30`.
+    /// Offsets with a seconds part are rounded toward zero to whole minutes,
+    /// since RFC 3339 cannot express them.
+    ///
+    /// RFC 3339 years have four digits. Years past 9999 are still written
+    /// in full (`"10000-01-01T00:00:00Z"`), but that is not RFC 3339, and
+    /// [`parse_rfc3339`](Self::parse_rfc3339) rejects it.
+    ///
+    /// If the offset shifts the local time outside years 0000-65535, it is
+    /// clamped to `0000-01-01T00:00:00` or
+    /// `65535-12-31T23:59:59.999999999`, so the output no longer names the
+    /// same instant.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, Precision, UtcOffset};
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 9, 0, 5, 123_400_000).unwrap();
+    /// assert_eq!(t.to_rfc3339_opts(Precision::Auto, None), "2026-02-22T09:00:05.1234Z");
+    ///
+    /// let ist = UtcOffset::from_minutes(330);
+    /// assert_eq!(
+    ///     t.to_rfc3339_opts(Precision::Millis, ist),
+    ///     "2026-02-22T14:30:05.123+05:30"
+    /// );
+    /// ```
+    pub fn to_rfc3339_opts(&self, precision: Precision, offset: Option<UtcOffset>) -> String {
+        match offset {
+            None => {
+                let mut out = String::with_capacity(30);
+                write_rfc3339_local(&mut out, self, precision);
+                out.push('Z');
+                out
+            }
+            Some(offset) => OffsetNanoTime::from_utc(self, offset).to_rfc3339_opts(precision),
+        }
+    }
+}
+
+/// Returns the English ordinal suffix for a day of the month.
+fn ordinal_suffix(n: u8) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+impl NanoTime {
+    /// Formats as long-form English for human-facing text, with a 12-hour
+    /// clock: `"Sunday, February 22nd, 2026 at 2:30 PM"`.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+    /// assert_eq!(t.format_long(), "Sunday, February 22nd, 2026 at 2:30 PM");
+    /// ```
+    pub fn format_long(&self) -> String {
+        let hour12 = match self.hour() % 12 {
+            0 => 12,
+            h => h,
+        };
+        format!(
+            "{}, {} {}{}, {} at {}:{:02} {}",
+            WEEKDAYS[self.weekday_index()],
+            MONTH_NAMES[self.month() as usize - 1],
+            self.day(),
+            ordinal_suffix(self.day()),
+            self.year(),
+            hour12,
+            self.minute(),
+            if self.hour() < 12 { "AM" } else { "PM" }
+        )
+    }
+}
+
+impl OffsetNanoTime {
+    /// Formats as RFC 3339 with the offset written numerically, even for
+    /// UTC (`+00:00`), so the value round-trips unchanged through
+    /// [`OffsetNanoTime::parse_rfc3339`] at full precision.
+    ///
+    /// The exception is an offset with a seconds part, such as a zone's
+    /// local mean time. RFC 3339 cannot express it, so it is rounded toward
+    /// zero to whole minutes and the local time shifted to match: the
+    /// instant is kept, but the parsed value has the rounded offset.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, OffsetNanoTime, Precision, UtcOffset};
+    ///
+    /// let utc = NanoTime::new(1880, 1, 1, 12, 0, 0, 0).unwrap();
+    /// let lmt = OffsetNanoTime::from_utc(&utc, UtcOffset::from_secs(3605).unwrap());
+    /// let s = lmt.to_rfc3339_opts(Precision::Secs);
+    /// assert_eq!(s, "1880-01-01T13:00:00+01:00");
+    /// assert!(OffsetNanoTime::parse_rfc3339(&s).unwrap().same_instant(&lmt));
+    /// ```
+    pub fn to_rfc3339_opts(&self, precision: Precision) -> String {
+        let t = self.with_whole_minute_offset();
+        let mut out = String::with_capacity(35);
+        write_rfc3339_local(&mut out, &t.local(), precision);
+        write_rfc3339_offset(&mut out, t.offset(), false);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t(nanos: u32) -> NanoTime {
+        NanoTime::new(2026, 2, 22, 14, 30, 5, nanos).unwrap()
+    }
+
+    #[test]
+    fn test_to_rfc3339_auto_trims() {
+        assert_eq!(t(0).to_rfc3339(), "2026-02-22T14:30:05Z");
+        assert_eq!(t(120_000_000).to_rfc3339(), "2026-02-22T14:30:05.12Z");
+        assert_eq!(
+            t(123_456_789).to_rfc3339(),
+            "2026-02-22T14:30:05.123456789Z"
+        );
+        assert_eq!(t(1).to_rfc3339(), "2026-02-22T14:30:05.000000001Z");
+    }
+
+    #[test]
+    fn test_to_rfc3339_fixed_precisions() {
+        let nt = t(123_456_789);
+        assert_eq!(
+            nt.to_rfc3339_opts(Precision::Secs, None),
+            "2026-02-22T14:30:05Z"
+        );
+        assert_eq!(
+            nt.to_rfc3339_opts(Precision::Millis, None),
+            "2026-02-22T14:30:05.123Z"
+        );
+        assert_eq!(
+            nt.to_rfc3339_opts(Precision::Micros, None),
+            "2026-02-22T14:30:05.123456Z"
+        );
+        assert_eq!(
+            t(0).to_rfc3339_opts(Precision::Nanos, None),
+            "2026-02-22T14:30:05.000000000Z"
+        );
+        assert_eq!(
+            nt.to_rfc3339_opts(Precision::Digits(2), None),
+            "2026-02-22T14:30:05.12Z"
+        );
+        assert_eq!(
+            nt.to_rfc3339_opts(Precision::Digits(20), None),
+            nt.to_rfc3339_opts(Precision::Nanos, None)
+        );
+    }
+
+    #[test]
+    fn test_to_rfc3339_with_offset() {
+        let nt = t(0);
+        let minus = UtcOffset::from_minutes(-210);
+        assert_eq!(
+            nt.to_rfc3339_opts(Precision::Secs, minus),
+            "2026-02-22T11:00:05-03:30"
+        );
+        assert_eq!(
+            nt.to_rfc3339_opts(Precision::Secs, Some(UtcOffset::UTC)),
+            "2026-02-22T14:30:05+00:00"
+        );
+        // Seconds in an offset are dropped before shifting.
+        let lmt = UtcOffset::from_secs(-17_762);
+        assert_eq!(
+            nt.to_rfc3339_opts(Precision::Secs, lmt),
+            "2026-02-22T09:34:05-04:56"
+        );
+        let local = OffsetNanoTime::from_utc(&nt, lmt.unwrap());
+        assert_eq!(
+            local.to_rfc3339_opts(Precision::Secs),
+            "2026-02-22T09:34:05-04:56"
+        );
+        let east = OffsetNanoTime::from_utc(&nt, UtcOffset::from_secs(3605).unwrap());
+        let back = OffsetNanoTime::parse_rfc3339(&east.to_rfc3339_opts(Precision::Nanos)).unwrap();
+        assert!(back.same_instant(&east));
+        assert_eq!(back.offset().as_secs(), 3600);
+    }
+
+    #[test]
+    fn test_to_rfc3339_with_offset_clamps_at_year_edges() {
+        let first = NanoTime::new(0, 1, 1, 3, 0, 0, 0).unwrap();
+        assert_eq!(
+            first.to_rfc3339_opts(Precision::Secs, UtcOffset::from_minutes(-300)),
+            "0000-01-01T00:00:00-05:00"
+        );
+        let last = NanoTime::new(65535, 12, 31, 22, 0, 0, 0).unwrap();
+        assert_eq!(
+            last.to_rfc3339_opts(Precision::Nanos, UtcOffset::from_minutes(300)),
+            "65535-12-31T23:59:59.999999999+05:00"
+        );
+    }
+
+    #[test]
+    fn test_to_rfc3339_past_four_digit_years() {
+        let t = NanoTime::new(10000, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(t.to_rfc3339(), "10000-01-01T00:00:00Z");
+        assert!(NanoTime::parse_rfc3339(&t.to_rfc3339()).is_err());
+        let last = NanoTime::new(9999, 12, 31, 23, 59, 59, 0).unwrap();
+        assert_eq!(NanoTime::parse_rfc3339(&last.to_rfc3339()), Ok(last));
+    }
+
+    #[test]
+    fn test_ordinal_suffix() {
+        let cases = [
+            (1, "st"),
+            (2, "nd"),
+            (3, "rd"),
+            (4, "th"),
+            (11, "th"),
+            (12, "th"),
+            (13, "th"),
+            (21, "st"),
+            (22, "nd"),
+            (23, "rd"),
+            (30, "th"),
+            (31, "st"),
+        ];
+        for (day, suffix) in cases {
+            assert_eq!(ordinal_suffix(day), suffix, "day {}", day);
+        }
+    }
+
+    #[test]
+    fn test_format_long() {
+        let at = |y, mo, d, h, mi| NanoTime::new(y, mo, d, h, mi, 0, 0).unwrap().format_long();
+        assert_eq!(
+            at(2026, 2, 22, 14, 30),
+            "Sunday, February 22nd, 2026 at 2:30 PM"
+        );
+        assert_eq!(
+            at(2026, 1, 1, 0, 5),
+            "Thursday, January 1st, 2026 at 12:05 AM"
+        );
+        assert_eq!(
+            at(2026, 12, 12, 12, 0),
+            "Saturday, December 12th, 2026 at 12:00 PM"
+        );
+        assert_eq!(
+            at(1970, 1, 1, 11, 59),
+            "Thursday, January 1st, 1970 at 11:59 AM"
+        );
+        assert_eq!(
+            at(2024, 2, 29, 23, 59),
+            "Thursday, February 29th, 2024 at 11:59 PM"
+        );
+    }
+
+    #[test]
+    fn test_rfc3339_round_trips_through_parser() {
+        let nt = t(123_456_789);
+        let off = UtcOffset::from_minutes(330);
+        let s = nt.to_rfc3339_opts(Precision::Nanos, off);
+        assert_eq!(NanoTime::parse_rfc3339(&s).unwrap(), nt);
+        assert_eq!(NanoTime::parse_rfc3339(&nt.to_rfc3339()).unwrap(), nt);
+    }
+}
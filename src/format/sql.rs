@@ -0,0 +1,122 @@
+//! SQL timestamp literals.
+
+use std::fmt::Write;
+
+use super::Precision;
+use crate::NanoTime;
+
+/// A SQL dialect for [`NanoTime::to_sql_literal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// `TIMESTAMP '2026-02-22 14:30:05.123456'`, microsecond precision.
+    Postgres,
+    /// `TIMESTAMP '2026-02-22 14:30:05.123456'`, microsecond precision.
+    MySql,
+    /// `CAST('2026-02-22T14:30:05.1234567' AS DATETIME2)`, 100-nanosecond
+    /// precision. SQL Server's `TIMESTAMP` is a row version, not a time.
+    SqlServer,
+    /// `'2026-02-22 14:30:05.123'`, millisecond precision, the text form
+    /// SQLite's date functions read.
+    Sqlite,
+}
+
+impl Dialect {
+    /// The smallest time step the dialect's timestamp type stores, in
+    /// nanoseconds.
+    fn resolution(self) -> u32 {
+        match self {
+            Dialect::Postgres | Dialect::MySql => 1_000,
+            Dialect::SqlServer => 100,
+            Dialect::Sqlite => 1_000_000,
+        }
+    }
+}
+
+impl NanoTime {
+    /// Formats as a timestamp literal for `dialect`, ready to splice into a
+    /// query.
+    ///
+    /// The fraction is truncated to the dialect's precision and trailing
+    /// zeros are trimmed, so whole seconds have no fraction. The output
+    /// contains only digits and fixed punctuation, so it needs no further
+    /// escaping.
+    ///
+    /// ```rust
+    /// use nanotime::{Dialect, NanoTime};
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+    /// assert_eq!(
+    ///     t.to_sql_literal(Dialect::Postgres),
+    ///     "TIMESTAMP '2026-02-22 14:30:05.123456'"
+    /// );
+    /// assert_eq!(
+    ///     t.to_sql_literal(Dialect::SqlServer),
+    ///     "CAST('2026-02-22T14:30:05.1234567' AS DATETIME2)"
+    /// );
+    /// ```
+    pub fn to_sql_literal(&self, dialect: Dialect) -> String {
+        let nanos = self.nanosecond() - self.nanosecond() % dialect.resolution();
+        let sep = if dialect == Dialect::SqlServer {
+            'T'
+        } else {
+            ' '
+        };
+        let mut out = String::with_capacity(52);
+        out.push_str(match dialect {
+            Dialect::Postgres | Dialect::MySql => "TIMESTAMP '",
+            Dialect::SqlServer => "CAST('",
+            Dialect::Sqlite => "'",
+        });
+        let _ = write!(
+            out,
+            "{}{}{:02}:{:02}:{:02}",
+            self.date(),
+            sep,
+            self.hour(),
+            self.minute(),
+            self.second()
+        );
+        Precision::Auto.write_fraction(&mut out, nanos);
+        out.push_str(match dialect {
+            Dialect::SqlServer => "' AS DATETIME2)",
+            _ => "'",
+        });
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_each_dialect() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+        assert_eq!(
+            t.to_sql_literal(Dialect::MySql),
+            "TIMESTAMP '2026-02-22 14:30:05.123456'"
+        );
+        assert_eq!(
+            t.to_sql_literal(Dialect::Sqlite),
+            "'2026-02-22 14:30:05.123'"
+        );
+    }
+
+    #[test]
+    fn test_truncation_trims_zeros() {
+        let t = NanoTime::new(1999, 12, 31, 23, 59, 59, 100_000_999).unwrap();
+        assert_eq!(
+            t.to_sql_literal(Dialect::Postgres),
+            "TIMESTAMP '1999-12-31 23:59:59.1'"
+        );
+        let t = NanoTime::new(1999, 12, 31, 23, 59, 59, 999).unwrap();
+        assert_eq!(
+            t.to_sql_literal(Dialect::Postgres),
+            "TIMESTAMP '1999-12-31 23:59:59'"
+        );
+        assert_eq!(
+            t.to_sql_literal(Dialect::SqlServer),
+            "CAST('1999-12-31T23:59:59.0000009' AS DATETIME2)"
+        );
+    }
+}
@@ -0,0 +1,484 @@
+//! C `strftime`-style format strings.
+//!
+//! Specifiers follow glibc, including the week-based (`%U`, `%W`, `%G`,
+//! `%V`) and epoch (`%s`) conversions and the `-`, `_`, and `0` padding
+//! flags. Unknown specifiers are copied to the output unchanged.
+
+use std::fmt::Write;
+
+use super::MONTH_NAMES;
+use crate::parse::{MONTHS, WEEKDAYS};
+use crate::week::iso_week;
+use crate::NanoTime;
+
+/// Padding applied to a numeric field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Pad {
+    Zero,
+    Space,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Item {
+    Literal(String),
+    Spec(char, Option<Pad>),
+    /// `%1N` to `%9N`: the first digits of the nanoseconds.
+    Fraction(usize),
+}
+
+/// A compiled `strftime`-style format string.
+///
+/// Compiling splits the string into literals and fields once, so a
+/// `Format` can be applied to many timestamps without re-parsing it. See
+/// [`NanoTime::format`] for the supported specifiers.
+///
+/// ```rust
+/// use nanotime::{Format, NanoTime};
+///
+/// let fmt = Format::new("%d/%m/%Y %H:%M");
+/// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+/// assert_eq!(fmt.format(&t), "22/02/2026 14:30");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Format {
+    items: Vec<Item>,
+}
+
+impl Format {
+    /// Compiles a format string. Unknown specifiers become literal text.
+    pub fn new(fmt: &str) -> Self {
+        let mut items = Vec::new();
+        compile(fmt, &mut items);
+        Format { items }
+    }
+
+    /// Formats `t` into a new string.
+    pub fn format(&self, t: &NanoTime) -> String {
+        let mut out = String::with_capacity(self.max_len());
+        self.write(&mut out, t);
+        out
+    }
+
+    /// Appends `t` to `out`.
+    pub fn write(&self, out: &mut String, t: &NanoTime) {
+        for item in &self.items {
+            match item {
+                Item::Literal(s) => out.push_str(s),
+                Item::Spec(spec, flag) => write_spec(out, t, *spec, *flag),
+                Item::Fraction(digits) => {
+                    let _ = write!(out, "{:09}", t.nanosecond());
+                    out.truncate(out.len() - (9 - digits));
+                }
+            }
+        }
+    }
+
+    pub(crate) fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Upper bound on the length in bytes of any formatted output.
+    pub fn max_len(&self) -> usize {
+        self.items
+            .iter()
+            .map(|item| match item {
+                Item::Literal(s) => s.len(),
+                Item::Spec(spec, _) => spec_max_len(*spec),
+                Item::Fraction(digits) => *digits,
+            })
+            .sum()
+    }
+}
+
+fn push_literal(items: &mut Vec<Item>, s: &str) {
+    if let Some(Item::Literal(last)) = items.last_mut() {
+        last.push_str(s);
+    } else {
+        items.push(Item::Literal(s.to_string()));
+    }
+}
+
+fn compile(fmt: &str, items: &mut Vec<Item>) {
+    let mut chars = fmt.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c != '%' {
+            push_literal(items, c.encode_utf8(&mut [0; 4]));
+            continue;
+        }
+        let mut spec = chars.next();
+        let flag = match spec {
+            Some((_, '-')) => Some(Pad::None),
+            Some((_, '_')) => Some(Pad::Space),
+            Some((_, '0')) => Some(Pad::Zero),
+            _ => None,
+        };
+        if flag.is_some() {
+            spec = chars.next();
+        }
+        let end = chars.peek().map_or(fmt.len(), |&(i, _)| i);
+        let Some((_, spec)) = spec else {
+            // A trailing `%` or `%-` is kept as written.
+            push_literal(items, &fmt[start..]);
+            break;
+        };
+        match spec {
+            'F' => compile("%Y-%m-%d", items),
+            'T' | 'X' => compile("%H:%M:%S", items),
+            'D' | 'x' => compile("%m/%d/%y", items),
+            'R' => compile("%H:%M", items),
+            'r' => compile("%I:%M:%S %p", items),
+            'c' => compile("%a %b %e %H:%M:%S %Y", items),
+            'n' => push_literal(items, "\n"),
+            't' => push_literal(items, "\t"),
+            '%' => push_literal(items, "%"),
+            '1'..='9' if flag.is_none() && chars.peek().map(|&(_, c)| c) == Some('N') => {
+                chars.next();
+                items.push(Item::Fraction(spec as usize - '0' as usize));
+            }
+            'Y' | 'C' | 'y' | 'G' | 'g' | 'm' | 'd' | 'e' | 'j' | 'u' | 'w' | 'U' | 'W' | 'V'
+            | 's' | 'H' | 'k' | 'I' | 'l' | 'M' | 'S' | 'f' | 'N' | 'a' | 'A' | 'b' | 'h' | 'B'
+            | 'p' | 'P' => items.push(Item::Spec(spec, flag)),
+            _ => push_literal(items, &fmt[start..end]),
+        }
+    }
+}
+
+/// Panics, at compile time when called in a constant, if `fmt` has a
+/// specifier that [`Format::new`] would copy through as literal text: an
+/// unknown letter or a trailing `%`. Used by
+/// [`format_spec!`](crate::format_spec).
+#[doc(hidden)]
+pub const fn check_spec(fmt: &str) {
+    let b = fmt.as_bytes();
+    let mut i = 0;
+    while i < b.len() {
+        if b[i] != b'%' {
+            i += 1;
+            continue;
+        }
+        i += 1;
+        if i < b.len() && matches!(b[i], b'-' | b'_' | b'0') {
+            i += 1;
+        }
+        if i == b.len() {
+            panic!("format string ends in a lone `%`");
+        }
+        if matches!(b[i], b'1'..=b'9') && b[i - 1] == b'%' {
+            i += 1;
+            if i == b.len() || b[i] != b'N' {
+                panic!("unknown format specifier");
+            }
+        } else if !matches!(
+            b[i],
+            b'F' | b'T'
+                | b'X'
+                | b'D'
+                | b'x'
+                | b'R'
+                | b'r'
+                | b'c'
+                | b'n'
+                | b't'
+                | b'%'
+                | b'Y'
+                | b'C'
+                | b'y'
+                | b'G'
+                | b'g'
+                | b'm'
+                | b'd'
+                | b'e'
+                | b'j'
+                | b'u'
+                | b'w'
+                | b'U'
+                | b'W'
+                | b'V'
+                | b's'
+                | b'H'
+                | b'k'
+                | b'I'
+                | b'l'
+                | b'M'
+                | b'S'
+                | b'f'
+                | b'N'
+                | b'a'
+                | b'A'
+                | b'b'
+                | b'h'
+                | b'B'
+                | b'p'
+                | b'P'
+        ) {
+            panic!("unknown format specifier");
+        }
+        i += 1;
+    }
+}
+
+/// Compiles a `strftime`-style format string once, checking it at compile
+/// time, and returns it as a `&'static Format`.
+///
+/// [`Format::new`] copies an unknown specifier such as `%Q` through as
+/// text, so a typo only shows up in the output. This macro rejects it
+/// while building instead.
+///
+/// ```rust
+/// use nanotime::{format_spec, NanoTime};
+///
+/// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+/// assert_eq!(format_spec!("%Y-%m-%dT%H:%M:%SZ").format(&t), "2026-02-22T14:30:05Z");
+/// ```
+///
+/// ```rust,compile_fail
+/// let fmt = nanotime::format_spec!("%Y-%m-%Q"); // unknown format specifier
+/// ```
+#[macro_export]
+macro_rules! format_spec {
+    ($fmt:literal) => {{
+        const { $crate::format::check_spec($fmt) };
+        static FORMAT: ::std::sync::OnceLock<$crate::Format> = ::std::sync::OnceLock::new();
+        FORMAT.get_or_init(|| $crate::Format::new($fmt))
+    }};
+}
+
+/// Longest output of a single field specifier.
+fn spec_max_len(spec: char) -> usize {
+    match spec {
+        's' => 20,
+        'A' | 'B' | 'N' => 9,
+        'f' => 6,
+        'Y' | 'G' => 5,
+        'C' | 'j' | 'a' | 'b' | 'h' => 3,
+        'u' | 'w' => 1,
+        _ => 2,
+    }
+}
+
+/// Writes `value` at `width` digits using `flag` if given, else `default`.
+fn num(out: &mut String, value: i64, width: usize, default: Pad, flag: Option<Pad>) {
+    let _ = match flag.unwrap_or(default) {
+        Pad::Zero => write!(out, "{:0width$}", value, width = width),
+        Pad::Space => write!(out, "{:width$}", value, width = width),
+        Pad::None => write!(out, "{}", value),
+    };
+}
+
+/// Writes one field conversion.
+fn write_spec(out: &mut String, t: &NanoTime, spec: char, flag: Option<Pad>) {
+    let hour12 = match t.hour() % 12 {
+        0 => 12,
+        h => h,
+    } as i64;
+    let weekday = t.weekday_index();
+    let monday_based = (weekday as i64 + 6) % 7;
+    match spec {
+        'Y' => num(out, t.year() as i64, 1, Pad::Zero, flag),
+        'C' => num(out, t.year() as i64 / 100, 2, Pad::Zero, flag),
+        'y' => num(out, t.year() as i64 % 100, 2, Pad::Zero, flag),
+        'G' => num(out, iso_week(t).0, 1, Pad::Zero, flag),
+        'g' => num(out, iso_week(t).0 % 100, 2, Pad::Zero, flag),
+        'm' => num(out, t.month() as i64, 2, Pad::Zero, flag),
+        'd' => num(out, t.day() as i64, 2, Pad::Zero, flag),
+        'e' => num(out, t.day() as i64, 2, Pad::Space, flag),
+        'j' => num(out, t.day_of_year() as i64, 3, Pad::Zero, flag),
+        'u' => num(out, monday_based + 1, 1, Pad::Zero, flag),
+        'w' => num(out, weekday as i64, 1, Pad::Zero, flag),
+        'U' => {
+            let yday = t.day_of_year() as i64 - 1;
+            num(out, (yday + 7 - weekday as i64) / 7, 2, Pad::Zero, flag)
+        }
+        'W' => {
+            let yday = t.day_of_year() as i64 - 1;
+            num(out, (yday + 7 - monday_based) / 7, 2, Pad::Zero, flag)
+        }
+        'V' => num(out, iso_week(t).1, 2, Pad::Zero, flag),
+        's' => num(out, t.unix_secs(), 1, Pad::Zero, flag),
+        'H' => num(out, t.hour() as i64, 2, Pad::Zero, flag),
+        'k' => num(out, t.hour() as i64, 2, Pad::Space, flag),
+        'I' => num(out, hour12, 2, Pad::Zero, flag),
+        'l' => num(out, hour12, 2, Pad::Space, flag),
+        'M' => num(out, t.minute() as i64, 2, Pad::Zero, flag),
+        'S' => num(out, t.second() as i64, 2, Pad::Zero, flag),
+        'f' => {
+            let _ = write!(out, "{:06}", t.microsecond());
+        }
+        'N' => {
+            let _ = write!(out, "{:09}", t.nanosecond());
+        }
+        'a' => out.push_str(&WEEKDAYS[weekday][..3]),
+        'A' => out.push_str(WEEKDAYS[weekday]),
+        'b' | 'h' => out.push_str(MONTHS[t.month() as usize - 1]),
+        'B' => out.push_str(MONTH_NAMES[t.month() as usize - 1]),
+        'p' => out.push_str(if t.hour() < 12 { "AM" } else { "PM" }),
+        'P' => out.push_str(if t.hour() < 12 { "am" } else { "pm" }),
+        _ => unreachable!("compile only emits known specifiers"),
+    }
+}
+
+impl NanoTime {
+    /// Formats with a C `strftime`-style format string.
+    ///
+    /// Supported specifiers:
+    ///
+    /// | Spec | Meaning | Spec | Meaning |
+    /// |------|---------|------|---------|
+    /// | `%Y` | Year | `%C` | Century (`20`) |
+    /// | `%y` | Two-digit year | `%G` / `%g` | ISO week-based year, full / two-digit |
+    /// | `%m` | Month `01`–`12` | `%b` / `%h` / `%B` | Month name, short / full |
+    /// | `%d` | Day `01`–`31` | `%e` | Day, space-padded |
+    /// | `%j` | Day of year `001`–`366` | `%a` / `%A` | Weekday name, short / full |
+    /// | `%u` | Weekday, Monday = 1 | `%w` | Weekday, Sunday = 0 |
+    /// | `%U` | Week of year, Sunday start | `%W` | Week of year, Monday start |
+    /// | `%V` | ISO 8601 week | `%s` | Seconds since the Unix epoch |
+    /// | `%H` | Hour `00`–`23` | `%k` | Hour, space-padded |
+    /// | `%I` | Hour `01`–`12` | `%l` | 12-hour, space-padded |
+    /// | `%M` | Minute | `%S` | Second |
+    /// | `%f` | Microseconds (6 digits) | `%N` | Nanoseconds (9 digits) |
+    /// | `%3N` | Milliseconds; `%1N` to `%9N` give that many digits | | |
+    /// | `%p` / `%P` | `AM`/`PM`, `am`/`pm` | `%%` | Literal `%` |
+    /// | `%F` | `%Y-%m-%d` | `%T` | `%H:%M:%S` |
+    /// | `%D` / `%x` | `%m/%d/%y` | `%R` | `%H:%M` |
+    /// | `%r` | `%I:%M:%S %p` | `%X` | `%H:%M:%S` |
+    /// | `%c` | `%a %b %e %H:%M:%S %Y` | `%n` / `%t` | Newline / tab |
+    ///
+    /// A flag between `%` and the specifier changes numeric padding: `-`
+    /// removes it, `_` pads with spaces, and `0` pads with zeros, so
+    /// `%-d` gives `5` and `%0e` gives `05`. Names are never padded.
+    /// Unknown specifiers are copied through verbatim, like glibc.
+    /// `%s` treats this time as UTC. To apply one format to many
+    /// timestamps, compile it once with [`Format::new`].
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+    /// assert_eq!(t.format("%Y-%m-%dT%H:%M:%S.%f"), "2026-02-22T14:30:05.123456");
+    /// assert_eq!(t.format("%a %-d %b, week %V"), "Sun 22 Feb, week 08");
+    /// assert_eq!(t.format("%d/%b/%Y:%T.%3N"), "22/Feb/2026:14:30:05.123");
+    /// ```
+    pub fn format(&self, fmt: &str) -> String {
+        Format::new(fmt).format(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `date -u -d @SECS '+FORMAT'` output from GNU coreutils 9.1 (glibc).
+    const GLIBC_FORMAT: &str = "%s|%Y|%C|%y|%m|%d|%e|%H|%I|%k|%l|%M|%S|%p|%P|%a|%A|%b|%B|%h|%j|%U|%W|%G|%g|%V|%u|%w|%D|%F|%T|%R|%r|%c|%x|%X|%-d|%_m|%0e|%-j|%_H|%-I|%%";
+    const GLIBC_OUTPUT: &[(u64, &str)] = &[
+        (1771770605, "1771770605|2026|20|26|02|22|22|14|02|14| 2|30|05|PM|pm|Sun|Sunday|Feb|February|Feb|053|08|07|2026|26|08|7|0|02/22/26|2026-02-22|14:30:05|14:30|02:30:05 PM|Sun Feb 22 14:30:05 2026|02/22/26|14:30:05|22| 2|22|53|14|2|%"),
+        (1735603200, "1735603200|2024|20|24|12|31|31|00|12| 0|12|00|00|AM|am|Tue|Tuesday|Dec|December|Dec|366|52|53|2025|25|01|2|2|12/31/24|2024-12-31|00:00:00|00:00|12:00:00 AM|Tue Dec 31 00:00:00 2024|12/31/24|00:00:00|31|12|31|366| 0|12|%"),
+        (1704067200, "1704067200|2024|20|24|01|01| 1|00|12| 0|12|00|00|AM|am|Mon|Monday|Jan|January|Jan|001|00|01|2024|24|01|1|1|01/01/24|2024-01-01|00:00:00|00:00|12:00:00 AM|Mon Jan  1 00:00:00 2024|01/01/24|00:00:00|1| 1|01|1| 0|12|%"),
+        (1609459199, "1609459199|2020|20|20|12|31|31|23|11|23|11|59|59|PM|pm|Thu|Thursday|Dec|December|Dec|366|52|52|2020|20|53|4|4|12/31/20|2020-12-31|23:59:59|23:59|11:59:59 PM|Thu Dec 31 23:59:59 2020|12/31/20|23:59:59|31|12|31|366|23|11|%"),
+        (1230768000, "1230768000|2009|20|09|01|01| 1|00|12| 0|12|00|00|AM|am|Thu|Thursday|Jan|January|Jan|001|00|00|2009|09|01|4|4|01/01/09|2009-01-01|00:00:00|00:00|12:00:00 AM|Thu Jan  1 00:00:00 2009|01/01/09|00:00:00|1| 1|01|1| 0|12|%"),
+        (1293753600, "1293753600|2010|20|10|12|31|31|00|12| 0|12|00|00|AM|am|Fri|Friday|Dec|December|Dec|365|52|52|2010|10|52|5|5|12/31/10|2010-12-31|00:00:00|00:00|12:00:00 AM|Fri Dec 31 00:00:00 2010|12/31/10|00:00:00|31|12|31|365| 0|12|%"),
+        (0, "0|1970|19|70|01|01| 1|00|12| 0|12|00|00|AM|am|Thu|Thursday|Jan|January|Jan|001|00|00|1970|70|01|4|4|01/01/70|1970-01-01|00:00:00|00:00|12:00:00 AM|Thu Jan  1 00:00:00 1970|01/01/70|00:00:00|1| 1|01|1| 0|12|%"),
+        (946684799, "946684799|1999|19|99|12|31|31|23|11|23|11|59|59|PM|pm|Fri|Friday|Dec|December|Dec|365|52|52|1999|99|52|5|5|12/31/99|1999-12-31|23:59:59|23:59|11:59:59 PM|Fri Dec 31 23:59:59 1999|12/31/99|23:59:59|31|12|31|365|23|11|%"),
+        (4102444799, "4102444799|2099|20|99|12|31|31|23|11|23|11|59|59|PM|pm|Thu|Thursday|Dec|December|Dec|365|52|52|2099|99|53|4|4|12/31/99|2099-12-31|23:59:59|23:59|11:59:59 PM|Thu Dec 31 23:59:59 2099|12/31/99|23:59:59|31|12|31|365|23|11|%"),
+    ];
+
+    #[test]
+    fn test_matches_glibc() {
+        for &(secs, expected) in GLIBC_OUTPUT {
+            let t = NanoTime::from_epoch(secs);
+            assert_eq!(t.format(GLIBC_FORMAT), expected, "epoch {}", secs);
+        }
+    }
+
+    #[test]
+    fn test_iso_week_year_boundaries() {
+        // (date, %G, %V, %U, %W) from GNU date.
+        let cases = [
+            ((2021, 1, 1), "2020|53|00|00"),
+            ((2010, 1, 3), "2009|53|01|00"),
+            ((2008, 12, 31), "2009|01|52|52"),
+        ];
+        for ((y, m, d), expected) in cases {
+            let t = NanoTime::new(y, m, d, 0, 0, 0, 0).unwrap();
+            assert_eq!(t.format("%G|%V|%U|%W"), expected, "{}", t.date());
+        }
+    }
+
+    #[test]
+    fn test_subsecond_specifiers() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+        assert_eq!(t.format("%S.%f"), "05.123456");
+        assert_eq!(t.format("%S.%N"), "05.123456789");
+    }
+
+    #[test]
+    fn test_unknown_and_trailing_specifiers_pass_through() {
+        let t = NanoTime::from_epoch(0);
+        assert_eq!(t.format("a%Qb"), "a%Qb");
+        assert_eq!(t.format("%-Q"), "%-Q");
+        assert_eq!(t.format("x%"), "x%");
+        assert_eq!(t.format("x%_"), "x%_");
+        assert_eq!(t.format("%-a|%_y|%-y"), "Thu|70|70");
+        assert_eq!(t.format(""), "");
+        assert_eq!(t.format("%n%t"), "\n\t");
+    }
+
+    #[test]
+    fn test_compiled_format() {
+        let fmt = Format::new("at %F%%%Q %");
+        assert_eq!(
+            fmt.items,
+            vec![
+                Item::Literal("at ".into()),
+                Item::Spec('Y', None),
+                Item::Literal("-".into()),
+                Item::Spec('m', None),
+                Item::Literal("-".into()),
+                Item::Spec('d', None),
+                Item::Literal("%%Q %".into()),
+            ]
+        );
+        let glibc = Format::new(GLIBC_FORMAT);
+        for &(_, expected) in GLIBC_OUTPUT {
+            assert!(glibc.max_len() >= expected.len());
+        }
+        let max = NanoTime::new(2099, 9, 29, 23, 59, 59, 0).unwrap();
+        assert!(Format::new("%A %B %c").max_len() >= max.format("%A %B %c").len());
+    }
+
+    #[test]
+    fn test_format_spec_matches_new() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+        let fmt: &'static Format = crate::format_spec!("%-d %B %Y, %F %%");
+        assert_eq!(fmt, &Format::new("%-d %B %Y, %F %%"));
+        assert_eq!(fmt.format(&t), "22 February 2026, 2026-02-22 %");
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown format specifier")]
+    fn test_check_spec_rejects_unknown() {
+        check_spec("%Y-%q");
+    }
+
+    #[test]
+    #[should_panic(expected = "lone `%`")]
+    fn test_check_spec_rejects_trailing_percent() {
+        check_spec("100%-");
+    }
+
+    #[test]
+    fn test_fraction_digits() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 120_456_789).unwrap();
+        assert_eq!(t.format("%1N|%3N|%6N|%9N"), "1|120|120456|120456789");
+        // Without a following `N`, or with a flag, digits stay literal.
+        assert_eq!(t.format("%3d %-3N"), "%3d %-3N");
+        assert_eq!(Format::new("%T.%3N").max_len(), 12);
+        check_spec("%T.%3N");
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown format specifier")]
+    fn test_check_spec_rejects_width_without_n() {
+        check_spec("%3S");
+    }
+}
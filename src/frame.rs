@@ -0,0 +1,274 @@
+//! Frame pacing and fixed-step updates for game and render loops.
+
+use std::time::{Duration, Instant};
+
+use crate::{compensated_sleep_until, sleep_overshoot, NanoDuration};
+
+/// Paces a loop to a target frame rate and reports the real time between
+/// frames.
+///
+/// Frames are due at absolute deadlines one frame time apart, and each wait
+/// uses [`compensated_sleep_until`], so frames neither drift from
+/// accumulated work time nor arrive late from sleep overshoot. When a frame
+/// runs a whole frame time or more over, the limiter gives up the missed
+/// deadlines rather than rendering a burst of catch-up frames.
+///
+/// ```rust
+/// use nanotime::FrameLimiter;
+///
+/// let mut limiter = FrameLimiter::new(240);
+/// for _ in 0..3 {
+///     let dt = limiter.wait_for_next_frame();
+///     // ... update the simulation by dt, then render ...
+///     assert!(!dt.is_negative());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameLimiter {
+    frame_time: Duration,
+    next: Instant,
+    last: Instant,
+}
+
+impl FrameLimiter {
+    /// Creates a limiter for `target_fps` frames per second. The first frame
+    /// is due one frame time from now.
+    ///
+    /// Calibrates [`compensated_sleep_until`] first if that has not been
+    /// done yet, which takes about 20ms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_fps` is zero.
+    pub fn new(target_fps: u32) -> Self {
+        assert!(target_fps > 0, "target frame rate must be non-zero");
+        sleep_overshoot();
+        Self::starting_at(Instant::now(), target_fps)
+    }
+
+    /// Creates a limiter whose first frame is due one frame time after
+    /// `start`.
+    fn starting_at(start: Instant, target_fps: u32) -> Self {
+        FrameLimiter {
+            frame_time: Duration::from_secs(1) / target_fps,
+            next: start,
+            last: start,
+        }
+    }
+
+    /// Returns the time budgeted for each frame.
+    pub fn frame_time(&self) -> Duration {
+        self.frame_time
+    }
+
+    /// Waits until the next frame is due and returns the time since the
+    /// previous frame began, or since the limiter was created.
+    pub fn wait_for_next_frame(&mut self) -> NanoDuration {
+        let (due, resync) = self.schedule(Instant::now());
+        compensated_sleep_until(due);
+        self.begin_frame(Instant::now(), resync)
+    }
+
+    /// Advances to the next deadline as of `now` and returns when the frame
+    /// is due, and whether the schedule restarts from it.
+    fn schedule(&mut self, now: Instant) -> (Instant, bool) {
+        self.next += self.frame_time;
+        let behind = now >= self.next + self.frame_time;
+        if behind {
+            // A whole frame behind; start a new schedule from this frame
+            // instead of bursting, but give it its full time if the last
+            // wait itself ran late.
+            self.next = self.last + self.frame_time;
+        }
+        (self.next, behind)
+    }
+
+    /// Records that the frame [`schedule`](Self::schedule) returned began at
+    /// `now`, and returns the time since the previous one.
+    fn begin_frame(&mut self, now: Instant, resync: bool) -> NanoDuration {
+        if resync {
+            self.next = now;
+        }
+        let delta = now.saturating_duration_since(self.last);
+        self.last = now;
+        NanoDuration::from_nanos(delta.as_nanos() as i128).unwrap_or_default()
+    }
+}
+
+/// Default cap on steps per [`FixedTimestep::update`].
+const DEFAULT_MAX_STEPS: u32 = 8;
+
+/// The accumulator pattern for running a simulation at a fixed step while
+/// rendering at whatever rate frames arrive.
+///
+/// Each frame, [`update`](Self::update) adds the real time since the last
+/// update to an accumulator and returns how many whole steps to simulate
+/// and how far into the next step the leftover time reaches, as an
+/// interpolation factor in `0.0..1.0` for blending the previous and current
+/// states when rendering.
+///
+/// A frame that falls far behind would otherwise demand more steps than can
+/// run in a frame, falling further behind each time. Steps per update are
+/// capped, 8 by default, and time beyond the cap is dropped so the
+/// simulation slows down instead.
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use nanotime::FixedTimestep;
+///
+/// let start = Instant::now();
+/// let mut timestep = FixedTimestep::starting_at(start, Duration::from_millis(10));
+///
+/// let (steps, alpha) = timestep.update(start + Duration::from_millis(25));
+/// assert_eq!(steps, 2);
+/// assert!((alpha - 0.5).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedTimestep {
+    step: Duration,
+    max_steps: u32,
+    last: Instant,
+    accumulated: Duration,
+}
+
+impl FixedTimestep {
+    /// Creates an accumulator with steps of `step`, measuring from now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn new(step: Duration) -> Self {
+        Self::starting_at(Instant::now(), step)
+    }
+
+    /// Creates an accumulator with steps of `step`, measuring from `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn starting_at(start: Instant, step: Duration) -> Self {
+        assert!(!step.is_zero(), "timestep must be non-zero");
+        FixedTimestep {
+            step,
+            max_steps: DEFAULT_MAX_STEPS,
+            last: start,
+            accumulated: Duration::ZERO,
+        }
+    }
+
+    /// Sets the most steps a single update may return. Defaults to 8.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_steps` is zero.
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        assert!(max_steps > 0, "max steps must be non-zero");
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Returns the simulation step.
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Returns the time accumulated toward the next step.
+    pub fn accumulated(&self) -> Duration {
+        self.accumulated
+    }
+
+    /// Adds the time since the previous update, as of `now`, and returns
+    /// the number of steps to run and the interpolation factor.
+    ///
+    /// A `now` earlier than the previous update adds nothing.
+    pub fn update(&mut self, now: Instant) -> (u32, f64) {
+        let frame = now.saturating_duration_since(self.last);
+        self.last = self.last.max(now);
+        self.accumulated += frame;
+
+        let available = self.accumulated.as_nanos() / self.step.as_nanos();
+        let steps = available.min(self.max_steps as u128) as u32;
+        self.accumulated -= self.step * steps;
+        if available > steps as u128 {
+            // Drop whole steps beyond the cap, keeping the phase.
+            self.accumulated =
+                Duration::from_nanos((self.accumulated.as_nanos() % self.step.as_nanos()) as u64);
+        }
+        let alpha = self.accumulated.as_secs_f64() / self.step.as_secs_f64();
+        (steps, alpha)
+    }
+
+    /// Like [`update`](Self::update), as of the current instant.
+    pub fn update_now(&mut self) -> (u32, f64) {
+        self.update(Instant::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs one frame: schedules at `now`, then begins at `woke`.
+    fn frame(limiter: &mut FrameLimiter, now: Instant, woke: Instant) -> (Instant, u128) {
+        let (due, resync) = limiter.schedule(now);
+        (due, limiter.begin_frame(woke, resync).as_nanos() as u128)
+    }
+
+    #[test]
+    fn test_paces_frames() {
+        let ms = Duration::from_millis;
+        let start = Instant::now();
+        let mut limiter = FrameLimiter::starting_at(start, 200);
+        assert_eq!(limiter.frame_time(), ms(5));
+        assert_eq!(
+            frame(&mut limiter, start + ms(1), start + ms(5)),
+            (start + ms(5), 5_000_000)
+        );
+        // Deadlines are absolute, so after waking 1ms late the next frame
+        // is 1ms short rather than the whole schedule drifting.
+        assert_eq!(
+            frame(&mut limiter, start + ms(6), start + ms(11)),
+            (start + ms(10), 6_000_000)
+        );
+        assert_eq!(
+            frame(&mut limiter, start + ms(12), start + ms(15)),
+            (start + ms(15), 4_000_000)
+        );
+    }
+
+    #[test]
+    fn test_fixed_timestep() {
+        let ms = Duration::from_millis;
+        let start = Instant::now();
+        let mut ts = FixedTimestep::starting_at(start, ms(10)).with_max_steps(4);
+        assert_eq!(ts.update(start + ms(4)), (0, 0.4));
+        let (steps, alpha) = ts.update(start + ms(27));
+        assert_eq!(steps, 2);
+        assert!((alpha - 0.7).abs() < 1e-9);
+        // Going backwards adds nothing.
+        assert_eq!(ts.update(start + ms(20)).0, 0);
+        assert_eq!(ts.accumulated(), ms(7));
+
+        // 108ms behind: capped at 4 steps, the rest dropped but the phase kept.
+        let (steps, alpha) = ts.update(start + ms(135));
+        assert_eq!(steps, 4);
+        assert!((alpha - 0.5).abs() < 1e-9);
+        assert_eq!(ts.update(start + ms(140)), (1, 0.0));
+    }
+
+    #[test]
+    fn test_slow_frame_resyncs() {
+        let ms = Duration::from_millis;
+        let start = Instant::now();
+        let mut limiter = FrameLimiter::starting_at(start, 100);
+        frame(&mut limiter, start, start + ms(10));
+        // The frame ran 35ms: the late frame is not held back...
+        let (due, dt) = frame(&mut limiter, start + ms(45), start + ms(45));
+        assert_eq!((due, dt), (start + ms(20), 35_000_000));
+        // ...and the next one gets a full frame time rather than none.
+        assert_eq!(
+            frame(&mut limiter, start + ms(46), start + ms(55)),
+            (start + ms(55), 10_000_000)
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Setting file timestamps.
+//!
+//! ```rust,no_run
+//! use nanotime::NanoTime;
+//!
+//! // Restore the times recorded in a backup manifest.
+//! let accessed = NanoTime::parse_rfc3339("2026-02-22T14:30:05.123456789Z").unwrap();
+//! let modified = NanoTime::parse_rfc3339("2026-02-20T09:00:00Z").unwrap();
+//! nanotime::fs::set_times("restored/report.pdf", &accessed, &modified)?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::io;
+use std::path::Path;
+
+use crate::NanoTime;
+
+#[cfg(unix)]
+mod sys {
+    use std::ffi::{c_char, CString};
+    use std::io;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    use crate::NanoTime;
+
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: i64,
+        tv_nsec: i64,
+    }
+
+    #[cfg(target_vendor = "apple")]
+    const AT_FDCWD: i32 = -2;
+    #[cfg(not(target_vendor = "apple"))]
+    const AT_FDCWD: i32 = -100;
+
+    extern "C" {
+        fn utimensat(dirfd: i32, path: *const c_char, times: *const Timespec, flags: i32) -> i32;
+    }
+
+    fn timespec(t: &NanoTime) -> Timespec {
+        Timespec {
+            tv_sec: t.unix_secs(),
+            tv_nsec: t.nanosecond() as i64,
+        }
+    }
+
+    pub fn set_times(path: &Path, accessed: &NanoTime, modified: &NanoTime) -> io::Result<()> {
+        let path = CString::new(path.as_os_str().as_bytes())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "path contains a NUL byte"))?;
+        let times = [timespec(accessed), timespec(modified)];
+        if unsafe { utimensat(AT_FDCWD, path.as_ptr(), times.as_ptr(), 0) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::ffi::c_void;
+    use std::io;
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::Path;
+
+    use crate::NanoTime;
+
+    #[repr(C)]
+    struct FILETIME {
+        low: u32,
+        high: u32,
+    }
+
+    const FILE_WRITE_ATTRIBUTES: u32 = 0x100;
+    const FILE_SHARE_ALL: u32 = 0x1 | 0x2 | 0x4;
+    const OPEN_EXISTING: u32 = 3;
+    /// Required to open directories.
+    const FILE_FLAG_BACKUP_SEMANTICS: u32 = 0x0200_0000;
+    const INVALID_HANDLE_VALUE: *mut c_void = -1isize as *mut c_void;
+    /// 100ns intervals from 1601-01-01 to the Unix epoch.
+    const EPOCH_DIFF: i128 = 116_444_736_000_000_000;
+
+    extern "system" {
+        fn CreateFileW(
+            name: *const u16,
+            access: u32,
+            share: u32,
+            security: *const c_void,
+            disposition: u32,
+            flags: u32,
+            template: *mut c_void,
+        ) -> *mut c_void;
+        fn SetFileTime(
+            file: *mut c_void,
+            creation: *const FILETIME,
+            access: *const FILETIME,
+            write: *const FILETIME,
+        ) -> i32;
+        fn CloseHandle(handle: *mut c_void) -> i32;
+    }
+
+    fn filetime(t: &NanoTime) -> io::Result<FILETIME> {
+        let ticks = t.unix_nanos().div_euclid(100) + EPOCH_DIFF;
+        let ticks = u64::try_from(ticks)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "time is before 1601"))?;
+        Ok(FILETIME {
+            low: ticks as u32,
+            high: (ticks >> 32) as u32,
+        })
+    }
+
+    pub fn set_times(path: &Path, accessed: &NanoTime, modified: &NanoTime) -> io::Result<()> {
+        let (atime, mtime) = (filetime(accessed)?, filetime(modified)?);
+        let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+        unsafe {
+            let handle = CreateFileW(
+                wide.as_ptr(),
+                FILE_WRITE_ATTRIBUTES,
+                FILE_SHARE_ALL,
+                std::ptr::null(),
+                OPEN_EXISTING,
+                FILE_FLAG_BACKUP_SEMANTICS,
+                std::ptr::null_mut(),
+            );
+            if handle == INVALID_HANDLE_VALUE {
+                return Err(io::Error::last_os_error());
+            }
+            let ok = SetFileTime(handle, std::ptr::null(), &atime, &mtime);
+            let err = io::Error::last_os_error();
+            CloseHandle(handle);
+            if ok == 0 {
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod sys {
+    use std::io;
+    use std::path::Path;
+
+    use crate::NanoTime;
+
+    pub fn set_times(_: &Path, _: &NanoTime, _: &NanoTime) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+/// Sets the last access and modification times of the file or directory
+/// at `path`, both taken as UTC.
+///
+/// Uses `utimensat` on Unix, which keeps full nanosecond precision where
+/// the file system stores it, and `SetFileTime` on Windows, which stores
+/// 100-nanosecond intervals and rounds down to one. Symbolic links are
+/// followed. Returns the operating system's error if the file does not
+/// exist or its times cannot be changed, and [`io::ErrorKind::Unsupported`]
+/// on other platforms.
+pub fn set_times(
+    path: impl AsRef<Path>,
+    accessed: &NanoTime,
+    modified: &NanoTime,
+) -> io::Result<()> {
+    sys::set_times(path.as_ref(), accessed, modified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn since_epoch(t: std::time::SystemTime) -> Duration {
+        t.duration_since(UNIX_EPOCH).unwrap()
+    }
+
+    #[test]
+    fn test_set_times() {
+        let path = std::env::temp_dir().join(format!("nanotime-fs-{}", std::process::id()));
+        std::fs::write(&path, b"x").unwrap();
+        let accessed = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_700).unwrap();
+        let modified = NanoTime::new(2001, 9, 9, 1, 46, 40, 0).unwrap();
+        let result = set_times(&path, &accessed, &modified);
+        let meta = std::fs::metadata(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        result.unwrap();
+
+        assert_eq!(
+            since_epoch(meta.modified().unwrap()),
+            Duration::from_secs(1_000_000_000)
+        );
+        let atime = since_epoch(meta.accessed().unwrap());
+        assert_eq!(atime.as_secs(), 1_771_770_605);
+        // Coarser file systems keep less of the fraction, never more.
+        assert!(atime.subsec_nanos() <= 123_456_700);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let t = NanoTime::from_epoch(0);
+        let err = set_times("/nonexistent/nanotime/file", &t, &t).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,135 @@
+//! Bucketing timestamps by a calendar component, for traffic patterns and
+//! seasonality.
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use crate::NanoTime;
+
+/// A calendar component to group timestamps by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component {
+    /// Hour of the day, 0 to 23.
+    HourOfDay,
+    /// Day of the week, 0 for Sunday to 6 for Saturday, as
+    /// [`Weekday::number_from_sunday`](crate::Weekday::number_from_sunday).
+    DayOfWeek,
+    /// Day of the month, 1 to 31.
+    DayOfMonth,
+    /// Month, 1 to 12.
+    Month,
+}
+
+impl Component {
+    /// Returns every value this component can take.
+    pub fn range(&self) -> RangeInclusive<u8> {
+        match self {
+            Component::HourOfDay => 0..=23,
+            Component::DayOfWeek => 0..=6,
+            Component::DayOfMonth => 1..=31,
+            Component::Month => 1..=12,
+        }
+    }
+
+    /// Returns this component of `t`.
+    pub fn of(&self, t: &NanoTime) -> u8 {
+        match self {
+            Component::HourOfDay => t.hour(),
+            Component::DayOfWeek => t.weekday().number_from_sunday(),
+            Component::DayOfMonth => t.day(),
+            Component::Month => t.month(),
+        }
+    }
+}
+
+/// Counts `times` by `component`.
+///
+/// The map holds every value of the component, so buckets with no events
+/// show up as zero rather than missing. Times are read as given; convert
+/// them to local time first to group by the local hour or day.
+///
+/// ```rust
+/// use nanotime::{group_by, Component, NanoTime};
+///
+/// let hits = [
+///     NanoTime::new(2026, 2, 22, 9, 15, 0, 0).unwrap(),
+///     NanoTime::new(2026, 2, 22, 9, 45, 0, 0).unwrap(),
+///     NanoTime::new(2026, 2, 23, 17, 5, 0, 0).unwrap(),
+/// ];
+/// let by_hour = group_by(&hits, Component::HourOfDay);
+/// assert_eq!(by_hour.len(), 24);
+/// assert_eq!((by_hour[&9], by_hour[&17], by_hour[&12]), (2, 1, 0));
+/// ```
+pub fn group_by<'a>(
+    times: impl IntoIterator<Item = &'a NanoTime>,
+    component: Component,
+) -> BTreeMap<u8, usize> {
+    group_fold(
+        times.into_iter().map(|t| (*t, ())),
+        component,
+        0,
+        |n, ()| n + 1,
+    )
+}
+
+/// Folds `items` into one accumulator per value of `component`, starting
+/// each from `init`, for sums or other aggregates per bucket.
+///
+/// As with [`group_by`], every value of the component has an entry.
+///
+/// ```rust
+/// use nanotime::{group_fold, Component, NanoTime};
+///
+/// // Bytes served per weekday.
+/// let requests = [
+///     (NanoTime::new(2026, 2, 22, 9, 0, 0, 0).unwrap(), 1200u64), // Sunday
+///     (NanoTime::new(2026, 2, 23, 9, 0, 0, 0).unwrap(), 800),      // Monday
+///     (NanoTime::new(2026, 3, 1, 9, 0, 0, 0).unwrap(), 300),       // Sunday
+/// ];
+/// let bytes = group_fold(requests, Component::DayOfWeek, 0, |sum, b| sum + b);
+/// assert_eq!((bytes[&0], bytes[&1], bytes[&2]), (1500, 800, 0));
+/// ```
+pub fn group_fold<T, A, F>(
+    items: impl IntoIterator<Item = (NanoTime, T)>,
+    component: Component,
+    init: A,
+    mut f: F,
+) -> BTreeMap<u8, A>
+where
+    A: Clone,
+    F: FnMut(A, T) -> A,
+{
+    let mut groups: BTreeMap<u8, A> = component.range().map(|k| (k, init.clone())).collect();
+    for (t, item) in items {
+        let slot = groups
+            .get_mut(&component.of(&t))
+            .expect("component in range");
+        *slot = f(slot.clone(), item);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buckets_cover_range() {
+        let times = [
+            NanoTime::new(2024, 2, 29, 23, 0, 0, 0).unwrap(),
+            NanoTime::new(2026, 12, 31, 0, 0, 0, 0).unwrap(),
+        ];
+        let days = group_by(&times, Component::DayOfMonth);
+        assert_eq!(
+            days.keys().copied().collect::<Vec<_>>(),
+            (1..=31).collect::<Vec<_>>()
+        );
+        assert_eq!((days[&29], days[&31], days[&1]), (1, 1, 0));
+        let months = group_by(&times, Component::Month);
+        assert_eq!((months.len(), months[&2], months[&12]), (12, 1, 1));
+        let weekdays = group_by(&times, Component::DayOfWeek);
+        // A Thursday and a Thursday.
+        assert_eq!(weekdays[&4], 2);
+        assert!(group_by([], Component::HourOfDay).values().all(|&n| n == 0));
+    }
+}
@@ -0,0 +1,351 @@
+//! InfluxDB line protocol.
+//!
+//! Each point is one line: the measurement and its tags, a space, the
+//! fields, a space, and the timestamp as a signed integer count of the
+//! write's precision since the epoch.
+//!
+//! ```text
+//! cpu,host=web\ 1 usage=0.5,cores=8i 1771770605123456789
+//! ```
+
+use std::fmt::Write;
+
+use crate::NanoTime;
+
+/// The unit of line-protocol timestamps, the `precision` parameter of a
+/// write request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum InfluxPrecision {
+    /// Nanoseconds, `ns`, the server's default.
+    #[default]
+    Nanos,
+    /// Microseconds, `us`.
+    Micros,
+    /// Milliseconds, `ms`.
+    Millis,
+    /// Seconds, `s`.
+    Secs,
+}
+
+impl InfluxPrecision {
+    /// Returns the value of the `precision` query parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InfluxPrecision::Nanos => "ns",
+            InfluxPrecision::Micros => "us",
+            InfluxPrecision::Millis => "ms",
+            InfluxPrecision::Secs => "s",
+        }
+    }
+
+    fn nanos_per_unit(&self) -> i128 {
+        match self {
+            InfluxPrecision::Nanos => 1,
+            InfluxPrecision::Micros => 1_000,
+            InfluxPrecision::Millis => 1_000_000,
+            InfluxPrecision::Secs => 1_000_000_000,
+        }
+    }
+}
+
+impl NanoTime {
+    /// Returns this UTC time as a line-protocol timestamp at `precision`,
+    /// rounding toward the past. Returns `None` outside the `i64` range,
+    /// which at nanosecond precision is 1677 to 2262.
+    ///
+    /// ```rust
+    /// use nanotime::{InfluxPrecision, NanoTime};
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+    /// assert_eq!(t.to_influx_timestamp(InfluxPrecision::Nanos), Some(1_771_770_605_123_456_789));
+    /// assert_eq!(t.to_influx_timestamp(InfluxPrecision::Millis), Some(1_771_770_605_123));
+    /// ```
+    pub fn to_influx_timestamp(&self, precision: InfluxPrecision) -> Option<i64> {
+        let nanos = self.unix_nanos();
+        i64::try_from(nanos.div_euclid(precision.nanos_per_unit())).ok()
+    }
+}
+
+/// A field value. Line protocol has no NaN or infinity, so
+/// [`LinePoint::with_field`] skips non-finite floats.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    /// A 64-bit float, written bare.
+    Float(f64),
+    /// A signed integer, written with an `i` suffix.
+    Integer(i64),
+    /// An unsigned integer, written with a `u` suffix.
+    UInteger(u64),
+    /// A string, written quoted. Line breaks are kept as they are, which
+    /// the spec allows inside quotes.
+    String(String),
+    /// A boolean, written `true` or `false`.
+    Boolean(bool),
+}
+
+impl From<f64> for FieldValue {
+    fn from(v: f64) -> Self {
+        FieldValue::Float(v)
+    }
+}
+
+impl From<i64> for FieldValue {
+    fn from(v: i64) -> Self {
+        FieldValue::Integer(v)
+    }
+}
+
+impl From<u64> for FieldValue {
+    fn from(v: u64) -> Self {
+        FieldValue::UInteger(v)
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(v: bool) -> Self {
+        FieldValue::Boolean(v)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(v: &str) -> Self {
+        FieldValue::String(v.to_string())
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(v: String) -> Self {
+        FieldValue::String(v)
+    }
+}
+
+/// Appends `s`, backslash-escaping every character in `special`.
+fn write_escaped(out: &mut String, s: &str, special: &[char]) {
+    for c in s.chars() {
+        if special.contains(&c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
+
+/// Appends a measurement name, key, or tag value, backslash-escaping every
+/// character in `special`. Line protocol has no escape for line breaks
+/// and tabs, and a raw newline would end the point and start another, so
+/// they are written as the two characters `\n`, `\r`, `\t`, and `\f`, as
+/// Telegraf does.
+fn write_name(out: &mut String, s: &str, special: &[char]) {
+    for c in s.chars() {
+        match c {
+            '\n' => out.push_str(r"\n"),
+            '\r' => out.push_str(r"\r"),
+            '\t' => out.push_str(r"\t"),
+            '\x0c' => out.push_str(r"\f"),
+            c => {
+                if special.contains(&c) {
+                    out.push('\\');
+                }
+                out.push(c);
+            }
+        }
+    }
+}
+
+/// Measurement names escape commas and spaces.
+const MEASUREMENT: &[char] = &[',', ' '];
+/// Tag keys, tag values, and field keys also escape equals signs.
+const KEY: &[char] = &[',', '=', ' '];
+/// String field values escape quotes and backslashes.
+const STRING: &[char] = &['"', '\\'];
+
+/// One line-protocol point.
+///
+/// Tags and fields are written in the order they were added; InfluxDB
+/// ingests fastest when tags are sorted by key. A point needs at least
+/// one field to be accepted. Without a time, the server stamps the point
+/// on arrival.
+///
+/// ```rust
+/// use nanotime::{InfluxPrecision, LinePoint, NanoTime};
+///
+/// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+/// let point = LinePoint::new("cpu")
+///     .with_tag("host", "web 1")
+///     .with_field("usage", 0.5)
+///     .with_field("cores", 8i64)
+///     .with_time(t);
+/// assert_eq!(
+///     point.to_line(InfluxPrecision::Millis).unwrap(),
+///     r"cpu,host=web\ 1 usage=0.5,cores=8i 1771770605123"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinePoint {
+    measurement: String,
+    tags: Vec<(String, String)>,
+    fields: Vec<(String, FieldValue)>,
+    time: Option<NanoTime>,
+}
+
+impl LinePoint {
+    /// Creates a point in `measurement` with no tags, fields, or time.
+    pub fn new(measurement: &str) -> Self {
+        LinePoint {
+            measurement: measurement.to_string(),
+            tags: Vec::new(),
+            fields: Vec::new(),
+            time: None,
+        }
+    }
+
+    /// Adds a tag.
+    pub fn with_tag(mut self, key: &str, value: &str) -> Self {
+        self.tags.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a field. A NaN or infinite float, which line protocol cannot
+    /// represent, is skipped.
+    pub fn with_field(mut self, key: &str, value: impl Into<FieldValue>) -> Self {
+        let value = value.into();
+        if !matches!(value, FieldValue::Float(v) if !v.is_finite()) {
+            self.fields.push((key.to_string(), value));
+        }
+        self
+    }
+
+    /// Sets the point's UTC time.
+    pub fn with_time(mut self, time: NanoTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Returns the point's time, if set.
+    pub fn time(&self) -> Option<NanoTime> {
+        self.time
+    }
+
+    /// Appends the point to `out` as one line, without a trailing newline,
+    /// with its timestamp at `precision`.
+    ///
+    /// Returns `None`, leaving `out` unchanged, if the time is outside the
+    /// `i64` range of the precision. Leaving the timestamp off instead
+    /// would have the server stamp the point with its arrival time.
+    pub fn write_line(&self, out: &mut String, precision: InfluxPrecision) -> Option<()> {
+        let ts = match self.time {
+            Some(t) => Some(t.to_influx_timestamp(precision)?),
+            None => None,
+        };
+        write_name(out, &self.measurement, MEASUREMENT);
+        for (key, value) in &self.tags {
+            out.push(',');
+            write_name(out, key, KEY);
+            out.push('=');
+            write_name(out, value, KEY);
+        }
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            out.push(if i == 0 { ' ' } else { ',' });
+            write_name(out, key, KEY);
+            out.push('=');
+            let _ = match value {
+                FieldValue::Float(v) => write!(out, "{}", v),
+                FieldValue::Integer(v) => write!(out, "{}i", v),
+                FieldValue::UInteger(v) => write!(out, "{}u", v),
+                FieldValue::Boolean(v) => write!(out, "{}", v),
+                FieldValue::String(v) => {
+                    out.push('"');
+                    write_escaped(out, v, STRING);
+                    out.push('"');
+                    Ok(())
+                }
+            };
+        }
+        if let Some(ts) = ts {
+            let _ = write!(out, " {}", ts);
+        }
+        Some(())
+    }
+
+    /// Returns the point as one line, without a trailing newline, or
+    /// `None` if the time is outside the `i64` range of `precision`.
+    pub fn to_line(&self, precision: InfluxPrecision) -> Option<String> {
+        let mut out = String::with_capacity(64);
+        self.write_line(&mut out, precision)?;
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_precisions() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+        assert_eq!(
+            t.to_influx_timestamp(InfluxPrecision::Micros),
+            Some(1_771_770_605_123_456)
+        );
+        assert_eq!(
+            t.to_influx_timestamp(InfluxPrecision::Secs),
+            Some(1_771_770_605)
+        );
+        // Before the epoch, truncation still rounds toward the past.
+        let before = NanoTime::new(1969, 12, 31, 23, 59, 59, 500_000_000).unwrap();
+        assert_eq!(before.to_influx_timestamp(InfluxPrecision::Secs), Some(-1));
+        assert_eq!(
+            before.to_influx_timestamp(InfluxPrecision::Millis),
+            Some(-500)
+        );
+        let far = NanoTime::new(2300, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(far.to_influx_timestamp(InfluxPrecision::Nanos), None);
+        assert!(far.to_influx_timestamp(InfluxPrecision::Micros).is_some());
+        assert_eq!(InfluxPrecision::default().as_str(), "ns");
+    }
+
+    #[test]
+    fn test_escaping() {
+        let point = LinePoint::new("disk usage,total")
+            .with_tag("path=", "C:\\ data")
+            .with_field("note", r#"say "hi" \o/"#)
+            .with_field("ok", true)
+            .with_field("free", 12u64);
+        assert_eq!(
+            point.to_line(InfluxPrecision::Nanos).unwrap(),
+            r#"disk\ usage\,total,path\==C:\\ data note="say \"hi\" \\o/",ok=true,free=12u"#
+        );
+    }
+
+    #[test]
+    fn test_line_breaks_cannot_start_a_new_point() {
+        let point = LinePoint::new("cpu\r\nevil")
+            .with_tag("host", "web\nevil,host=x v=1i 0")
+            .with_field("k\tey", "line one\nline two")
+            .with_field("nan", f64::NAN)
+            .with_field("inf", f64::NEG_INFINITY);
+        let line = point.to_line(InfluxPrecision::Nanos).unwrap();
+        assert_eq!(
+            line,
+            "cpu\\r\\nevil,host=web\\nevil\\,host\\=x\\ v\\=1i\\ 0 k\\tey=\"line one\nline two\""
+        );
+        // The only line break left is inside the quoted string.
+        assert_eq!(line.lines().count(), 2);
+        assert!(line.split('"').step_by(2).all(|s| !s.contains('\n')));
+    }
+
+    #[test]
+    fn test_time_out_of_range_is_refused() {
+        let far = NanoTime::new(2300, 1, 1, 0, 0, 0, 0).unwrap();
+        let point = LinePoint::new("m").with_field("v", -1.5).with_time(far);
+        assert_eq!(point.to_line(InfluxPrecision::Nanos), None);
+        let mut out = String::from("kept\n");
+        assert_eq!(point.write_line(&mut out, InfluxPrecision::Nanos), None);
+        assert_eq!(out, "kept\n");
+        assert_eq!(
+            point.to_line(InfluxPrecision::Secs).unwrap(),
+            "m v=-1.5 10413792000"
+        );
+        let untimed = LinePoint::new("m").with_field("v", 1i64);
+        assert_eq!(untimed.to_line(InfluxPrecision::Nanos).unwrap(), "m v=1i");
+    }
+}
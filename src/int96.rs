@@ -0,0 +1,102 @@
+//! Parquet's deprecated INT96 timestamps.
+//!
+//! Impala, Hive, and older Spark versions write timestamps as a 12-byte
+//! INT96: a little-endian signed 64-bit count of nanoseconds since
+//! midnight, followed by a little-endian signed 32-bit Julian Day Number.
+//! Modern writers use INT64 with a `TIMESTAMP` logical type instead, but
+//! data lakes still hold many files in the old encoding.
+
+use crate::{NanoTime, NANOS_PER_DAY};
+
+/// The Julian Day Number of 1970-01-01.
+const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
+
+impl NanoTime {
+    /// Decodes a Parquet INT96 timestamp. Returns `None` if the nanoseconds
+    /// fall outside a day or the year outside `0..=65535`.
+    ///
+    /// Days count in the proleptic Gregorian calendar. Spark 2.x wrote
+    /// dates before 1582-10-15 in the Julian calendar instead, so those
+    /// come out shifted by up to ten days unless the writer rebased them.
+    /// Whether the value is UTC or local depends on the writer: Impala
+    /// stores local wall time unless configured otherwise.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+    /// let int96 = t.to_int96();
+    /// assert_eq!(&int96[8..], &2_461_094i32.to_le_bytes());
+    /// assert_eq!(NanoTime::from_int96(&int96), Some(t));
+    /// ```
+    pub fn from_int96(bytes: &[u8; 12]) -> Option<Self> {
+        let nanos = i64::from_le_bytes(bytes[..8].try_into().ok()?);
+        let julian_day = i32::from_le_bytes(bytes[8..].try_into().ok()?);
+        if !(0..NANOS_PER_DAY as i64).contains(&nanos) {
+            return None;
+        }
+        let days = julian_day as i64 - JULIAN_DAY_OF_EPOCH;
+        let secs = days * 86_400 + nanos / 1_000_000_000;
+        Self::checked_from_unix(secs, (nanos % 1_000_000_000) as u32)
+    }
+
+    /// Encodes this time as a Parquet INT96 timestamp, for writers that
+    /// must match a legacy table.
+    pub fn to_int96(&self) -> [u8; 12] {
+        let secs = self.unix_secs();
+        let days = secs.div_euclid(86_400);
+        let nanos = secs.rem_euclid(86_400) * 1_000_000_000 + self.nanosecond() as i64;
+        let julian_day = (days + JULIAN_DAY_OF_EPOCH) as i32;
+        let mut out = [0u8; 12];
+        out[..8].copy_from_slice(&nanos.to_le_bytes());
+        out[8..].copy_from_slice(&julian_day.to_le_bytes());
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int96(nanos: i64, julian_day: i32) -> [u8; 12] {
+        let mut out = [0u8; 12];
+        out[..8].copy_from_slice(&nanos.to_le_bytes());
+        out[8..].copy_from_slice(&julian_day.to_le_bytes());
+        out
+    }
+
+    #[test]
+    fn test_epoch() {
+        assert_eq!(
+            NanoTime::from_int96(&int96(0, 2_440_588)),
+            Some(NanoTime::from_epoch(0))
+        );
+        assert_eq!(NanoTime::from_epoch(0).to_int96(), int96(0, 2_440_588));
+        let before = NanoTime::new(1969, 12, 31, 23, 59, 59, 1).unwrap();
+        assert_eq!(
+            before.to_int96(),
+            int96(NANOS_PER_DAY as i64 - 999_999_999, 2_440_587)
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid() {
+        assert_eq!(NanoTime::from_int96(&int96(-1, 2_440_588)), None);
+        assert_eq!(
+            NanoTime::from_int96(&int96(NANOS_PER_DAY as i64, 2_440_588)),
+            None
+        );
+        assert_eq!(NanoTime::from_int96(&int96(0, i32::MAX)), None);
+        assert_eq!(NanoTime::from_int96(&int96(0, 0)), None);
+    }
+
+    #[test]
+    fn test_round_trip_extremes() {
+        for t in [
+            NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap(),
+            NanoTime::new(65535, 12, 31, 23, 59, 59, 999_999_999).unwrap(),
+        ] {
+            assert_eq!(NanoTime::from_int96(&t.to_int96()), Some(t));
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Timing the processing of each item of an iterator.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::checkpoint::human;
+use crate::{LatencyHistogram, TimingStats};
+
+/// Something measured durations can be added to.
+///
+/// Implemented for [`TimingStats`], [`LatencyHistogram`], mutable
+/// references to either, and pairs, so one [`IteratorExt::timed`] adapter
+/// can feed both a summary and a histogram.
+pub trait RecordDuration {
+    /// Adds one measurement.
+    fn record_duration(&mut self, d: Duration);
+}
+
+impl RecordDuration for TimingStats {
+    fn record_duration(&mut self, d: Duration) {
+        self.record(d);
+    }
+}
+
+impl RecordDuration for LatencyHistogram {
+    fn record_duration(&mut self, d: Duration) {
+        self.record(d);
+    }
+}
+
+impl<T: RecordDuration + ?Sized> RecordDuration for &mut T {
+    fn record_duration(&mut self, d: Duration) {
+        (**self).record_duration(d);
+    }
+}
+
+impl<A: RecordDuration, B: RecordDuration> RecordDuration for (A, B) {
+    fn record_duration(&mut self, d: Duration) {
+        self.0.record_duration(d);
+        self.1.record_duration(d);
+    }
+}
+
+/// Adds [`timed`](IteratorExt::timed) to every iterator.
+pub trait IteratorExt: Iterator + Sized {
+    /// Measures how long each item takes to process and records it in
+    /// `sink`.
+    ///
+    /// An item's processing time runs from when the adapter yields it until
+    /// the next item is requested, which in a `for` loop is one pass of the
+    /// loop body. The time the underlying iterator takes to produce items is
+    /// not counted. If the loop ends early, the last item is recorded when
+    /// the adapter is dropped.
+    ///
+    /// ```rust
+    /// use nanotime::{IteratorExt, LatencyHistogram, TimingStats};
+    ///
+    /// let mut stats = TimingStats::new();
+    /// let mut latency = LatencyHistogram::new();
+    /// for line in ["a", "b", "c"].iter().timed((&mut stats, &mut latency)) {
+    ///     let _ = line.len();
+    /// }
+    /// assert_eq!(stats.count(), 3);
+    /// assert_eq!(latency.count(), 3);
+    /// ```
+    fn timed<'a, S: RecordDuration>(self, sink: S) -> Timed<'a, Self, S> {
+        Timed {
+            iter: self,
+            sink,
+            slow: None,
+            started: None,
+            index: 0,
+        }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Called with the index and processing time of a slow item.
+type SlowItem<'a> = Box<dyn FnMut(usize, Duration) + 'a>;
+
+/// The iterator returned by [`IteratorExt::timed`].
+pub struct Timed<'a, I, S: RecordDuration> {
+    iter: I,
+    sink: S,
+    slow: Option<(Duration, SlowItem<'a>)>,
+    /// When the item being processed was yielded.
+    started: Option<Instant>,
+    /// The index of the next item to be recorded.
+    index: usize,
+}
+
+impl<'a, I: Iterator, S: RecordDuration> Timed<'a, I, S> {
+    /// Calls `f` with the index and processing time of each item that takes
+    /// longer than `threshold`.
+    pub fn on_slow(mut self, threshold: Duration, f: impl FnMut(usize, Duration) + 'a) -> Self {
+        self.slow = Some((threshold, Box::new(f)));
+        self
+    }
+
+    /// Prints the index and processing time of each item that takes longer
+    /// than `threshold` to standard error.
+    pub fn log_slow(self, threshold: Duration) -> Self {
+        self.on_slow(threshold, move |index, took| {
+            eprintln!(
+                "slow item {}: took {} (threshold {})",
+                index,
+                human(took),
+                human(threshold)
+            )
+        })
+    }
+}
+
+impl<I, S: RecordDuration> Timed<'_, I, S> {
+    fn finish_item(&mut self) {
+        let Some(started) = self.started.take() else {
+            return;
+        };
+        let took = started.elapsed();
+        self.sink.record_duration(took);
+        if let Some((threshold, f)) = &mut self.slow {
+            if took > *threshold {
+                f(self.index, took);
+            }
+        }
+        self.index += 1;
+    }
+}
+
+impl<I: fmt::Debug, S: RecordDuration + fmt::Debug> fmt::Debug for Timed<'_, I, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timed")
+            .field("iter", &self.iter)
+            .field("sink", &self.sink)
+            .field("slow_threshold", &self.slow.as_ref().map(|(t, _)| t))
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<I: Iterator, S: RecordDuration> Iterator for Timed<'_, I, S> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.finish_item();
+        let item = self.iter.next()?;
+        self.started = Some(Instant::now());
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, S: RecordDuration> Drop for Timed<'_, I, S> {
+    fn drop(&mut self) {
+        self.finish_item();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_loop_body() {
+        let mut stats = TimingStats::new();
+        let mut slow = Vec::new();
+        for ms in [0u64, 5, 0, 5]
+            .into_iter()
+            .timed(&mut stats)
+            .on_slow(Duration::from_millis(4), |i, _| slow.push(i))
+        {
+            std::thread::sleep(Duration::from_millis(ms));
+        }
+        assert_eq!(stats.count(), 4);
+        assert!(stats.max().unwrap() >= Duration::from_millis(5));
+        assert_eq!(slow, [1, 3]);
+    }
+
+    #[test]
+    fn test_early_exit_records_last_item() {
+        let mut hist = LatencyHistogram::new();
+        for n in (0..10).timed(&mut hist) {
+            if n == 2 {
+                break;
+            }
+        }
+        assert_eq!(hist.count(), 3);
+
+        let mut stats = TimingStats::new();
+        let mut it = (0..10).timed(&mut stats).log_slow(Duration::from_secs(60));
+        assert_eq!(it.next(), Some(0));
+        assert_eq!(it.size_hint(), (9, Some(9)));
+        drop(it);
+        assert_eq!(stats.count(), 1);
+    }
+}
@@ -0,0 +1,272 @@
+//! systemd journal timestamps.
+//!
+//! Journal entries carry `__REALTIME_TIMESTAMP`, microseconds since the
+//! Unix epoch, and `__MONOTONIC_TIMESTAMP`, microseconds of
+//! `CLOCK_MONOTONIC` since the boot named by `_BOOT_ID`. The export format
+//! writes both as decimal text.
+
+use std::fmt::Write;
+
+use crate::parse::{civil, ParseError, Scanner, MONTHS, OUT_OF_RANGE};
+use crate::{NanoTime, OffsetNanoTime, UtcOffset};
+
+/// A timestamp style of `journalctl --output`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JournalFormat {
+    /// `Feb 22 14:30:05`, the default `short` output.
+    Short,
+    /// `Feb 22 14:30:05.123456`, from `short-precise`.
+    ShortPrecise,
+    /// `2026-02-22T14:30:05+01:00`, from `short-iso`.
+    ShortIso,
+    /// `2026-02-22T14:30:05.123456+01:00`, from `short-iso-precise`.
+    ShortIsoPrecise,
+    /// `1771770605.123456`, from `short-unix`.
+    ShortUnix,
+}
+
+impl NanoTime {
+    /// Converts a `__REALTIME_TIMESTAMP` value. Returns `None` if the year
+    /// would exceed 65535.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::from_journal_realtime(1_771_770_605_123_456).unwrap();
+    /// assert_eq!(t.to_rfc3339(), "2026-02-22T14:30:05.123456Z");
+    /// assert_eq!(t.to_journal_realtime(), Some(1_771_770_605_123_456));
+    /// ```
+    pub fn from_journal_realtime(usec: u64) -> Option<Self> {
+        let secs = (usec / 1_000_000) as i64;
+        Self::checked_from_unix(secs, (usec % 1_000_000) as u32 * 1_000)
+    }
+
+    /// Returns this time as a `__REALTIME_TIMESTAMP`, truncating to
+    /// microseconds. Returns `None` before 1970.
+    pub fn to_journal_realtime(&self) -> Option<u64> {
+        let secs = u64::try_from(self.unix_secs()).ok()?;
+        Some(secs * 1_000_000 + (self.nanosecond() / 1_000) as u64)
+    }
+
+    /// Converts a `__MONOTONIC_TIMESTAMP` value to wall time, given the UTC
+    /// time its boot began, such as [`boot_time`](crate::boot_time) for
+    /// the current boot.
+    ///
+    /// The monotonic clock stops while the system is suspended, so entries
+    /// logged after a suspend come out early by the time spent asleep.
+    /// Prefer `__REALTIME_TIMESTAMP` when the entry has one.
+    pub fn from_journal_monotonic(usec: u64, boot_time: &NanoTime) -> Option<Self> {
+        boot_time.checked_add_nanos(usec as i128 * 1_000)
+    }
+}
+
+impl Scanner<'_> {
+    /// `±HH:MM` or `±HHMM`, as written by different systemd versions.
+    fn journal_offset(&mut self) -> Result<UtcOffset, ParseError> {
+        let sign = if self.eat('+') {
+            1
+        } else if self.eat('-') {
+            -1
+        } else {
+            return Err(self.error("UTC offset"));
+        };
+        let hours = self.number(2, 0, 23, "offset hours")? as i32;
+        self.eat(':');
+        let minutes = self.number(2, 0, 59, "offset minutes")? as i32;
+        Ok(UtcOffset::from_secs(sign * (hours * 3600 + minutes * 60))
+            .expect("offsets under 24 hours are in range"))
+    }
+
+    /// `<epoch seconds>.<fraction>`
+    fn journal_unix(&mut self) -> Result<OffsetNanoTime, ParseError> {
+        let secs = self.integer("epoch seconds")?;
+        let nanos = if self.eat('.') { self.fraction()? } else { 0 };
+        let utc = NanoTime::checked_from_unix(secs, nanos)
+            .ok_or_else(|| self.error("epoch seconds within years 0-65535"))?;
+        Ok(OffsetNanoTime::from_utc(&utc, UtcOffset::UTC))
+    }
+
+    /// `YYYY-MM-DDTHH:MM:SS[.ffffff]±HH:MM`
+    fn journal_iso(&mut self) -> Result<OffsetNanoTime, ParseError> {
+        let date = self.date()?;
+        self.literal('T')?;
+        let time = self.time()?;
+        let offset = self.journal_offset()?;
+        Ok(OffsetNanoTime::new(civil(date, time), offset))
+    }
+}
+
+impl NanoTime {
+    /// Parses a timestamp from `journalctl` output in the `short-iso`,
+    /// `short-iso-precise`, or `short-unix` style and returns the instant
+    /// in UTC.
+    ///
+    /// The `short` and `short-precise` styles omit the year and offset, so
+    /// they cannot be read back into an instant. An offset that moves the
+    /// instant outside years 0-65535 is an error.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let a = NanoTime::parse_journalctl("2026-02-22T15:30:05.123456+01:00").unwrap();
+    /// let b = NanoTime::parse_journalctl("1771770605.123456").unwrap();
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn parse_journalctl(input: &str) -> Result<Self, ParseError> {
+        let t = OffsetNanoTime::parse_journalctl(input)?;
+        t.checked_to_utc().ok_or_else(|| {
+            // Only `short-iso` carries an offset, and its sign is the last
+            // '+' or '-' of the input.
+            let at = input.rfind(['+', '-']).unwrap_or(0);
+            Scanner::new(input).error_at(at, input.len() - at, OUT_OF_RANGE)
+        })
+    }
+}
+
+impl OffsetNanoTime {
+    /// Parses a `short-iso`, `short-iso-precise`, or `short-unix`
+    /// timestamp, keeping the offset as written. `short-unix` values get a
+    /// UTC offset. Offsets are accepted with or without a colon, as older
+    /// systemd versions omit it.
+    pub fn parse_journalctl(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let is_iso = input.as_bytes().get(4) == Some(&b'-');
+        let t = if is_iso {
+            s.journal_iso()?
+        } else {
+            s.journal_unix()?
+        };
+        s.finish()?;
+        Ok(t)
+    }
+
+    /// Formats the local time the way `journalctl` prints it in `format`,
+    /// to microseconds.
+    ///
+    /// `journalctl` prints times in the host's zone; pass
+    /// [`TimeZone::to_local`](crate::tz::TimeZone::to_local) output to match
+    /// it, or a UTC [`OffsetNanoTime`] to match `--utc`.
+    ///
+    /// ```rust
+    /// use nanotime::{JournalFormat, NanoTime, OffsetNanoTime, UtcOffset};
+    ///
+    /// let local = NanoTime::new(2026, 2, 22, 15, 30, 5, 123_456_789).unwrap();
+    /// let t = OffsetNanoTime::new(local, UtcOffset::from_minutes(60).unwrap());
+    /// assert_eq!(t.to_journalctl(JournalFormat::Short), "Feb 22 15:30:05");
+    /// assert_eq!(
+    ///     t.to_journalctl(JournalFormat::ShortIsoPrecise),
+    ///     "2026-02-22T15:30:05.123456+01:00"
+    /// );
+    /// assert_eq!(t.to_journalctl(JournalFormat::ShortUnix), "1771770605.123456");
+    /// ```
+    pub fn to_journalctl(&self, format: JournalFormat) -> String {
+        let t = self.local();
+        let micros = t.nanosecond() / 1_000;
+        let mut out = String::with_capacity(32);
+        let _ = match format {
+            JournalFormat::Short | JournalFormat::ShortPrecise => write!(
+                out,
+                "{} {:02} {:02}:{:02}:{:02}",
+                MONTHS[t.month() as usize - 1],
+                t.day(),
+                t.hour(),
+                t.minute(),
+                t.second()
+            ),
+            JournalFormat::ShortIso | JournalFormat::ShortIsoPrecise => write!(
+                out,
+                "{}T{:02}:{:02}:{:02}",
+                t.date(),
+                t.hour(),
+                t.minute(),
+                t.second()
+            ),
+            JournalFormat::ShortUnix => write!(out, "{}", self.to_utc().unix_secs()),
+        };
+        if matches!(
+            format,
+            JournalFormat::ShortPrecise | JournalFormat::ShortIsoPrecise | JournalFormat::ShortUnix
+        ) {
+            let _ = write!(out, ".{:06}", micros);
+        }
+        if matches!(
+            format,
+            JournalFormat::ShortIso | JournalFormat::ShortIsoPrecise
+        ) {
+            let _ = write!(out, "{}", self.offset());
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cet(local: NanoTime) -> OffsetNanoTime {
+        OffsetNanoTime::new(local, UtcOffset::from_minutes(60).unwrap())
+    }
+
+    #[test]
+    fn test_realtime() {
+        assert_eq!(
+            NanoTime::from_journal_realtime(0),
+            Some(NanoTime::from_epoch(0))
+        );
+        let before = NanoTime::new(1969, 12, 31, 0, 0, 0, 0).unwrap();
+        assert_eq!(before.to_journal_realtime(), None);
+        assert_eq!(NanoTime::from_journal_realtime(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_monotonic() {
+        let boot = NanoTime::new(2026, 2, 22, 8, 0, 0, 0).unwrap();
+        let t = NanoTime::from_journal_monotonic(3_600_000_001, &boot).unwrap();
+        assert_eq!(t, NanoTime::new(2026, 2, 22, 9, 0, 0, 1_000).unwrap());
+    }
+
+    #[test]
+    fn test_formats() {
+        let t = cet(NanoTime::new(2026, 3, 1, 9, 5, 0, 7_000).unwrap());
+        assert_eq!(t.to_journalctl(JournalFormat::Short), "Mar 01 09:05:00");
+        assert_eq!(
+            t.to_journalctl(JournalFormat::ShortPrecise),
+            "Mar 01 09:05:00.000007"
+        );
+        assert_eq!(
+            t.to_journalctl(JournalFormat::ShortIso),
+            "2026-03-01T09:05:00+01:00"
+        );
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let t = cet(NanoTime::new(2026, 2, 22, 15, 30, 5, 123_456_000).unwrap());
+        for format in [JournalFormat::ShortIsoPrecise, JournalFormat::ShortUnix] {
+            let text = t.to_journalctl(format);
+            let parsed = OffsetNanoTime::parse_journalctl(&text).unwrap();
+            assert_eq!(parsed.to_utc(), t.to_utc(), "{}", text);
+        }
+        assert_eq!(
+            OffsetNanoTime::parse_journalctl("2026-02-22T15:30:05.123456+01:00"),
+            Ok(t)
+        );
+        // Older systemd writes the offset without a colon.
+        let old = OffsetNanoTime::parse_journalctl("2026-02-22T15:30:05+0100").unwrap();
+        assert_eq!(old.offset(), t.offset());
+    }
+
+    #[test]
+    fn test_parse_rejections() {
+        let err = |input| NanoTime::parse_journalctl(input).unwrap_err();
+        assert_eq!(err("Feb 22 15:30:05").expected(), "epoch seconds");
+        assert_eq!(err("2026-02-22T15:30:05").expected(), "UTC offset");
+        assert_eq!(err("1771770605.").expected(), "fractional seconds");
+        let e = err("0000-01-01T00:30:00+01:00");
+        assert_eq!(
+            (e.offset(), e.found(), e.expected()),
+            (19, "+01:00", OUT_OF_RANGE)
+        );
+        assert!(OffsetNanoTime::parse_journalctl("0000-01-01T00:30:00+01:00").is_ok());
+    }
+}
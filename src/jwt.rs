@@ -0,0 +1,143 @@
+//! JWT `NumericDate` values (RFC 7519 §2) and claim time checks.
+
+use std::time::Duration;
+
+use crate::{NanoTime, NANOS_PER_SEC};
+
+fn leeway_nanos(leeway: Duration) -> i128 {
+    leeway.as_nanos().min(i128::MAX as u128) as i128
+}
+
+impl NanoTime {
+    /// Returns this time, taken as UTC, as a JWT `NumericDate`: whole
+    /// seconds since the Unix epoch. The fraction is truncated.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 900_000_000).unwrap();
+    /// assert_eq!(t.to_numeric_date(), 1_771_770_605);
+    /// ```
+    pub fn to_numeric_date(&self) -> i64 {
+        self.unix_secs()
+    }
+
+    /// Reads an integer `NumericDate` such as an `exp`, `iat`, or `nbf`
+    /// claim. Returns `None` if the year would fall outside `0..=65535`.
+    pub fn from_numeric_date(secs: i64) -> Option<Self> {
+        NanoTime::checked_from_unix(secs, 0)
+    }
+
+    /// Reads a fractional `NumericDate`, which RFC 7519 permits, rounding
+    /// to the nearest nanosecond. Returns `None` for NaN, infinities, and
+    /// values whose year would fall outside `0..=65535`.
+    pub fn from_numeric_date_f64(secs: f64) -> Option<Self> {
+        if !secs.is_finite() {
+            return None;
+        }
+        let whole = secs.floor();
+        if !(i64::MIN as f64..i64::MAX as f64).contains(&whole) {
+            return None;
+        }
+        let (mut whole, mut nanos) = (whole as i64, ((secs - whole) * 1e9).round() as u32);
+        if nanos >= NANOS_PER_SEC as u32 {
+            whole += 1;
+            nanos -= NANOS_PER_SEC as u32;
+        }
+        NanoTime::checked_from_unix(whole, nanos)
+    }
+
+    /// Checks an `exp` claim: returns `true` if `now` is at or past this
+    /// expiration time plus `leeway`. Both times are UTC.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use nanotime::NanoTime;
+    ///
+    /// let exp = NanoTime::from_numeric_date(1_771_770_600).unwrap();
+    /// let now = NanoTime::from_numeric_date(1_771_770_630).unwrap();
+    /// assert!(exp.is_expired_at(&now, Duration::ZERO));
+    /// assert!(!exp.is_expired_at(&now, Duration::from_secs(60)));
+    /// ```
+    pub fn is_expired_at(&self, now: &NanoTime, leeway: Duration) -> bool {
+        now.unix_nanos() >= self.unix_nanos().saturating_add(leeway_nanos(leeway))
+    }
+
+    /// [`is_expired_at`](Self::is_expired_at) against the current UTC time.
+    pub fn is_expired(&self, leeway: Duration) -> bool {
+        self.is_expired_at(&NanoTime::now_utc(), leeway)
+    }
+
+    /// Checks an `nbf` claim: returns `true` if `now` is still before this
+    /// not-before time minus `leeway`. Both times are UTC.
+    pub fn is_not_yet_valid_at(&self, now: &NanoTime, leeway: Duration) -> bool {
+        now.unix_nanos() < self.unix_nanos().saturating_sub(leeway_nanos(leeway))
+    }
+
+    /// [`is_not_yet_valid_at`](Self::is_not_yet_valid_at) against the
+    /// current UTC time.
+    pub fn is_not_yet_valid(&self, leeway: Duration) -> bool {
+        self.is_not_yet_valid_at(&NanoTime::now_utc(), leeway)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: i64) -> NanoTime {
+        NanoTime::from_numeric_date(secs).unwrap()
+    }
+
+    #[test]
+    fn test_numeric_date_round_trip() {
+        for secs in [0, 1_771_770_605, -86_400, 253_402_300_799] {
+            assert_eq!(at(secs).to_numeric_date(), secs);
+        }
+        assert_eq!(NanoTime::from_numeric_date(i64::MAX), None);
+        assert_eq!(NanoTime::from_numeric_date(-62_167_219_201), None);
+    }
+
+    #[test]
+    fn test_numeric_date_f64() {
+        let t = NanoTime::from_numeric_date_f64(1_771_770_605.25).unwrap();
+        assert_eq!(
+            t,
+            NanoTime::new(2026, 2, 22, 14, 30, 5, 250_000_000).unwrap()
+        );
+        let t = NanoTime::from_numeric_date_f64(-0.5).unwrap();
+        assert_eq!(
+            t,
+            NanoTime::new(1969, 12, 31, 23, 59, 59, 500_000_000).unwrap()
+        );
+        // Rounding up to a whole second carries.
+        let t = NanoTime::from_numeric_date_f64(1.999_999_999_9).unwrap();
+        assert_eq!(t, at(2));
+        assert_eq!(NanoTime::from_numeric_date_f64(f64::NAN), None);
+        assert_eq!(NanoTime::from_numeric_date_f64(f64::INFINITY), None);
+        assert_eq!(NanoTime::from_numeric_date_f64(1e300), None);
+    }
+
+    #[test]
+    fn test_expiry_with_leeway() {
+        let exp = at(1_000);
+        let leeway = Duration::from_secs(30);
+        assert!(!exp.is_expired_at(&at(999), Duration::ZERO));
+        assert!(exp.is_expired_at(&at(1_000), Duration::ZERO));
+        assert!(!exp.is_expired_at(&at(1_029), leeway));
+        assert!(exp.is_expired_at(&at(1_030), leeway));
+        assert!(!exp.is_expired_at(&at(0), Duration::MAX));
+        assert!(at(0).is_expired(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_not_before_with_leeway() {
+        let nbf = at(1_000);
+        let leeway = Duration::from_secs(30);
+        assert!(nbf.is_not_yet_valid_at(&at(999), Duration::ZERO));
+        assert!(!nbf.is_not_yet_valid_at(&at(1_000), Duration::ZERO));
+        assert!(!nbf.is_not_yet_valid_at(&at(970), leeway));
+        assert!(nbf.is_not_yet_valid_at(&at(969), leeway));
+        assert!(!at(0).is_not_yet_valid(Duration::ZERO));
+    }
+}
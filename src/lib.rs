@@ -33,6 +33,123 @@
 use std::fmt;
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod atomic;
+mod audio;
+mod avro;
+#[cfg(feature = "bson")]
+pub mod bson;
+mod budget;
+mod business;
+mod checkpoint;
+mod clock;
+mod coarse;
+mod column;
+mod countdown;
+mod cpu;
+mod deadline;
+mod diagnostics;
+mod dos;
+mod duration;
+mod fiscal;
+mod fix;
+pub mod format;
+mod frame;
+pub mod fs;
+mod group;
+mod influx;
+mod int96;
+mod iter;
+mod journal;
+mod jwt;
+mod market;
+mod metronome;
+#[cfg(feature = "otel")]
+pub mod otel;
+pub mod parse;
+mod pcap;
+mod precise;
+pub mod profiler;
+mod prometheus;
+mod prorate;
+#[cfg(feature = "prost")]
+pub mod prost;
+mod rate;
+mod replay;
+mod retry;
+pub mod rewrite;
+mod round;
+mod sequencer;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod sim;
+mod snapshot;
+#[cfg(feature = "solar")]
+pub mod solar;
+mod stamp;
+mod stats;
+#[cfg(feature = "toml")]
+pub mod toml;
+pub mod tz;
+mod uptime;
+mod video;
+mod wait;
+mod watch;
+mod week;
+mod window;
+mod wire;
+
+pub use atomic::AtomicNanoTime;
+pub use audio::{duration_to_samples, samples_to_duration, SampleClock};
+pub use avro::AvroTimestamp;
+pub use budget::{Budget, BudgetReport, StageBudget};
+pub use business::{add_business_duration, BusinessSchedule};
+pub use checkpoint::{Checkpoint, CheckpointReport};
+pub use clock::{Clock, SkewedClock, SystemClock};
+pub use coarse::CoarseClock;
+pub use column::NanoTimeColumn;
+pub use countdown::{format_countdown, CountdownFormat, CountdownUnit};
+pub use cpu::{CpuTime, CpuTimer, CpuUsage, ThreadCpuTimer};
+pub use deadline::{run_with_deadline, Deadline, DeadlineExceeded};
+pub use diagnostics::{diagnostics, Diagnostics, LocalZoneSource};
+pub use duration::NanoDuration;
+pub use fiscal::{FiscalCalendar, WeekPattern};
+pub use format::{BulkFormatter, Dialect, Format, Precision};
+#[cfg(feature = "locale")]
+pub use format::{DateStyle, TimeStyle};
+pub use frame::{FixedTimestep, FrameLimiter};
+pub use group::{group_by, group_fold, Component};
+pub use influx::{FieldValue, InfluxPrecision, LinePoint};
+pub use iter::{IteratorExt, RecordDuration, Timed};
+pub use journal::JournalFormat;
+pub use market::SessionRollover;
+pub use metronome::{Metronome, MissedTick};
+pub use parse::{ParseError, PartialNanoTime};
+pub use pcap::PcapResolution;
+pub use precise::{
+    calibrate, compensated_sleep, compensated_sleep_until, sleep_overshoot, SleepCalibration,
+};
+pub use profiler::ScopedTimer;
+pub use prometheus::{align_to_scrape_interval, scrape_times};
+pub use prorate::prorate;
+pub use rate::{Debouncer, Throttle, TokenBucket};
+pub use replay::{ReplayClock, ReplayMode};
+pub use retry::{Backoff, RetrySchedule};
+pub use round::RoundMode;
+pub use sequencer::Sequencer;
+pub use sim::SimClock;
+pub use snapshot::{CheckpointSnapshot, HistogramSnapshot, TimingSnapshot};
+pub use stamp::Stamp;
+pub use stats::{inter_arrival_stats, InterArrivalStats, LatencyHistogram, TimingStats};
+pub use tz::{OffsetNanoTime, TimeZone, UtcOffset};
+pub use uptime::{boot_time, format_uptime, format_uptime_iso, uptime};
+pub use video::{duration_to_pts, pts_to_duration, FrameRate, PTS_CLOCK_RATE};
+pub use wait::{park_until, sleep_until, wait_until, wait_until_while};
+pub use watch::{ClockJump, ClockWatch};
+pub use week::{week_starts, WeekScheme, Weekday};
+pub use window::RecentWindow;
+
 // Platform-specific FFI for local time resolution.
 
 #[cfg(unix)]
@@ -65,33 +182,32 @@ mod platform {
         fn clock_gettime(clk_id: i32, tp: *mut Timespec) -> i32;
     }
 
+    const ZERO: NanoTime = NanoTime {
+        year: 0,
+        month: 0,
+        day: 0,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanosecond: 0,
+    };
+
     #[cfg(not(tarpaulin_include))]
-    pub fn now() -> NanoTime {
+    fn realtime() -> Option<Timespec> {
         unsafe {
             let mut ts = std::mem::zeroed::<Timespec>();
-            if clock_gettime(CLOCK_REALTIME, &mut ts) != 0 {
-                return NanoTime {
-                    year: 0,
-                    month: 0,
-                    day: 0,
-                    hour: 0,
-                    minute: 0,
-                    second: 0,
-                    nanosecond: 0,
-                };
-            }
+            (clock_gettime(CLOCK_REALTIME, &mut ts) == 0).then_some(ts)
+        }
+    }
+
+    /// Breaks `ts` down in the host's local zone.
+    #[cfg(not(tarpaulin_include))]
+    fn local(ts: &Timespec) -> NanoTime {
+        unsafe {
             let mut tm = std::mem::zeroed::<Tm>();
             let result = localtime_r(&ts.tv_sec, &mut tm);
             if result.is_null() {
-                return NanoTime {
-                    year: 0,
-                    month: 0,
-                    day: 0,
-                    hour: 0,
-                    minute: 0,
-                    second: 0,
-                    nanosecond: 0,
-                };
+                return ZERO;
             }
             NanoTime {
                 year: (tm.tm_year + 1900) as u16,
@@ -104,6 +220,22 @@ mod platform {
             }
         }
     }
+
+    #[cfg(not(tarpaulin_include))]
+    pub fn now() -> NanoTime {
+        realtime().map_or(ZERO, |ts| local(&ts))
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    pub fn now_pair() -> (NanoTime, NanoTime) {
+        match realtime() {
+            Some(ts) => (
+                local(&ts),
+                NanoTime::from_unix(ts.tv_sec, ts.tv_nsec as u32),
+            ),
+            None => (ZERO, ZERO),
+        }
+    }
 }
 
 #[cfg(windows)]
@@ -124,21 +256,44 @@ mod platform {
 
     extern "system" {
         fn GetLocalTime(lp_system_time: *mut SYSTEMTIME);
+        fn GetSystemTime(lp_system_time: *mut SYSTEMTIME);
+        fn SystemTimeToTzSpecificLocalTime(
+            lp_time_zone_information: *const std::ffi::c_void,
+            lp_universal_time: *const SYSTEMTIME,
+            lp_local_time: *mut SYSTEMTIME,
+        ) -> i32;
+    }
+
+    fn to_nanotime(st: &SYSTEMTIME) -> NanoTime {
+        NanoTime {
+            year: st.w_year,
+            month: st.w_month as u8,
+            day: st.w_day as u8,
+            hour: st.w_hour as u8,
+            minute: st.w_minute as u8,
+            second: st.w_second as u8,
+            nanosecond: st.w_milliseconds as u32 * 1_000_000,
+        }
     }
 
     pub fn now() -> NanoTime {
         unsafe {
             let mut st = std::mem::zeroed::<SYSTEMTIME>();
             GetLocalTime(&mut st as *mut SYSTEMTIME);
-            NanoTime {
-                year: st.w_year,
-                month: st.w_month as u8,
-                day: st.w_day as u8,
-                hour: st.w_hour as u8,
-                minute: st.w_minute as u8,
-                second: st.w_second as u8,
-                nanosecond: st.w_milliseconds as u32 * 1_000_000,
+            to_nanotime(&st)
+        }
+    }
+
+    pub fn now_pair() -> (NanoTime, NanoTime) {
+        unsafe {
+            let mut utc = std::mem::zeroed::<SYSTEMTIME>();
+            GetSystemTime(&mut utc as *mut SYSTEMTIME);
+            let mut local = std::mem::zeroed::<SYSTEMTIME>();
+            let utc_nt = to_nanotime(&utc);
+            if SystemTimeToTzSpecificLocalTime(std::ptr::null(), &utc, &mut local) == 0 {
+                return (utc_nt, utc_nt);
             }
+            (to_nanotime(&local), utc_nt)
         }
     }
 }
@@ -162,15 +317,10 @@ fn days_in_month(year: u16, month: u8) -> u8 {
     }
 }
 
-/// Converts Unix epoch seconds to a NanoTime using Howard Hinnant's civil_from_days algorithm.
-fn epoch_to_date(secs: u64) -> NanoTime {
-    let day_secs = secs % 86400;
-    let hour = (day_secs / 3600) as u8;
-    let minute = ((day_secs % 3600) / 60) as u8;
-    let second = (day_secs % 60) as u8;
-
-    // Days since 1970-01-01
-    let z = (secs / 86400) as i64 + 719468; // shift epoch to 0000-03-01
+/// Converts days since 1970-01-01 to a (year, month, day) triple using
+/// Howard Hinnant's civil_from_days algorithm.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719468; // shift epoch to 0000-03-01
     let era = if z >= 0 { z } else { z - 146096 } / 146097;
     let doe = (z - era * 146097) as u32; // day of era [0, 146096]
     let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // year of era [0, 399]
@@ -179,19 +329,63 @@ fn epoch_to_date(secs: u64) -> NanoTime {
     let mp = (5 * doy + 2) / 153; // month proxy [0, 11]
     let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
     let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
-    let year = (if month <= 2 { y + 1 } else { y }) as u16;
+    (if month <= 2 { y + 1 } else { y }, month, day)
+}
+
+/// Converts a civil date to days since 1970-01-01 using Hinnant's
+/// days_from_civil algorithm.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as u32;
+    let m = month as u32;
+    let d = day as u32;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe as i64 - 719468
+}
+
+/// Nanoseconds in one second.
+pub(crate) const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// Nanoseconds in one day.
+pub(crate) const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SEC;
+
+/// Unix seconds of `0000-01-01 00:00:00`, the first representable second.
+pub(crate) const MIN_UNIX_SECS: i64 = -62_167_219_200;
+
+/// Unix seconds of `65535-12-31 23:59:59`, the last representable second.
+pub(crate) const MAX_UNIX_SECS: i64 = 2_005_949_145_599;
+
+/// Converts signed Unix epoch seconds to a NanoTime with `nanosecond: 0`.
+fn unix_to_date(secs: i64) -> NanoTime {
+    let day_secs = secs.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(secs.div_euclid(86400));
 
     NanoTime {
-        year,
+        year: year as u16,
         month,
         day,
-        hour,
-        minute,
-        second,
+        hour: (day_secs / 3600) as u8,
+        minute: ((day_secs % 3600) / 60) as u8,
+        second: (day_secs % 60) as u8,
         nanosecond: 0,
     }
 }
 
+/// Converts Unix epoch seconds to a NanoTime using Howard Hinnant's civil_from_days algorithm.
+fn epoch_to_date(secs: u64) -> NanoTime {
+    unix_to_date(secs as i64)
+}
+
+/// Nanoseconds since the Unix epoch by the system clock, 0 if the clock is
+/// set before 1970.
+fn system_nanos() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos() as u64)
+}
+
 /// A nanosecond-precision timestamp.
 ///
 /// `NanoTime` stores a calendar date and time with nanosecond resolution.
@@ -310,6 +504,35 @@ impl NanoTime {
         nt
     }
 
+    /// Returns the current local and UTC times, in that order, from a single
+    /// clock read.
+    ///
+    /// Calling [`now`](Self::now) and [`now_utc`](Self::now_utc) separately
+    /// reads the clock twice, so the two can straddle a second boundary and
+    /// disagree. Both halves of the pair always denote the same instant.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let (local, utc) = NanoTime::now_pair();
+    /// assert_eq!(local.nanosecond(), utc.nanosecond());
+    /// ```
+    pub fn now_pair() -> (Self, Self) {
+        platform::now_pair()
+    }
+
+    /// Returns the current wall-clock time in `zone`.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, TimeZone, UtcOffset};
+    ///
+    /// let tokyo = TimeZone::fixed(UtcOffset::from_minutes(9 * 60).unwrap());
+    /// println!("{}", NanoTime::now_in(&tokyo).datetime());
+    /// ```
+    pub fn now_in(zone: &TimeZone) -> Self {
+        OffsetNanoTime::now_in(zone).local()
+    }
+
     /// Constructs a NanoTime from Unix epoch seconds.
     /// Ergonomic wrapper around `epoch_to_date`.
     pub fn from_epoch(secs: u64) -> Self {
@@ -402,22 +625,84 @@ impl NanoTime {
     /// Converts this NanoTime back to Unix epoch seconds.
     /// Reverse of `epoch_to_date` using Hinnant's `days_from_civil` algorithm.
     pub fn to_epoch_secs(&self) -> u64 {
-        let y = if self.month <= 2 {
-            self.year as i64 - 1
-        } else {
-            self.year as i64
-        };
-        let era = if y >= 0 { y } else { y - 399 } / 400;
-        let yoe = (y - era * 400) as u32;
-        let m = self.month as u32;
-        let d = self.day as u32;
-        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
-        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
-        let days = era * 146097 + doe as i64 - 719468;
-        (days as u64) * 86400
-            + self.hour as u64 * 3600
-            + self.minute as u64 * 60
-            + self.second as u64
+        self.unix_secs() as u64
+    }
+
+    /// Signed Unix epoch seconds, valid for dates before 1970 as well.
+    pub(crate) fn unix_secs(&self) -> i64 {
+        days_from_civil(self.year as i64, self.month, self.day) * 86400
+            + self.hour as i64 * 3600
+            + self.minute as i64 * 60
+            + self.second as i64
+    }
+
+    /// Day of the week, 0 for Sunday through 6 for Saturday.
+    pub(crate) fn weekday_index(&self) -> usize {
+        // 1970-01-01 was a Thursday.
+        (days_from_civil(self.year as i64, self.month, self.day) + 4).rem_euclid(7) as usize
+    }
+
+    /// Day of the year, 1 for January 1st.
+    pub(crate) fn day_of_year(&self) -> u16 {
+        let year = self.year as i64;
+        (days_from_civil(year, self.month, self.day) - days_from_civil(year, 1, 1) + 1) as u16
+    }
+
+    /// Builds a NanoTime from signed Unix epoch seconds and a sub-second part.
+    pub(crate) fn from_unix(secs: i64, nanosecond: u32) -> Self {
+        let mut nt = unix_to_date(secs);
+        nt.nanosecond = nanosecond;
+        nt
+    }
+
+    /// Signed nanoseconds since the Unix epoch, valid before 1970 as well.
+    pub(crate) fn unix_nanos(&self) -> i128 {
+        self.unix_secs() as i128 * 1_000_000_000 + self.nanosecond as i128
+    }
+
+    /// Builds a NanoTime from signed Unix epoch nanoseconds.
+    pub(crate) fn from_unix_nanos(nanos: i128) -> Self {
+        Self::from_unix(
+            nanos.div_euclid(1_000_000_000) as i64,
+            nanos.rem_euclid(1_000_000_000) as u32,
+        )
+    }
+
+    /// Like [`from_unix_nanos`](Self::from_unix_nanos), but `None` if the
+    /// year would fall outside `0..=65535`.
+    pub(crate) fn checked_from_unix_nanos(nanos: i128) -> Option<Self> {
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+        Self::checked_from_unix(secs, nanos.rem_euclid(1_000_000_000) as u32)
+    }
+
+    /// Shifts by a signed number of nanoseconds.
+    pub(crate) fn add_nanos(&self, nanos: i128) -> Self {
+        Self::from_unix_nanos(self.unix_nanos() + nanos)
+    }
+
+    /// Adds a signed number of nanoseconds, returning `None` if the year
+    /// would fall outside `0..=65535`.
+    pub fn checked_add_nanos(&self, nanos: i128) -> Option<Self> {
+        Self::checked_from_unix_nanos(self.unix_nanos().checked_add(nanos)?)
+    }
+
+    /// Like [`from_unix`](Self::from_unix), but `None` if the year would
+    /// fall outside `0..=65535`.
+    pub(crate) fn checked_from_unix(secs: i64, nanosecond: u32) -> Option<Self> {
+        let (year, _, _) = civil_from_days(secs.div_euclid(86400));
+        u16::try_from(year).ok()?;
+        Some(Self::from_unix(secs, nanosecond))
+    }
+
+    /// Like [`from_unix`](Self::from_unix), but clamps to
+    /// `0000-01-01 00:00:00` or `65535-12-31 23:59:59.999999999` instead of
+    /// wrapping the year.
+    pub(crate) fn saturating_from_unix(secs: i64, nanosecond: u32) -> Self {
+        match Self::checked_from_unix(secs, nanosecond) {
+            Some(t) => t,
+            None if secs < 0 => Self::from_unix(MIN_UNIX_SECS, 0),
+            None => Self::from_unix(MAX_UNIX_SECS, 999_999_999),
+        }
     }
 
     /// Returns total nanoseconds since Unix epoch.
@@ -496,6 +781,7 @@ impl fmt::Display for NanoTime {
 /// ```
 pub struct Elapsed {
     start: Instant,
+    checkpoints: Vec<(String, Instant)>,
 }
 
 impl Elapsed {
@@ -503,6 +789,7 @@ impl Elapsed {
     pub fn start() -> Self {
         Self {
             start: Instant::now(),
+            checkpoints: Vec::new(),
         }
     }
 
@@ -967,6 +1254,22 @@ mod tests {
         assert!(nt.second() <= 59);
     }
 
+    #[test]
+    fn test_now_pair_is_one_instant() {
+        let (local, utc) = NanoTime::now_pair();
+        assert_eq!(local.nanosecond(), utc.nanosecond());
+        let offset = local.unix_secs() - utc.unix_secs();
+        assert!(offset.abs() <= 26 * 3600);
+        assert_eq!(offset % 60, 0);
+    }
+
+    #[test]
+    fn test_now_in_tracks_utc() {
+        let utc = NanoTime::now_utc();
+        let in_utc = NanoTime::now_in(&TimeZone::utc());
+        assert!(in_utc.diff_secs(&utc).abs() <= 1);
+    }
+
     #[test]
     fn test_ago_returns_string() {
         let past = NanoTime::from_epoch(0);
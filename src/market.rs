@@ -0,0 +1,184 @@
+//! Market-data timestamps counted in nanoseconds since midnight.
+//!
+//! Feeds such as Nasdaq ITCH and OUCH stamp each message with nanoseconds
+//! since midnight and carry the trading date separately, once per session.
+//! Venues disagree on what happens when a session crosses midnight, which
+//! [`SessionRollover`] describes.
+
+use std::time::Duration;
+
+use crate::{NanoTime, NANOS_PER_DAY};
+
+/// How a feed's nanoseconds-since-midnight relate to its trading date when
+/// a session crosses midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SessionRollover {
+    /// The count starts at midnight beginning the trading date and keeps
+    /// rising past 24 hours instead of restarting. This is the ITCH
+    /// convention, and the only one needed by sessions that stay within a
+    /// single day.
+    Continuous,
+    /// The session opens the evening before the trading date, and the count
+    /// restarts at each midnight. Times of day at or after `opens_at` fall on
+    /// the previous calendar day, as on futures and FX venues.
+    PriorEvening { opens_at: Duration },
+    /// The session runs past midnight into the next calendar day, and the
+    /// count restarts at midnight. Times of day before `closes_at` fall on
+    /// the day after the trading date.
+    PastMidnight { closes_at: Duration },
+}
+
+impl SessionRollover {
+    /// Returns the time `nanos` after midnight under this convention, for
+    /// the session whose trading date is the date of `trading_date`.
+    ///
+    /// Like the feed's timestamps, the result is a wall-clock time in the
+    /// venue's time zone; convert it with
+    /// [`TimeZone::resolve`](crate::tz::TimeZone::resolve) if that is not
+    /// UTC. Returns `None` if a convention that restarts at midnight gets 24
+    /// hours or more, or the year would exceed 65535.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use nanotime::{NanoTime, SessionRollover};
+    ///
+    /// let globex = SessionRollover::PriorEvening { opens_at: Duration::from_secs(17 * 3600) };
+    /// let trading_date = NanoTime::new(2026, 2, 23, 0, 0, 0, 0).unwrap();
+    /// let t = globex.resolve(&trading_date, 17 * 3_600_000_000_000).unwrap();
+    /// assert_eq!(t.datetime(), "2026-02-22 17:00:00.000");
+    /// ```
+    pub fn resolve(&self, trading_date: &NanoTime, nanos: u64) -> Option<NanoTime> {
+        let nanos = nanos as i128;
+        let day = match *self {
+            SessionRollover::Continuous => 0,
+            _ if nanos >= NANOS_PER_DAY => return None,
+            SessionRollover::PriorEvening { opens_at } if nanos >= opens_at.as_nanos() as i128 => {
+                -1
+            }
+            SessionRollover::PastMidnight { closes_at } if nanos < closes_at.as_nanos() as i128 => {
+                1
+            }
+            _ => 0,
+        };
+        midnight(trading_date).checked_add_nanos(day * NANOS_PER_DAY + nanos)
+    }
+
+    /// Returns the feed timestamp for the wall-clock time `t` in the
+    /// session whose trading date is the date of `trading_date`. Returns
+    /// `None` if `t` falls outside that session under this convention.
+    pub fn nanos_since_midnight(&self, t: &NanoTime, trading_date: &NanoTime) -> Option<u64> {
+        let since = t.diff_nanos(&midnight(trading_date));
+        let day = since.div_euclid(NANOS_PER_DAY);
+        let time_of_day = since.rem_euclid(NANOS_PER_DAY);
+        let in_session = match *self {
+            SessionRollover::Continuous => return u64::try_from(since).ok(),
+            SessionRollover::PriorEvening { opens_at } => {
+                let evening = time_of_day >= opens_at.as_nanos() as i128;
+                (day == -1 && evening) || (day == 0 && !evening)
+            }
+            SessionRollover::PastMidnight { closes_at } => {
+                let early = time_of_day < closes_at.as_nanos() as i128;
+                (day == 1 && early) || (day == 0 && !early)
+            }
+        };
+        in_session.then_some(time_of_day as u64)
+    }
+}
+
+/// Midnight starting the day of `t`.
+fn midnight(t: &NanoTime) -> NanoTime {
+    let since = t.hour() as i128 * 3_600_000_000_000
+        + t.minute() as i128 * 60_000_000_000
+        + t.second() as i128 * 1_000_000_000
+        + t.nanosecond() as i128;
+    t.add_nanos(-since)
+}
+
+impl NanoTime {
+    /// Returns the time `nanos` after midnight on the date of
+    /// `trading_date`, as carried by ITCH and OUCH messages. Counts of 24
+    /// hours or more continue into the following days.
+    ///
+    /// This is [`SessionRollover::Continuous`]; use [`SessionRollover`]
+    /// directly for sessions that restart the count at midnight.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let trading_date = NanoTime::new(2026, 2, 20, 0, 0, 0, 0).unwrap();
+    /// let t = NanoTime::from_nanos_since_midnight(&trading_date, 34_200_000_000_123).unwrap();
+    /// assert_eq!(t.to_rfc3339(), "2026-02-20T09:30:00.000000123Z");
+    /// assert_eq!(t.to_nanos_since_midnight(&trading_date), Some(34_200_000_000_123));
+    /// ```
+    pub fn from_nanos_since_midnight(trading_date: &NanoTime, nanos: u64) -> Option<Self> {
+        SessionRollover::Continuous.resolve(trading_date, nanos)
+    }
+
+    /// Returns the nanoseconds from midnight on the date of `trading_date`
+    /// to this time, or `None` if this time is earlier.
+    pub fn to_nanos_since_midnight(&self, trading_date: &NanoTime) -> Option<u64> {
+        SessionRollover::Continuous.nanos_since_midnight(self, trading_date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = 3_600_000_000_000;
+
+    fn date(day: u8) -> NanoTime {
+        NanoTime::new(2026, 2, day, 0, 0, 0, 0).unwrap()
+    }
+
+    fn at(day: u8, hour: u8) -> NanoTime {
+        NanoTime::new(2026, 2, day, hour, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_continuous() {
+        // The time of day in `trading_date` is ignored.
+        let noon = at(20, 12);
+        let t = NanoTime::from_nanos_since_midnight(&noon, 25 * HOUR).unwrap();
+        assert_eq!(t, at(21, 1));
+        assert_eq!(t.to_nanos_since_midnight(&noon), Some(25 * HOUR));
+        assert_eq!(at(19, 23).to_nanos_since_midnight(&noon), None);
+        assert_eq!(
+            NanoTime::from_nanos_since_midnight(&date(20), u64::MAX).map(|t| t.year()),
+            Some(2610)
+        );
+    }
+
+    #[test]
+    fn test_prior_evening() {
+        let r = SessionRollover::PriorEvening {
+            opens_at: Duration::from_secs(17 * 3600),
+        };
+        assert_eq!(r.resolve(&date(23), 18 * HOUR), Some(at(22, 18)));
+        assert_eq!(r.resolve(&date(23), 9 * HOUR), Some(at(23, 9)));
+        assert_eq!(r.resolve(&date(23), 24 * HOUR), None);
+        assert_eq!(
+            r.nanos_since_midnight(&at(22, 18), &date(23)),
+            Some(18 * HOUR)
+        );
+        assert_eq!(
+            r.nanos_since_midnight(&at(23, 9), &date(23)),
+            Some(9 * HOUR)
+        );
+        // Before the open, or already part of the next session.
+        assert_eq!(r.nanos_since_midnight(&at(22, 16), &date(23)), None);
+        assert_eq!(r.nanos_since_midnight(&at(23, 17), &date(23)), None);
+    }
+
+    #[test]
+    fn test_past_midnight() {
+        let r = SessionRollover::PastMidnight {
+            closes_at: Duration::from_secs(2 * 3600),
+        };
+        assert_eq!(r.resolve(&date(20), HOUR), Some(at(21, 1)));
+        assert_eq!(r.resolve(&date(20), 20 * HOUR), Some(at(20, 20)));
+        assert_eq!(r.nanos_since_midnight(&at(21, 1), &date(20)), Some(HOUR));
+        assert_eq!(r.nanos_since_midnight(&at(20, 1), &date(20)), None);
+        assert_eq!(r.nanos_since_midnight(&at(21, 3), &date(20)), None);
+    }
+}
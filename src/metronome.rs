@@ -0,0 +1,218 @@
+//! Fixed-rate ticking on absolute deadlines.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// What a [`Metronome`] does after falling a full period or more behind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MissedTick {
+    /// Fire the missed ticks back to back until caught up, keeping the
+    /// total number of ticks correct.
+    #[default]
+    Burst,
+    /// Drop the missed ticks and resume at the next deadline on the
+    /// original schedule.
+    Skip,
+    /// Start a new schedule one period after the late tick, giving up the
+    /// original phase.
+    Delay,
+}
+
+/// Ticks at a fixed period measured from a start instant, so time spent
+/// working between ticks does not accumulate as drift.
+///
+/// A loop that sleeps for the period after each iteration runs slower than
+/// intended by however long the work takes. A `Metronome` instead sleeps
+/// until `start + n * period`, so the `n`th tick happens on schedule no
+/// matter how long the previous iterations took, as long as each one fits
+/// in a period. When one does not, the [`MissedTick`] policy decides how to
+/// recover.
+///
+/// Deadlines use the monotonic clock, so wall-clock adjustments do not
+/// affect the schedule.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{Metronome, MissedTick};
+///
+/// let mut metronome = Metronome::new(Duration::from_millis(10)).with_missed_tick(MissedTick::Skip);
+/// for _ in 0..3 {
+///     metronome.tick();
+///     // ... periodic work ...
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Metronome {
+    period: Duration,
+    next: Instant,
+    policy: MissedTick,
+}
+
+impl Metronome {
+    /// Creates a metronome whose first tick is due immediately and whose
+    /// later ticks follow every `period`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: Duration) -> Self {
+        Self::starting_at(Instant::now(), period)
+    }
+
+    /// Creates a metronome whose first tick is due at `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn starting_at(start: Instant, period: Duration) -> Self {
+        assert!(!period.is_zero(), "metronome period must be non-zero");
+        Metronome {
+            period,
+            next: start,
+            policy: MissedTick::default(),
+        }
+    }
+
+    /// Sets how to recover after falling behind. Defaults to
+    /// [`MissedTick::Burst`].
+    pub fn with_missed_tick(mut self, policy: MissedTick) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the period between ticks.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Returns when the next tick is due.
+    pub fn next_deadline(&self) -> Instant {
+        self.next
+    }
+
+    /// Sleeps until the next tick is due and returns its deadline.
+    ///
+    /// Returns without sleeping if the deadline has already passed.
+    pub fn tick(&mut self) -> Instant {
+        let now = Instant::now();
+        if let Some(wait) = self.next.checked_duration_since(now) {
+            thread::sleep(wait);
+        }
+        let now = Instant::now().max(self.next);
+        self.try_tick_at(now).expect("deadline has passed")
+    }
+
+    /// Takes the next tick if it is due at `now`, returning its deadline,
+    /// or returns `None` without waiting if it is not yet due.
+    ///
+    /// This is the non-blocking form of [`tick`](Self::tick), for event
+    /// loops that do their own waiting.
+    pub fn try_tick_at(&mut self, now: Instant) -> Option<Instant> {
+        if now < self.next {
+            return None;
+        }
+        let deadline = self.next;
+        let on_schedule = deadline + self.period;
+        self.next = if now < on_schedule {
+            on_schedule
+        } else {
+            match self.policy {
+                MissedTick::Burst => on_schedule,
+                MissedTick::Skip => {
+                    let period = self.period.as_nanos();
+                    let periods = (now - deadline).as_nanos() / period + 1;
+                    // Past what an Instant can hold, resume a period from now.
+                    u64::try_from(period * periods)
+                        .ok()
+                        .and_then(|skip| deadline.checked_add(Duration::from_nanos(skip)))
+                        .unwrap_or(now + self.period)
+                }
+                MissedTick::Delay => now + self.period,
+            }
+        };
+        Some(deadline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    fn metronome(policy: MissedTick) -> (Instant, Metronome) {
+        let start = Instant::now();
+        let m = Metronome::starting_at(start, 10 * MS).with_missed_tick(policy);
+        (start, m)
+    }
+
+    #[test]
+    fn test_on_time_ticks_do_not_drift() {
+        let (start, mut m) = metronome(MissedTick::Delay);
+        assert_eq!(m.try_tick_at(start), Some(start));
+        assert_eq!(m.try_tick_at(start + 9 * MS), None);
+        // Each tick is late by 3ms of work, but the schedule holds.
+        for n in 1..5u32 {
+            let deadline = start + 10 * n * MS;
+            assert_eq!(m.try_tick_at(deadline + 3 * MS), Some(deadline));
+        }
+        assert_eq!(m.next_deadline(), start + 50 * MS);
+    }
+
+    #[test]
+    fn test_burst_catches_up() {
+        let (start, mut m) = metronome(MissedTick::Burst);
+        m.try_tick_at(start);
+        let now = start + 35 * MS;
+        let ticks: Vec<_> = std::iter::from_fn(|| m.try_tick_at(now)).collect();
+        assert_eq!(ticks, [start + 10 * MS, start + 20 * MS, start + 30 * MS]);
+        assert_eq!(m.next_deadline(), start + 40 * MS);
+    }
+
+    #[test]
+    fn test_skip_keeps_phase() {
+        let (start, mut m) = metronome(MissedTick::Skip);
+        m.try_tick_at(start);
+        assert_eq!(m.try_tick_at(start + 35 * MS), Some(start + 10 * MS));
+        assert_eq!(m.try_tick_at(start + 35 * MS), None);
+        assert_eq!(m.next_deadline(), start + 40 * MS);
+        // Landing exactly on a deadline skips past it too.
+        assert_eq!(m.try_tick_at(start + 50 * MS), Some(start + 40 * MS));
+        assert_eq!(m.next_deadline(), start + 60 * MS);
+    }
+
+    #[test]
+    fn test_skip_past_many_periods() {
+        // A 5s stall misses more than 2^32 one-nanosecond ticks.
+        let start = Instant::now();
+        let mut m = Metronome::starting_at(start, Duration::from_nanos(1))
+            .with_missed_tick(MissedTick::Skip);
+        let now = start + Duration::from_secs(5);
+        assert_eq!(m.try_tick_at(now), Some(start));
+        assert_eq!(m.next_deadline(), now + Duration::from_nanos(1));
+        assert_eq!(m.try_tick_at(now), None);
+    }
+
+    #[test]
+    fn test_delay_reanchors() {
+        let (start, mut m) = metronome(MissedTick::Delay);
+        m.try_tick_at(start);
+        assert_eq!(m.try_tick_at(start + 35 * MS), Some(start + 10 * MS));
+        assert_eq!(m.next_deadline(), start + 45 * MS);
+    }
+
+    #[test]
+    fn test_tick_sleeps_until_deadline() {
+        let mut m = Metronome::new(5 * MS);
+        let first = m.tick();
+        let second = m.tick();
+        assert_eq!(second - first, 5 * MS);
+        assert!(Instant::now() >= second);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero")]
+    fn test_zero_period_panics() {
+        Metronome::new(Duration::ZERO);
+    }
+}
@@ -0,0 +1,140 @@
+//! Conversions for OpenTelemetry's OTLP timestamps, as generated by
+//! `opentelemetry-proto`. Requires the `otel` feature.
+//!
+//! OTLP stores every time as a `*_time_unix_nano: u64` field counting
+//! nanoseconds since the Unix epoch, which covers 1970 to 2554. A value of
+//! zero means the time is unknown.
+//!
+//! ```rust
+//! use nanotime::{otel, Elapsed, NanoTime};
+//! use opentelemetry_proto::tonic::trace::v1::Span;
+//!
+//! let start = NanoTime::now_utc();
+//! let timer = Elapsed::start();
+//! // ... handle the request ...
+//! let mut span = Span::default();
+//! otel::set_span_times_elapsed(&mut span, &start, &timer).unwrap();
+//! let (s, e) = otel::span_times(&span).unwrap();
+//! assert!(s <= e);
+//! ```
+
+use std::fmt;
+
+use opentelemetry_proto::tonic::trace::v1::Span;
+
+use crate::{Elapsed, NanoTime};
+
+/// A time outside the range OTLP can represent, from the Unix epoch
+/// (exclusive, as zero means unknown) to 2554-07-21T23:34:33.709551615Z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfRange;
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("time out of range for OTLP time_unix_nano")
+    }
+}
+
+impl std::error::Error for OutOfRange {}
+
+/// Converts a `time_unix_nano` field to UTC, or `None` for zero, which
+/// OTLP uses for an unknown time.
+pub fn from_time_unix_nano(nanos: u64) -> Option<NanoTime> {
+    (nanos != 0).then(|| NanoTime::from_epoch_nanos(nanos as u128))
+}
+
+/// Returns `t` as a `time_unix_nano` field value.
+///
+/// ```rust
+/// use nanotime::{otel, NanoTime};
+///
+/// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+/// assert_eq!(otel::to_time_unix_nano(&t), Ok(1_771_770_605_123_456_789));
+/// assert_eq!(otel::from_time_unix_nano(1_771_770_605_123_456_789), Some(t));
+/// ```
+pub fn to_time_unix_nano(t: &NanoTime) -> Result<u64, OutOfRange> {
+    let nanos = t.unix_nanos();
+    match u64::try_from(nanos) {
+        Ok(0) | Err(_) => Err(OutOfRange),
+        Ok(nanos) => Ok(nanos),
+    }
+}
+
+/// Sets `start_time_unix_nano` and `end_time_unix_nano`. Leaves `span`
+/// unchanged if either time is out of range.
+pub fn set_span_times(span: &mut Span, start: &NanoTime, end: &NanoTime) -> Result<(), OutOfRange> {
+    let (start, end) = (to_time_unix_nano(start)?, to_time_unix_nano(end)?);
+    span.start_time_unix_nano = start;
+    span.end_time_unix_nano = end;
+    Ok(())
+}
+
+/// Sets the span to run from `start` for as long as `elapsed` has been
+/// running, for a span timed with an [`Elapsed`] started at `start`.
+///
+/// The end is `start` plus the monotonic elapsed time rather than a second
+/// wall-clock read, so the duration is right even if the system clock is
+/// adjusted while the span is open.
+pub fn set_span_times_elapsed(
+    span: &mut Span,
+    start: &NanoTime,
+    elapsed: &Elapsed,
+) -> Result<(), OutOfRange> {
+    let end = start
+        .checked_add_nanos(elapsed.elapsed_nanos() as i128)
+        .ok_or(OutOfRange)?;
+    set_span_times(span, start, &end)
+}
+
+/// Returns the span's start and end times, or `None` if either is unknown.
+pub fn span_times(span: &Span) -> Option<(NanoTime, NanoTime)> {
+    Some((
+        from_time_unix_nano(span.start_time_unix_nano)?,
+        from_time_unix_nano(span.end_time_unix_nano)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_unix_nano_range() {
+        assert_eq!(from_time_unix_nano(0), None);
+        assert_eq!(to_time_unix_nano(&NanoTime::from_epoch(0)), Err(OutOfRange));
+        let before = NanoTime::new(1969, 12, 31, 23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(to_time_unix_nano(&before), Err(OutOfRange));
+
+        let last = from_time_unix_nano(u64::MAX).unwrap();
+        assert_eq!(last.to_rfc3339(), "2554-07-21T23:34:33.709551615Z");
+        assert_eq!(to_time_unix_nano(&last), Ok(u64::MAX));
+        assert_eq!(to_time_unix_nano(&last.add_nanos(1)), Err(OutOfRange));
+    }
+
+    #[test]
+    fn test_span_times() {
+        let start = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+        let end = start.add_nanos(1_500);
+        let mut span = Span::default();
+        assert_eq!(span_times(&span), None);
+        set_span_times(&mut span, &start, &end).unwrap();
+        assert_eq!(span.end_time_unix_nano - span.start_time_unix_nano, 1_500);
+        assert_eq!(span_times(&span), Some((start, end)));
+
+        let before = NanoTime::new(1960, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(set_span_times(&mut span, &before, &end), Err(OutOfRange));
+        assert_eq!(span_times(&span), Some((start, end)));
+    }
+
+    #[test]
+    fn test_span_times_elapsed() {
+        let start = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+        let timer = Elapsed::start();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let mut span = Span::default();
+        set_span_times_elapsed(&mut span, &start, &timer).unwrap();
+        let (s, e) = span_times(&span).unwrap();
+        assert_eq!(s, start);
+        assert!(e.diff_nanos(&s) >= 2_000_000);
+    }
+}
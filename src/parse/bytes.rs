@@ -0,0 +1,209 @@
+//! Parsing timestamps straight out of byte buffers.
+
+use super::{ParseError, Scanner, MAX_FRAGMENT};
+use crate::{NanoTime, OffsetNanoTime, Precision};
+
+/// Returns the leading bytes of `input` that could belong to an RFC 3339
+/// timestamp, as a `str`.
+///
+/// Stopping at the first byte no layout here accepts keeps the scan short
+/// in a large buffer; a space is only taken at the date-time separator.
+fn timestamp_prefix(input: &[u8]) -> &str {
+    let len = input
+        .iter()
+        .enumerate()
+        .position(|(i, &b)| {
+            !(b.is_ascii_digit() || b"-:.+TtZz".contains(&b) || (i == 10 && b == b' '))
+        })
+        .unwrap_or(input.len());
+    // SAFETY: every byte taken is ASCII, and ASCII is valid UTF-8.
+    unsafe { std::str::from_utf8_unchecked(&input[..len]) }
+}
+
+/// Runs `parse` over the front of `input`, returning the value and the
+/// number of bytes it consumed.
+fn scan<T>(
+    input: &[u8],
+    parse: impl Fn(&mut Scanner) -> Result<T, ParseError>,
+) -> Result<(T, usize), ParseError> {
+    let prefix = timestamp_prefix(input);
+    let mut s = Scanner::new(prefix);
+    match parse(&mut s) {
+        Ok(t) => Ok((t, s.pos)),
+        Err(err) => {
+            // The prefix ends where the input stopped looking like a
+            // timestamp, so rerun over the text around it for an error that
+            // quotes what was actually there.
+            let end = input.len().min(prefix.len() + 4 * MAX_FRAGMENT);
+            let text = String::from_utf8_lossy(&input[..end]);
+            Err(parse(&mut Scanner::new(&text)).err().unwrap_or(err))
+        }
+    }
+}
+
+/// `YYYY-MM-DDTHH:MM:SS`, a fraction of exactly the digits `precision`
+/// writes, and `Z`.
+fn fixed(s: &mut Scanner, precision: Precision) -> Result<NanoTime, ParseError> {
+    let date = s.date()?;
+    s.literal('T')?;
+    let hour = s.number(2, 0, 23, "hour")? as u8;
+    s.literal(':')?;
+    let minute = s.number(2, 0, 59, "minute")? as u8;
+    s.literal(':')?;
+    let second = s.number(2, 0, 59, "second")? as u8;
+    let digits = match precision {
+        Precision::Auto => None,
+        Precision::Secs => Some(0),
+        Precision::Millis => Some(3),
+        Precision::Micros => Some(6),
+        Precision::Nanos => Some(9),
+        Precision::Digits(d) => Some(d.min(9) as usize),
+    };
+    let nanos = match digits {
+        None if s.eat('.') => s.fraction()?,
+        None | Some(0) => 0,
+        Some(n) => {
+            s.literal('.')?;
+            let max = 10u32.pow(n as u32) - 1;
+            s.number(n, 0, max, "fraction")? * 10u32.pow(9 - n as u32)
+        }
+    };
+    s.literal('Z')?;
+    Ok(super::civil(date, (hour, minute, second, nanos)))
+}
+
+impl NanoTime {
+    /// Parses an RFC 3339 timestamp from the front of a byte buffer,
+    /// returning the instant in UTC and the number of bytes consumed.
+    ///
+    /// Accepts what [`parse_rfc3339`](Self::parse_rfc3339) does, but reads
+    /// the bytes directly, without UTF-8 validation or allocation, and
+    /// stops at the end of the timestamp instead of requiring the input to
+    /// end there. This suits timestamps embedded in network buffers and
+    /// memory-mapped logs.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let line = b"2026-02-22T14:30:05.123Z GET /index.html";
+    /// let (t, n) = NanoTime::parse_rfc3339_bytes(line).unwrap();
+    /// assert_eq!(t, NanoTime::new(2026, 2, 22, 14, 30, 5, 123_000_000).unwrap());
+    /// assert_eq!(&line[n..], b" GET /index.html");
+    /// ```
+    pub fn parse_rfc3339_bytes(input: &[u8]) -> Result<(Self, usize), ParseError> {
+        scan(input, |s| s.rfc3339_utc())
+    }
+
+    /// Parses the fixed layout that [`to_rfc3339_opts(precision, None)`](Self::to_rfc3339_opts)
+    /// writes, such as `2026-02-22T14:30:05.123Z` for [`Precision::Millis`],
+    /// from the front of a byte buffer. Returns the time and the number of
+    /// bytes consumed.
+    ///
+    /// Unlike [`parse_rfc3339_bytes`](Self::parse_rfc3339_bytes), only the
+    /// `T` separator and `Z` suffix are accepted, and the fraction must
+    /// have exactly the precision's digits. With [`Precision::Auto`] the
+    /// fraction is optional and any length.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, Precision};
+    ///
+    /// let record = b"2026-02-22T14:30:05.123456Z\x01\x02";
+    /// let (t, n) = NanoTime::parse_fixed_bytes(record, Precision::Micros).unwrap();
+    /// assert_eq!(n, 27);
+    /// assert_eq!(t.nanosecond(), 123_456_000);
+    /// assert!(NanoTime::parse_fixed_bytes(record, Precision::Millis).is_err());
+    /// ```
+    pub fn parse_fixed_bytes(
+        input: &[u8],
+        precision: Precision,
+    ) -> Result<(Self, usize), ParseError> {
+        scan(input, |s| fixed(s, precision))
+    }
+}
+
+impl OffsetNanoTime {
+    /// Parses an RFC 3339 timestamp from the front of a byte buffer,
+    /// keeping the local time and offset as written. Returns the time and
+    /// the number of bytes consumed.
+    pub fn parse_rfc3339_bytes(input: &[u8]) -> Result<(Self, usize), ParseError> {
+        scan(input, |s| s.rfc3339())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc3339_bytes_matches_str() {
+        for input in [
+            "2026-02-22T14:30:05Z",
+            "2026-02-22 14:30:05.123456789+05:30",
+            "2026-02-22t14:30:05.1200000009z",
+        ] {
+            let (t, n) = NanoTime::parse_rfc3339_bytes(input.as_bytes()).unwrap();
+            assert_eq!(t, NanoTime::parse_rfc3339(input).unwrap());
+            assert_eq!(n, input.len());
+        }
+        let (o, n) = OffsetNanoTime::parse_rfc3339_bytes(b"2026-02-22T14:30:05-08:00 x").unwrap();
+        assert_eq!((o.offset().as_secs(), n), (-28_800, 25));
+    }
+
+    #[test]
+    fn test_stops_at_end_of_timestamp() {
+        // Trailing bytes need not be UTF-8, or even text.
+        let buf = b"2026-02-22T14:30:05.5Z\xff\xfe 2026-02-23T00:00:00Z";
+        let (t, n) = NanoTime::parse_rfc3339_bytes(buf).unwrap();
+        assert_eq!(n, 22);
+        assert_eq!(t.nanosecond(), 500_000_000);
+        let (next, _) = NanoTime::parse_rfc3339_bytes(&buf[n + 3..]).unwrap();
+        assert_eq!(next.day(), 23);
+    }
+
+    #[test]
+    fn test_errors_quote_input() {
+        let e = NanoTime::parse_rfc3339_bytes(b"2026-xx-22T14:30:05Z").unwrap_err();
+        assert_eq!((e.offset(), e.found()), (5, "xx"));
+        let e = NanoTime::parse_rfc3339_bytes(b"2026-02-22T14:30:05\xffZ").unwrap_err();
+        assert_eq!(e.offset(), 19);
+        assert_eq!(e.expected(), "'Z' or UTC offset");
+        assert_eq!(e.found(), "\u{fffd}");
+        let e = NanoTime::parse_rfc3339_bytes(b"2026-02").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "expected '-' at offset 7, found end of input"
+        );
+        let e = NanoTime::parse_rfc3339_bytes(b"0000-01-01T00:00:00+01:00 x").unwrap_err();
+        assert_eq!((e.offset(), e.found()), (19, "+01:00"));
+        assert_eq!(e.expected(), crate::parse::OUT_OF_RANGE);
+    }
+
+    #[test]
+    fn test_fixed_layouts() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+        for precision in [
+            Precision::Secs,
+            Precision::Millis,
+            Precision::Micros,
+            Precision::Nanos,
+            Precision::Digits(2),
+        ] {
+            let text = t.to_rfc3339_opts(precision, None);
+            let (parsed, n) = NanoTime::parse_fixed_bytes(text.as_bytes(), precision).unwrap();
+            assert_eq!(n, text.len());
+            assert_eq!(parsed.to_rfc3339_opts(precision, None), text);
+        }
+        let (_, n) =
+            NanoTime::parse_fixed_bytes(b"2026-02-22T14:30:05.1Z", Precision::Auto).unwrap();
+        assert_eq!(n, 22);
+
+        let e =
+            NanoTime::parse_fixed_bytes(b"2026-02-22T14:30:05.12Z", Precision::Millis).unwrap_err();
+        assert_eq!((e.offset(), e.expected()), (20, "3-digit fraction"));
+        let e =
+            NanoTime::parse_fixed_bytes(b"2026-02-22T14:30:05+00:00", Precision::Secs).unwrap_err();
+        assert_eq!((e.offset(), e.expected()), (19, "'Z'"));
+        let e = NanoTime::parse_fixed_bytes(b"2026-02-22 14:30:05Z", Precision::Secs).unwrap_err();
+        assert_eq!(e.expected(), "'T'");
+    }
+}
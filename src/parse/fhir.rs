@@ -0,0 +1,245 @@
+//! HL7 FHIR `instant` and `dateTime` values.
+//!
+//! ```text
+//! instant    2026-02-22T14:30:05.123+05:30    always to the second, with an offset
+//! dateTime   2026 | 2026-02 | 2026-02-22 | 2026-02-22T14:30:05Z
+//! ```
+//!
+//! A `dateTime` that has a time must carry seconds and an offset. Years
+//! start at 0001, and the `T` and `Z` are uppercase only.
+
+use std::fmt::Write;
+
+use super::{civil, ParseError, PartialNanoTime, Scanner};
+use crate::format::{write_rfc3339_offset, Precision};
+use crate::{days_in_month, NanoTime, OffsetNanoTime, UtcOffset};
+
+impl Scanner<'_> {
+    fn fhir_year(&mut self) -> Result<u16, ParseError> {
+        Ok(self.number(4, 1, 9999, "year")? as u16)
+    }
+
+    fn fhir_month(&mut self) -> Result<u8, ParseError> {
+        Ok(self.number(2, 1, 12, "month")? as u8)
+    }
+
+    fn fhir_day(&mut self, year: u16, month: u8) -> Result<u8, ParseError> {
+        let max_day = days_in_month(year, month) as u32;
+        Ok(self.number(2, 1, max_day, "day")? as u8)
+    }
+
+    /// `Z` or `±HH:MM`, with no lowercase `z`.
+    fn fhir_offset(&mut self) -> Result<UtcOffset, ParseError> {
+        match self.peek() {
+            Some('Z') | Some('+') | Some('-') => self.offset(),
+            _ => Err(self.error("'Z' or UTC offset")),
+        }
+    }
+
+    /// `THH:MM:SS[.f]` and an offset, after a full date.
+    fn fhir_time(&mut self, date: (u16, u8, u8)) -> Result<OffsetNanoTime, ParseError> {
+        self.literal('T')?;
+        let time = self.time()?;
+        let offset = self.fhir_offset()?;
+        Ok(OffsetNanoTime::new(civil(date, time), offset))
+    }
+
+    fn fhir_instant(&mut self) -> Result<OffsetNanoTime, ParseError> {
+        let year = self.fhir_year()?;
+        self.literal('-')?;
+        let month = self.fhir_month()?;
+        self.literal('-')?;
+        let day = self.fhir_day(year, month)?;
+        self.fhir_time((year, month, day))
+    }
+
+    fn fhir_date_time(&mut self) -> Result<PartialNanoTime, ParseError> {
+        let year = self.fhir_year()?;
+        if !self.eat('-') {
+            return Ok(PartialNanoTime::date_only(year, None, None));
+        }
+        let month = self.fhir_month()?;
+        if !self.eat('-') {
+            return Ok(PartialNanoTime::date_only(year, Some(month), None));
+        }
+        let day = self.fhir_day(year, month)?;
+        if self.peek().is_none() {
+            return Ok(PartialNanoTime::date_only(year, Some(month), Some(day)));
+        }
+        let t = self.fhir_time((year, month, day))?;
+        Ok(PartialNanoTime::with_offset(t.local(), t.offset()))
+    }
+}
+
+impl NanoTime {
+    /// Parses a FHIR `instant` such as `"2026-02-22T14:30:05.123+05:30"`
+    /// and returns it in UTC.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::parse_fhir_instant("2026-02-22T20:00:05.123+05:30").unwrap();
+    /// assert_eq!(t.to_fhir_instant().as_deref(), Some("2026-02-22T14:30:05.123Z"));
+    /// assert!(NanoTime::parse_fhir_instant("2026-02-22T14:30:05").is_err());
+    /// ```
+    pub fn parse_fhir_instant(input: &str) -> Result<Self, ParseError> {
+        OffsetNanoTime::parse_fhir_instant(input).map(|t| t.to_utc())
+    }
+
+    /// Formats this UTC time as a FHIR `instant`, trimming trailing
+    /// fractional zeros.
+    ///
+    /// Returns `None` outside years 0001 to 9999, which FHIR cannot express.
+    pub fn to_fhir_instant(&self) -> Option<String> {
+        if !(1..=9999).contains(&self.year()) {
+            return None;
+        }
+        Some(self.to_rfc3339())
+    }
+}
+
+impl OffsetNanoTime {
+    /// Parses a FHIR `instant`, keeping the offset as written.
+    pub fn parse_fhir_instant(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let t = s.fhir_instant()?;
+        s.finish()?;
+        Ok(t)
+    }
+}
+
+impl PartialNanoTime {
+    /// Parses a FHIR `dateTime`: a year, a year and month, a date, or a
+    /// full timestamp with seconds and an offset.
+    ///
+    /// ```rust
+    /// use nanotime::{PartialNanoTime, UtcOffset};
+    ///
+    /// let born = PartialNanoTime::parse_fhir_date_time("1974-12").unwrap();
+    /// assert_eq!((born.month(), born.day()), (Some(12), None));
+    ///
+    /// let seen = PartialNanoTime::parse_fhir_date_time("2026-02-22T14:30:05Z").unwrap();
+    /// assert_eq!(seen.offset(), Some(UtcOffset::UTC));
+    ///
+    /// // A time without an offset is not a valid dateTime.
+    /// let err = PartialNanoTime::parse_fhir_date_time("2026-02-22T14:30:05").unwrap_err();
+    /// assert_eq!(err.expected(), "'Z' or UTC offset");
+    /// ```
+    pub fn parse_fhir_date_time(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let p = s.fhir_date_time()?;
+        s.finish()?;
+        Ok(p)
+    }
+
+    /// Formats as a FHIR `dateTime` at the precision it holds, trimming
+    /// trailing fractional zeros.
+    ///
+    /// Returns `None` for values FHIR cannot express: year 0, or a time
+    /// without seconds or an offset.
+    pub fn to_fhir_date_time(&self) -> Option<String> {
+        if self.year() == 0 {
+            return None;
+        }
+        let mut out = String::with_capacity(35);
+        let _ = write!(out, "{:04}", self.year());
+        if let Some(month) = self.month() {
+            let _ = write!(out, "-{:02}", month);
+        }
+        if let Some(day) = self.day() {
+            let _ = write!(out, "-{:02}", day);
+        }
+        if self.hour().is_some() {
+            let (second, offset) = (self.second()?, self.offset()?);
+            let _ = write!(
+                out,
+                "T{:02}:{:02}:{:02}",
+                self.hour()?,
+                self.minute()?,
+                second
+            );
+            Precision::Auto.write_fraction(&mut out, self.nanosecond().unwrap_or(0));
+            write_rfc3339_offset(&mut out, offset, true);
+        }
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instant_round_trip() {
+        let t = OffsetNanoTime::parse_fhir_instant("2026-02-22T14:30:05-05:00").unwrap();
+        assert_eq!(t.offset(), UtcOffset::from_minutes(-300).unwrap());
+        assert_eq!(
+            NanoTime::parse_fhir_instant(&t.to_utc().to_fhir_instant().unwrap()),
+            Ok(t.to_utc())
+        );
+    }
+
+    #[test]
+    fn test_instant_years_outside_fhir() {
+        let first = NanoTime::new(1, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(
+            first.to_fhir_instant().as_deref(),
+            Some("0001-01-01T00:00:00Z")
+        );
+        let last = NanoTime::new(9999, 12, 31, 23, 59, 59, 0).unwrap();
+        assert!(last.to_fhir_instant().is_some());
+        // 0001-01-01T00:30:00+01:00 is valid FHIR but falls in year 0 in UTC.
+        let early = NanoTime::parse_fhir_instant("0001-01-01T00:30:00+01:00").unwrap();
+        assert_eq!(early.to_fhir_instant(), None);
+        let late = NanoTime::new(10000, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(late.to_fhir_instant(), None);
+    }
+
+    #[test]
+    fn test_instant_rejections() {
+        let err = |input| NanoTime::parse_fhir_instant(input).unwrap_err();
+        assert_eq!(err("0000-01-01T00:00:00Z").expected(), "year 0001-9999");
+        assert_eq!(err("2026-02-22t14:30:05Z").expected(), "'T'");
+        assert_eq!(err("2026-02-22T14:30:05z").expected(), "'Z' or UTC offset");
+        assert_eq!(err("2026-02-22T14:30Z").expected(), "':'");
+        assert_eq!(err("2026-02-22").expected(), "'T'");
+    }
+
+    #[test]
+    fn test_date_time_precisions() {
+        for input in [
+            "2026",
+            "2026-02",
+            "2026-02-22",
+            "2026-02-22T14:30:05Z",
+            "2026-02-22T14:30:05.25+05:30",
+        ] {
+            let p = PartialNanoTime::parse_fhir_date_time(input).unwrap();
+            assert_eq!(p.to_fhir_date_time().as_deref(), Some(input));
+        }
+        let p = PartialNanoTime::parse_fhir_date_time("2026-02-22T14:30:05.25+05:30").unwrap();
+        assert_eq!(p.nanosecond(), Some(250_000_000));
+        assert!(p.is_complete());
+    }
+
+    #[test]
+    fn test_date_time_rejections() {
+        let err = |input| PartialNanoTime::parse_fhir_date_time(input).unwrap_err();
+        assert_eq!(err("2026-02-22T14").expected(), "':'");
+        assert_eq!(err("2026-02-22 14:30:05Z").expected(), "'T'");
+        assert_eq!(err("2026-02-30").expected(), "day 01-28");
+        assert_eq!(err("2026-").expected(), "2-digit month");
+    }
+
+    #[test]
+    fn test_unrepresentable() {
+        let no_offset = PartialNanoTime::parse("2026-02-22T14:30:05").unwrap();
+        assert_eq!(no_offset.to_fhir_date_time(), None);
+        let no_seconds = PartialNanoTime::parse("2026-02-22T14:30Z").unwrap();
+        assert_eq!(no_seconds.to_fhir_date_time(), None);
+        assert_eq!(
+            PartialNanoTime::parse("0000").unwrap().to_fhir_date_time(),
+            None
+        );
+    }
+}
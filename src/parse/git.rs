@@ -0,0 +1,220 @@
+//! Git commit and author dates.
+//!
+//! ```text
+//! raw       1771771805 +0100                  git log --date=raw
+//! default   Sun Feb 22 15:50:05 2026 +0100    git log
+//! ```
+//!
+//! Both keep the committer's UTC offset and have whole-second resolution.
+
+use std::fmt::Write;
+
+use super::guess::weekday_abbr;
+use super::{civil, ParseError, Scanner, MONTHS, OUT_OF_RANGE, WEEKDAYS};
+use crate::{days_in_month, NanoTime, OffsetNanoTime, UtcOffset};
+
+impl Scanner<'_> {
+    /// `<epoch seconds> ±hhmm`
+    fn git_raw(&mut self) -> Result<OffsetNanoTime, ParseError> {
+        let start = self.pos;
+        let secs = self.integer("epoch seconds")?;
+        let utc = NanoTime::checked_from_unix(secs, 0)
+            .ok_or_else(|| self.error_at(start, self.pos - start, "epoch seconds"))?;
+        self.spaces()?;
+        let at = self.pos;
+        let offset = self.compact_offset()?;
+        // The local time must be in range too, or it would be clamped.
+        NanoTime::checked_from_unix(secs + offset.as_secs() as i64, 0)
+            .ok_or_else(|| self.error_at(at, self.pos - at, OUT_OF_RANGE))?;
+        Ok(OffsetNanoTime::from_utc(&utc, offset))
+    }
+
+    /// `Day Mon D HH:MM:SS YYYY ±hhmm`
+    fn git_default(&mut self) -> Result<OffsetNanoTime, ParseError> {
+        weekday_abbr(self)?;
+        self.spaces()?;
+        let month = self.name(&MONTHS, "month name")? as u8 + 1;
+        self.spaces()?;
+        let day_at = self.pos;
+        let day = self.number_var(2, 1, 31, "day")?;
+        self.spaces()?;
+        let time = self.time()?;
+        self.spaces()?;
+        let year = self.number(4, 0, 9999, "year")? as u16;
+        let max_day = days_in_month(year, month) as u32;
+        if day > max_day {
+            return Err(self.error_at(day_at, 2, format!("day 1-{}", max_day)));
+        }
+        self.spaces()?;
+        let offset = self.compact_offset()?;
+        let local = civil((year, month, day as u8), time);
+        Ok(OffsetNanoTime::new(local, offset))
+    }
+}
+
+/// Writes `±hhmm`.
+pub(crate) fn write_compact_offset(out: &mut String, offset: UtcOffset) {
+    let secs = offset.as_secs();
+    let sign = if secs < 0 { '-' } else { '+' };
+    let abs = secs.unsigned_abs();
+    let _ = write!(out, "{}{:02}{:02}", sign, abs / 3600, (abs % 3600) / 60);
+}
+
+impl NanoTime {
+    /// Parses a git date in the raw (`1771771805 +0100`) or default
+    /// (`Sun Feb 22 15:50:05 2026 +0100`) format and returns the instant in
+    /// UTC. An offset that moves the instant outside years 0-65535 is an
+    /// error.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let a = NanoTime::parse_git_date("1771771805 +0100").unwrap();
+    /// let b = NanoTime::parse_git_date("Sun Feb 22 15:50:05 2026 +0100").unwrap();
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.to_rfc3339(), "2026-02-22T14:50:05Z");
+    /// ```
+    pub fn parse_git_date(input: &str) -> Result<Self, ParseError> {
+        let t = OffsetNanoTime::parse_git_date(input)?;
+        t.checked_to_utc().ok_or_else(|| {
+            // The offset is the last word of the input.
+            let at = input.rfind(' ').map_or(0, |i| i + 1);
+            Scanner::new(input).error_at(at, input.len() - at, OUT_OF_RANGE)
+        })
+    }
+}
+
+impl OffsetNanoTime {
+    /// Parses a git date in the raw or default format, keeping the
+    /// committer's offset. The format is chosen by the first character:
+    /// raw dates start with a digit. A raw date whose local time falls
+    /// outside years 0-65535 is an error.
+    pub fn parse_git_date(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let t = if s.peek().is_some_and(|c| c.is_ascii_digit()) {
+            s.git_raw()?
+        } else {
+            s.git_default()?
+        };
+        s.finish()?;
+        Ok(t)
+    }
+
+    /// Formats as git's raw date: epoch seconds and the offset,
+    /// `"1771771805 +0100"`.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, OffsetNanoTime, UtcOffset};
+    ///
+    /// let local = NanoTime::new(2026, 2, 22, 15, 50, 5, 0).unwrap();
+    /// let t = OffsetNanoTime::new(local, UtcOffset::from_minutes(60).unwrap());
+    /// assert_eq!(t.to_git_raw(), "1771771805 +0100");
+    /// assert_eq!(t.to_git_default(), "Sun Feb 22 15:50:05 2026 +0100");
+    /// ```
+    pub fn to_git_raw(&self) -> String {
+        let mut out = self.to_utc().unix_secs().to_string();
+        out.push(' ');
+        write_compact_offset(&mut out, self.offset());
+        out
+    }
+
+    /// Formats as git's default date, in the stored offset:
+    /// `"Sun Feb 22 15:50:05 2026 +0100"`. The day is not padded. Offsets
+    /// with a seconds part are rounded toward zero to whole minutes, and
+    /// the local time shifted so the instant stays the same.
+    pub fn to_git_default(&self) -> String {
+        let t = self.with_whole_minute_offset();
+        let local = t.local();
+        let mut out = String::with_capacity(30);
+        let _ = write!(
+            out,
+            "{} {} {} {:02}:{:02}:{:02} {} ",
+            &WEEKDAYS[local.weekday_index()][..3],
+            MONTHS[local.month() as usize - 1],
+            local.day(),
+            local.hour(),
+            local.minute(),
+            local.second(),
+            local.year()
+        );
+        write_compact_offset(&mut out, t.offset());
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn est(local: NanoTime) -> OffsetNanoTime {
+        OffsetNanoTime::new(local, UtcOffset::from_minutes(-300).unwrap())
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let t = est(NanoTime::new(2016, 2, 23, 9, 20, 23, 0).unwrap());
+        assert_eq!(t.to_git_raw(), "1456237223 -0500");
+        assert_eq!(t.to_git_default(), "Tue Feb 23 09:20:23 2016 -0500");
+        assert_eq!(OffsetNanoTime::parse_git_date("1456237223 -0500"), Ok(t));
+        assert_eq!(
+            OffsetNanoTime::parse_git_date("Tue Feb 23 09:20:23 2016 -0500"),
+            Ok(t)
+        );
+    }
+
+    #[test]
+    fn test_unpadded_day() {
+        let t = est(NanoTime::new(2026, 3, 1, 8, 0, 0, 0).unwrap());
+        assert_eq!(t.to_git_default(), "Sun Mar 1 08:00:00 2026 -0500");
+        assert_eq!(
+            OffsetNanoTime::parse_git_date("Sun Mar 1 08:00:00 2026 -0500"),
+            Ok(t)
+        );
+    }
+
+    #[test]
+    fn test_offset_seconds_keep_the_instant() {
+        let utc = NanoTime::new(1847, 12, 1, 12, 0, 0, 0).unwrap();
+        let lmt = OffsetNanoTime::from_utc(&utc, UtcOffset::from_secs(75).unwrap());
+        assert_eq!(lmt.to_git_default(), "Wed Dec 1 12:01:00 1847 +0001");
+        let back = OffsetNanoTime::parse_git_date(&lmt.to_git_default()).unwrap();
+        assert!(back.same_instant(&lmt));
+    }
+
+    #[test]
+    fn test_fraction_truncated() {
+        let t = est(NanoTime::new(2026, 2, 22, 9, 30, 5, 999_000_000).unwrap());
+        assert_eq!(t.to_git_raw(), "1771770605 -0500");
+    }
+
+    #[test]
+    fn test_rejections() {
+        let err = |input| NanoTime::parse_git_date(input).unwrap_err();
+        assert_eq!(err("1456237223").expected(), "' '");
+        assert_eq!(err("1456237223 -05:00").offset(), 14);
+        assert_eq!(err("Tue Feb 30 09:20:23 2016 -0500").expected(), "day 1-29");
+        assert_eq!(
+            err("Tue Foo 23 09:20:23 2016 -0500").expected(),
+            "month name"
+        );
+        assert_eq!(
+            err("99999999999999999999 +0000").expected(),
+            "epoch seconds"
+        );
+        let e = err("Sat Jan 1 00:00:00 0000 +0100");
+        assert_eq!(
+            (e.offset(), e.found(), e.expected()),
+            (24, "+0100", OUT_OF_RANGE)
+        );
+        assert!(OffsetNanoTime::parse_git_date("Sat Jan 1 00:00:00 0000 +0100").is_ok());
+        let e = err("2005949145599 +0100");
+        assert_eq!(
+            (e.offset(), e.found(), e.expected()),
+            (14, "+0100", OUT_OF_RANGE)
+        );
+        assert_eq!(
+            NanoTime::parse_git_date("2005949145599 -0100").unwrap(),
+            NanoTime::checked_from_unix(2005949145599, 0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,564 @@
+//! Best-effort parsing of timestamps in unknown formats.
+
+use std::fmt;
+use std::ops::Range;
+
+use super::{civil, ParseError, Scanner, YearPivot, MONTHS, OUT_OF_RANGE, WEEKDAYS};
+use crate::{days_in_month, NanoTime, OffsetNanoTime, UtcOffset};
+
+/// An offset and the byte range of input it was read from.
+pub(crate) type Zone = (UtcOffset, Range<usize>);
+
+/// A parsed local time and, if the format carries one, its offset.
+type Scanned = Result<(NanoTime, Option<Zone>), ParseError>;
+
+/// A well-known timestamp layout recognized by [`NanoTime::parse_guess`].
+///
+/// Variants are listed in the order they are tried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KnownFormat {
+    /// `2026-02-22T14:30:05.123+05:30`
+    Rfc3339,
+    /// `2026-02-22T14:30:05.123`, ISO 8601 without an offset.
+    IsoLocal,
+    /// `2026-02-22 14:30:05.123`, as produced by [`NanoTime::datetime`].
+    Datetime,
+    /// `2026/02/22 14:30:05`, common in application logs.
+    SlashDatetime,
+    /// `20260222T143005Z`, ISO 8601 basic format with optional fraction and offset.
+    IsoBasic,
+    /// `Sun, 22 Feb 2026 14:30:05 +0000`, as in email and HTTP headers.
+    Rfc2822,
+    /// `Sunday, 22-Feb-26 14:30:05 GMT`, the obsolete HTTP date format.
+    Rfc850,
+    /// `Sun Feb 22 14:30:05 2026`, C's `asctime()` output.
+    Asctime,
+    /// `22/Feb/2026:14:30:05 +0000`, Apache/nginx access logs, optionally bracketed.
+    CommonLog,
+    /// `2026-02-22`, a calendar date at midnight.
+    Date,
+    /// Up to 11 digits of Unix seconds, optionally with a fraction: `1771770605.5`.
+    EpochSeconds,
+    /// 12–14 digits of Unix milliseconds.
+    EpochMillis,
+    /// 15–17 digits of Unix microseconds.
+    EpochMicros,
+    /// 18–20 digits of Unix nanoseconds.
+    EpochNanos,
+}
+
+impl KnownFormat {
+    pub(crate) const ALL: [KnownFormat; 14] = [
+        KnownFormat::Rfc3339,
+        KnownFormat::IsoLocal,
+        KnownFormat::Datetime,
+        KnownFormat::SlashDatetime,
+        KnownFormat::IsoBasic,
+        KnownFormat::Rfc2822,
+        KnownFormat::Rfc850,
+        KnownFormat::Asctime,
+        KnownFormat::CommonLog,
+        KnownFormat::Date,
+        KnownFormat::EpochSeconds,
+        KnownFormat::EpochMillis,
+        KnownFormat::EpochMicros,
+        KnownFormat::EpochNanos,
+    ];
+
+    /// Returns a short human-readable name, e.g. `"RFC 3339"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            KnownFormat::Rfc3339 => "RFC 3339",
+            KnownFormat::IsoLocal => "ISO 8601 local",
+            KnownFormat::Datetime => "datetime",
+            KnownFormat::SlashDatetime => "slash datetime",
+            KnownFormat::IsoBasic => "ISO 8601 basic",
+            KnownFormat::Rfc2822 => "RFC 2822",
+            KnownFormat::Rfc850 => "RFC 850",
+            KnownFormat::Asctime => "asctime",
+            KnownFormat::CommonLog => "Common Log Format",
+            KnownFormat::Date => "date",
+            KnownFormat::EpochSeconds => "epoch seconds",
+            KnownFormat::EpochMillis => "epoch milliseconds",
+            KnownFormat::EpochMicros => "epoch microseconds",
+            KnownFormat::EpochNanos => "epoch nanoseconds",
+        }
+    }
+
+    /// Parses `s` as this format, returning the local time and the offset
+    /// if the format carries one.
+    fn scan(&self, s: &mut Scanner) -> Scanned {
+        match self {
+            KnownFormat::Rfc3339 => {
+                let local = s.date_time()?;
+                let at = s.pos;
+                let offset = s.offset()?;
+                Ok((local, Some((offset, at..s.pos))))
+            }
+            KnownFormat::IsoLocal => naive(s, '-', 'T'),
+            KnownFormat::Datetime => naive(s, '-', ' '),
+            KnownFormat::SlashDatetime => naive(s, '/', ' '),
+            KnownFormat::IsoBasic => iso_basic(s),
+            KnownFormat::Rfc2822 => rfc2822(s),
+            KnownFormat::Rfc850 => rfc850(s),
+            KnownFormat::Asctime => asctime(s),
+            KnownFormat::CommonLog => common_log(s),
+            KnownFormat::Date => Ok((civil(s.date()?, (0, 0, 0, 0)), None)),
+            KnownFormat::EpochSeconds => epoch(s, 1, 11, 0),
+            KnownFormat::EpochMillis => epoch(s, 12, 14, 3),
+            KnownFormat::EpochMicros => epoch(s, 15, 17, 6),
+            KnownFormat::EpochNanos => epoch(s, 18, 20, 9),
+        }
+    }
+}
+
+impl fmt::Display for KnownFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl NanoTime {
+    /// Parses a timestamp in any of the [`KnownFormat`]s, returning the
+    /// value and the format that matched.
+    ///
+    /// Formats are tried in declaration order and the first full match wins.
+    /// Formats with an offset or zone are converted to UTC; formats without
+    /// one are returned as written. Surrounding whitespace is ignored. When
+    /// nothing matches, the error comes from the format that got furthest.
+    /// An offset that moves the instant outside years 0-65535 is an error.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    /// use nanotime::parse::KnownFormat;
+    ///
+    /// let (t, fmt) = NanoTime::parse_guess("22/Feb/2026:14:30:05 +0100").unwrap();
+    /// assert_eq!(fmt, KnownFormat::CommonLog);
+    /// assert_eq!(t.datetime(), "2026-02-22 13:30:05.000");
+    /// ```
+    pub fn parse_guess(input: &str) -> Result<(Self, KnownFormat), ParseError> {
+        let (local, zone, format) = guess(input)?;
+        let t = match zone {
+            Some(zone) => checked_utc(input, local, zone)?,
+            None => local,
+        };
+        Ok((t, format))
+    }
+}
+
+/// Converts `local` to UTC with an offset read from `input`, failing on the
+/// offset if the instant falls outside years 0-65535.
+pub(crate) fn checked_utc(
+    input: &str,
+    local: NanoTime,
+    zone: Zone,
+) -> Result<NanoTime, ParseError> {
+    let (offset, at) = zone;
+    OffsetNanoTime::new(local, offset)
+        .checked_to_utc()
+        .ok_or_else(|| Scanner::new(input).error_at(at.start, at.len(), OUT_OF_RANGE))
+}
+
+/// Tries every [`KnownFormat`] in order, returning the first match's local
+/// time and offset as written. Offset ranges index into `input`.
+pub(crate) fn guess(input: &str) -> Result<(NanoTime, Option<Zone>, KnownFormat), ParseError> {
+    let trimmed = input.trim();
+    let lead = input.len() - input.trim_start().len();
+    let mut best: Option<ParseError> = None;
+
+    for format in KnownFormat::ALL {
+        let mut s = Scanner::new(trimmed);
+        let result = format.scan(&mut s).and_then(|t| s.finish().map(|()| t));
+        match result {
+            Ok((local, zone)) => {
+                let zone = zone.map(|(offset, at)| (offset, at.start + lead..at.end + lead));
+                return Ok((local, zone, format));
+            }
+            Err(e) => {
+                if best.as_ref().is_none_or(|b| e.offset > b.offset) {
+                    best = Some(e);
+                }
+            }
+        }
+    }
+
+    let mut err = best.expect("at least one format is tried");
+    err.offset += lead;
+    Err(err)
+}
+
+/// Finds the longest match among `formats` at the start of `input` that
+/// ends at a word boundary, returning its length in bytes, local time, and
+/// offset. Earlier formats win ties.
+pub(crate) fn match_prefix(
+    input: &str,
+    formats: &[KnownFormat],
+) -> Option<(usize, NanoTime, Option<UtcOffset>)> {
+    let mut best: Option<(usize, NanoTime, Option<UtcOffset>)> = None;
+    for format in formats {
+        let mut s = Scanner::new(input);
+        let Ok((t, zone)) = format.scan(&mut s) else {
+            continue;
+        };
+        let offset = zone.map(|(offset, _)| offset);
+        let at_boundary = s.peek().is_none_or(|c| !c.is_alphanumeric());
+        if at_boundary && s.pos > 0 && best.is_none_or(|(len, _, _)| s.pos > len) {
+            best = Some((s.pos, t, offset));
+        }
+    }
+    best
+}
+
+/// `YYYY<sep>MM<sep>DD<T>HH:MM:SS[.f]` with no offset.
+fn naive(s: &mut Scanner, sep: char, t: char) -> Scanned {
+    let year = s.number(4, 0, 9999, "year")? as u16;
+    s.literal(sep)?;
+    let month = s.number(2, 1, 12, "month")? as u8;
+    s.literal(sep)?;
+    let day = s.number(2, 1, days_in_month(year, month) as u32, "day")? as u8;
+    s.literal(t)?;
+    let time = s.time()?;
+    Ok((civil((year, month, day), time), None))
+}
+
+/// `YYYYMMDDTHHMMSS[.f][Z|±hhmm]`
+fn iso_basic(s: &mut Scanner) -> Scanned {
+    let year = s.number(4, 0, 9999, "year")? as u16;
+    let month = s.number(2, 1, 12, "month")? as u8;
+    let day = s.number(2, 1, days_in_month(year, month) as u32, "day")? as u8;
+    s.literal('T')?;
+    let hour = s.number(2, 0, 23, "hour")? as u8;
+    let minute = s.number(2, 0, 59, "minute")? as u8;
+    let second = s.number(2, 0, 59, "second")? as u8;
+    let nanos = if s.eat('.') || s.eat(',') {
+        s.fraction()?
+    } else {
+        0
+    };
+    let at = s.pos;
+    let offset = match s.peek() {
+        Some('Z') | Some('z') => {
+            s.pos += 1;
+            Some(UtcOffset::UTC)
+        }
+        Some('+') | Some('-') => Some(s.compact_offset()?),
+        _ => None,
+    };
+    let t = civil((year, month, day), (hour, minute, second, nanos));
+    Ok((t, offset.map(|offset| (offset, at..s.pos))))
+}
+
+/// `[Day, ]D Mon YYYY HH:MM[:SS] zone`
+pub(crate) fn rfc2822(s: &mut Scanner) -> Scanned {
+    let weekday = if s.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+        let at = s.pos;
+        let idx = s.name(&WEEKDAYS.map(|day| &day[..3]), "weekday name")?;
+        s.literal(',')?;
+        s.spaces()?;
+        Some((at, idx))
+    } else {
+        None
+    };
+    let day_at = s.pos;
+    let day = s.number_var(2, 1, 31, "day")? as u8;
+    s.spaces()?;
+    let month = s.name(&MONTHS, "month name")? as u8 + 1;
+    s.spaces()?;
+    let year = s.number(4, 0, 9999, "year")? as u16;
+    check_day(s, day_at, year, month, day)?;
+    s.spaces()?;
+    let hour = s.number(2, 0, 23, "hour")? as u8;
+    s.literal(':')?;
+    let minute = s.number(2, 0, 59, "minute")? as u8;
+    let second = if s.eat(':') {
+        s.number(2, 0, 59, "second")? as u8
+    } else {
+        0
+    };
+    s.spaces()?;
+    let at = s.pos;
+    let offset = zone(s)?;
+    let zone = (offset, at..s.pos);
+    let local = civil((year, month, day), (hour, minute, second, 0));
+    // RFC 5322 section 3.3: the day name must match the date.
+    if let Some((at, idx)) = weekday {
+        if idx != local.weekday_index() {
+            return Err(s.error_at(at, 3, "weekday matching the date"));
+        }
+    }
+    Ok((local, Some(zone)))
+}
+
+/// `Weekday, DD-Mon-YY HH:MM:SS GMT`
+fn rfc850(s: &mut Scanner) -> Scanned {
+    s.name(&WEEKDAYS, "weekday name")?;
+    s.literal(',')?;
+    s.spaces()?;
+    let day_at = s.pos;
+    let day = s.number(2, 1, 31, "day")? as u8;
+    s.literal('-')?;
+    let month = s.name(&MONTHS, "month name")? as u8 + 1;
+    s.literal('-')?;
+    let year = YearPivot::Posix.scan(s)?;
+    check_day(s, day_at, year, month, day)?;
+    s.spaces()?;
+    let time = s.time()?;
+    s.spaces()?;
+    let at = s.pos;
+    let offset = zone(s)?;
+    Ok((civil((year, month, day), time), Some((offset, at..s.pos))))
+}
+
+/// `Day Mon D HH:MM:SS YYYY`, with the day space- or zero-padded.
+fn asctime(s: &mut Scanner) -> Scanned {
+    weekday_abbr(s)?;
+    s.spaces()?;
+    let month = s.name(&MONTHS, "month name")? as u8 + 1;
+    s.spaces()?;
+    let day_at = s.pos;
+    let day = s.number_var(2, 1, 31, "day")? as u8;
+    s.spaces()?;
+    let time = s.time()?;
+    s.spaces()?;
+    let year = s.number(4, 0, 9999, "year")? as u16;
+    check_day(s, day_at, year, month, day)?;
+    Ok((civil((year, month, day), time), None))
+}
+
+/// `[DD/Mon/YYYY:HH:MM:SS ±hhmm]`, brackets optional.
+fn common_log(s: &mut Scanner) -> Scanned {
+    let bracketed = s.eat('[');
+    let day_at = s.pos;
+    let day = s.number(2, 1, 31, "day")? as u8;
+    s.literal('/')?;
+    let month = s.name(&MONTHS, "month name")? as u8 + 1;
+    s.literal('/')?;
+    let year = s.number(4, 0, 9999, "year")? as u16;
+    check_day(s, day_at, year, month, day)?;
+    s.literal(':')?;
+    let time = s.time()?;
+    s.spaces()?;
+    let at = s.pos;
+    let offset = s.compact_offset()?;
+    let zone = (offset, at..s.pos);
+    if bracketed {
+        s.literal(']')?;
+    }
+    Ok((civil((year, month, day), time), Some(zone)))
+}
+
+/// Unix time as `min_digits..=max_digits` digits in units of 10^-`scale`
+/// seconds. Seconds may carry a fraction.
+fn epoch(s: &mut Scanner, min_digits: usize, max_digits: usize, scale: u32) -> Scanned {
+    let start = s.pos;
+    let digits = s.input.as_bytes()[start..]
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    if digits < min_digits || digits > max_digits {
+        let expected = format!("{}-{} digit epoch value", min_digits, max_digits);
+        return Err(s.error_at(start, digits.max(1), expected));
+    }
+    let value: u128 = s.input[start..start + digits]
+        .parse()
+        .map_err(|_| s.error_at(start, digits, "epoch value"))?;
+    s.pos += digits;
+    let fraction = if scale == 0 && s.eat('.') {
+        s.fraction()?
+    } else {
+        0
+    };
+    let nanos = value * 10u128.pow(9 - scale) + fraction as u128;
+    let t = NanoTime::from_epoch_nanos(nanos);
+    if t.to_epoch_nanos() != nanos {
+        return Err(s.error_at(start, digits, "epoch value within years 0-9999"));
+    }
+    Ok((t, None))
+}
+
+/// RFC 2822 zone: `±hhmm`, or one of the obsolete names `UT`, `GMT`, `Z`,
+/// and the US zones `EST`..`PDT`.
+fn zone(s: &mut Scanner) -> Result<UtcOffset, ParseError> {
+    if matches!(s.peek(), Some('+') | Some('-')) {
+        return s.compact_offset();
+    }
+    const ZONES: [(&str, i16); 12] = [
+        ("UTC", 0),
+        ("UT", 0),
+        ("GMT", 0),
+        ("Z", 0),
+        ("EST", -5),
+        ("EDT", -4),
+        ("CST", -6),
+        ("CDT", -5),
+        ("MST", -7),
+        ("MDT", -6),
+        ("PST", -8),
+        ("PDT", -7),
+    ];
+    let names = ZONES.map(|(name, _)| name);
+    let idx = s.name(&names, "UTC offset or zone name")?;
+    Ok(UtcOffset::from_minutes(ZONES[idx].1 * 60).expect("zone offsets are in range"))
+}
+
+/// Consumes a three-letter weekday abbreviation.
+pub(crate) fn weekday_abbr(s: &mut Scanner) -> Result<(), ParseError> {
+    let abbrs = WEEKDAYS.map(|day| &day[..3]);
+    s.name(&abbrs, "weekday name").map(|_| ())
+}
+
+/// Validates a day parsed before its month and year were known.
+fn check_day(s: &Scanner, at: usize, year: u16, month: u8, day: u8) -> Result<(), ParseError> {
+    let max = days_in_month(year, month);
+    if day > max {
+        return Err(s.error_at(at, 2, format!("day 01-{:02}", max)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(input: &str) -> (String, KnownFormat) {
+        let (t, f) = NanoTime::parse_guess(input).unwrap();
+        (t.datetime_fmt(3), f)
+    }
+
+    #[test]
+    fn test_guess_iso_family() {
+        assert_eq!(
+            guess("2026-02-22T14:30:05.123+01:00"),
+            ("2026-02-22 13:30:05.123".to_string(), KnownFormat::Rfc3339)
+        );
+        assert_eq!(
+            guess("2026-02-22T14:30:05.123"),
+            ("2026-02-22 14:30:05.123".to_string(), KnownFormat::IsoLocal)
+        );
+        assert_eq!(
+            guess("2026-02-22 14:30:05.123"),
+            ("2026-02-22 14:30:05.123".to_string(), KnownFormat::Datetime)
+        );
+        assert_eq!(
+            guess("2026/02/22 14:30:05"),
+            (
+                "2026-02-22 14:30:05.000".to_string(),
+                KnownFormat::SlashDatetime
+            )
+        );
+        assert_eq!(
+            guess("20260222T143005Z"),
+            ("2026-02-22 14:30:05.000".to_string(), KnownFormat::IsoBasic)
+        );
+        assert_eq!(
+            guess("20260222T143005.5-0130"),
+            ("2026-02-22 16:00:05.500".to_string(), KnownFormat::IsoBasic)
+        );
+        assert_eq!(
+            guess("2026-02-22"),
+            ("2026-02-22 00:00:00.000".to_string(), KnownFormat::Date)
+        );
+    }
+
+    #[test]
+    fn test_guess_textual_formats() {
+        let expected = "2026-02-22 14:30:05.000".to_string();
+        assert_eq!(
+            guess("Sun, 22 Feb 2026 14:30:05 +0000"),
+            (expected.clone(), KnownFormat::Rfc2822)
+        );
+        assert_eq!(
+            guess("22 Feb 2026 09:30:05 EST"),
+            (expected.clone(), KnownFormat::Rfc2822)
+        );
+        assert_eq!(
+            guess("Sunday, 22-Feb-26 14:30:05 GMT"),
+            (expected.clone(), KnownFormat::Rfc850)
+        );
+        assert_eq!(
+            guess("Sun Feb 22 14:30:05 2026"),
+            (expected.clone(), KnownFormat::Asctime)
+        );
+        assert_eq!(
+            guess("Sun Feb  1 14:30:05 2026"),
+            ("2026-02-01 14:30:05.000".to_string(), KnownFormat::Asctime)
+        );
+        assert_eq!(
+            guess("[22/Feb/2026:15:30:05 +0100]"),
+            (expected, KnownFormat::CommonLog)
+        );
+    }
+
+    #[test]
+    fn test_guess_epoch_units() {
+        let expected = "2001-09-09 01:46:40.000".to_string();
+        assert_eq!(
+            guess("1000000000"),
+            (expected.clone(), KnownFormat::EpochSeconds)
+        );
+        assert_eq!(
+            guess("1000000000.25"),
+            (
+                "2001-09-09 01:46:40.250".to_string(),
+                KnownFormat::EpochSeconds
+            )
+        );
+        assert_eq!(
+            guess("1000000000000"),
+            (expected.clone(), KnownFormat::EpochMillis)
+        );
+        assert_eq!(
+            guess("1000000000000000"),
+            (expected.clone(), KnownFormat::EpochMicros)
+        );
+        assert_eq!(
+            guess("1000000000000000000"),
+            (expected, KnownFormat::EpochNanos)
+        );
+    }
+
+    #[test]
+    fn test_guess_trims_whitespace() {
+        assert_eq!(guess("  2026-02-22\n").1, KnownFormat::Date);
+    }
+
+    #[test]
+    fn test_guess_error_from_furthest_attempt() {
+        let err = NanoTime::parse_guess("2026-02-30T14:30:05Z").unwrap_err();
+        assert_eq!(err.offset(), 8);
+        assert_eq!(err.expected(), "day 01-28");
+
+        let err = NanoTime::parse_guess("  Sun, 22 Feb 2026 14:30:05 XYZ").unwrap_err();
+        assert_eq!(err.offset(), 28);
+        assert_eq!(err.expected(), "UTC offset or zone name");
+    }
+
+    #[test]
+    fn test_guess_rejects_offsets_out_of_range() {
+        let err = NanoTime::parse_guess("0000-01-01T00:00:00+01:00").unwrap_err();
+        assert_eq!((err.offset(), err.found()), (19, "+01:00"));
+        assert_eq!(err.expected(), crate::parse::OUT_OF_RANGE);
+        let err = NanoTime::parse_guess(" [01/Jan/0000:00:30:00 +0100]").unwrap_err();
+        assert_eq!((err.offset(), err.found()), (23, "+0100"));
+        assert_eq!(
+            guess("0000-01-01T01:00:00+01:00"),
+            ("0000-01-01 00:00:00.000".to_string(), KnownFormat::Rfc3339)
+        );
+        assert_eq!(
+            NanoTime::parse_guess("Sat, 01 Jan 0000 00:00:00 -0100").map(|(t, _)| t),
+            Ok(NanoTime::new(0, 1, 1, 1, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_guess_rejects_garbage() {
+        assert!(NanoTime::parse_guess("").is_err());
+        assert!(NanoTime::parse_guess("yesterday").is_err());
+        assert!(NanoTime::parse_guess("123456789012345678901").is_err());
+    }
+
+    #[test]
+    fn test_known_format_display() {
+        assert_eq!(KnownFormat::CommonLog.to_string(), "Common Log Format");
+    }
+}
@@ -0,0 +1,463 @@
+//! iCalendar (RFC 5545) DATE, DATE-TIME, and DURATION values.
+//!
+//! ```text
+//! DATE         19970714
+//! DATE-TIME    19980118T230000            floating: local time wherever it is read
+//!              19980119T070000Z           UTC
+//!              TZID=America/New_York:19980119T020000
+//! DURATION     P15DT5H0M20S   -PT15M   P7W
+//! ```
+
+use std::fmt;
+
+use super::{civil, ParseError, Scanner};
+use crate::tz::{Resolution, TimeZone};
+use crate::{days_in_month, NanoDuration, NanoTime};
+
+impl Scanner<'_> {
+    /// `YYYYMMDD`
+    fn ical_date(&mut self) -> Result<(u16, u8, u8), ParseError> {
+        let year = self.number(4, 0, 9999, "year")? as u16;
+        let month = self.number(2, 1, 12, "month")? as u8;
+        let max_day = days_in_month(year, month) as u32;
+        let day = self.number(2, 1, max_day, "day")? as u8;
+        Ok((year, month, day))
+    }
+
+    /// `YYYYMMDDTHHMMSS`
+    fn ical_date_time(&mut self) -> Result<NanoTime, ParseError> {
+        let date = self.ical_date()?;
+        self.literal('T')?;
+        let hour = self.number(2, 0, 23, "hour")? as u8;
+        let minute = self.number(2, 0, 59, "minute")? as u8;
+        let second = self.number(2, 0, 59, "second")? as u8;
+        Ok(civil(date, (hour, minute, second, 0)))
+    }
+
+    /// `TZID=` followed by a bare or double-quoted zone identifier and `:`.
+    fn ical_tzid(&mut self) -> Result<String, ParseError> {
+        for c in "TZID=".chars() {
+            self.literal(c)?;
+        }
+        let rest = &self.input[self.pos..];
+        let (tzid, len) = match rest.strip_prefix('"') {
+            Some(quoted) => match quoted.find('"') {
+                Some(end) => (&quoted[..end], end + 2),
+                None => return Err(self.error_at(self.pos, 16, "closing '\"'")),
+            },
+            None => {
+                let end = rest.find(':').unwrap_or(rest.len());
+                (&rest[..end], end)
+            }
+        };
+        if tzid.is_empty() {
+            return Err(self.error("time zone identifier"));
+        }
+        self.pos += len;
+        self.literal(':')?;
+        Ok(tzid.to_string())
+    }
+
+    /// Digits followed by `unit`, or `None` if the next unit differs.
+    fn ical_component(&mut self, unit: char) -> Result<Option<i64>, ParseError> {
+        let start = self.pos;
+        let digits = self.input.as_bytes()[start..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if self.input.as_bytes().get(start + digits) != Some(&(unit as u8)) {
+            return Ok(None);
+        }
+        let n = self.integer(&format!("number before '{}'", unit))?;
+        self.pos += 1;
+        Ok(Some(n))
+    }
+
+    /// `T` then hours, minutes, and seconds in that order, returning
+    /// seconds. RFC 5545 forbids skipping a unit between two written ones,
+    /// as in `PT1H5S`, but such values are accepted.
+    fn ical_dur_time(&mut self) -> Result<i64, ParseError> {
+        self.literal('T')?;
+        let mut secs = 0i64;
+        let mut seen = false;
+        for (unit, scale) in [('H', 3600), ('M', 60), ('S', 1)] {
+            if let Some(n) = self.ical_component(unit)? {
+                secs = n
+                    .checked_mul(scale)
+                    .and_then(|n| n.checked_add(secs))
+                    .ok_or_else(|| self.error("shorter duration"))?;
+                seen = true;
+            }
+        }
+        if !seen {
+            return Err(self.error("hours, minutes, or seconds"));
+        }
+        Ok(secs)
+    }
+
+    fn ical_duration(&mut self) -> Result<ICalDuration, ParseError> {
+        let negative = if self.eat('-') {
+            true
+        } else {
+            self.eat('+');
+            false
+        };
+        self.literal('P')?;
+        let (days, secs) = if let Some(weeks) = self.ical_component('W')? {
+            let days = weeks
+                .checked_mul(7)
+                .ok_or_else(|| self.error("shorter duration"))?;
+            (days, 0)
+        } else if let Some(days) = self.ical_component('D')? {
+            let secs = if self.peek().is_some() {
+                self.ical_dur_time()?
+            } else {
+                0
+            };
+            (days, secs)
+        } else if self.peek() == Some('T') {
+            (0, self.ical_dur_time()?)
+        } else {
+            return Err(self.error("weeks, days, or 'T'"));
+        };
+        let sign = if negative { -1 } else { 1 };
+        Ok(ICalDuration {
+            days: days * sign,
+            secs: secs * sign,
+        })
+    }
+}
+
+impl NanoTime {
+    /// Parses an iCalendar DATE such as `"19970714"` as midnight on that
+    /// day.
+    pub fn parse_ical_date(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let date = s.ical_date()?;
+        s.finish()?;
+        Ok(civil(date, (0, 0, 0, 0)))
+    }
+
+    /// Formats the date as an iCalendar DATE, `YYYYMMDD`.
+    pub fn to_ical_date(&self) -> String {
+        format!("{:04}{:02}{:02}", self.year(), self.month(), self.day())
+    }
+}
+
+/// Writes `YYYYMMDDTHHMMSS`, dropping fractional seconds.
+fn write_ical(f: &mut fmt::Formatter<'_>, t: &NanoTime) -> fmt::Result {
+    write!(
+        f,
+        "{}T{:02}{:02}{:02}",
+        t.to_ical_date(),
+        t.hour(),
+        t.minute(),
+        t.second()
+    )
+}
+
+/// An iCalendar DATE-TIME value, in one of the three forms RFC 5545
+/// defines.
+///
+/// Displays in the form it was parsed from. Fractional seconds are not
+/// representable and are dropped.
+///
+/// ```rust
+/// use nanotime::NanoTime;
+/// use nanotime::parse::ICalDateTime;
+/// use nanotime::tz::TimeZone;
+///
+/// let v = ICalDateTime::parse("TZID=America/New_York:19980119T020000").unwrap();
+/// assert_eq!(v.tzid(), Some("America/New_York"));
+/// assert_eq!(v.to_string(), "TZID=America/New_York:19980119T020000");
+///
+/// let utc = ICalDateTime::parse("19980119T070000Z").unwrap();
+/// assert_eq!(utc.to_utc(&TimeZone::utc()), NanoTime::new(1998, 1, 19, 7, 0, 0, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ICalDateTime {
+    /// A local time with no zone, `19980118T230000`, read as the same
+    /// wall-clock time in whatever zone the calendar is viewed.
+    Floating(NanoTime),
+    /// A UTC time, `19980119T070000Z`.
+    Utc(NanoTime),
+    /// A local time in the zone named by the `TZID` parameter.
+    Zoned {
+        /// The zone identifier, usually an IANA name.
+        tzid: String,
+        /// The wall-clock time in that zone.
+        local: NanoTime,
+    },
+}
+
+impl ICalDateTime {
+    /// Parses a DATE-TIME value, optionally preceded by its property's
+    /// `TZID=` parameter and a `:`, as in
+    /// `"TZID=America/New_York:19980119T020000"`. The identifier may be
+    /// double-quoted. A `TZID` may not be combined with a UTC value.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let tzid = if s.peek() == Some('T') {
+            Some(s.ical_tzid()?)
+        } else {
+            None
+        };
+        let t = s.ical_date_time()?;
+        let value = match tzid {
+            Some(tzid) => ICalDateTime::Zoned { tzid, local: t },
+            None if s.eat('Z') => ICalDateTime::Utc(t),
+            None => ICalDateTime::Floating(t),
+        };
+        s.finish()?;
+        Ok(value)
+    }
+
+    /// Returns the time as written: UTC for [`Utc`](Self::Utc) values and
+    /// wall-clock time otherwise.
+    pub fn time(&self) -> NanoTime {
+        match self {
+            ICalDateTime::Floating(t) | ICalDateTime::Utc(t) => *t,
+            ICalDateTime::Zoned { local, .. } => *local,
+        }
+    }
+
+    /// Returns the `TZID` of a zoned value.
+    pub fn tzid(&self) -> Option<&str> {
+        match self {
+            ICalDateTime::Zoned { tzid, .. } => Some(tzid),
+            _ => None,
+        }
+    }
+
+    /// Converts to a UTC instant, reading floating times in `floating`,
+    /// typically the viewer's [`TimeZone::local`].
+    ///
+    /// Zoned values look up their `TZID` with [`TimeZone::named`] and
+    /// return `None` if the host does not know it; resolve calendars that
+    /// define their own `VTIMEZONE`s with [`TimeZone::resolve`] instead.
+    /// Local times skipped or repeated by a transition follow RFC 5545,
+    /// which is [`Resolution::Shift`]. Returns `None` if the instant falls
+    /// outside years 0000-65535.
+    pub fn to_utc(&self, floating: &TimeZone) -> Option<NanoTime> {
+        let (zone, local) = match self {
+            ICalDateTime::Utc(t) => return Some(*t),
+            ICalDateTime::Floating(t) => (floating.clone(), t),
+            ICalDateTime::Zoned { tzid, local } => (TimeZone::named(tzid)?, local),
+        };
+        zone.resolve(local, Resolution::Shift)
+            .ok()
+            .and_then(|t| t.checked_to_utc())
+    }
+}
+
+impl fmt::Display for ICalDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ICalDateTime::Floating(t) => write_ical(f, t),
+            ICalDateTime::Utc(t) => {
+                write_ical(f, t)?;
+                f.write_str("Z")
+            }
+            ICalDateTime::Zoned { tzid, local } => {
+                if tzid.contains([':', ';', ',']) {
+                    write!(f, "TZID=\"{}\":", tzid)?;
+                } else {
+                    write!(f, "TZID={}:", tzid)?;
+                }
+                write_ical(f, local)
+            }
+        }
+    }
+}
+
+/// An iCalendar DURATION value such as `P15DT5H0M20S` or `-PT15M`.
+///
+/// Days and weeks are nominal: a day added to a local time keeps the
+/// wall-clock time across a DST change, so RFC 5545 keeps them apart from
+/// the exact hours, minutes, and seconds. Both parts carry the duration's
+/// sign. Weeks are stored as seven days.
+///
+/// Displays in the shortest form RFC 5545 allows.
+///
+/// ```rust
+/// use nanotime::parse::ICalDuration;
+///
+/// let d = ICalDuration::parse("P1DT2H").unwrap();
+/// assert_eq!((d.days(), d.secs()), (1, 7200));
+/// assert_eq!(d.to_string(), "P1DT2H");
+/// assert_eq!(ICalDuration::parse("-P2W").unwrap().days(), -14);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ICalDuration {
+    days: i64,
+    secs: i64,
+}
+
+impl ICalDuration {
+    /// Parses a DURATION value.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let d = s.ical_duration()?;
+        s.finish()?;
+        Ok(d)
+    }
+
+    /// The nominal days, including weeks.
+    pub fn days(&self) -> i64 {
+        self.days
+    }
+
+    /// The exact seconds from the hours, minutes, and seconds.
+    pub fn secs(&self) -> i64 {
+        self.secs
+    }
+
+    /// Returns the duration with each day counted as exactly 24 hours,
+    /// which is right for UTC and floating times but not across a DST
+    /// change in a zoned one. Returns `None` on overflow.
+    pub fn as_nano_duration(&self) -> Option<NanoDuration> {
+        let secs = self.days as i128 * 86_400 + self.secs as i128;
+        NanoDuration::from_nanos(secs * 1_000_000_000)
+    }
+}
+
+impl fmt::Display for ICalDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.days < 0 || self.secs < 0 {
+            f.write_str("-")?;
+        }
+        let (days, secs) = (self.days.unsigned_abs(), self.secs.unsigned_abs());
+        f.write_str("P")?;
+        if secs == 0 && days != 0 && days % 7 == 0 {
+            return write!(f, "{}W", days / 7);
+        }
+        if days != 0 {
+            write!(f, "{}D", days)?;
+            if secs == 0 {
+                return Ok(());
+            }
+        }
+        let parts = [
+            (secs / 3600, 'H'),
+            (secs % 3600 / 60, 'M'),
+            (secs % 60, 'S'),
+        ];
+        // Units must be contiguous, so zeros between written units stay.
+        let first = parts.iter().position(|p| p.0 != 0).unwrap_or(2);
+        let last = parts.iter().rposition(|p| p.0 != 0).unwrap_or(2);
+        f.write_str("T")?;
+        for (n, unit) in &parts[first..=last] {
+            write!(f, "{}{}", n, unit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UtcOffset;
+
+    #[test]
+    fn test_date() {
+        let t = NanoTime::parse_ical_date("19970714").unwrap();
+        assert_eq!(t, NanoTime::new(1997, 7, 14, 0, 0, 0, 0).unwrap());
+        assert_eq!(t.to_ical_date(), "19970714");
+        assert_eq!(
+            NanoTime::parse_ical_date("19970230")
+                .unwrap_err()
+                .expected(),
+            "day 01-28"
+        );
+    }
+
+    #[test]
+    fn test_date_time_forms() {
+        let t = NanoTime::new(1998, 1, 18, 23, 0, 0, 0).unwrap();
+        assert_eq!(
+            ICalDateTime::parse("19980118T230000"),
+            Ok(ICalDateTime::Floating(t))
+        );
+        assert_eq!(
+            ICalDateTime::parse("19980118T230000Z"),
+            Ok(ICalDateTime::Utc(t))
+        );
+        let quoted = ICalDateTime::parse("TZID=\"Europe/Berlin\":19980118T230000").unwrap();
+        assert_eq!(quoted.tzid(), Some("Europe/Berlin"));
+        assert_eq!(quoted.time(), t);
+        assert_eq!(quoted.to_string(), "TZID=Europe/Berlin:19980118T230000");
+        let odd = ICalDateTime::Zoned {
+            tzid: "a:b".to_string(),
+            local: t,
+        };
+        assert_eq!(ICalDateTime::parse(&odd.to_string()), Ok(odd));
+    }
+
+    #[test]
+    fn test_date_time_rejections() {
+        let err = |input| ICalDateTime::parse(input).unwrap_err();
+        assert_eq!(
+            err("TZID=Europe/Berlin:19980118T230000Z").expected(),
+            "end of input"
+        );
+        assert_eq!(err("19980118 230000").expected(), "'T'");
+        assert_eq!(
+            err("TZID=:19980118T230000").expected(),
+            "time zone identifier"
+        );
+        assert_eq!(err("TZID=\"x:19980118T230000").expected(), "closing '\"'");
+        assert_eq!(err("1998-01-18T23:00:00").expected(), "2-digit month");
+    }
+
+    #[test]
+    fn test_to_utc() {
+        let est = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        let floating = ICalDateTime::parse("20260308T023000").unwrap();
+        // 02:30 is skipped on this day and shifts forward to 03:30 EDT.
+        assert_eq!(
+            floating.to_utc(&est),
+            NanoTime::new(2026, 3, 8, 7, 30, 0, 0)
+        );
+        let fixed = TimeZone::fixed(UtcOffset::from_minutes(60).unwrap());
+        assert_eq!(
+            floating.to_utc(&fixed),
+            NanoTime::new(2026, 3, 8, 1, 30, 0, 0)
+        );
+        let unknown = ICalDateTime::parse("TZID=Not/AZone:20260308T023000").unwrap();
+        assert_eq!(unknown.to_utc(&fixed), None);
+        let first = ICalDateTime::parse("00000101T000000").unwrap();
+        assert_eq!(first.to_utc(&fixed), None);
+    }
+
+    #[test]
+    fn test_duration_forms() {
+        for (input, days, secs, display) in [
+            ("P15DT5H0M20S", 15, 5 * 3600 + 20, "P15DT5H0M20S"),
+            ("-PT15M", 0, -900, "-PT15M"),
+            ("+P7W", 49, 0, "P7W"),
+            ("P14D", 14, 0, "P2W"),
+            ("PT1H5S", 0, 3605, "PT1H0M5S"),
+            ("PT0S", 0, 0, "PT0S"),
+        ] {
+            let d = ICalDuration::parse(input).unwrap();
+            assert_eq!((d.days(), d.secs()), (days, secs), "{}", input);
+            assert_eq!(d.to_string(), display);
+        }
+        let d = ICalDuration::parse("-P1DT1S").unwrap();
+        assert_eq!(
+            d.as_nano_duration().unwrap().as_nanos(),
+            -86_401_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_duration_rejections() {
+        let err = |input| ICalDuration::parse(input).unwrap_err();
+        assert_eq!(err("15D").expected(), "'P'");
+        assert_eq!(err("P").expected(), "weeks, days, or 'T'");
+        assert_eq!(err("PT").expected(), "hours, minutes, or seconds");
+        assert_eq!(err("P1W2D").expected(), "end of input");
+        assert_eq!(err("PT5S1H").expected(), "end of input");
+        assert_eq!(err("P1D2H").expected(), "'T'");
+    }
+}
@@ -0,0 +1,592 @@
+//! Parsing timestamps from text.
+//!
+//! Every parser reports failures as a [`ParseError`] that pinpoints where
+//! the input went wrong and what was expected there:
+//!
+//! ```rust
+//! use nanotime::NanoTime;
+//!
+//! let err = NanoTime::parse_rfc3339("2026-xx-22T14:30:05Z").unwrap_err();
+//! assert_eq!(err.offset(), 5);
+//! assert_eq!(err.to_string(), "expected 2-digit month at offset 5, found 'xx'");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{days_in_month, NanoTime, OffsetNanoTime, UtcOffset};
+
+mod bytes;
+mod fhir;
+mod git;
+pub(crate) mod guess;
+mod ical;
+mod normalize;
+mod numeric;
+mod partial;
+mod pivot;
+mod rfc2822;
+mod strict;
+mod strptime;
+mod validate;
+mod yaml;
+
+pub use guess::KnownFormat;
+pub use ical::{ICalDateTime, ICalDuration};
+pub use normalize::{normalize, NormalizeOptions};
+pub use numeric::DateOrder;
+pub use partial::PartialNanoTime;
+pub use pivot::YearPivot;
+pub use strict::ParseOptions;
+pub use validate::{is_valid_date, is_valid_rfc3339, is_valid_time};
+
+/// An error from parsing a timestamp.
+///
+/// Carries the byte offset of the problem, the offending fragment of input,
+/// and a description of what was expected there.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    offset: usize,
+    found: String,
+    expected: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(offset: usize, found: &str, expected: impl Into<String>) -> Self {
+        Self {
+            offset,
+            found: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Byte offset into the input where parsing failed.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The input fragment found at [`offset`](Self::offset); empty at end of input.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    /// What the parser expected at [`offset`](Self::offset), e.g. `"2-digit month"`.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} at offset {}, ", self.expected, self.offset)?;
+        if self.found.is_empty() {
+            write!(f, "found end of input")
+        } else {
+            write!(f, "found '{}'", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Longest fragment quoted in an error message.
+const MAX_FRAGMENT: usize = 16;
+
+/// A cursor over parser input that produces positioned [`ParseError`]s.
+pub(crate) struct Scanner<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    /// Consumes `c` if it is next, returning whether it was.
+    pub(crate) fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Up to `chars` characters of input starting at byte `offset`.
+    fn fragment(&self, offset: usize, chars: usize) -> &'a str {
+        let rest = &self.input[offset..];
+        let end = rest
+            .char_indices()
+            .nth(chars.min(MAX_FRAGMENT))
+            .map_or(rest.len(), |(i, _)| i);
+        &rest[..end]
+    }
+
+    /// An error at byte `offset`, quoting `chars` characters of input.
+    pub(crate) fn error_at(
+        &self,
+        offset: usize,
+        chars: usize,
+        expected: impl Into<String>,
+    ) -> ParseError {
+        ParseError::new(offset, self.fragment(offset, chars), expected)
+    }
+
+    /// An error at the current position, quoting the next character.
+    pub(crate) fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.pos, 1, expected)
+    }
+
+    /// Consumes the literal `c`.
+    pub(crate) fn literal(&mut self, c: char) -> Result<(), ParseError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(format!("'{}'", c)))
+        }
+    }
+
+    /// Consumes exactly `width` ASCII digits and checks them against `min..=max`.
+    pub(crate) fn number(
+        &mut self,
+        width: usize,
+        min: u32,
+        max: u32,
+        what: &str,
+    ) -> Result<u32, ParseError> {
+        let start = self.pos;
+        let digits = self.input.as_bytes().get(start..start + width);
+        let Some(digits) = digits.filter(|d| d.iter().all(u8::is_ascii_digit)) else {
+            return Err(self.error_at(start, width, format!("{}-digit {}", width, what)));
+        };
+        let n = digits.iter().fold(0u32, |n, &d| n * 10 + (d - b'0') as u32);
+        if n < min || n > max {
+            return Err(self.error_at(
+                start,
+                width,
+                format!("{} {:0w$}-{:0w$}", what, min, max, w = width),
+            ));
+        }
+        self.pos += width;
+        Ok(n)
+    }
+
+    /// Consumes one or more fractional-second digits, returning nanoseconds.
+    /// Digits beyond the ninth are accepted and truncated.
+    pub(crate) fn fraction(&mut self) -> Result<u32, ParseError> {
+        let digits = self.input.as_bytes()[self.pos..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return Err(self.error("fractional seconds"));
+        }
+        let mut nanos = 0u32;
+        for (i, &d) in self.input.as_bytes()[self.pos..self.pos + digits]
+            .iter()
+            .take(9)
+            .enumerate()
+        {
+            nanos += (d - b'0') as u32 * 10u32.pow(8 - i as u32);
+        }
+        self.pos += digits;
+        Ok(nanos)
+    }
+
+    /// Fails unless all input has been consumed.
+    pub(crate) fn finish(&self) -> Result<(), ParseError> {
+        if self.pos == self.input.len() {
+            Ok(())
+        } else {
+            Err(self.error_at(self.pos, MAX_FRAGMENT, "end of input"))
+        }
+    }
+
+    /// Parses `YYYY-MM-DD`, validating the day against the month.
+    pub(crate) fn date(&mut self) -> Result<(u16, u8, u8), ParseError> {
+        let year = self.number(4, 0, 9999, "year")? as u16;
+        self.literal('-')?;
+        let month = self.number(2, 1, 12, "month")? as u8;
+        self.literal('-')?;
+        let max_day = days_in_month(year, month) as u32;
+        let day = self.number(2, 1, max_day, "day")? as u8;
+        Ok((year, month, day))
+    }
+
+    /// Parses `HH:MM:SS` with optional `.fraction`.
+    pub(crate) fn time(&mut self) -> Result<(u8, u8, u8, u32), ParseError> {
+        let hour = self.number(2, 0, 23, "hour")? as u8;
+        self.literal(':')?;
+        let minute = self.number(2, 0, 59, "minute")? as u8;
+        self.literal(':')?;
+        let second = self.number(2, 0, 59, "second")? as u8;
+        let nanos = if self.eat('.') { self.fraction()? } else { 0 };
+        Ok((hour, minute, second, nanos))
+    }
+
+    /// Parses an RFC 3339 offset: `Z`, `z`, or `±HH:MM`.
+    pub(crate) fn offset(&mut self) -> Result<UtcOffset, ParseError> {
+        if self.eat('Z') || self.eat('z') {
+            return Ok(UtcOffset::UTC);
+        }
+        let start = self.pos;
+        let sign = if self.eat('+') {
+            1
+        } else if self.eat('-') {
+            -1
+        } else {
+            return Err(self.error("'Z' or UTC offset"));
+        };
+        let hours = self.number(2, 0, 23, "offset hours")? as i32;
+        self.literal(':')?;
+        let minutes = self.number(2, 0, 59, "offset minutes")? as i32;
+        UtcOffset::from_secs(sign * (hours * 3600 + minutes * 60))
+            .ok_or_else(|| self.error_at(start, 6, "UTC offset"))
+    }
+
+    /// Parses the date and time of an RFC 3339 timestamp, up to the offset.
+    pub(crate) fn date_time(&mut self) -> Result<NanoTime, ParseError> {
+        let date = self.date()?;
+        if !(self.eat('T') || self.eat('t') || self.eat(' ')) {
+            return Err(self.error("'T' date-time separator"));
+        }
+        Ok(civil(date, self.time()?))
+    }
+
+    /// Parses a full RFC 3339 timestamp.
+    pub(crate) fn rfc3339(&mut self) -> Result<OffsetNanoTime, ParseError> {
+        let local = self.date_time()?;
+        let offset = self.offset()?;
+        Ok(OffsetNanoTime::new(local, offset))
+    }
+
+    /// Like [`rfc3339`](Self::rfc3339), but returns the instant in UTC.
+    pub(crate) fn rfc3339_utc(&mut self) -> Result<NanoTime, ParseError> {
+        let local = self.date_time()?;
+        self.offset_to_utc(local)
+    }
+
+    /// Reads an RFC 3339 offset and converts `local` to UTC with it,
+    /// failing on the offset if the instant falls outside years 0-65535.
+    pub(crate) fn offset_to_utc(&mut self, local: NanoTime) -> Result<NanoTime, ParseError> {
+        let start = self.pos;
+        let offset = self.offset()?;
+        OffsetNanoTime::new(local, offset)
+            .checked_to_utc()
+            .ok_or_else(|| self.error_at(start, self.pos - start, OUT_OF_RANGE))
+    }
+
+    /// Consumes one to `max_width` ASCII digits and checks them against `min..=max`.
+    pub(crate) fn number_var(
+        &mut self,
+        max_width: usize,
+        min: u32,
+        max: u32,
+        what: &str,
+    ) -> Result<u32, ParseError> {
+        let width = self.input.as_bytes()[self.pos..]
+            .iter()
+            .take(max_width)
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if width == 0 {
+            return Err(self.error(what.to_string()));
+        }
+        let start = self.pos;
+        self.number(width, min, max, what)
+            .map_err(|_| self.error_at(start, width, format!("{} {}-{}", what, min, max)))
+    }
+
+    /// Consumes one or more ASCII digits as an `i64`.
+    pub(crate) fn integer(&mut self, what: &str) -> Result<i64, ParseError> {
+        let start = self.pos;
+        let digits = self.input.as_bytes()[start..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        let value = self.input[start..start + digits]
+            .parse()
+            .map_err(|_| self.error_at(start, digits.max(1), what.to_string()))?;
+        self.pos += digits;
+        Ok(value)
+    }
+
+    /// Consumes one of `names` (ASCII case-insensitive), returning its index.
+    pub(crate) fn name(&mut self, names: &[&str], what: &str) -> Result<usize, ParseError> {
+        let rest = self.input.as_bytes().get(self.pos..).unwrap_or_default();
+        for (i, name) in names.iter().enumerate() {
+            if rest.len() >= name.len() && rest[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+            {
+                self.pos += name.len();
+                return Ok(i);
+            }
+        }
+        Err(self.error_at(self.pos, 3, what.to_string()))
+    }
+
+    /// Consumes one or more spaces.
+    pub(crate) fn spaces(&mut self) -> Result<(), ParseError> {
+        if !self.eat(' ') {
+            return Err(self.error("' '"));
+        }
+        while self.eat(' ') {}
+        Ok(())
+    }
+
+    /// Parses a colon-less offset: `+hhmm` or `-hhmm`.
+    pub(crate) fn compact_offset(&mut self) -> Result<UtcOffset, ParseError> {
+        let start = self.pos;
+        let sign = if self.eat('+') {
+            1
+        } else if self.eat('-') {
+            -1
+        } else {
+            return Err(self.error("'+' or '-' UTC offset"));
+        };
+        let hours = self.number(2, 0, 23, "offset hours")? as i32;
+        let minutes = self.number(2, 0, 59, "offset minutes")? as i32;
+        UtcOffset::from_secs(sign * (hours * 3600 + minutes * 60))
+            .ok_or_else(|| self.error_at(start, 5, "UTC offset"))
+    }
+}
+
+/// What an offset that moves the instant outside the supported years was
+/// expected to be.
+pub(crate) const OUT_OF_RANGE: &str = "offset keeping UTC within years 0000-65535";
+
+/// English month abbreviations, January first.
+pub(crate) const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// English weekday names, Sunday first.
+pub(crate) const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Assembles an already-validated date and time.
+pub(crate) fn civil(date: (u16, u8, u8), time: (u8, u8, u8, u32)) -> NanoTime {
+    NanoTime {
+        year: date.0,
+        month: date.1,
+        day: date.2,
+        hour: time.0,
+        minute: time.1,
+        second: time.2,
+        nanosecond: time.3,
+    }
+}
+
+impl NanoTime {
+    /// Parses an RFC 3339 timestamp such as `"2026-02-22T14:30:05.123+05:30"`
+    /// and returns the instant in UTC.
+    ///
+    /// Lowercase `t`/`z` and a space separator are accepted; fractional
+    /// digits beyond nanoseconds are truncated. Leap seconds (`:60`) are
+    /// rejected.
+    ///
+    /// An offset that moves the instant outside years 0-65535, as in
+    /// `0000-01-01T00:00:00+01:00`, is an error.
+    pub fn parse_rfc3339(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let t = s.rfc3339_utc()?;
+        s.finish()?;
+        Ok(t)
+    }
+}
+
+impl OffsetNanoTime {
+    /// Parses an RFC 3339 timestamp, keeping the local time and offset as
+    /// written.
+    pub fn parse_rfc3339(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let t = s.rfc3339()?;
+        s.finish()?;
+        Ok(t)
+    }
+}
+
+/// Reads the crate's own [`NanoTime::date`] and [`NanoTime::datetime`]
+/// output and RFC 3339.
+///
+/// A bare date is midnight. A time follows a space or `T` and may have
+/// any number of fractional digits. With a `Z` or `±HH:MM` offset the
+/// result is converted to UTC; without one it is returned as written.
+///
+/// ```rust
+/// use nanotime::NanoTime;
+///
+/// let t: NanoTime = "2026-02-22 09:05:03.000".parse().unwrap();
+/// assert_eq!(t, NanoTime::new(2026, 2, 22, 9, 5, 3, 0).unwrap());
+/// assert_eq!("2026-02-22".parse::<NanoTime>().unwrap().datetime(), "2026-02-22 00:00:00.000");
+/// assert_eq!("2026-02-22T14:30:05+05:30".parse::<NanoTime>().unwrap().to_rfc3339(), "2026-02-22T09:00:05Z");
+///
+/// let err = "2026-02-22 25:00:00".parse::<NanoTime>().unwrap_err();
+/// assert_eq!((err.offset(), err.expected()), (11, "hour 00-23"));
+/// assert!("0000-01-01 00:00:00+01:00".parse::<NanoTime>().is_err());
+/// ```
+impl FromStr for NanoTime {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let date = s.date()?;
+        if s.peek().is_none() {
+            return Ok(civil(date, (0, 0, 0, 0)));
+        }
+        if !(s.eat(' ') || s.eat('T') || s.eat('t')) {
+            return Err(s.error("' ' or 'T' date-time separator"));
+        }
+        let t = civil(date, s.time()?);
+        if s.peek().is_none() {
+            return Ok(t);
+        }
+        let utc = s.offset_to_utc(t)?;
+        s.finish()?;
+        Ok(utc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn err(input: &str) -> ParseError {
+        NanoTime::parse_rfc3339(input).unwrap_err()
+    }
+
+    #[test]
+    fn test_parse_rfc3339_utc() {
+        let t = NanoTime::parse_rfc3339("2026-02-22T14:30:05Z").unwrap();
+        assert_eq!(t, NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_rfc3339_offset_and_fraction() {
+        let t = OffsetNanoTime::parse_rfc3339("2026-02-22T14:30:05.123456789+05:30").unwrap();
+        assert_eq!(t.offset().as_secs(), 19_800);
+        assert_eq!(t.local().nanosecond(), 123_456_789);
+        assert_eq!(
+            t.to_utc(),
+            NanoTime::new(2026, 2, 22, 9, 0, 5, 123_456_789).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_rfc3339_lenient_forms() {
+        let expected = NanoTime::new(2026, 2, 22, 14, 30, 5, 120_000_000).unwrap();
+        assert_eq!(
+            NanoTime::parse_rfc3339("2026-02-22t14:30:05.12z").unwrap(),
+            expected
+        );
+        assert_eq!(
+            NanoTime::parse_rfc3339("2026-02-22 14:30:05.1200000009Z").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_rfc3339_year_range_ends() {
+        let e = err("0000-01-01T00:59:59.9+01:00");
+        assert_eq!((e.offset(), e.expected()), (21, OUT_OF_RANGE));
+        assert_eq!(
+            NanoTime::parse_rfc3339("0000-01-01T01:00:00+01:00"),
+            Ok(NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap())
+        );
+        // Four-digit years end at 9999, which a western offset carries
+        // into 10000, still in range.
+        assert_eq!(
+            NanoTime::parse_rfc3339("9999-12-31T23:59:59-23:59"),
+            Ok(NanoTime::new(10000, 1, 1, 23, 58, 59, 0).unwrap())
+        );
+        let local = OffsetNanoTime::parse_rfc3339("0000-01-01T00:00:00+01:00").unwrap();
+        assert_eq!(local.checked_to_utc(), None);
+    }
+
+    #[test]
+    fn test_error_reports_position_and_expectation() {
+        let e = err("2026-xx-22T14:30:05Z");
+        assert_eq!(e.offset(), 5);
+        assert_eq!(e.found(), "xx");
+        assert_eq!(e.expected(), "2-digit month");
+        assert_eq!(
+            e.to_string(),
+            "expected 2-digit month at offset 5, found 'xx'"
+        );
+    }
+
+    #[test]
+    fn test_error_range_checks() {
+        assert_eq!(err("2026-13-01T00:00:00Z").expected(), "month 01-12");
+        let e = err("2025-02-29T00:00:00Z");
+        assert_eq!(e.offset(), 8);
+        assert_eq!(e.expected(), "day 01-28");
+        assert_eq!(err("2026-02-22T24:00:00Z").expected(), "hour 00-23");
+        assert_eq!(err("2026-02-22T23:59:60Z").expected(), "second 00-59");
+    }
+
+    #[test]
+    fn test_error_end_of_input() {
+        let e = err("2026-02-22T14:30");
+        assert_eq!(e.offset(), 16);
+        assert_eq!(e.found(), "");
+        assert_eq!(
+            e.to_string(),
+            "expected ':' at offset 16, found end of input"
+        );
+        assert_eq!(err("2026-02-22T14:30:05").expected(), "'Z' or UTC offset");
+    }
+
+    #[test]
+    fn test_error_trailing_input() {
+        let e = err("2026-02-22T14:30:05Z junk");
+        assert_eq!(e.offset(), 20);
+        assert_eq!(e.found(), " junk");
+        assert_eq!(e.expected(), "end of input");
+    }
+
+    #[test]
+    fn test_error_multibyte_fragment() {
+        let e = err("2026-0é-22T14:30:05Z");
+        assert_eq!(e.offset(), 5);
+        assert_eq!(e.found(), "0é");
+    }
+
+    #[test]
+    fn test_from_str_own_output_and_rfc3339() {
+        let t = NanoTime::new(2026, 2, 22, 9, 5, 3, 120_000_000).unwrap();
+        assert_eq!(t.datetime().parse::<NanoTime>(), Ok(t));
+        assert_eq!(
+            t.date().parse::<NanoTime>().unwrap(),
+            NanoTime::new(2026, 2, 22, 0, 0, 0, 0).unwrap()
+        );
+        assert_eq!(t.to_rfc3339().parse::<NanoTime>(), Ok(t));
+        assert_eq!("2026-02-22T09:05:03.12".parse::<NanoTime>(), Ok(t));
+        assert_eq!("2026-02-22 14:05:03.12+05:00".parse::<NanoTime>(), Ok(t));
+    }
+
+    #[test]
+    fn test_from_str_errors_name_the_component() {
+        let err = |input: &str| input.parse::<NanoTime>().unwrap_err();
+        assert_eq!(err("2026-02-30").expected(), "day 01-28");
+        assert_eq!(err("2026-02-22 09:60:00").expected(), "minute 00-59");
+        assert_eq!(err("2026-02-22 09:05").expected(), "':'");
+        assert_eq!(
+            err("2026-02-22/09:05:03").expected(),
+            "' ' or 'T' date-time separator"
+        );
+        assert_eq!(err("2026-02-22 09:05:03 UTC").offset(), 19);
+        assert_eq!(err("").expected(), "4-digit year");
+    }
+}
@@ -0,0 +1,146 @@
+//! One-call conversion of arbitrary timestamps to canonical RFC 3339.
+
+use super::guess::{checked_utc, guess};
+use super::{ParseError, Scanner, OUT_OF_RANGE};
+use crate::tz::Resolution;
+use crate::{Precision, TimeZone};
+
+/// Settings for [`normalize`].
+///
+/// ```rust
+/// use nanotime::parse::NormalizeOptions;
+/// use nanotime::{Precision, TimeZone, UtcOffset};
+///
+/// let opts = NormalizeOptions::new()
+///     .with_precision(Precision::Millis)
+///     .with_zone(TimeZone::fixed(UtcOffset::from_minutes(-300).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    precision: Precision,
+    zone: TimeZone,
+}
+
+impl NormalizeOptions {
+    /// Trims fractional zeros and reads inputs without an offset as UTC.
+    pub fn new() -> Self {
+        NormalizeOptions {
+            precision: Precision::Auto,
+            zone: TimeZone::utc(),
+        }
+    }
+
+    /// Sets the fractional-second precision of the output.
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the zone that inputs without an offset, such as
+    /// `2026-02-22 14:30:05`, were recorded in. Times repeated by a DST
+    /// transition take the earlier instant, and times skipped by one move
+    /// forward by the length of the gap, as [`Resolution::Shift`] does.
+    pub fn with_zone(mut self, zone: TimeZone) -> Self {
+        self.zone = zone;
+        self
+    }
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses `input` in any format [`NanoTime::parse_guess`] recognizes and
+/// returns it as an RFC 3339 string in UTC.
+///
+/// Inputs with an offset or zone name keep the instant they denote, and an
+/// offset that moves it outside years 0-65535 is an error. Inputs without
+/// one are read in the options' zone, UTC by default, and it is an error
+/// too if that zone's offset moves them out of range.
+///
+/// ```rust
+/// use nanotime::parse::{normalize, NormalizeOptions};
+///
+/// let opts = NormalizeOptions::new();
+/// assert_eq!(
+///     normalize("Sun, 22 Feb 2026 14:30:05 +0100", &opts).unwrap(),
+///     "2026-02-22T13:30:05Z"
+/// );
+/// assert_eq!(normalize("1771770605123", &opts).unwrap(), "2026-02-22T14:30:05.123Z");
+/// ```
+///
+/// [`NanoTime::parse_guess`]: crate::NanoTime::parse_guess
+pub fn normalize(input: &str, opts: &NormalizeOptions) -> Result<String, ParseError> {
+    let (local, zone, _) = guess(input)?;
+    let utc = match zone {
+        Some(zone) => checked_utc(input, local, zone)?,
+        None => opts
+            .zone
+            .resolve(&local, Resolution::Shift)
+            .expect("Shift always resolves")
+            .checked_to_utc()
+            .ok_or_else(|| {
+                // There is no offset to point at, so point at the whole time.
+                let at = input.len() - input.trim_start().len();
+                Scanner::new(input).error_at(at, input.trim().len(), OUT_OF_RANGE)
+            })?,
+    };
+    Ok(utc.to_rfc3339_opts(opts.precision, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UtcOffset;
+
+    #[test]
+    fn test_zoned_inputs_keep_their_instant() {
+        let opts = NormalizeOptions::new()
+            .with_zone(TimeZone::fixed(UtcOffset::from_minutes(600).unwrap()));
+        assert_eq!(
+            normalize("2026-02-22T14:30:05.5+05:30", &opts).unwrap(),
+            "2026-02-22T09:00:05.5Z"
+        );
+        assert_eq!(
+            normalize("  [22/Feb/2026:14:30:05 +0000]\n", &opts).unwrap(),
+            "2026-02-22T14:30:05Z"
+        );
+    }
+
+    #[test]
+    fn test_zoneless_inputs_use_the_zone() {
+        let opts = NormalizeOptions::new();
+        assert_eq!(
+            normalize("2026-02-22 14:30:05", &opts).unwrap(),
+            "2026-02-22T14:30:05Z"
+        );
+        let opts = opts.with_zone(TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap());
+        assert_eq!(
+            normalize("2026-07-04 12:00:00", &opts).unwrap(),
+            "2026-07-04T16:00:00Z"
+        );
+        // 02:30 is skipped on 2026-03-08; it shifts to 03:30 EDT.
+        assert_eq!(
+            normalize("2026-03-08 02:30:00", &opts).unwrap(),
+            "2026-03-08T07:30:00Z"
+        );
+    }
+
+    #[test]
+    fn test_precision_and_errors() {
+        let opts = NormalizeOptions::new().with_precision(Precision::Micros);
+        assert_eq!(
+            normalize("2026-02-22", &opts).unwrap(),
+            "2026-02-22T00:00:00.000000Z"
+        );
+        let err = normalize("  yesterday", &opts).unwrap_err();
+        assert_eq!(err.offset(), 2);
+        let err = normalize("0000-01-01T00:00:00+01:00", &opts).unwrap_err();
+        assert_eq!((err.offset(), err.expected()), (19, OUT_OF_RANGE));
+        let east = opts.with_zone(TimeZone::fixed(UtcOffset::from_minutes(600).unwrap()));
+        let err = normalize(" 0000-01-01 00:00:00", &east).unwrap_err();
+        assert_eq!((err.offset(), err.expected()), (1, OUT_OF_RANGE));
+    }
+}
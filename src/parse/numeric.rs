@@ -0,0 +1,156 @@
+//! All-numeric dates such as `02/03/2026`, whose field order varies by
+//! region.
+
+use super::{civil, ParseError, Scanner};
+use crate::{days_in_month, NanoTime};
+
+/// The field order of an all-numeric date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    /// Month, day, year: `02/22/2026`, as in the United States.
+    Mdy,
+    /// Day, month, year: `22/02/2026` or `22.02.2026`, as in most of Europe.
+    Dmy,
+    /// Year, month, day: `2026/02/22`, as in East Asia.
+    Ymd,
+}
+
+impl Scanner<'_> {
+    fn date_separator(&mut self) -> Result<char, ParseError> {
+        for c in ['/', '.', '-'] {
+            if self.eat(c) {
+                return Ok(c);
+            }
+        }
+        Err(self.error("'/', '.', or '-' separator"))
+    }
+
+    /// Reads the fields in `order`, returning the year, month, day, and the
+    /// day's offset. The day is checked against 1–31 only.
+    fn numeric_date(&mut self, order: DateOrder) -> Result<(u16, u8, u8, usize), ParseError> {
+        let year = |s: &mut Self| s.number(4, 0, 9999, "year").map(|y| y as u16);
+        let month = |s: &mut Self| s.number_var(2, 1, 12, "month").map(|m| m as u8);
+        let day = |s: &mut Self| Ok((s.pos, s.number_var(2, 1, 31, "day")? as u8));
+        Ok(match order {
+            DateOrder::Ymd => {
+                let y = year(self)?;
+                let sep = self.date_separator()?;
+                let m = month(self)?;
+                self.literal(sep)?;
+                let (at, d) = day(self)?;
+                (y, m, d, at)
+            }
+            DateOrder::Mdy => {
+                let m = month(self)?;
+                let sep = self.date_separator()?;
+                let (at, d) = day(self)?;
+                self.literal(sep)?;
+                (year(self)?, m, d, at)
+            }
+            DateOrder::Dmy => {
+                let (at, d) = day(self)?;
+                let sep = self.date_separator()?;
+                let m = month(self)?;
+                self.literal(sep)?;
+                (year(self)?, m, d, at)
+            }
+        })
+    }
+}
+
+fn parse(input: &str, order: DateOrder, strict: bool) -> Result<NanoTime, ParseError> {
+    let mut s = Scanner::new(input);
+    let (year, month, day, day_at) = s.numeric_date(order)?;
+    s.finish()?;
+    let max_day = days_in_month(year, month);
+    if day > max_day {
+        let width = input[day_at..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        return Err(s.error_at(day_at, width, format!("day 1-{}", max_day)));
+    }
+    // Swapping day and month gives another valid date.
+    let ambiguous = order != DateOrder::Ymd && day != month && day <= 12;
+    if strict && ambiguous {
+        return Err(s.error_at(0, input.len(), "unambiguous day and month"));
+    }
+    Ok(civil((year, month, day), (0, 0, 0, 0)))
+}
+
+impl NanoTime {
+    /// Parses an all-numeric date such as `"02/03/2026"`, reading the fields
+    /// in `order`. Returns midnight on that date.
+    ///
+    /// Fields may be separated by `/`, `.`, or `-`, used consistently. Day
+    /// and month take one or two digits; the year takes four.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    /// use nanotime::parse::DateOrder;
+    ///
+    /// let us = NanoTime::parse_numeric_date("02/03/2026", DateOrder::Mdy).unwrap();
+    /// let eu = NanoTime::parse_numeric_date("02/03/2026", DateOrder::Dmy).unwrap();
+    /// assert_eq!(us.date(), "2026-02-03");
+    /// assert_eq!(eu.date(), "2026-03-02");
+    /// ```
+    pub fn parse_numeric_date(input: &str, order: DateOrder) -> Result<Self, ParseError> {
+        parse(input, order, false)
+    }
+
+    /// Like [`parse_numeric_date`](Self::parse_numeric_date), but rejects
+    /// dates that would also be valid with day and month swapped, such as
+    /// `02/03/2026`. Dates like `22/02/2026` or `02/02/2026` read the same
+    /// either way and are accepted. [`DateOrder::Ymd`] is never ambiguous.
+    ///
+    /// Use this when the convention is assumed rather than known, so a wrong
+    /// assumption fails instead of silently producing a different date.
+    pub fn parse_numeric_date_strict(input: &str, order: DateOrder) -> Result<Self, ParseError> {
+        parse(input, order, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(input: &str, order: DateOrder) -> String {
+        NanoTime::parse_numeric_date(input, order).unwrap().date()
+    }
+
+    fn err(input: &str, order: DateOrder) -> ParseError {
+        NanoTime::parse_numeric_date(input, order).unwrap_err()
+    }
+
+    #[test]
+    fn test_each_order() {
+        assert_eq!(date("2/22/2026", DateOrder::Mdy), "2026-02-22");
+        assert_eq!(date("22.2.2026", DateOrder::Dmy), "2026-02-22");
+        assert_eq!(date("2026-02-22", DateOrder::Ymd), "2026-02-22");
+        assert_eq!(date("2026/2/3", DateOrder::Ymd), "2026-02-03");
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        assert_eq!(err("22/02/2026", DateOrder::Mdy).expected(), "month 1-12");
+        let e = err("29/02/2026", DateOrder::Dmy);
+        assert_eq!((e.offset(), e.found(), e.expected()), (0, "29", "day 1-28"));
+        let e = err("2/30/2026", DateOrder::Mdy);
+        assert_eq!((e.offset(), e.found()), (2, "30"));
+        assert_eq!(err("02/22.2026", DateOrder::Mdy).expected(), "'/'");
+        assert_eq!(err("02 22 2026", DateOrder::Mdy).offset(), 2);
+        assert_eq!(err("02/22/26", DateOrder::Mdy).expected(), "4-digit year");
+    }
+
+    #[test]
+    fn test_strict_rejects_ambiguous() {
+        let strict = NanoTime::parse_numeric_date_strict;
+        let e = strict("02/03/2026", DateOrder::Mdy).unwrap_err();
+        assert_eq!(e.expected(), "unambiguous day and month");
+        assert!(strict("02/03/2026", DateOrder::Dmy).is_err());
+        assert!(strict("02/22/2026", DateOrder::Mdy).is_ok());
+        assert!(strict("02/02/2026", DateOrder::Dmy).is_ok());
+        assert!(strict("2026/02/03", DateOrder::Ymd).is_ok());
+        assert!(strict("12/31/2026", DateOrder::Mdy).is_ok());
+    }
+}
@@ -0,0 +1,255 @@
+//! Reduced-precision ISO 8601 timestamps such as `2026` or `2026-02`.
+
+use super::{ParseError, Scanner};
+use crate::{days_in_month, NanoTime, UtcOffset};
+
+/// A timestamp that may stop short of full precision, recording which
+/// fields were written.
+///
+/// ISO 8601 allows dropping trailing fields, and metadata standards such
+/// as Dublin Core and XMP use that routinely: `2026`, `2026-02`,
+/// `2026-02-22`, `2026-02-22T14`, and `2026-02-22T14:30` are all valid.
+/// Fields are present in order, so a missing month implies a missing day
+/// and every time field.
+///
+/// ```rust
+/// use nanotime::{NanoTime, PartialNanoTime};
+///
+/// let p = PartialNanoTime::parse("2026-02").unwrap();
+/// assert_eq!((p.year(), p.month(), p.day()), (2026, Some(2), None));
+/// assert_eq!(p.start().datetime(), "2026-02-01 00:00:00.000");
+///
+/// let noon = NanoTime::new(1970, 1, 15, 12, 0, 0, 0).unwrap();
+/// assert_eq!(p.resolve(&noon).unwrap().datetime(), "2026-02-15 12:00:00.000");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartialNanoTime {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+    hour: Option<u8>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    nanosecond: Option<u32>,
+    offset: Option<UtcOffset>,
+}
+
+impl PartialNanoTime {
+    /// Parses an ISO 8601 extended-format timestamp with any number of
+    /// trailing fields left off, down to a bare year.
+    ///
+    /// A `T`, `t`, or space separates the date from the time. When an hour
+    /// is present, a `Z` or `±HH:MM` offset may follow the last time field.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let mut p = PartialNanoTime {
+            year: s.number(4, 0, 9999, "year")? as u16,
+            month: None,
+            day: None,
+            hour: None,
+            minute: None,
+            second: None,
+            nanosecond: None,
+            offset: None,
+        };
+        if s.eat('-') {
+            let month = s.number(2, 1, 12, "month")? as u8;
+            p.month = Some(month);
+            if s.eat('-') {
+                let max_day = days_in_month(p.year, month) as u32;
+                p.day = Some(s.number(2, 1, max_day, "day")? as u8);
+                if s.eat('T') || s.eat('t') || s.eat(' ') {
+                    p.parse_time(&mut s)?;
+                }
+            }
+        }
+        s.finish()?;
+        Ok(p)
+    }
+
+    fn parse_time(&mut self, s: &mut Scanner) -> Result<(), ParseError> {
+        self.hour = Some(s.number(2, 0, 23, "hour")? as u8);
+        if s.eat(':') {
+            self.minute = Some(s.number(2, 0, 59, "minute")? as u8);
+            if s.eat(':') {
+                self.second = Some(s.number(2, 0, 59, "second")? as u8);
+                if s.eat('.') {
+                    self.nanosecond = Some(s.fraction()?);
+                }
+            }
+        }
+        if s.peek().is_some() {
+            self.offset = Some(s.offset()?);
+        }
+        Ok(())
+    }
+
+    /// A date with trailing fields left off and no time, from fields a
+    /// parser has already validated.
+    pub(super) fn date_only(year: u16, month: Option<u8>, day: Option<u8>) -> Self {
+        PartialNanoTime {
+            year,
+            month,
+            day,
+            hour: None,
+            minute: None,
+            second: None,
+            nanosecond: None,
+            offset: None,
+        }
+    }
+
+    /// A complete timestamp with the offset it was written at.
+    pub(super) fn with_offset(local: NanoTime, offset: UtcOffset) -> Self {
+        PartialNanoTime {
+            offset: Some(offset),
+            ..PartialNanoTime::from(local)
+        }
+    }
+
+    /// The year, always present.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+    /// The month, if written.
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+    /// The day of the month, if written.
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+    /// The hour, if written.
+    pub fn hour(&self) -> Option<u8> {
+        self.hour
+    }
+    /// The minute, if written.
+    pub fn minute(&self) -> Option<u8> {
+        self.minute
+    }
+    /// The second, if written.
+    pub fn second(&self) -> Option<u8> {
+        self.second
+    }
+    /// The fractional second in nanoseconds, if written.
+    pub fn nanosecond(&self) -> Option<u32> {
+        self.nanosecond
+    }
+    /// The UTC offset, if written.
+    pub fn offset(&self) -> Option<UtcOffset> {
+        self.offset
+    }
+
+    /// Returns `true` if every field down to the second was written.
+    pub fn is_complete(&self) -> bool {
+        self.second.is_some()
+    }
+
+    /// Fills missing fields from `defaults` and returns the time as
+    /// written, without applying any offset. Returns `None` if the result
+    /// is not a valid date, such as `2026-02` with a default day of 30.
+    pub fn resolve(&self, defaults: &NanoTime) -> Option<NanoTime> {
+        NanoTime::new(
+            self.year,
+            self.month.unwrap_or(defaults.month()),
+            self.day.unwrap_or(defaults.day()),
+            self.hour.unwrap_or(defaults.hour()),
+            self.minute.unwrap_or(defaults.minute()),
+            self.second.unwrap_or(defaults.second()),
+            self.nanosecond.unwrap_or(defaults.nanosecond()),
+        )
+    }
+
+    /// The earliest instant the value covers, such as January 1st at
+    /// midnight for a bare year. The offset is not applied.
+    pub fn start(&self) -> NanoTime {
+        NanoTime::new(
+            self.year,
+            self.month.unwrap_or(1),
+            self.day.unwrap_or(1),
+            self.hour.unwrap_or(0),
+            self.minute.unwrap_or(0),
+            self.second.unwrap_or(0),
+            self.nanosecond.unwrap_or(0),
+        )
+        .expect("parsed fields are valid")
+    }
+}
+
+impl From<NanoTime> for PartialNanoTime {
+    fn from(t: NanoTime) -> Self {
+        PartialNanoTime {
+            year: t.year(),
+            month: Some(t.month()),
+            day: Some(t.day()),
+            hour: Some(t.hour()),
+            minute: Some(t.minute()),
+            second: Some(t.second()),
+            nanosecond: Some(t.nanosecond()),
+            offset: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> PartialNanoTime {
+        PartialNanoTime::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_each_precision() {
+        let p = parse("2026");
+        assert_eq!((p.year(), p.month()), (2026, None));
+        assert_eq!(parse("2026-02").day(), None);
+        assert_eq!(parse("2026-02-22").hour(), None);
+
+        let p = parse("2026-02-22T14");
+        assert_eq!((p.hour(), p.minute()), (Some(14), None));
+        let p = parse("2026-02-22T14:30");
+        assert_eq!((p.minute(), p.second()), (Some(30), None));
+        assert!(!p.is_complete());
+
+        let p = parse("2026-02-22 14:30:05.25");
+        assert_eq!(p.nanosecond(), Some(250_000_000));
+        assert!(p.is_complete());
+    }
+
+    #[test]
+    fn test_offsets() {
+        let p = parse("2026-02-22T14:30+05:30");
+        assert_eq!(p.offset(), UtcOffset::from_minutes(330));
+        assert_eq!(parse("2026-02-22T14Z").offset(), Some(UtcOffset::UTC));
+        assert_eq!(parse("2026-02-22T14:30:05").offset(), None);
+    }
+
+    #[test]
+    fn test_resolve_and_start() {
+        let defaults = NanoTime::new(2000, 6, 30, 9, 15, 45, 5).unwrap();
+        assert_eq!(
+            parse("2026").resolve(&defaults),
+            NanoTime::new(2026, 6, 30, 9, 15, 45, 5)
+        );
+        assert_eq!(parse("2026-02").resolve(&defaults), None);
+        assert_eq!(
+            parse("2026-02-22T14").start(),
+            NanoTime::new(2026, 2, 22, 14, 0, 0, 0).unwrap()
+        );
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 1).unwrap();
+        assert_eq!(PartialNanoTime::from(t).resolve(&defaults), Some(t));
+    }
+
+    #[test]
+    fn test_rejections() {
+        let err = |input| PartialNanoTime::parse(input).unwrap_err();
+        assert_eq!(err("26").expected(), "4-digit year");
+        assert_eq!(err("2026-2").expected(), "2-digit month");
+        assert_eq!(err("2026-02-30").expected(), "day 01-28");
+        assert_eq!(err("2026-02-22T").expected(), "2-digit hour");
+        assert_eq!(err("2026-02-22T14:").expected(), "2-digit minute");
+        assert_eq!(err("2026-02-22T14+0530").expected(), "':'");
+        assert_eq!(err("2026-02X").expected(), "end of input");
+    }
+}
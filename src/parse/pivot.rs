@@ -0,0 +1,170 @@
+//! Two-digit year expansion and ASN.1 `UTCTime`.
+
+use super::{civil, ParseError, Scanner, OUT_OF_RANGE};
+use crate::{days_in_month, NanoTime, OffsetNanoTime, UtcOffset};
+
+/// How a two-digit year maps to a full year.
+///
+/// ```rust
+/// use nanotime::parse::YearPivot;
+///
+/// assert_eq!(YearPivot::Posix.expand(68), 2068);
+/// assert_eq!(YearPivot::Posix.expand(69), 1969);
+/// assert_eq!(YearPivot::Window(1950).expand(49), 2049);
+/// assert_eq!(YearPivot::Window(1950).expand(50), 1950);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum YearPivot {
+    /// `69`–`99` are 1969–1999 and `00`–`68` are 2000–2068, the POSIX
+    /// `strptime` rule.
+    #[default]
+    Posix,
+    /// The hundred years starting at the given year. `Window(1950)` is the
+    /// X.509 `UTCTime` rule from RFC 5280.
+    Window(u16),
+}
+
+impl YearPivot {
+    /// Expands `yy` (taken modulo 100) to a full year. Saturates at 65535
+    /// for windows starting near the end of the year range.
+    pub fn expand(self, yy: u8) -> u16 {
+        self.checked_expand(yy).unwrap_or(u16::MAX)
+    }
+
+    /// Expands `yy` (taken modulo 100), or `None` if the year would pass
+    /// 65535.
+    fn checked_expand(self, yy: u8) -> Option<u16> {
+        let yy = (yy % 100) as u16;
+        match self {
+            YearPivot::Posix => {
+                if yy < 69 {
+                    Some(2000 + yy)
+                } else {
+                    Some(1900 + yy)
+                }
+            }
+            YearPivot::Window(start) => {
+                let offset = (yy + 100 - start % 100) % 100;
+                start.checked_add(offset)
+            }
+        }
+    }
+
+    /// Parses two digits as a year under this pivot, failing if the year
+    /// would pass 65535.
+    pub(crate) fn scan(self, s: &mut Scanner) -> Result<u16, ParseError> {
+        let start = s.pos;
+        let yy = s.number(2, 0, 99, "year")? as u8;
+        self.checked_expand(yy)
+            .ok_or_else(|| s.error_at(start, 2, "2-digit year expanding to at most 65535"))
+    }
+}
+
+impl Scanner<'_> {
+    /// `YYMMDDhhmm[ss](Z|±hhmm)`, returned in UTC.
+    fn utc_time(&mut self, pivot: YearPivot) -> Result<NanoTime, ParseError> {
+        let year = pivot.scan(self)?;
+        let month = self.number(2, 1, 12, "month")? as u8;
+        let max_day = days_in_month(year, month) as u32;
+        let day = self.number(2, 1, max_day, "day")? as u8;
+        let hour = self.number(2, 0, 23, "hour")? as u8;
+        let minute = self.number(2, 0, 59, "minute")? as u8;
+        let second = match self.peek() {
+            Some(c) if c.is_ascii_digit() => self.number(2, 0, 59, "second")? as u8,
+            _ => 0,
+        };
+        let start = self.pos;
+        let offset = if self.eat('Z') {
+            UtcOffset::UTC
+        } else {
+            self.compact_offset()?
+        };
+        let local = civil((year, month, day), (hour, minute, second, 0));
+        OffsetNanoTime::new(local, offset)
+            .checked_to_utc()
+            .ok_or_else(|| self.error_at(start, self.pos - start, OUT_OF_RANGE))
+    }
+}
+
+impl NanoTime {
+    /// Parses an ASN.1 `UTCTime` such as `"260222143005Z"` and returns the
+    /// instant in UTC, expanding the two-digit year with `pivot`.
+    ///
+    /// Seconds are optional and the zone may be `Z` or `±hhmm`, per X.680.
+    /// Certificates follow RFC 5280, which requires seconds and `Z` and
+    /// pivots with [`YearPivot::Window(1950)`](YearPivot::Window).
+    ///
+    /// A year the pivot would carry past 65535, or an offset that moves
+    /// the instant outside years 0-65535, is an error.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    /// use nanotime::parse::YearPivot;
+    ///
+    /// let t = NanoTime::parse_utc_time("491231235959Z", YearPivot::Window(1950)).unwrap();
+    /// assert_eq!(t.date(), "2049-12-31");
+    /// ```
+    pub fn parse_utc_time(input: &str, pivot: YearPivot) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let t = s.utc_time(pivot)?;
+        s.finish()?;
+        Ok(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_posix_pivot() {
+        assert_eq!(YearPivot::default(), YearPivot::Posix);
+        assert_eq!(YearPivot::Posix.expand(0), 2000);
+        assert_eq!(YearPivot::Posix.expand(99), 1999);
+    }
+
+    #[test]
+    fn test_window_pivot() {
+        let w = YearPivot::Window(1987);
+        assert_eq!(w.expand(87), 1987);
+        assert_eq!(w.expand(86), 2086);
+        assert_eq!(w.expand(0), 2000);
+        assert_eq!(YearPivot::Window(2000).expand(99), 2099);
+        assert_eq!(YearPivot::Window(65500).expand(35), 65535);
+        assert_eq!(YearPivot::Window(65500).expand(36), 65535);
+    }
+
+    #[test]
+    fn test_parse_utc_time() {
+        let rfc5280 = YearPivot::Window(1950);
+        assert_eq!(
+            NanoTime::parse_utc_time("500101000000Z", rfc5280).unwrap(),
+            NanoTime::new(1950, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            NanoTime::parse_utc_time("2602221430+0530", YearPivot::Posix).unwrap(),
+            NanoTime::new(2026, 2, 22, 9, 0, 0, 0).unwrap()
+        );
+        let err = NanoTime::parse_utc_time("260222143005", rfc5280).unwrap_err();
+        assert_eq!(err.expected(), "'+' or '-' UTC offset");
+        let err = NanoTime::parse_utc_time("2602291430Z", rfc5280).unwrap_err();
+        assert_eq!(err.expected(), "day 01-28");
+    }
+
+    #[test]
+    fn test_parse_utc_time_range() {
+        let err = NanoTime::parse_utc_time("000101000000+0100", YearPivot::Window(0)).unwrap_err();
+        assert_eq!((err.offset(), err.found()), (12, "+0100"));
+        assert_eq!(err.expected(), OUT_OF_RANGE);
+        assert_eq!(
+            NanoTime::parse_utc_time("000101000000-0100", YearPivot::Window(0)).unwrap(),
+            NanoTime::new(0, 1, 1, 1, 0, 0, 0).unwrap()
+        );
+        let err = NanoTime::parse_utc_time("360101000000Z", YearPivot::Window(65500)).unwrap_err();
+        assert_eq!((err.offset(), err.found()), (0, "36"));
+        assert_eq!(
+            NanoTime::parse_utc_time("351231235959Z", YearPivot::Window(65500)).unwrap(),
+            NanoTime::new(65535, 12, 31, 23, 59, 59, 0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! RFC 2822 dates, as in email `Date:` headers.
+//!
+//! ```text
+//! Sun, 22 Feb 2026 14:30:05 +0000
+//! ```
+//!
+//! RFC 5322 keeps the same syntax. The weekday and seconds are optional,
+//! and the obsolete zone names `UT`, `GMT`, and the US zones are read.
+
+use std::fmt::Write;
+
+use super::git::write_compact_offset;
+use super::guess::rfc2822;
+use super::{ParseError, Scanner, MONTHS, OUT_OF_RANGE, WEEKDAYS};
+use crate::{NanoTime, OffsetNanoTime, UtcOffset};
+
+impl NanoTime {
+    /// Formats this UTC time as an RFC 2822 date with a `+0000` offset:
+    /// `"Sun, 22 Feb 2026 14:30:05 +0000"`. The fraction is dropped.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_000_000).unwrap();
+    /// assert_eq!(t.to_rfc2822(), "Sun, 22 Feb 2026 14:30:05 +0000");
+    /// let back = NanoTime::parse_rfc2822(&t.to_rfc2822()).unwrap();
+    /// assert_eq!(back.datetime(), "2026-02-22 14:30:05.000");
+    /// ```
+    pub fn to_rfc2822(&self) -> String {
+        OffsetNanoTime::new(*self, UtcOffset::UTC).to_rfc2822()
+    }
+
+    /// Parses an RFC 2822 date such as `"Sun, 22 Feb 2026 14:30:05 +0100"`
+    /// and returns the instant in UTC.
+    ///
+    /// A zone that moves the instant before year 0 is an error.
+    pub fn parse_rfc2822(input: &str) -> Result<Self, ParseError> {
+        let t = OffsetNanoTime::parse_rfc2822(input)?;
+        t.checked_to_utc().ok_or_else(|| {
+            // The zone is the last word of the input.
+            let at = input.rfind(' ').map_or(0, |i| i + 1);
+            Scanner::new(input).error_at(at, input.len() - at, OUT_OF_RANGE)
+        })
+    }
+}
+
+impl OffsetNanoTime {
+    /// Formats as an RFC 2822 date in the stored offset:
+    /// `"Sun, 22 Feb 2026 15:30:05 +0100"`. The day is not padded. Offsets
+    /// with a seconds part are rounded toward zero to whole minutes, and
+    /// the local time shifted so the instant stays the same.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, OffsetNanoTime, UtcOffset};
+    ///
+    /// let local = NanoTime::new(2026, 3, 1, 9, 5, 0, 0).unwrap();
+    /// let t = OffsetNanoTime::new(local, UtcOffset::from_minutes(-300).unwrap());
+    /// assert_eq!(t.to_rfc2822(), "Sun, 1 Mar 2026 09:05:00 -0500");
+    /// ```
+    pub fn to_rfc2822(&self) -> String {
+        let t = self.with_whole_minute_offset();
+        let local = t.local();
+        let mut out = String::with_capacity(31);
+        let _ = write!(
+            out,
+            "{}, {} {} {:04} {:02}:{:02}:{:02} ",
+            &WEEKDAYS[local.weekday_index()][..3],
+            local.day(),
+            MONTHS[local.month() as usize - 1],
+            local.year(),
+            local.hour(),
+            local.minute(),
+            local.second()
+        );
+        write_compact_offset(&mut out, t.offset());
+        out
+    }
+
+    /// Parses an RFC 2822 date, keeping its offset. Zone names are read
+    /// as their fixed offsets, and `-0000` as UTC. A day name that does
+    /// not match the date is an error.
+    ///
+    /// ```rust
+    /// use nanotime::OffsetNanoTime;
+    ///
+    /// let t = OffsetNanoTime::parse_rfc2822("22 Feb 2026 09:30 EST").unwrap();
+    /// assert_eq!(t.to_rfc2822(), "Sun, 22 Feb 2026 09:30:00 -0500");
+    /// ```
+    pub fn parse_rfc2822(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let (local, offset) = rfc2822(&mut s)?;
+        s.finish()?;
+        Ok(OffsetNanoTime::new(
+            local,
+            offset.expect("RFC 2822 dates carry a zone").0,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let local = NanoTime::new(2016, 2, 29, 23, 59, 59, 0).unwrap();
+        let t = OffsetNanoTime::new(local, UtcOffset::from_minutes(330).unwrap());
+        assert_eq!(t.to_rfc2822(), "Mon, 29 Feb 2016 23:59:59 +0530");
+        assert_eq!(OffsetNanoTime::parse_rfc2822(&t.to_rfc2822()), Ok(t));
+        assert_eq!(
+            NanoTime::parse_rfc2822("Mon, 29 Feb 2016 23:59:59 +0530")
+                .unwrap()
+                .to_rfc3339(),
+            "2016-02-29T18:29:59Z"
+        );
+    }
+
+    #[test]
+    fn test_offset_seconds_keep_the_instant() {
+        let utc = NanoTime::new(1847, 12, 1, 12, 0, 0, 0).unwrap();
+        let lmt = OffsetNanoTime::from_utc(&utc, UtcOffset::from_secs(75).unwrap());
+        assert_eq!(lmt.to_rfc2822(), "Wed, 1 Dec 1847 12:01:00 +0001");
+        let back = OffsetNanoTime::parse_rfc2822(&lmt.to_rfc2822()).unwrap();
+        assert!(back.same_instant(&lmt));
+    }
+
+    #[test]
+    fn test_optional_parts_and_zone_names() {
+        let utc = NanoTime::new(2026, 2, 22, 14, 30, 0, 0).unwrap();
+        for input in [
+            "Sun, 22 Feb 2026 14:30:00 +0000",
+            "22 Feb 2026 14:30 GMT",
+            "Sun,  22 Feb 2026 14:30:00 -0000",
+            "22 Feb 2026 09:30:00 EST",
+        ] {
+            assert_eq!(NanoTime::parse_rfc2822(input), Ok(utc), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_rejections() {
+        let err = |input| NanoTime::parse_rfc2822(input).unwrap_err();
+        assert_eq!(
+            err("Sun, 30 Feb 2026 14:30:00 +0000").expected(),
+            "day 01-28"
+        );
+        assert_eq!(err("Sun, 22 Foo 2026 14:30:00 +0000").offset(), 8);
+        assert_eq!(err("Sun, 22 Feb 2026 14:30:00").expected(), "' '");
+        assert_eq!(
+            err("Sun, 22 Feb 2026 14:30:00 +0000 x").expected(),
+            "end of input"
+        );
+        let e = err("Mon, 22 Feb 2026 14:30:00 +0000");
+        assert_eq!((e.offset(), e.expected()), (0, "weekday matching the date"));
+        let e = err("Sat, 01 Jan 0000 00:00:00 +0100");
+        assert_eq!(
+            (e.offset(), e.found(), e.expected()),
+            (26, "+0100", OUT_OF_RANGE)
+        );
+        assert_eq!(
+            NanoTime::parse_rfc2822("Sat, 01 Jan 0000 00:00:00 -0100").unwrap(),
+            NanoTime::new(0, 1, 1, 1, 0, 0, 0).unwrap()
+        );
+    }
+}
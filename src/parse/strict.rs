@@ -0,0 +1,212 @@
+//! Limits for parsing untrusted input.
+
+use super::{civil, ParseError, Scanner, OUT_OF_RANGE};
+use crate::{NanoTime, OffsetNanoTime};
+
+/// Longest RFC 3339 timestamp [`ParseOptions::strict`] accepts:
+/// `YYYY-MM-DDTHH:MM:SS.nnnnnnnnn+HH:MM`.
+const STRICT_MAX_LEN: usize = 35;
+
+/// Limits on what [`NanoTime::parse_rfc3339_with`] accepts.
+///
+/// [`new`](Self::new) accepts what [`NanoTime::parse_rfc3339`] does.
+/// [`strict`](Self::strict) is a profile for input from the network:
+/// parsing stops before reading anything past the length limit, and forms
+/// that systems disagree on are rejected rather than guessed at. Parsing
+/// allocates only to build an error.
+///
+/// ```rust
+/// use nanotime::parse::ParseOptions;
+/// use nanotime::NanoTime;
+///
+/// let strict = ParseOptions::strict();
+/// assert!(NanoTime::parse_rfc3339_with("2026-02-22T14:30:05.123Z", &strict).is_ok());
+/// // Lowercase and space separators are RFC 3339 but not ISO 8601.
+/// assert!(NanoTime::parse_rfc3339_with("2026-02-22 14:30:05z", &strict).is_err());
+/// // A thousand fractional digits are not a timestamp.
+/// let padded = format!("2026-02-22T14:30:05.{}Z", "0".repeat(1000));
+/// assert!(NanoTime::parse_rfc3339_with(&padded, &strict).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    max_len: usize,
+    max_fraction_digits: usize,
+    lenient_forms: bool,
+}
+
+impl ParseOptions {
+    /// No length or digit limits, and lowercase `t` and `z`, a space
+    /// separator, and the `-00:00` unknown offset all accepted.
+    pub fn new() -> Self {
+        ParseOptions {
+            max_len: usize::MAX,
+            max_fraction_digits: usize::MAX,
+            lenient_forms: true,
+        }
+    }
+
+    /// At most 35 bytes and 9 fractional digits, an uppercase `T` and `Z`
+    /// only, and `-00:00` rejected, since RFC 3339 gives it a different
+    /// meaning from `+00:00` that most readers ignore.
+    pub fn strict() -> Self {
+        ParseOptions {
+            max_len: STRICT_MAX_LEN,
+            max_fraction_digits: 9,
+            lenient_forms: false,
+        }
+    }
+
+    /// Rejects input longer than `max_len` bytes without reading it.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Rejects fractional seconds with more than `digits` digits, rather
+    /// than truncating them to nanoseconds.
+    pub fn with_max_fraction_digits(mut self, digits: usize) -> Self {
+        self.max_fraction_digits = digits;
+        self
+    }
+
+    /// Sets whether lowercase `t` and `z`, a space separator, and the
+    /// `-00:00` offset are accepted.
+    pub fn with_lenient_forms(mut self, lenient: bool) -> Self {
+        self.lenient_forms = lenient;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scanner<'_> {
+    /// Parses an RFC 3339 timestamp within `opts`.
+    fn rfc3339_with(&mut self, opts: &ParseOptions) -> Result<OffsetNanoTime, ParseError> {
+        if self.input.len() > opts.max_len {
+            let at = (0..=opts.max_len)
+                .rev()
+                .find(|&i| self.input.is_char_boundary(i))
+                .unwrap_or(0);
+            let limit = format!("at most {} bytes of input", opts.max_len);
+            return Err(self.error_at(at, 1, limit));
+        }
+        let lenient = opts.lenient_forms;
+        let date = self.date()?;
+        if !(self.eat('T') || lenient && (self.eat('t') || self.eat(' '))) {
+            return Err(self.error("'T' date-time separator"));
+        }
+        let hour = self.number(2, 0, 23, "hour")? as u8;
+        self.literal(':')?;
+        let minute = self.number(2, 0, 59, "minute")? as u8;
+        self.literal(':')?;
+        let second = self.number(2, 0, 59, "second")? as u8;
+        let nanos = if self.eat('.') {
+            let start = self.pos;
+            let digits = self.input.as_bytes()[start..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            if digits > opts.max_fraction_digits {
+                let limit = format!("at most {} fractional digits", opts.max_fraction_digits);
+                return Err(self.error_at(start, digits, limit));
+            }
+            self.fraction()?
+        } else {
+            0
+        };
+        if !lenient && (self.peek() == Some('z') || self.input[self.pos..].starts_with("-00:00")) {
+            return Err(self.error_at(self.pos, 6, "'Z' or known UTC offset"));
+        }
+        let offset = self.offset()?;
+        Ok(OffsetNanoTime::new(
+            civil(date, (hour, minute, second, nanos)),
+            offset,
+        ))
+    }
+}
+
+impl NanoTime {
+    /// Parses an RFC 3339 timestamp within the limits of `opts`, and
+    /// returns the instant in UTC.
+    pub fn parse_rfc3339_with(input: &str, opts: &ParseOptions) -> Result<Self, ParseError> {
+        let t = OffsetNanoTime::parse_rfc3339_with(input, opts)?;
+        // Only a `±HH:MM` offset can push the instant out of range, and it
+        // ends the input.
+        t.checked_to_utc()
+            .ok_or_else(|| Scanner::new(input).error_at(input.len() - 6, 6, OUT_OF_RANGE))
+    }
+}
+
+impl OffsetNanoTime {
+    /// Parses an RFC 3339 timestamp within the limits of `opts`, keeping
+    /// the local time and offset as written.
+    pub fn parse_rfc3339_with(input: &str, opts: &ParseOptions) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let t = s.rfc3339_with(opts)?;
+        s.finish()?;
+        Ok(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strict(input: &str) -> Result<NanoTime, ParseError> {
+        NanoTime::parse_rfc3339_with(input, &ParseOptions::strict())
+    }
+
+    #[test]
+    fn test_new_matches_parse_rfc3339() {
+        let inputs = [
+            "2026-02-22T14:30:05Z",
+            "2026-02-22t14:30:05.1234567891z",
+            "2026-02-22 14:30:05-00:00",
+            "2026-02-22T14:30:05+24:00",
+            "2026-02-30T14:30:05Z",
+        ];
+        for input in inputs {
+            assert_eq!(
+                NanoTime::parse_rfc3339_with(input, &ParseOptions::new()),
+                NanoTime::parse_rfc3339(input),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_strict_rejections() {
+        let longest = "2026-02-22T14:30:05.123456789+05:30";
+        assert_eq!(longest.len(), STRICT_MAX_LEN);
+        assert!(strict(longest).is_ok());
+        let err = strict(&format!("{} ", longest)).unwrap_err();
+        assert_eq!(
+            (err.offset(), err.expected()),
+            (35, "at most 35 bytes of input")
+        );
+        let err = strict("2026-02-22T14:30:05.1234567891Z").unwrap_err();
+        assert_eq!(err.expected(), "at most 9 fractional digits");
+        assert_eq!(strict("2026-02-22 14:30:05Z").unwrap_err().offset(), 10);
+        for input in ["2026-02-22T14:30:05z", "2026-02-22T14:30:05-00:00"] {
+            assert_eq!(
+                strict(input).unwrap_err().expected(),
+                "'Z' or known UTC offset"
+            );
+        }
+        assert!(strict("2026-02-22T14:30:05+00:00").is_ok());
+        let err = strict("0000-01-01T00:00:00+01:00").unwrap_err();
+        assert_eq!((err.offset(), err.expected()), (19, OUT_OF_RANGE));
+    }
+
+    #[test]
+    fn test_limit_on_char_boundary() {
+        let opts = ParseOptions::new().with_max_len(2);
+        let err = NanoTime::parse_rfc3339_with("2é26", &opts).unwrap_err();
+        assert_eq!((err.offset(), err.found()), (1, "é"));
+    }
+}
@@ -0,0 +1,359 @@
+//! `strptime`-style parsing with the specifiers [`Format`] writes.
+
+use super::{civil, ParseError, Scanner, YearPivot, MONTHS, WEEKDAYS};
+use crate::format::{Item, MONTH_NAMES};
+use crate::{civil_from_days, days_from_civil, days_in_month, Format, NanoTime, UtcOffset};
+
+/// Byte range of a field in the input.
+type Span = (usize, usize);
+
+/// Fields read so far. Absent date fields default to 1970-01-01 and
+/// absent time fields to zero.
+#[derive(Default)]
+struct Fields {
+    year: Option<(i64, Span)>,
+    century: Option<i64>,
+    yy: Option<u8>,
+    month: Option<u8>,
+    day: Option<(u8, Span)>,
+    yday: Option<(i64, Span)>,
+    hour: Option<u8>,
+    hour12: Option<u8>,
+    pm: bool,
+    minute: u8,
+    second: u8,
+    nanos: u32,
+    epoch: Option<(i64, Span)>,
+    /// Fields that do not set the date, as the specifier that formats
+    /// them, checked against the result.
+    checks: Vec<(char, i64, Span)>,
+}
+
+/// Whether `spec` reads text rather than a number.
+fn is_name(spec: char) -> bool {
+    matches!(spec, 'a' | 'A' | 'b' | 'h' | 'B' | 'p' | 'P')
+}
+
+impl Scanner<'_> {
+    /// Matches format text: whitespace matches any run of whitespace,
+    /// including none, and anything else matches itself.
+    fn format_literal(&mut self, text: &str) -> Result<(), ParseError> {
+        for c in text.chars() {
+            if c.is_whitespace() {
+                while let Some(w) = self.peek().filter(|w| w.is_whitespace()) {
+                    self.pos += w.len_utf8();
+                }
+            } else {
+                self.literal(c)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads one conversion into `f`. `packed` is set when another number
+    /// follows directly, limiting years to four digits.
+    fn format_spec(&mut self, spec: char, f: &mut Fields, packed: bool) -> Result<(), ParseError> {
+        if !is_name(spec) && spec != 'f' && spec != 'N' {
+            while self.eat(' ') {}
+        }
+        let year_width = if packed { 4 } else { 5 };
+        let start = self.pos;
+        let span = |s: &Self| (start, s.pos);
+        match spec {
+            'Y' => {
+                let year = self.number_var(year_width, 0, 65535, "year")?;
+                f.year = Some((year as i64, span(self)));
+            }
+            'C' => f.century = Some(self.number_var(3, 0, 655, "century")? as i64),
+            'y' => f.yy = Some(self.number_var(2, 0, 99, "year")? as u8),
+            'm' => f.month = Some(self.number_var(2, 1, 12, "month")? as u8),
+            'd' | 'e' => f.day = Some((self.number_var(2, 1, 31, "day")? as u8, span(self))),
+            'j' => {
+                let yday = self.number_var(3, 1, 366, "day of year")?;
+                f.yday = Some((yday as i64, span(self)));
+            }
+            'H' | 'k' => f.hour = Some(self.number_var(2, 0, 23, "hour")? as u8),
+            'I' | 'l' => f.hour12 = Some(self.number_var(2, 1, 12, "hour")? as u8),
+            'M' => f.minute = self.number_var(2, 0, 59, "minute")? as u8,
+            'S' => f.second = self.number_var(2, 0, 59, "second")? as u8,
+            'f' | 'N' => f.nanos = self.fraction()?,
+            's' => {
+                let negative = self.eat('-');
+                let secs = self.integer("epoch seconds")?;
+                f.epoch = Some((if negative { -secs } else { secs }, span(self)));
+            }
+            'p' | 'P' => f.pm = self.name(&["AM", "PM"], "AM or PM")? == 1,
+            'b' | 'h' | 'B' => {
+                let month = self
+                    .name(&MONTH_NAMES, "month name")
+                    .or_else(|_| self.name(&MONTHS, "month name"))?;
+                f.month = Some(month as u8 + 1);
+            }
+            'a' | 'A' => {
+                let weekday = self
+                    .name(&WEEKDAYS, "weekday name")
+                    .or_else(|_| self.name(&WEEKDAYS.map(|w| &w[..3]), "weekday name"))?;
+                f.checks.push(('w', weekday as i64, span(self)));
+            }
+            _ => {
+                let value = match spec {
+                    'u' => self.number_var(1, 1, 7, "weekday")?,
+                    'w' => self.number_var(1, 0, 6, "weekday")?,
+                    'U' | 'W' => self.number_var(2, 0, 53, "week")?,
+                    'V' => self.number_var(2, 1, 53, "week")?,
+                    'G' => self.number_var(year_width, 0, 65535, "year")?,
+                    'g' => self.number_var(2, 0, 99, "year")?,
+                    _ => unreachable!("compile only emits known specifiers"),
+                };
+                f.checks.push((spec, value as i64, span(self)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Assembles the fields into a timestamp.
+    fn format_fields(&self, f: Fields) -> Result<NanoTime, ParseError> {
+        let error = |(start, end): Span, expected: String| {
+            self.error_at(start, self.input[start..end].chars().count(), expected)
+        };
+        let t = if let Some((secs, at)) = f.epoch {
+            NanoTime::checked_from_unix(secs, f.nanos)
+                .ok_or_else(|| error(at, "epoch seconds in range".into()))?
+        } else {
+            let year = match (f.year, f.century, f.yy) {
+                (Some((year, _)), ..) => year,
+                (None, Some(century), yy) => century * 100 + yy.unwrap_or(0) as i64,
+                (None, None, Some(yy)) => YearPivot::Posix.expand(yy) as i64,
+                (None, None, None) => 1970,
+            };
+            let Ok(year) = u16::try_from(year) else {
+                return Err(self.error_at(0, self.input.len(), "year 0-65535"));
+            };
+            let (month, day) = match (f.month, f.day, f.yday) {
+                (None, None, Some((yday, at))) => {
+                    let jan1 = days_from_civil(year as i64, 1, 1);
+                    let (y, month, day) = civil_from_days(jan1 + yday - 1);
+                    if y != year as i64 {
+                        return Err(error(at, "day of year in range".into()));
+                    }
+                    (month, day)
+                }
+                (month, day, _) => {
+                    let month = month.unwrap_or(1);
+                    let max_day = days_in_month(year, month);
+                    match day {
+                        Some((day, at)) if day > max_day => {
+                            return Err(error(at, format!("day 01-{:02}", max_day)));
+                        }
+                        day => (month, day.map_or(1, |(day, _)| day)),
+                    }
+                }
+            };
+            let hour = match (f.hour, f.hour12) {
+                (Some(hour), _) => hour,
+                (None, Some(hour12)) => hour12 % 12 + if f.pm { 12 } else { 0 },
+                (None, None) => 0,
+            };
+            civil((year, month, day), (hour, f.minute, f.second, f.nanos))
+        };
+        let mut checks = f.checks;
+        if f.epoch.is_none() && (f.month.is_some() || f.day.is_some()) {
+            checks.extend(f.yday.map(|(yday, at)| ('j', yday, at)));
+        }
+        for (spec, value, at) in checks {
+            let actual: i64 = t.format(&format!("%-{}", spec)).parse().unwrap_or(-1);
+            if actual != value {
+                let what = match spec {
+                    'u' | 'w' => "weekday",
+                    'U' | 'W' | 'V' => "week",
+                    'G' | 'g' => "week-based year",
+                    _ => "day of year",
+                };
+                return Err(error(at, format!("{} matching the date", what)));
+            }
+        }
+        Ok(t)
+    }
+}
+
+impl Format {
+    /// Parses `input` as written by this format, the inverse of
+    /// [`Format::format`]. See [`NanoTime::parse`] for the rules.
+    ///
+    /// ```rust
+    /// use nanotime::{Format, NanoTime};
+    ///
+    /// let fmt = Format::new("%d/%b/%Y:%T");
+    /// let t = fmt.parse("22/Feb/2026:14:30:05").unwrap();
+    /// assert_eq!(fmt.format(&t), "22/Feb/2026:14:30:05");
+    /// ```
+    pub fn parse(&self, input: &str) -> Result<NanoTime, ParseError> {
+        let mut s = Scanner::new(input);
+        let f = self.scan(&mut s)?;
+        s.finish()?;
+        s.format_fields(f)
+    }
+
+    /// Matches this format at the start of `input`, returning the length
+    /// of the match in bytes, the time, and a UTC offset if the time came
+    /// from `%s` rather than local fields.
+    pub(crate) fn parse_prefix(&self, input: &str) -> Option<(usize, NanoTime, Option<UtcOffset>)> {
+        let mut s = Scanner::new(input);
+        let f = self.scan(&mut s).ok()?;
+        let offset = f.epoch.map(|_| UtcOffset::UTC);
+        let t = s.format_fields(f).ok()?;
+        Some((s.pos, t, offset))
+    }
+
+    /// Reads every item of this format from `s`.
+    fn scan(&self, s: &mut Scanner) -> Result<Fields, ParseError> {
+        let mut f = Fields::default();
+        let items = self.items();
+        for (i, item) in items.iter().enumerate() {
+            let packed = match items.get(i + 1) {
+                Some(Item::Spec(next, _)) => !is_name(*next),
+                Some(Item::Fraction(_)) => true,
+                _ => false,
+            };
+            match item {
+                Item::Literal(text) => s.format_literal(text)?,
+                Item::Spec(spec, _) => s.format_spec(*spec, &mut f, packed)?,
+                Item::Fraction(digits) => {
+                    let max = 10u32.pow(*digits as u32) - 1;
+                    let value = s.number(*digits, 0, max, "fraction")?;
+                    f.nanos = value * 10u32.pow(9 - *digits as u32);
+                }
+            }
+        }
+        Ok(f)
+    }
+}
+
+impl NanoTime {
+    /// Parses `input` with a C `strptime`-style format string, using the
+    /// specifiers and flags of [`NanoTime::format`].
+    ///
+    /// Numeric fields take one digit up to their formatted width, after
+    /// any leading spaces, so `%d` reads `05`, `5`, and ` 5`. `%Y` reads up
+    /// to five digits, or four when another number follows directly, as in
+    /// `%Y%m%d`. `%f` and
+    /// `%N` read any number of fractional digits, while `%3N` reads
+    /// exactly three. Names match either the full or abbreviated form,
+    /// ignoring case. Whitespace in the format matches any run of
+    /// whitespace, including none.
+    ///
+    /// Date fields left out default to 1970-01-01 and time fields to
+    /// zero. `%y` alone expands with [`YearPivot::Posix`]; `%j` sets the
+    /// date when no month or day is given. `%s` sets the date and time
+    /// from Unix seconds, ignoring other date and time fields. Fields
+    /// that cannot set the date, such as weekday names and week numbers,
+    /// must agree with it. Unknown specifiers must appear verbatim, as
+    /// [`Format`] writes them.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::parse("22/02/2026 14:30:05.123", "%d/%m/%Y %H:%M:%S.%3N").unwrap();
+    /// assert_eq!(t.datetime(), "2026-02-22 14:30:05.123");
+    ///
+    /// let t = NanoTime::parse("Sun Feb 22 2:30 PM 2026", "%a %b %e %l:%M %p %Y").unwrap();
+    /// assert_eq!(t.datetime(), "2026-02-22 14:30:00.000");
+    ///
+    /// let err = NanoTime::parse("Mon Feb 22 2026", "%a %b %d %Y").unwrap_err();
+    /// assert_eq!(err.to_string(), "expected weekday matching the date at offset 0, found 'Mon'");
+    /// ```
+    pub fn parse(input: &str, fmt: &str) -> Result<Self, ParseError> {
+        Format::new(fmt).parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str, fmt: &str) -> NanoTime {
+        NanoTime::parse(input, fmt).unwrap()
+    }
+
+    #[test]
+    fn test_round_trips_format() {
+        let fmt = "%s|%Y|%C|%y|%m|%d|%e|%H|%I|%k|%l|%M|%S|%p|%P|%a|%A|%b|%B|%h|%j|%U|%W|%G|%g|%V|%u|%w|%D|%F|%T|%R|%r|%c|%x|%X|%-d|%_m|%0e|%-j|%_H|%-I|%%";
+        for secs in [0, 946_684_799, 1_230_768_000, 1_735_603_200, 1_771_770_605] {
+            let t = NanoTime::from_epoch(secs);
+            assert_eq!(parse(&t.format(fmt), fmt), t, "epoch {}", secs);
+        }
+        let fmts = [
+            "%F %T.%N",
+            "%Y%m%d%H%M%S%6N",
+            "%c",
+            "%-d %B %Y %r",
+            "%j %Y %H",
+            "%G-W%V-%u %F",
+        ];
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 120_456_000).unwrap();
+        for fmt in fmts {
+            let written = t.format(fmt);
+            let back = parse(&written, fmt);
+            assert_eq!(back.format(fmt), written, "{}", fmt);
+        }
+    }
+
+    #[test]
+    fn test_defaults_and_partial_fields() {
+        assert_eq!(
+            parse("14:30", "%H:%M").datetime(),
+            "1970-01-01 14:30:00.000"
+        );
+        assert_eq!(parse("2026", "%Y").date(), "2026-01-01");
+        assert_eq!(parse("60 2024", "%j %Y").date(), "2024-02-29");
+        assert_eq!(parse("68", "%y").year(), 2068);
+        assert_eq!(parse("69", "%y").year(), 1969);
+        assert_eq!(parse("19 69", "%C %y").year(), 1969);
+        assert_eq!(parse("12 AM", "%I %p").hour(), 0);
+        assert_eq!(parse("12 pm", "%I %p").hour(), 12);
+    }
+
+    #[test]
+    fn test_lenient_digits_and_whitespace() {
+        assert_eq!(parse("2026-2-5", "%Y-%m-%d").date(), "2026-02-05");
+        assert_eq!(parse("Feb  5", "%b %e").day(), 5);
+        assert_eq!(parse("feb5", "%b %d").day(), 5);
+        assert_eq!(parse("FEBRUARY 5", "%b %d").month(), 2);
+        assert_eq!(parse("05.1", "%S.%f").nanosecond(), 100_000_000);
+        assert_eq!(parse("-1.5", "%s.%N").datetime(), "1969-12-31 23:59:59.500");
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = |input: &str, fmt: &str| NanoTime::parse(input, fmt).unwrap_err().to_string();
+        assert_eq!(
+            err("2026-02-30", "%Y-%m-%d"),
+            "expected day 01-28 at offset 8, found '30'"
+        );
+        assert_eq!(
+            err("2026/02", "%Y-%m"),
+            "expected '-' at offset 4, found '/'"
+        );
+        assert_eq!(
+            err("2026-13", "%Y-%m"),
+            "expected month 1-12 at offset 5, found '13'"
+        );
+        assert_eq!(
+            err("05.12", "%S.%3N"),
+            "expected 3-digit fraction at offset 3, found '12'"
+        );
+        assert_eq!(
+            err("2026-02-22x", "%F"),
+            "expected end of input at offset 10, found 'x'"
+        );
+        assert_eq!(
+            err("366 2026", "%j %Y"),
+            "expected day of year in range at offset 0, found '366'"
+        );
+        assert_eq!(
+            err("2026-02-22 3", "%F %u"),
+            "expected weekday matching the date at offset 11, found '3'"
+        );
+        assert_eq!(parse("a%Qb", "a%Qb"), NanoTime::from_epoch(0));
+    }
+}
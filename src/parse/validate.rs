@@ -0,0 +1,149 @@
+//! Allocation-free format checks.
+//!
+//! These walk the input bytes directly rather than going through the
+//! parsers, which build a [`ParseError`](super::ParseError) on failure.
+//! Each accepts exactly what the matching parser accepts.
+
+use crate::days_in_month;
+
+/// Reads exactly `n` ASCII digits from the front of `b`.
+fn digits(b: &[u8], n: usize) -> Option<(u32, &[u8])> {
+    let (head, rest) = b.split_at_checked(n)?;
+    head.iter()
+        .try_fold(0u32, |acc, &d| {
+            d.is_ascii_digit().then(|| acc * 10 + (d - b'0') as u32)
+        })
+        .map(|v| (v, rest))
+}
+
+/// Reads `n` digits in `min..=max`.
+fn field(b: &[u8], n: usize, min: u32, max: u32) -> Option<(u32, &[u8])> {
+    digits(b, n).filter(|&(v, _)| (min..=max).contains(&v))
+}
+
+fn byte(b: &[u8], c: u8) -> Option<&[u8]> {
+    b.strip_prefix(&[c])
+}
+
+/// `YYYY-MM-DD`
+fn date(b: &[u8]) -> Option<&[u8]> {
+    let (year, b) = digits(b, 4)?;
+    let (month, b) = field(byte(b, b'-')?, 2, 1, 12)?;
+    let max_day = days_in_month(year as u16, month as u8) as u32;
+    let (_, b) = field(byte(b, b'-')?, 2, 1, max_day)?;
+    Some(b)
+}
+
+/// `HH:MM:SS` with an optional fraction of one or more digits.
+fn time(b: &[u8]) -> Option<&[u8]> {
+    let (_, b) = field(b, 2, 0, 23)?;
+    let (_, b) = field(byte(b, b':')?, 2, 0, 59)?;
+    let (_, b) = field(byte(b, b':')?, 2, 0, 59)?;
+    match byte(b, b'.') {
+        Some(frac) => {
+            let n = frac.iter().take_while(|d| d.is_ascii_digit()).count();
+            (n > 0).then(|| &frac[n..])
+        }
+        None => Some(b),
+    }
+}
+
+/// `Z`, `z`, or `±HH:MM`.
+fn offset(b: &[u8]) -> Option<&[u8]> {
+    if let Some(rest) = byte(b, b'Z').or_else(|| byte(b, b'z')) {
+        return Some(rest);
+    }
+    let b = byte(b, b'+').or_else(|| byte(b, b'-'))?;
+    let (_, b) = field(b, 2, 0, 23)?;
+    let (_, b) = field(byte(b, b':')?, 2, 0, 59)?;
+    Some(b)
+}
+
+/// Returns `true` if `input` is a `YYYY-MM-DD` calendar date, with the day
+/// checked against the month and leap years.
+///
+/// ```rust
+/// use nanotime::parse::is_valid_date;
+///
+/// assert!(is_valid_date("2024-02-29"));
+/// assert!(!is_valid_date("2026-02-29"));
+/// ```
+pub fn is_valid_date(input: &str) -> bool {
+    date(input.as_bytes()).is_some_and(<[u8]>::is_empty)
+}
+
+/// Returns `true` if `input` is an `HH:MM:SS` time of day with an optional
+/// fraction, as in RFC 3339. Leap seconds (`:60`) are rejected.
+pub fn is_valid_time(input: &str) -> bool {
+    time(input.as_bytes()).is_some_and(<[u8]>::is_empty)
+}
+
+/// Returns `true` if [`NanoTime::parse_rfc3339`] would accept `input`,
+/// without building the timestamp or an error.
+///
+/// [`NanoTime::parse_rfc3339`]: crate::NanoTime::parse_rfc3339
+///
+/// ```rust
+/// use nanotime::parse::is_valid_rfc3339;
+///
+/// assert!(is_valid_rfc3339("2026-02-22T14:30:05.123+05:30"));
+/// assert!(!is_valid_rfc3339("2026-02-22T14:30:05"));
+/// ```
+pub fn is_valid_rfc3339(input: &str) -> bool {
+    let valid = || {
+        let b = date(input.as_bytes())?;
+        let b = [b'T', b't', b' '].iter().find_map(|&c| byte(b, c))?;
+        offset(time(b)?)
+    };
+    valid().is_some_and(<[u8]>::is_empty) && !before_year_zero(input.as_bytes())
+}
+
+/// Whether a well-formed RFC 3339 timestamp falls before year 0 in UTC,
+/// which takes a date of 0000-01-01 and an offset ahead of its time of day.
+fn before_year_zero(b: &[u8]) -> bool {
+    let offset = &b[b.len() - 6..];
+    b.starts_with(b"0000-01-01") && offset[0] == b'+' && b[11..16] < offset[1..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dates() {
+        assert!(is_valid_date("0000-01-01"));
+        assert!(is_valid_date("2000-02-29"));
+        assert!(!is_valid_date("1900-02-29"));
+        assert!(!is_valid_date("2026-13-01"));
+        assert!(!is_valid_date("2026-04-31"));
+        assert!(!is_valid_date("2026-4-01"));
+        assert!(!is_valid_date("2026-04-01 "));
+        assert!(!is_valid_date(""));
+    }
+
+    #[test]
+    fn test_times() {
+        assert!(is_valid_time("00:00:00"));
+        assert!(is_valid_time("23:59:59.123456789123"));
+        assert!(!is_valid_time("23:59:60"));
+        assert!(!is_valid_time("24:00:00"));
+        assert!(!is_valid_time("12:00:00."));
+        assert!(!is_valid_time("12:00"));
+    }
+
+    #[test]
+    fn test_rfc3339() {
+        assert!(is_valid_rfc3339("2026-02-22T14:30:05Z"));
+        assert!(is_valid_rfc3339("2026-02-22t14:30:05.1z"));
+        assert!(is_valid_rfc3339("2026-02-22 14:30:05-23:59"));
+        assert!(!is_valid_rfc3339("2026-02-22T14:30:05+24:00"));
+        assert!(!is_valid_rfc3339("2026-02-22T14:30:05+0530"));
+        assert!(!is_valid_rfc3339("2026-02-22_14:30:05Z"));
+        assert!(!is_valid_rfc3339("2026-02-22T14:30:05Zjunk"));
+        assert!(!is_valid_rfc3339("2026-02-22T14:30:05é"));
+        assert!(!is_valid_rfc3339("0000-01-01T00:59:59.9+01:00"));
+        assert!(is_valid_rfc3339("0000-01-01T01:00:00+01:00"));
+        assert!(is_valid_rfc3339("0000-01-01T00:00:00-01:00"));
+        assert!(is_valid_rfc3339("9999-12-31T23:59:59-23:59"));
+    }
+}
@@ -0,0 +1,188 @@
+//! YAML 1.1 `!!timestamp` scalars.
+//!
+//! See <https://yaml.org/type/timestamp.html>. The accepted forms are:
+//!
+//! ```text
+//! 2002-12-14                      date only, midnight UTC
+//! 2001-12-15T02:59:43.1Z          canonical
+//! 2001-12-14t21:59:43.10-05:00    ISO 8601
+//! 2001-12-14 21:59:43.10 -5       space separated, short offset
+//! 2001-12-15 2:59:43.10           no zone, taken as UTC
+//! ```
+
+use super::{civil, ParseError, Scanner, OUT_OF_RANGE};
+use crate::{days_in_month, NanoTime, OffsetNanoTime, UtcOffset};
+
+impl Scanner<'_> {
+    /// Consumes spaces and tabs, returning whether there were any.
+    fn yaml_blanks(&mut self) -> bool {
+        let start = self.pos;
+        while self.eat(' ') || self.eat('\t') {}
+        self.pos > start
+    }
+
+    /// Parses a timestamp, returning it with the byte offset its zone
+    /// starts at, or the end of the timestamp if it has none.
+    fn yaml_timestamp(&mut self) -> Result<(OffsetNanoTime, usize), ParseError> {
+        let year = self.number(4, 0, 9999, "year")? as u16;
+        self.literal('-')?;
+        let month_start = self.pos;
+        let month = self.number_var(2, 1, 12, "month")? as u8;
+        self.literal('-')?;
+        let max_day = days_in_month(year, month) as u32;
+        let day = self.number_var(2, 1, max_day, "day")? as u8;
+        let date = (year, month, day);
+
+        if self.peek().is_none() {
+            // The date-only form requires two-digit fields.
+            if self.pos - month_start != 5 {
+                return Err(self.error_at(month_start, 5, "2-digit month and day"));
+            }
+            let t = OffsetNanoTime::new(civil(date, (0, 0, 0, 0)), UtcOffset::UTC);
+            return Ok((t, self.pos));
+        }
+        if !(self.eat('T') || self.eat('t') || self.yaml_blanks()) {
+            return Err(self.error("'T' or space date-time separator"));
+        }
+
+        let hour = self.number_var(2, 0, 23, "hour")? as u8;
+        self.literal(':')?;
+        let minute = self.number(2, 0, 59, "minute")? as u8;
+        self.literal(':')?;
+        let second = self.number(2, 0, 59, "second")? as u8;
+        let mut nanos = 0;
+        if self.eat('.') && self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            nanos = self.fraction()?;
+        }
+        let time = (hour, minute, second, nanos);
+
+        let blanks_start = self.pos;
+        self.yaml_blanks();
+        let no_zone = self.peek().is_none() && self.pos == blanks_start;
+        let zone_start = self.pos;
+        let offset = if no_zone || self.eat('Z') {
+            UtcOffset::UTC
+        } else {
+            self.yaml_offset()?
+        };
+        Ok((OffsetNanoTime::new(civil(date, time), offset), zone_start))
+    }
+
+    /// Parses `±H`, `±HH`, or `±HH:MM`.
+    fn yaml_offset(&mut self) -> Result<UtcOffset, ParseError> {
+        let start = self.pos;
+        let sign = if self.eat('+') {
+            1
+        } else if self.eat('-') {
+            -1
+        } else {
+            return Err(self.error("'Z' or UTC offset"));
+        };
+        let hours = self.number_var(2, 0, 23, "offset hours")? as i32;
+        let minutes = if self.eat(':') {
+            self.number(2, 0, 59, "offset minutes")? as i32
+        } else {
+            0
+        };
+        UtcOffset::from_secs(sign * (hours * 3600 + minutes * 60))
+            .ok_or_else(|| self.error_at(start, 6, "UTC offset"))
+    }
+}
+
+impl NanoTime {
+    /// Parses a YAML 1.1 timestamp such as `"2001-12-14 21:59:43.10 -5"`
+    /// and returns the instant in UTC.
+    ///
+    /// Accepts the date-only form, `T` or whitespace separators, one-digit
+    /// month, day, and hour fields, and offsets written `Z`, `±H`, `±HH`, or
+    /// `±HH:MM`, optionally preceded by whitespace. Timestamps without a
+    /// zone are UTC, as the YAML spec defines. An offset that moves the
+    /// instant outside years 0-65535 is an error.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::parse_yaml_timestamp("2001-12-14 21:59:43.10 -5").unwrap();
+    /// assert_eq!(t.datetime(), "2001-12-15 02:59:43.100");
+    /// ```
+    pub fn parse_yaml_timestamp(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let (t, zone_start) = s.yaml_timestamp()?;
+        s.finish()?;
+        t.checked_to_utc()
+            .ok_or_else(|| s.error_at(zone_start, input.len() - zone_start, OUT_OF_RANGE))
+    }
+}
+
+impl OffsetNanoTime {
+    /// Parses a YAML 1.1 timestamp, keeping the local time and offset as
+    /// written. Timestamps without a zone get a UTC offset.
+    pub fn parse_yaml_timestamp(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        let (t, _) = s.yaml_timestamp()?;
+        s.finish()?;
+        Ok(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(input: &str) -> NanoTime {
+        NanoTime::parse_yaml_timestamp(input).unwrap()
+    }
+
+    #[test]
+    fn test_spec_examples() {
+        // The examples from the YAML 1.1 timestamp type, all one instant.
+        let expected = NanoTime::new(2001, 12, 15, 2, 59, 43, 100_000_000).unwrap();
+        assert_eq!(utc("2001-12-15T02:59:43.1Z"), expected);
+        assert_eq!(utc("2001-12-14t21:59:43.10-05:00"), expected);
+        assert_eq!(utc("2001-12-14 21:59:43.10 -5"), expected);
+        assert_eq!(utc("2001-12-15 2:59:43.10"), expected);
+        assert_eq!(
+            utc("2002-12-14"),
+            NanoTime::new(2002, 12, 14, 0, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_loose_fields() {
+        assert_eq!(
+            utc("2026-2-2\t\t3:04:05"),
+            NanoTime::new(2026, 2, 2, 3, 4, 5, 0).unwrap()
+        );
+        assert_eq!(
+            utc("2026-02-22T14:30:05."),
+            NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap()
+        );
+        assert_eq!(
+            utc("2026-02-22 14:30:05 Z"),
+            NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap()
+        );
+        let t = OffsetNanoTime::parse_yaml_timestamp("2026-02-22 14:30:05+05:30").unwrap();
+        assert_eq!(t.offset(), UtcOffset::from_minutes(330).unwrap());
+    }
+
+    #[test]
+    fn test_rejections() {
+        let err = |input| NanoTime::parse_yaml_timestamp(input).unwrap_err();
+        assert_eq!(err("2026-2-22").expected(), "2-digit month and day");
+        assert_eq!(err("2026-02-30").expected(), "day 1-28");
+        assert_eq!(err("2026-02-22 14:30:05 ").expected(), "'Z' or UTC offset");
+        assert_eq!(err("2026-02-22_14:30:05").offset(), 10);
+        assert_eq!(err("2026-02-22 24:00:00").expected(), "hour 0-23");
+        assert_eq!(err("2026-02-22 14:30:05 +5:3").offset(), 23);
+        let e = err("0000-01-01 00:00:00 +1");
+        assert_eq!(
+            (e.offset(), e.found(), e.expected()),
+            (20, "+1", OUT_OF_RANGE)
+        );
+        assert!(OffsetNanoTime::parse_yaml_timestamp("0000-01-01 00:00:00 +1").is_ok());
+        assert_eq!(
+            utc("0000-01-01 00:00:00 -1"),
+            NanoTime::new(0, 1, 1, 1, 0, 0, 0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! Packet capture timestamps, as stored in pcap and pcapng files.
+//!
+//! Classic pcap records hold unsigned 32-bit seconds and a sub-second part
+//! in microseconds, or nanoseconds in files with the nanosecond magic
+//! number. pcapng records hold one 64-bit count of ticks since the epoch,
+//! split into high and low 32-bit words, whose unit is set per interface by
+//! the `if_tsresol` option.
+
+use crate::NanoTime;
+
+/// The tick length of pcapng timestamps, from an interface's `if_tsresol`
+/// option.
+///
+/// When the high bit of the option is clear, the low seven bits are a
+/// negative power of ten: `6` means microseconds. When it is set, they are
+/// a negative power of two. Interfaces without the option use
+/// microseconds, which is the [`Default`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PcapResolution(u8);
+
+impl PcapResolution {
+    /// Microseconds, the resolution when `if_tsresol` is absent.
+    pub const MICROS: Self = PcapResolution(6);
+    /// Nanoseconds.
+    pub const NANOS: Self = PcapResolution(9);
+
+    /// Interprets an `if_tsresol` option value. Returns `None` if a second
+    /// has more ticks than fit in 64 bits: decimal exponents above 19 and
+    /// binary exponents above 63.
+    pub fn from_if_tsresol(value: u8) -> Option<Self> {
+        let exp = value & 0x7F;
+        let ok = if value & 0x80 == 0 {
+            exp <= 19
+        } else {
+            exp <= 63
+        };
+        ok.then_some(PcapResolution(value))
+    }
+
+    /// Returns the `if_tsresol` option value.
+    pub fn if_tsresol(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the number of ticks in one second.
+    pub fn ticks_per_sec(&self) -> u64 {
+        let exp = (self.0 & 0x7F) as u32;
+        if self.0 & 0x80 == 0 {
+            10u64.pow(exp)
+        } else {
+            1u64 << exp
+        }
+    }
+}
+
+impl Default for PcapResolution {
+    fn default() -> Self {
+        Self::MICROS
+    }
+}
+
+impl NanoTime {
+    /// Converts a classic pcap record timestamp: `ts_sec` and `ts_usec`
+    /// from a file with the microsecond magic number `0xA1B2C3D4`.
+    /// Returns `None` if `ts_usec` is a second or more.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::from_pcap(1_771_770_605, 123_456).unwrap();
+    /// assert_eq!(t.to_rfc3339(), "2026-02-22T14:30:05.123456Z");
+    /// assert_eq!(t.to_pcap(), Some((1_771_770_605, 123_456)));
+    /// ```
+    pub fn from_pcap(ts_sec: u32, ts_usec: u32) -> Option<Self> {
+        if ts_usec >= 1_000_000 {
+            return None;
+        }
+        Some(Self::from_unix(ts_sec as i64, ts_usec * 1_000))
+    }
+
+    /// Converts a pcap record timestamp from a file with the nanosecond
+    /// magic number `0xA1B23C4D`. Returns `None` if `ts_nsec` is a second
+    /// or more.
+    pub fn from_pcap_nanos(ts_sec: u32, ts_nsec: u32) -> Option<Self> {
+        if ts_nsec >= 1_000_000_000 {
+            return None;
+        }
+        Some(Self::from_unix(ts_sec as i64, ts_nsec))
+    }
+
+    /// Returns the pcap `(ts_sec, ts_usec)` pair, truncating to
+    /// microseconds. Returns `None` outside 1970 through early 2106, the
+    /// range of unsigned 32-bit seconds.
+    pub fn to_pcap(&self) -> Option<(u32, u32)> {
+        self.to_pcap_nanos()
+            .map(|(secs, nanos)| (secs, nanos / 1_000))
+    }
+
+    /// Returns the `(ts_sec, ts_nsec)` pair for a nanosecond pcap file.
+    /// Returns `None` outside 1970 through early 2106.
+    pub fn to_pcap_nanos(&self) -> Option<(u32, u32)> {
+        let secs = u32::try_from(self.unix_secs()).ok()?;
+        Some((secs, self.nanosecond()))
+    }
+
+    /// Converts a pcapng timestamp: `(high << 32) | low` from an Enhanced
+    /// Packet Block, in ticks of `resolution`.
+    ///
+    /// Resolutions of a nanosecond or coarser convert exactly. Finer ones,
+    /// and binary resolutions that do not divide a second into whole
+    /// nanoseconds, are truncated to the nanosecond. Returns `None` if the
+    /// year would exceed 65535. Any `if_tsoffset` seconds are not included.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, PcapResolution};
+    ///
+    /// let ticks = 1_771_770_605_123_456_789;
+    /// let t = NanoTime::from_pcapng(ticks, PcapResolution::NANOS).unwrap();
+    /// assert_eq!(t.to_rfc3339(), "2026-02-22T14:30:05.123456789Z");
+    /// assert_eq!(t.to_pcapng(PcapResolution::NANOS), Some(ticks));
+    /// ```
+    pub fn from_pcapng(ticks: u64, resolution: PcapResolution) -> Option<Self> {
+        let per_sec = resolution.ticks_per_sec();
+        let secs = i64::try_from(ticks / per_sec).ok()?;
+        let nanos = (ticks % per_sec) as u128 * 1_000_000_000 / per_sec as u128;
+        Self::checked_from_unix(secs, nanos as u32)
+    }
+
+    /// Returns the pcapng timestamp in ticks of `resolution`, truncating
+    /// anything finer. Returns `None` before 1970 or if the count does not
+    /// fit in 64 bits.
+    pub fn to_pcapng(&self, resolution: PcapResolution) -> Option<u64> {
+        let secs = u64::try_from(self.unix_secs()).ok()?;
+        let per_sec = resolution.ticks_per_sec();
+        let frac = self.nanosecond() as u128 * per_sec as u128 / 1_000_000_000;
+        secs.checked_mul(per_sec)?.checked_add(frac as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t() -> NanoTime {
+        NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap()
+    }
+
+    #[test]
+    fn test_pcap_round_trip() {
+        assert_eq!(t().to_pcap(), Some((1_771_770_605, 123_456)));
+        assert_eq!(t().to_pcap_nanos(), Some((1_771_770_605, 123_456_789)));
+        assert_eq!(
+            NanoTime::from_pcap_nanos(1_771_770_605, 123_456_789),
+            Some(t())
+        );
+        assert_eq!(NanoTime::from_pcap(0, 1_000_000), None);
+        assert_eq!(NanoTime::from_pcap_nanos(0, 1_000_000_000), None);
+        let max = NanoTime::from_pcap(u32::MAX, 0).unwrap();
+        assert_eq!(max.to_rfc3339(), "2106-02-07T06:28:15Z");
+        let before = NanoTime::new(1969, 12, 31, 23, 59, 59, 0).unwrap();
+        assert_eq!(before.to_pcap(), None);
+    }
+
+    #[test]
+    fn test_resolution() {
+        assert_eq!(PcapResolution::default().ticks_per_sec(), 1_000_000);
+        assert_eq!(
+            PcapResolution::from_if_tsresol(9),
+            Some(PcapResolution::NANOS)
+        );
+        let binary = PcapResolution::from_if_tsresol(0x80 | 20).unwrap();
+        assert_eq!(binary.ticks_per_sec(), 1 << 20);
+        assert_eq!(binary.if_tsresol(), 0x94);
+        assert_eq!(
+            PcapResolution::from_if_tsresol(19).unwrap().ticks_per_sec(),
+            10_000_000_000_000_000_000
+        );
+        assert_eq!(PcapResolution::from_if_tsresol(20), None);
+        assert_eq!(PcapResolution::from_if_tsresol(0x80 | 64), None);
+    }
+
+    #[test]
+    fn test_pcapng_decimal() {
+        let micros = PcapResolution::MICROS;
+        let ticks = t().to_pcapng(micros).unwrap();
+        assert_eq!(ticks, 1_771_770_605_123_456);
+        assert_eq!(
+            NanoTime::from_pcapng(ticks, micros).unwrap().nanosecond(),
+            123_456_000
+        );
+        // Milliseconds.
+        let millis = PcapResolution::from_if_tsresol(3).unwrap();
+        assert_eq!(t().to_pcapng(millis), Some(1_771_770_605_123));
+        // Picoseconds overflow 64 bits by 2026.
+        let picos = PcapResolution::from_if_tsresol(12).unwrap();
+        assert_eq!(t().to_pcapng(picos), None);
+        let early = NanoTime::from_pcapng(1_500_000_000_001, picos).unwrap();
+        assert_eq!((early.second(), early.nanosecond()), (1, 500_000_000));
+    }
+
+    #[test]
+    fn test_pcapng_binary() {
+        let res = PcapResolution::from_if_tsresol(0x80 | 10).unwrap();
+        // 1.5 seconds in 1/1024 ticks.
+        let t = NanoTime::from_pcapng(1024 + 512, res).unwrap();
+        assert_eq!((t.second(), t.nanosecond()), (1, 500_000_000));
+        assert_eq!(t.to_pcapng(res), Some(1536));
+        // One tick is 976562.5ns, truncated.
+        assert_eq!(NanoTime::from_pcapng(1, res).unwrap().nanosecond(), 976_562);
+    }
+}
@@ -0,0 +1,172 @@
+//! Sleeping for exact durations on the monotonic clock.
+//!
+//! `thread::sleep` never wakes early but often wakes late, by anything from
+//! tens of microseconds to several milliseconds depending on the platform
+//! and timer resolution. For pacing loops that matters: a 2ms overshoot on
+//! a 10ms audio period is a 20% error. [`compensated_sleep`] sleeps for the
+//! requested time minus the measured overshoot, then spins for the rest.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::LatencyHistogram;
+
+/// The sleep length used to measure overshoot.
+const PROBE: Duration = Duration::from_millis(1);
+
+/// Samples taken by the first [`compensated_sleep`] if [`calibrate`] was
+/// never called.
+const DEFAULT_SAMPLES: u32 = 20;
+
+/// The margin used by [`compensated_sleep`], in nanoseconds, or `u64::MAX`
+/// before calibration.
+static MARGIN: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// How late `thread::sleep` woke up across a calibration run.
+///
+/// ```rust
+/// use nanotime::SleepCalibration;
+///
+/// let cal = SleepCalibration::measure(10);
+/// println!("sleeps overshoot by {:?} typically, {:?} at p99", cal.median(), cal.p99());
+/// assert!(cal.median() <= cal.max());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SleepCalibration {
+    samples: u32,
+    median: Duration,
+    p99: Duration,
+    max: Duration,
+}
+
+impl SleepCalibration {
+    /// Sleeps for 1ms `samples` times and records how late each wake-up
+    /// was. Takes at least `samples` milliseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is zero.
+    pub fn measure(samples: u32) -> Self {
+        assert!(samples > 0, "calibration needs at least one sample");
+        let mut late = LatencyHistogram::new();
+        for _ in 0..samples {
+            let start = Instant::now();
+            thread::sleep(PROBE);
+            late.record(start.elapsed().saturating_sub(PROBE));
+        }
+        SleepCalibration {
+            samples,
+            median: late.percentile(50.0).unwrap_or_default(),
+            p99: late.percentile(99.0).unwrap_or_default(),
+            max: late.max().unwrap_or_default(),
+        }
+    }
+
+    /// Returns the number of sleeps measured.
+    pub fn samples(&self) -> u32 {
+        self.samples
+    }
+
+    /// Returns the typical overshoot.
+    pub fn median(&self) -> Duration {
+        self.median
+    }
+
+    /// Returns the overshoot that 99% of sleeps stayed within.
+    pub fn p99(&self) -> Duration {
+        self.p99
+    }
+
+    /// Returns the largest overshoot seen.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+}
+
+/// Measures sleep overshoot with `samples` sleeps of 1ms and makes
+/// [`compensated_sleep`] use the p99 overshoot as its spin margin.
+///
+/// Call it at startup, or again after the system's timer resolution may
+/// have changed. Takes at least `samples` milliseconds.
+///
+/// # Panics
+///
+/// Panics if `samples` is zero.
+pub fn calibrate(samples: u32) -> SleepCalibration {
+    let cal = SleepCalibration::measure(samples);
+    MARGIN.store(cal.p99.as_nanos() as u64, Ordering::Relaxed);
+    cal
+}
+
+/// Returns the spin margin [`compensated_sleep`] uses, calibrating with 20
+/// samples first if [`calibrate`] has not been called.
+pub fn sleep_overshoot() -> Duration {
+    match MARGIN.load(Ordering::Relaxed) {
+        u64::MAX => calibrate(DEFAULT_SAMPLES).p99,
+        nanos => Duration::from_nanos(nanos),
+    }
+}
+
+/// Sleeps for `duration`, waking much closer to it than `thread::sleep`.
+///
+/// Sleeps for `duration` minus [`sleep_overshoot`], then spins on the
+/// monotonic clock until the full duration has passed, so it costs CPU for
+/// up to the overshoot margin. The first call calibrates if [`calibrate`]
+/// has not been called, which takes about 20ms.
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use nanotime::compensated_sleep;
+///
+/// let start = Instant::now();
+/// compensated_sleep(Duration::from_millis(5));
+/// assert!(start.elapsed() >= Duration::from_millis(5));
+/// ```
+pub fn compensated_sleep(duration: Duration) {
+    compensated_sleep_until(Instant::now() + duration)
+}
+
+/// Sleeps until `deadline` on the monotonic clock, like
+/// [`compensated_sleep`].
+///
+/// Waking against an absolute deadline keeps periodic loops from drifting,
+/// since time spent between sleeps is not added to the period.
+pub fn compensated_sleep_until(deadline: Instant) {
+    let margin = sleep_overshoot();
+    let now = Instant::now();
+    if let Some(coarse) = deadline.checked_duration_since(now + margin) {
+        thread::sleep(coarse);
+    }
+    while Instant::now() < deadline {
+        std::hint::spin_loop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibration() {
+        let cal = SleepCalibration::measure(5);
+        assert_eq!(cal.samples(), 5);
+        assert!(cal.median() <= cal.p99() && cal.p99() <= cal.max());
+    }
+
+    #[test]
+    fn test_compensated_sleep() {
+        let margin = sleep_overshoot();
+        for ms in [0, 1, 3] {
+            let requested = Duration::from_millis(ms);
+            let start = Instant::now();
+            compensated_sleep(requested);
+            let took = start.elapsed();
+            assert!(took >= requested);
+            // Generous, since the test machine may be busy.
+            assert!(took < requested + margin + Duration::from_millis(50));
+        }
+        let past = Instant::now();
+        compensated_sleep_until(past);
+    }
+}
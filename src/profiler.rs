@@ -0,0 +1,644 @@
+//! An opt-in, process-wide registry of [`ScopedTimer`] measurements.
+//!
+//! Nothing is collected until [`enable`] is called. After that, every
+//! `ScopedTimer` adds its duration to the registry under its label when it
+//! drops, and [`report`] aggregates them into a table:
+//!
+//! ```rust
+//! use nanotime::{profiler, ScopedTimer};
+//!
+//! fn parse() {
+//!     let _t = ScopedTimer::new("parse");
+//!     // ... work ...
+//! }
+//!
+//! profiler::enable();
+//! for _ in 0..3 {
+//!     parse();
+//! }
+//! let report = profiler::report();
+//! assert_eq!(report.get("parse").unwrap().count(), 3);
+//! println!("{}", report);
+//! ```
+//!
+//! To print the table when `main` returns, hold the guard from
+//! [`report_on_exit`] for the life of `main`.
+//!
+//! Timers also track nesting within a thread, and [`folded`] exports the
+//! time spent in each call path in the folded-stack format read by
+//! flamegraph tools such as `inferno-flamegraph` and `flamegraph.pl`.
+//! With [`enable_trace`], each timer is also kept as an event on a
+//! timeline, which [`chrome_trace`] exports for `about://tracing` and
+//! Perfetto.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::checkpoint::human;
+use crate::TimingStats;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static TRACING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Default)]
+struct Registry {
+    by_label: HashMap<Cow<'static, str>, TimingStats>,
+    /// Self time in nanoseconds per `;`-joined call path.
+    folded: BTreeMap<String, u128>,
+    /// Every timer since [`enable_trace`], when tracing.
+    events: Vec<TraceEvent>,
+    /// Names of the threads that recorded events, by trace thread id.
+    threads: BTreeMap<u64, String>,
+}
+
+/// One finished timer on the trace timeline.
+struct TraceEvent {
+    label: String,
+    /// Start, relative to [`trace_origin`].
+    start: Duration,
+    elapsed: Duration,
+    tid: u64,
+}
+
+/// The instant trace timestamps count from.
+fn trace_origin() -> Instant {
+    static ORIGIN: OnceLock<Instant> = OnceLock::new();
+    *ORIGIN.get_or_init(Instant::now)
+}
+
+static NEXT_TID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Small sequential thread ids, since `ThreadId` has no stable number.
+    static TID: u64 = NEXT_TID.fetch_add(1, Ordering::Relaxed);
+}
+
+/// An open timer on the current thread's stack.
+struct Frame {
+    id: u64,
+    path: String,
+    /// Total time of the timers nested directly inside this one.
+    children: Duration,
+}
+
+static NEXT_FRAME: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static STACK: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pushes a frame for `label` onto the current thread's stack and returns
+/// its id.
+fn push_frame(label: &str) -> Option<u64> {
+    let id = NEXT_FRAME.fetch_add(1, Ordering::Relaxed);
+    STACK
+        .try_with(|stack| {
+            let mut stack = stack.borrow_mut();
+            let path = match stack.last() {
+                Some(parent) => format!("{};{}", parent.path, label),
+                None => label.to_string(),
+            };
+            stack.push(Frame {
+                id,
+                path,
+                children: Duration::ZERO,
+            });
+            id
+        })
+        .ok()
+}
+
+/// Pops frame `id` and any frames left open above it, charges `elapsed` to
+/// its parent, and returns its path and self time.
+///
+/// Returns `None` if the frame is not on this thread's stack, for example
+/// because the timer was moved to another thread.
+fn pop_frame(id: u64, elapsed: Duration) -> Option<(String, Duration)> {
+    STACK
+        .try_with(|stack| {
+            let mut stack = stack.borrow_mut();
+            let pos = stack.iter().rposition(|f| f.id == id)?;
+            let frame = stack.drain(pos..).next()?;
+            if let Some(parent) = stack.last_mut() {
+                parent.children += elapsed;
+            }
+            Some((frame.path, elapsed.saturating_sub(frame.children)))
+        })
+        .ok()
+        .flatten()
+}
+
+fn registry() -> MutexGuard<'static, Registry> {
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+    REGISTRY
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Starts collecting measurements from every [`ScopedTimer`].
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stops collecting measurements. Those already collected are kept.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Starts collecting measurements, and also keeps the start time and
+/// duration of every [`ScopedTimer`] for [`chrome_trace`].
+///
+/// Unlike the per-label statistics, the trace grows with every timer, so
+/// enable it for a bounded run rather than for the life of a server.
+pub fn enable_trace() {
+    trace_origin();
+    TRACING.store(true, Ordering::Relaxed);
+    enable();
+}
+
+/// Stops keeping timers for the trace. Those already kept are kept, and
+/// per-label collection continues until [`disable`].
+pub fn disable_trace() {
+    TRACING.store(false, Ordering::Relaxed);
+}
+
+/// Returns `true` if measurements are being collected.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Adds a measurement under `label`, if collection is enabled.
+pub fn record(label: impl Into<Cow<'static, str>>, elapsed: Duration) {
+    if is_enabled() {
+        registry()
+            .by_label
+            .entry(label.into())
+            .or_default()
+            .record(elapsed);
+    }
+}
+
+/// Discards all collected measurements.
+pub fn reset() {
+    let mut registry = registry();
+    registry.by_label.clear();
+    registry.folded.clear();
+    registry.events.clear();
+    registry.threads.clear();
+}
+
+/// Returns the measurements collected so far, per label.
+pub fn report() -> ProfileReport {
+    let mut entries: Vec<ProfileEntry> = registry()
+        .by_label
+        .iter()
+        .map(|(label, stats)| ProfileEntry {
+            label: label.to_string(),
+            stats: stats.clone(),
+        })
+        .collect();
+    entries.sort_by(|a, b| {
+        b.stats
+            .total()
+            .cmp(&a.stats.total())
+            .then_with(|| a.label.cmp(&b.label))
+    });
+    ProfileReport { entries }
+}
+
+/// Returns the self time of each call path as folded-stack lines, one per
+/// path, such as `main;load;parse 123456`.
+///
+/// Paths are the labels of nested [`ScopedTimer`]s on one thread, outermost
+/// first, and the value is the time in nanoseconds spent in the innermost
+/// timer but not in timers nested inside it. Pipe the output to
+/// `inferno-flamegraph` or `flamegraph.pl` to draw a flame graph. Labels
+/// should not contain `;` or line breaks.
+///
+/// ```rust
+/// use nanotime::{profiler, ScopedTimer};
+///
+/// profiler::enable();
+/// {
+///     let _main = ScopedTimer::new("folded_doc_main");
+///     let _load = ScopedTimer::new("load");
+/// }
+/// assert!(profiler::folded().contains("folded_doc_main;load "));
+/// ```
+pub fn folded() -> String {
+    let mut out = String::new();
+    for (path, nanos) in &registry().folded {
+        out.push_str(path);
+        out.push(' ');
+        out.push_str(&nanos.to_string());
+        out.push('\n');
+    }
+    out
+}
+
+/// Returns the timers kept since [`enable_trace`] as Chrome trace-event
+/// JSON, for loading into `about://tracing` or <https://ui.perfetto.dev>.
+///
+/// Each timer becomes a complete (`"ph":"X"`) event with its label as the
+/// name, `ts` and `dur` in microseconds from the first call to
+/// `enable_trace`, the process id as `pid`, and a small per-thread number
+/// as `tid`. Named threads also get a `thread_name` metadata event.
+///
+/// ```rust
+/// use nanotime::{profiler, ScopedTimer};
+///
+/// profiler::enable_trace();
+/// ScopedTimer::new("trace_doc_parse").stop();
+/// let json = profiler::chrome_trace();
+/// assert!(json.starts_with(r#"{"traceEvents":["#));
+/// assert!(json.contains(r#""name":"trace_doc_parse","ph":"X","ts":"#));
+/// // std::fs::write("trace.json", json)?;
+/// ```
+pub fn chrome_trace() -> String {
+    let registry = registry();
+    let mut events: Vec<&TraceEvent> = registry.events.iter().collect();
+    events.sort_by_key(|e| (e.start, std::cmp::Reverse(e.elapsed)));
+    let pid = std::process::id();
+    let mut lines = Vec::with_capacity(events.len() + registry.threads.len());
+    for (tid, name) in &registry.threads {
+        lines.push(format!(
+            r#"{{"name":"thread_name","ph":"M","pid":{},"tid":{},"args":{{"name":"{}"}}}}"#,
+            pid,
+            tid,
+            json_escape(name)
+        ));
+    }
+    let micros = |d: Duration| format!("{}.{:03}", d.as_micros(), d.subsec_nanos() % 1_000);
+    for e in events {
+        lines.push(format!(
+            r#"{{"name":"{}","ph":"X","ts":{},"dur":{},"pid":{},"tid":{}}}"#,
+            json_escape(&e.label),
+            micros(e.start),
+            micros(e.elapsed),
+            pid,
+            e.tid
+        ));
+    }
+    format!(
+        "{{\"traceEvents\":[\n{}\n],\"displayTimeUnit\":\"ns\"}}\n",
+        lines.join(",\n")
+    )
+}
+
+/// Escapes `s` for use inside a JSON string.
+fn json_escape(s: &str) -> Cow<'_, str> {
+    if !s.chars().any(|c| c == '"' || c == '\\' || c.is_control()) {
+        return Cow::Borrowed(s);
+    }
+    let mut out = String::with_capacity(s.len() + 8);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Enables collection and returns a guard that prints the [`report`] to
+/// standard error when dropped.
+///
+/// Rust runs no destructors for statics at exit, so bind the guard to a
+/// local at the top of `main`:
+///
+/// ```rust,no_run
+/// let _profile = nanotime::profiler::report_on_exit();
+/// // ... the rest of main ...
+/// ```
+pub fn report_on_exit() -> ReportOnExit {
+    enable();
+    ReportOnExit { _private: () }
+}
+
+/// The guard returned by [`report_on_exit`].
+#[derive(Debug)]
+#[must_use = "the report is printed when the guard is dropped"]
+pub struct ReportOnExit {
+    _private: (),
+}
+
+impl Drop for ReportOnExit {
+    fn drop(&mut self) {
+        let report = report();
+        if !report.entries.is_empty() {
+            eprintln!("{}", report);
+        }
+    }
+}
+
+/// Aggregated measurements for one label in a [`ProfileReport`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileEntry {
+    label: String,
+    stats: TimingStats,
+}
+
+impl ProfileEntry {
+    /// Returns the timer label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the statistics over all measurements with this label.
+    pub fn stats(&self) -> &TimingStats {
+        &self.stats
+    }
+}
+
+/// Collected measurements per label, most total time first.
+///
+/// Displays as an aligned table:
+///
+/// ```text
+/// label     count     total      mean       min       max
+/// parse      1200     1.42s     1.2ms   900.0µs     8.1ms
+/// load          1    12.4ms    12.4ms    12.4ms    12.4ms
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileReport {
+    entries: Vec<ProfileEntry>,
+}
+
+impl ProfileReport {
+    /// Returns the entries, most total time first.
+    pub fn entries(&self) -> &[ProfileEntry] {
+        &self.entries
+    }
+
+    /// Returns the statistics for `label`, if any were recorded.
+    pub fn get(&self, label: &str) -> Option<&TimingStats> {
+        self.entries
+            .iter()
+            .find(|e| e.label == label)
+            .map(|e| &e.stats)
+    }
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .entries
+            .iter()
+            .map(|e| e.label.chars().count())
+            .chain([5])
+            .max()
+            .unwrap_or(0);
+        write!(
+            f,
+            "{:<width$}  {:>8}  {:>8}  {:>8}  {:>8}  {:>8}",
+            "label",
+            "count",
+            "total",
+            "mean",
+            "min",
+            "max",
+            width = width
+        )?;
+        let cell = |d: Option<Duration>| d.map_or_else(|| "-".to_string(), human);
+        for e in &self.entries {
+            write!(
+                f,
+                "\n{:<width$}  {:>8}  {:>8}  {:>8}  {:>8}  {:>8}",
+                e.label,
+                e.stats.count(),
+                human(e.stats.total()),
+                cell(e.stats.mean()),
+                cell(e.stats.min()),
+                cell(e.stats.max()),
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Measures the time until it is dropped and adds it to the [`profiler`]
+/// registry under its label.
+///
+/// Timers created while another is running on the same thread are nested
+/// inside it, which [`profiler::folded`] reports as call paths. A timer is
+/// expected to drop before the one it is nested in, as with scope guards.
+///
+/// When the profiler is not enabled a timer costs one `Instant::now` and
+/// one atomic load.
+///
+/// [`profiler`]: crate::profiler
+/// [`profiler::folded`]: crate::profiler::folded
+#[derive(Debug)]
+pub struct ScopedTimer {
+    label: Option<Cow<'static, str>>,
+    start: Instant,
+    /// The id of this timer's frame on the thread's stack, if the profiler
+    /// was enabled when it started.
+    frame: Option<u64>,
+}
+
+impl ScopedTimer {
+    /// Starts a timer. Pass a `&'static str` to avoid allocating.
+    pub fn new(label: impl Into<Cow<'static, str>>) -> Self {
+        let label = label.into();
+        let frame = if is_enabled() {
+            push_frame(&label)
+        } else {
+            None
+        };
+        ScopedTimer {
+            label: Some(label),
+            start: Instant::now(),
+            frame,
+        }
+    }
+
+    /// Returns the label.
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the time since the timer started.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Stops the timer now, records the measurement, and returns it.
+    pub fn stop(mut self) -> Duration {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Duration {
+        let elapsed = self.start.elapsed();
+        let Some(label) = self.label.take() else {
+            return elapsed;
+        };
+        let path = self.frame.and_then(|id| pop_frame(id, elapsed));
+        if is_enabled() {
+            let mut registry = registry();
+            if TRACING.load(Ordering::Relaxed) {
+                let tid = TID.try_with(|tid| *tid).unwrap_or(0);
+                if let Some(name) = std::thread::current().name() {
+                    registry
+                        .threads
+                        .entry(tid)
+                        .or_insert_with(|| name.to_string());
+                }
+                registry.events.push(TraceEvent {
+                    label: label.to_string(),
+                    start: self.start.saturating_duration_since(trace_origin()),
+                    elapsed,
+                    tid,
+                });
+            }
+            registry.by_label.entry(label).or_default().record(elapsed);
+            if let Some((path, self_time)) = path {
+                *registry.folded.entry(path).or_default() += self_time.as_nanos();
+            }
+        }
+        elapsed
+    }
+}
+
+impl Drop for ScopedTimer {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The registry is global and tests run in parallel, so each test uses
+    // its own labels and never disables or resets.
+
+    #[test]
+    fn test_scoped_timers_aggregate() {
+        enable();
+        for _ in 0..3 {
+            let _t = ScopedTimer::new("test_scoped_timers_aggregate");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        let measured = ScopedTimer::new(String::from("test_scoped_timers_stop")).stop();
+        let report = report();
+        let stats = report.get("test_scoped_timers_aggregate").unwrap();
+        assert_eq!(stats.count(), 3);
+        assert!(stats.min().unwrap() >= Duration::from_millis(1));
+        let stopped = report.get("test_scoped_timers_stop").unwrap();
+        assert_eq!((stopped.count(), stopped.max()), (1, Some(measured)));
+    }
+
+    fn folded_nanos(path: &str) -> u128 {
+        folded()
+            .lines()
+            .find_map(|line| line.strip_prefix(path)?.strip_prefix(' '))
+            .map_or(0, |n| n.parse().unwrap())
+    }
+
+    #[test]
+    fn test_folded_stacks() {
+        enable();
+        let outer = ScopedTimer::new("test_folded_outer");
+        for _ in 0..2 {
+            let _inner = ScopedTimer::new("inner");
+            let _leaf = ScopedTimer::new("leaf");
+            std::thread::sleep(Duration::from_millis(2));
+        }
+        let total = outer.stop().as_nanos();
+
+        let outer_self = folded_nanos("test_folded_outer");
+        let inner_self = folded_nanos("test_folded_outer;inner");
+        let leaf_self = folded_nanos("test_folded_outer;inner;leaf");
+        assert!(leaf_self >= 4_000_000);
+        assert!(inner_self < leaf_self);
+        assert_eq!(outer_self + inner_self + leaf_self, total);
+    }
+
+    #[test]
+    fn test_out_of_order_drop() {
+        enable();
+        let a = ScopedTimer::new("test_out_of_order_a");
+        let b = ScopedTimer::new("test_out_of_order_b");
+        drop(a);
+        drop(b);
+        ScopedTimer::new("test_out_of_order_c").stop();
+        // `b` was unwound with `a`, so `c` starts a new stack.
+        assert!(folded()
+            .lines()
+            .any(|line| line.starts_with("test_out_of_order_c ")));
+        assert_eq!(folded_nanos("test_out_of_order_a;test_out_of_order_b"), 0);
+        let b = report().get("test_out_of_order_b").map(TimingStats::count);
+        assert_eq!(b, Some(1));
+    }
+
+    #[test]
+    fn test_chrome_trace_events() {
+        enable_trace();
+        let handle = std::thread::Builder::new()
+            .name("trace \"worker\"".to_string())
+            .spawn(|| {
+                let outer = ScopedTimer::new("test_trace_outer");
+                ScopedTimer::new("test_trace_inner").stop();
+                drop(outer);
+            })
+            .unwrap();
+        handle.join().unwrap();
+        let json = chrome_trace();
+        let line = |name: &str| {
+            json.lines()
+                .find(|l| l.contains(&format!(r#""name":"{}""#, name)))
+                .unwrap()
+                .trim_end_matches(',')
+                .to_string()
+        };
+        let (outer, inner) = (line("test_trace_outer"), line("test_trace_inner"));
+        let tid = |l: &str| l.rsplit(r#""tid":"#).next().unwrap().to_string();
+        assert_eq!(tid(&outer), tid(&inner));
+        // Parents sort before the children they enclose.
+        assert!(json.find(&outer).unwrap() < json.find(&inner).unwrap());
+        assert!(json.contains(r#""args":{"name":"trace \"worker\""}"#));
+        assert!(json.ends_with("],\"displayTimeUnit\":\"ns\"}\n"));
+    }
+
+    #[test]
+    fn test_json_escape() {
+        assert_eq!(json_escape("plain"), "plain");
+        assert_eq!(json_escape("a\"b\\c\n\u{1}"), r#"a\"b\\c\n\u0001"#);
+    }
+
+    #[test]
+    fn test_display_table() {
+        let mut slow = TimingStats::new();
+        slow.record(Duration::from_millis(12));
+        let report = ProfileReport {
+            entries: vec![
+                ProfileEntry {
+                    label: "load".to_string(),
+                    stats: slow,
+                },
+                ProfileEntry {
+                    label: "x".to_string(),
+                    stats: TimingStats::new(),
+                },
+            ],
+        };
+        assert_eq!(
+            report.to_string(),
+            "label     count     total      mean       min       max\n\
+             load          1    12.0ms    12.0ms    12.0ms    12.0ms\n\
+             x             0       0ns         -         -         -"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Prometheus sample timestamps and scrape alignment.
+//!
+//! The exposition format, remote write, and TSDB blocks all store sample
+//! times as signed milliseconds since the epoch. Prometheus scrapes each
+//! target at a fixed phase within its interval, so real samples land on
+//! `offset + k * interval`.
+
+use std::time::Duration;
+
+use crate::NanoTime;
+
+impl NanoTime {
+    /// Returns this UTC time as a Prometheus sample timestamp, truncating
+    /// toward the past to whole milliseconds.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+    /// assert_eq!(t.to_prometheus_millis(), 1_771_770_605_123);
+    /// ```
+    pub fn to_prometheus_millis(&self) -> i64 {
+        self.unix_secs() * 1_000 + self.millisecond() as i64
+    }
+
+    /// Converts a Prometheus sample timestamp. Returns `None` if the year
+    /// would fall outside `0..=65535`.
+    pub fn from_prometheus_millis(ms: i64) -> Option<Self> {
+        Self::checked_from_unix(
+            ms.div_euclid(1_000),
+            ms.rem_euclid(1_000) as u32 * 1_000_000,
+        )
+    }
+}
+
+/// Converts a scrape interval to nanoseconds, panicking if it is zero.
+fn interval_nanos(interval: Duration) -> i128 {
+    let nanos = interval.as_nanos() as i128;
+    assert!(nanos > 0, "scrape interval must be nonzero");
+    nanos
+}
+
+/// Nanoseconds from the latest boundary at or before `t` to `t`.
+fn phase(t: &NanoTime, interval: i128, offset: Duration) -> i128 {
+    let nanos = t.unix_nanos();
+    (nanos - offset.as_nanos() as i128).rem_euclid(interval)
+}
+
+/// Returns the latest scrape boundary at or before `t`: the latest time
+/// that is `offset` past a whole multiple of `interval` since the epoch.
+///
+/// Backfilled or simulated samples anchored this way line up with the
+/// ones a real scrape at the same phase would record. Offsets longer than
+/// the interval wrap around.
+///
+/// # Panics
+///
+/// Panics if `interval` is zero.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{align_to_scrape_interval, NanoTime};
+///
+/// let t = NanoTime::new(2026, 2, 22, 14, 30, 17, 250_000_000).unwrap();
+/// let aligned = align_to_scrape_interval(&t, Duration::from_secs(15), Duration::from_secs(3));
+/// assert_eq!(aligned, NanoTime::new(2026, 2, 22, 14, 30, 3, 0).unwrap());
+/// ```
+pub fn align_to_scrape_interval(t: &NanoTime, interval: Duration, offset: Duration) -> NanoTime {
+    let interval = interval_nanos(interval);
+    t.add_nanos(-phase(t, interval, offset))
+}
+
+/// Returns the scrape boundaries from `start` up to but not including
+/// `end`, as [`align_to_scrape_interval`] defines them, for generating a
+/// backfilled series.
+///
+/// # Panics
+///
+/// Panics if `interval` is zero.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{scrape_times, NanoTime};
+///
+/// let start = NanoTime::new(2026, 2, 22, 14, 0, 1, 0).unwrap();
+/// let end = NanoTime::new(2026, 2, 22, 14, 1, 0, 0).unwrap();
+/// let times: Vec<_> = scrape_times(&start, &end, Duration::from_secs(15), Duration::ZERO).collect();
+/// assert_eq!(times.len(), 3); // 14:00:15, 14:00:30, 14:00:45
+/// ```
+pub fn scrape_times(
+    start: &NanoTime,
+    end: &NanoTime,
+    interval: Duration,
+    offset: Duration,
+) -> impl Iterator<Item = NanoTime> {
+    let step = interval_nanos(interval);
+    let first = match phase(start, step, offset) {
+        0 => *start,
+        p => start.add_nanos(step - p),
+    };
+    let end = *end;
+    std::iter::successors(Some(first), move |t| t.checked_add_nanos(step))
+        .take_while(move |t| *t < end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_millis_round_trip() {
+        let before = NanoTime::new(1969, 12, 31, 23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(before.to_prometheus_millis(), -1);
+        assert_eq!(
+            NanoTime::from_prometheus_millis(-1),
+            NanoTime::new(1969, 12, 31, 23, 59, 59, 999_000_000)
+        );
+        assert_eq!(NanoTime::from_prometheus_millis(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_align() {
+        let interval = Duration::from_secs(15);
+        let on = NanoTime::new(2026, 2, 22, 14, 30, 3, 0).unwrap();
+        assert_eq!(
+            align_to_scrape_interval(&on, interval, Duration::from_secs(3)),
+            on
+        );
+        // An offset beyond the interval wraps: 18s is 3s into a 15s cycle.
+        assert_eq!(
+            align_to_scrape_interval(&on, interval, Duration::from_secs(18)),
+            on
+        );
+        let before = NanoTime::new(1969, 12, 31, 23, 59, 50, 0).unwrap();
+        assert_eq!(
+            align_to_scrape_interval(&before, interval, Duration::ZERO),
+            NanoTime::new(1969, 12, 31, 23, 59, 45, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_scrape_times_include_start_boundary() {
+        let start = NanoTime::new(2026, 2, 22, 14, 0, 0, 0).unwrap();
+        let end = NanoTime::new(2026, 2, 22, 14, 0, 30, 0).unwrap();
+        let times: Vec<_> =
+            scrape_times(&start, &end, Duration::from_secs(10), Duration::ZERO).collect();
+        assert_eq!(times.len(), 3);
+        assert_eq!(times[0], start);
+        assert_eq!(times[2].second(), 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "scrape interval must be nonzero")]
+    fn test_zero_interval_panics() {
+        align_to_scrape_interval(&NanoTime::from_epoch(0), Duration::ZERO, Duration::ZERO);
+    }
+}
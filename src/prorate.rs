@@ -0,0 +1,106 @@
+//! Splitting monthly amounts over partial months, for billing.
+
+use std::ops::Range;
+
+use crate::{days_from_civil, NanoTime, NANOS_PER_DAY};
+
+/// Epoch nanoseconds of midnight starting month `month` (1-based, may
+/// run past 12) of `year`.
+fn month_start(year: i64, month: i64) -> i128 {
+    let months = year * 12 + month - 1;
+    let days = days_from_civil(months.div_euclid(12), (months.rem_euclid(12) + 1) as u8, 1);
+    days as i128 * NANOS_PER_DAY
+}
+
+impl NanoTime {
+    /// Returns how far through its calendar month this time is, from 0.0
+    /// at midnight on the 1st up to but not including 1.0.
+    ///
+    /// Each month counts its own length, so noon on February 15th is
+    /// further through February than noon on March 15th is through March.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 15, 0, 0, 0, 0).unwrap();
+    /// assert_eq!(t.fraction_of_month_elapsed(), 0.5); // 14 of 28 days
+    /// let t = NanoTime::new(2026, 3, 15, 0, 0, 0, 0).unwrap();
+    /// assert_eq!(t.fraction_of_month_elapsed(), 14.0 / 31.0);
+    /// ```
+    pub fn fraction_of_month_elapsed(&self) -> f64 {
+        let (year, month) = (self.year() as i64, self.month() as i64);
+        let start = month_start(year, month);
+        let len = month_start(year, month + 1) - start;
+        (self.unix_nanos() - start) as f64 / len as f64
+    }
+}
+
+/// Returns the share of `monthly_amount` due for `range`, charging each
+/// calendar month it touches by the fraction of that month it covers.
+///
+/// A range from the 15th of one month to the 15th of the next is not
+/// exactly one month's charge, since the two halves come from months of
+/// different lengths. The range is half-open, and an empty or reversed
+/// one costs nothing. Round the result to the currency's minor unit once,
+/// after summing, to avoid accumulating rounding errors.
+///
+/// ```rust
+/// use nanotime::{prorate, NanoTime};
+///
+/// let day = |m, d| NanoTime::new(2026, m, d, 0, 0, 0, 0).unwrap();
+/// // Half of February is half its price.
+/// assert_eq!(prorate(&(day(2, 1)..day(2, 15)), 30.0), 15.0);
+/// // A full calendar month is always the full price.
+/// assert_eq!(prorate(&(day(3, 1)..day(4, 1)), 30.0), 30.0);
+/// // Feb 15 to Mar 15: 14/28 of February plus 14/31 of March.
+/// let due = prorate(&(day(2, 15)..day(3, 15)), 31.0);
+/// assert!((due - 29.5).abs() < 1e-9);
+/// ```
+pub fn prorate(range: &Range<NanoTime>, monthly_amount: f64) -> f64 {
+    let (start, end) = (range.start.unix_nanos(), range.end.unix_nanos());
+    if start >= end {
+        return 0.0;
+    }
+    let (mut year, mut month) = (range.start.year() as i64, range.start.month() as i64);
+    let mut total = 0.0;
+    loop {
+        let month_begin = month_start(year, month);
+        if month_begin >= end {
+            break;
+        }
+        let month_end = month_start(year, month + 1);
+        let covered = end.min(month_end) - start.max(month_begin);
+        total += monthly_amount * covered as f64 / (month_end - month_begin) as f64;
+        if month == 12 {
+            (year, month) = (year + 1, 1);
+        } else {
+            month += 1;
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fraction_of_month() {
+        let leap = NanoTime::new(2024, 2, 29, 12, 0, 0, 0).unwrap();
+        assert_eq!(leap.fraction_of_month_elapsed(), 28.5 / 29.0);
+        let last = NanoTime::new(65535, 12, 31, 23, 59, 59, 999_999_999).unwrap();
+        assert!(last.fraction_of_month_elapsed() < 1.0);
+        assert_eq!(NanoTime::from_epoch(0).fraction_of_month_elapsed(), 0.0);
+    }
+
+    #[test]
+    fn test_prorate_spans_years() {
+        let start = NanoTime::new(2025, 12, 16, 0, 0, 0, 0).unwrap();
+        let end = NanoTime::new(2026, 2, 15, 0, 0, 0, 0).unwrap();
+        // 16/31 of December, all of January, 14/28 of February.
+        let due = prorate(&(start..end), 62.0);
+        assert!((due - (32.0 + 62.0 + 31.0)).abs() < 1e-9);
+        assert_eq!(prorate(&(end..start), 62.0), 0.0);
+        assert_eq!(prorate(&(start..start), 62.0), 0.0);
+    }
+}
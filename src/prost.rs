@@ -0,0 +1,217 @@
+//! Conversions to and from the protobuf well-known types
+//! `google.protobuf.Timestamp` and `google.protobuf.Duration`, as generated
+//! by `prost-types`. Requires the `prost` feature.
+//!
+//! Both directions validate against the ranges the protobuf spec defines:
+//! timestamps from `0001-01-01T00:00:00Z` to `9999-12-31T23:59:59.999999999Z`,
+//! and durations up to about ±10,000 years with `seconds` and `nanos` of
+//! the same sign.
+//!
+//! ```rust
+//! use nanotime::NanoTime;
+//!
+//! let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 120_000_000).unwrap();
+//! let ts = prost_types::Timestamp::try_from(t).unwrap();
+//! assert_eq!((ts.seconds, ts.nanos), (1_771_770_605, 120_000_000));
+//! assert_eq!(NanoTime::try_from(ts).unwrap(), t);
+//! ```
+
+use std::fmt;
+
+use prost_types::{Duration, Timestamp};
+
+use crate::{NanoDuration, NanoTime, NANOS_PER_SEC};
+
+/// `0001-01-01T00:00:00Z` in Unix seconds.
+const MIN_TIMESTAMP_SECS: i64 = -62_135_596_800;
+/// `9999-12-31T23:59:59Z` in Unix seconds.
+const MAX_TIMESTAMP_SECS: i64 = 253_402_300_799;
+/// About 10,000 years, the bound on `Duration.seconds`.
+const MAX_DURATION_SECS: i64 = 315_576_000_000;
+
+/// Why a protobuf conversion failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConversionError {
+    /// `seconds` is outside the range the protobuf spec allows.
+    SecondsOutOfRange,
+    /// `nanos` is outside `0..=999_999_999` for a timestamp, or
+    /// `-999_999_999..=999_999_999` for a duration.
+    NanosOutOfRange,
+    /// A duration's `seconds` and `nanos` have opposite signs.
+    SignMismatch,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ConversionError::SecondsOutOfRange => "protobuf seconds out of range",
+            ConversionError::NanosOutOfRange => "protobuf nanos out of range",
+            ConversionError::SignMismatch => "protobuf duration seconds and nanos differ in sign",
+        })
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// Fails for years 0 and after 9999, which protobuf cannot represent.
+impl TryFrom<NanoTime> for Timestamp {
+    type Error = ConversionError;
+
+    fn try_from(t: NanoTime) -> Result<Self, Self::Error> {
+        let seconds = t.unix_secs();
+        if !(MIN_TIMESTAMP_SECS..=MAX_TIMESTAMP_SECS).contains(&seconds) {
+            return Err(ConversionError::SecondsOutOfRange);
+        }
+        Ok(Timestamp {
+            seconds,
+            nanos: t.nanosecond() as i32,
+        })
+    }
+}
+
+/// The result is UTC. Non-normalized timestamps, such as negative `nanos`,
+/// are rejected rather than adjusted.
+impl TryFrom<Timestamp> for NanoTime {
+    type Error = ConversionError;
+
+    fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
+        if !(MIN_TIMESTAMP_SECS..=MAX_TIMESTAMP_SECS).contains(&ts.seconds) {
+            return Err(ConversionError::SecondsOutOfRange);
+        }
+        let nanos = u32::try_from(ts.nanos).map_err(|_| ConversionError::NanosOutOfRange)?;
+        if nanos >= NANOS_PER_SEC as u32 {
+            return Err(ConversionError::NanosOutOfRange);
+        }
+        Ok(NanoTime::from_unix(ts.seconds, nanos))
+    }
+}
+
+/// Fails beyond the ±10,000 year protobuf limit.
+impl TryFrom<NanoDuration> for Duration {
+    type Error = ConversionError;
+
+    fn try_from(d: NanoDuration) -> Result<Self, Self::Error> {
+        let (mut seconds, mut nanos) = (d.secs_floor(), d.subsec_nanos() as i32);
+        // Protobuf carries the sign on both fields.
+        if seconds < 0 && nanos > 0 {
+            seconds += 1;
+            nanos -= NANOS_PER_SEC as i32;
+        }
+        if !(-MAX_DURATION_SECS..=MAX_DURATION_SECS).contains(&seconds) {
+            return Err(ConversionError::SecondsOutOfRange);
+        }
+        Ok(Duration { seconds, nanos })
+    }
+}
+
+impl TryFrom<Duration> for NanoDuration {
+    type Error = ConversionError;
+
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        if !(-MAX_DURATION_SECS..=MAX_DURATION_SECS).contains(&d.seconds) {
+            return Err(ConversionError::SecondsOutOfRange);
+        }
+        if d.nanos.unsigned_abs() >= NANOS_PER_SEC as u32 {
+            return Err(ConversionError::NanosOutOfRange);
+        }
+        if (d.seconds < 0 && d.nanos > 0) || (d.seconds > 0 && d.nanos < 0) {
+            return Err(ConversionError::SignMismatch);
+        }
+        let total = d.seconds as i128 * NANOS_PER_SEC + d.nanos as i128;
+        NanoDuration::from_nanos(total).ok_or(ConversionError::SecondsOutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(seconds: i64, nanos: i32) -> Timestamp {
+        Timestamp { seconds, nanos }
+    }
+
+    fn dur(seconds: i64, nanos: i32) -> Duration {
+        Duration { seconds, nanos }
+    }
+
+    #[test]
+    fn test_timestamp_round_trip() {
+        let times = [
+            NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap(),
+            NanoTime::new(1969, 12, 31, 23, 59, 59, 999_999_999).unwrap(),
+            NanoTime::new(1, 1, 1, 0, 0, 0, 0).unwrap(),
+            NanoTime::new(9999, 12, 31, 23, 59, 59, 999_999_999).unwrap(),
+        ];
+        for t in times {
+            let back = NanoTime::try_from(Timestamp::try_from(t).unwrap()).unwrap();
+            assert_eq!(back, t);
+        }
+        let pre_epoch = Timestamp::try_from(times[1]).unwrap();
+        assert_eq!((pre_epoch.seconds, pre_epoch.nanos), (-1, 999_999_999));
+    }
+
+    #[test]
+    fn test_timestamp_range() {
+        let year_zero = NanoTime::new(0, 12, 31, 23, 59, 59, 0).unwrap();
+        let year_10k = NanoTime::new(10000, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(
+            Timestamp::try_from(year_zero),
+            Err(ConversionError::SecondsOutOfRange)
+        );
+        assert_eq!(
+            Timestamp::try_from(year_10k),
+            Err(ConversionError::SecondsOutOfRange)
+        );
+        assert_eq!(
+            NanoTime::try_from(ts(MAX_TIMESTAMP_SECS + 1, 0)),
+            Err(ConversionError::SecondsOutOfRange)
+        );
+        assert_eq!(
+            NanoTime::try_from(ts(0, -1)),
+            Err(ConversionError::NanosOutOfRange)
+        );
+        assert_eq!(
+            NanoTime::try_from(ts(0, 1_000_000_000)),
+            Err(ConversionError::NanosOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_duration_signs() {
+        let d = NanoDuration::from_nanos(-1_500_000_000).unwrap();
+        assert_eq!(Duration::try_from(d), Ok(dur(-1, -500_000_000)));
+        assert_eq!(NanoDuration::try_from(dur(-1, -500_000_000)), Ok(d));
+
+        let d = NanoDuration::from_nanos(-250).unwrap();
+        assert_eq!(Duration::try_from(d), Ok(dur(0, -250)));
+        assert_eq!(NanoDuration::try_from(dur(0, -250)), Ok(d));
+
+        assert_eq!(
+            NanoDuration::try_from(dur(1, -1)),
+            Err(ConversionError::SignMismatch)
+        );
+        assert_eq!(
+            NanoDuration::try_from(dur(-1, 1)),
+            Err(ConversionError::SignMismatch)
+        );
+    }
+
+    #[test]
+    fn test_duration_range() {
+        assert!(NanoDuration::try_from(dur(MAX_DURATION_SECS, 999_999_999)).is_ok());
+        assert!(NanoDuration::try_from(dur(-MAX_DURATION_SECS, -999_999_999)).is_ok());
+        assert_eq!(
+            NanoDuration::try_from(dur(MAX_DURATION_SECS + 1, 0)),
+            Err(ConversionError::SecondsOutOfRange)
+        );
+        assert_eq!(
+            NanoDuration::try_from(dur(0, -1_000_000_000)),
+            Err(ConversionError::NanosOutOfRange)
+        );
+        let long = NanoDuration::from_nanos(i64::MAX as i128 * 1_000).unwrap();
+        assert_eq!(
+            Duration::try_from(long),
+            Err(ConversionError::SecondsOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! Rate shaping and limiting driven by caller-supplied timestamps.
+//!
+//! Each type here is a small state machine that is told the current time
+//! rather than reading a clock, so it works the same in event loops,
+//! simulations, and tests. Pass UTC times, such as from
+//! [`NanoTime::now_utc`].
+
+use std::time::Duration;
+
+use crate::NanoTime;
+
+/// Whether at least `wait` has passed from `since` to `now`.
+fn has_elapsed(now: &NanoTime, since: &NanoTime, wait: Duration) -> bool {
+    now.diff_nanos(since) >= wait.as_nanos() as i128
+}
+
+/// Fires once after events stop arriving for a quiet period.
+///
+/// Each [`trigger`](Self::trigger) restarts the wait, so a burst of
+/// keystrokes or file-change notifications collapses into a single action
+/// once the burst is over. An optional maximum wait guarantees the action
+/// still runs during a burst that never pauses.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{Debouncer, NanoTime};
+///
+/// let mut search = Debouncer::new(Duration::from_millis(300));
+/// search.trigger(NanoTime::from_epoch_ms(1_771_770_605_000));
+/// assert!(!search.should_fire(NanoTime::from_epoch_ms(1_771_770_605_100)));
+/// assert!(search.should_fire(NanoTime::from_epoch_ms(1_771_770_605_300)));
+/// assert!(!search.should_fire(NanoTime::from_epoch_ms(1_771_770_605_400)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Debouncer {
+    wait: Duration,
+    max_wait: Option<Duration>,
+    first: Option<NanoTime>,
+    last: Option<NanoTime>,
+}
+
+impl Debouncer {
+    /// Creates a debouncer that fires once `wait` has passed without a new
+    /// event.
+    pub fn new(wait: Duration) -> Self {
+        Debouncer {
+            wait,
+            max_wait: None,
+            first: None,
+            last: None,
+        }
+    }
+
+    /// Also fires once `max_wait` has passed since the first pending
+    /// event, even if events are still arriving.
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// Records an event at `now`, restarting the quiet period.
+    pub fn trigger(&mut self, now: NanoTime) {
+        self.first.get_or_insert(now);
+        self.last = Some(now);
+    }
+
+    /// Returns `true` if an event is waiting to fire.
+    pub fn is_pending(&self) -> bool {
+        self.last.is_some()
+    }
+
+    /// Returns `true`, and clears the pending event, if the debouncer is
+    /// due to fire at `now`.
+    pub fn should_fire(&mut self, now: NanoTime) -> bool {
+        let (Some(first), Some(last)) = (self.first, self.last) else {
+            return false;
+        };
+        let quiet = has_elapsed(&now, &last, self.wait);
+        let overdue = self
+            .max_wait
+            .is_some_and(|max| has_elapsed(&now, &first, max));
+        if quiet || overdue {
+            self.cancel();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Runs `f` if the debouncer is due to fire at `now`.
+    pub fn call<R>(&mut self, now: NanoTime, f: impl FnOnce() -> R) -> Option<R> {
+        self.should_fire(now).then(f)
+    }
+
+    /// Drops the pending event without firing.
+    pub fn cancel(&mut self) {
+        self.first = None;
+        self.last = None;
+    }
+}
+
+/// Fires at most once per interval.
+///
+/// The first call fires immediately; calls within `interval` of the last
+/// firing are rejected. Use it to cap how often an API is hit or a
+/// progress line is redrawn.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{NanoTime, Throttle};
+///
+/// let mut redraw = Throttle::new(Duration::from_secs(1));
+/// let t = |ms| NanoTime::from_epoch_ms(ms);
+/// assert_eq!(redraw.call(t(1_771_770_605_000), || "drawn"), Some("drawn"));
+/// assert_eq!(redraw.call(t(1_771_770_605_500), || "drawn"), None);
+/// assert_eq!(redraw.call(t(1_771_770_606_000), || "drawn"), Some("drawn"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Throttle {
+    interval: Duration,
+    last_fired: Option<NanoTime>,
+}
+
+impl Throttle {
+    /// Creates a throttle that fires at most once per `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Throttle {
+            interval,
+            last_fired: None,
+        }
+    }
+
+    /// Returns `true`, and records a firing at `now`, if at least the
+    /// interval has passed since the last firing.
+    pub fn should_fire(&mut self, now: NanoTime) -> bool {
+        let ready = self
+            .last_fired
+            .is_none_or(|last| has_elapsed(&now, &last, self.interval));
+        if ready {
+            self.last_fired = Some(now);
+        }
+        ready
+    }
+
+    /// Runs `f` if the throttle allows a firing at `now`.
+    pub fn call<R>(&mut self, now: NanoTime, f: impl FnOnce() -> R) -> Option<R> {
+        self.should_fire(now).then(f)
+    }
+
+    /// Returns when the next firing will be allowed, or `None` if it is
+    /// allowed now because nothing has fired yet.
+    pub fn next_allowed(&self) -> Option<NanoTime> {
+        self.last_fired
+            .map(|last| last.add_nanos(self.interval.as_nanos() as i128))
+    }
+
+    /// Forgets the last firing, so the next call fires immediately.
+    pub fn reset(&mut self) {
+        self.last_fired = None;
+    }
+}
+
+/// A token-bucket rate limiter.
+///
+/// The bucket holds up to `capacity` tokens and gains `refill` tokens every
+/// `per`, spread evenly down to the nanosecond. Each operation takes one or
+/// more tokens, so bursts up to the capacity are allowed while the
+/// long-run rate is capped at the refill rate. Token counts are tracked
+/// with integer arithmetic, so no fraction of a token is lost to rounding.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{NanoTime, TokenBucket};
+///
+/// // Bursts of 10, refilling 5 per second.
+/// let mut bucket = TokenBucket::new(10, 5, Duration::from_secs(1)).unwrap();
+/// let t = NanoTime::from_epoch(1_771_770_605);
+/// assert!(bucket.try_acquire(10, t));
+/// assert!(!bucket.try_acquire(1, t));
+/// assert_eq!(bucket.time_until_available(1, t), Some(Duration::from_millis(200)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenBucket {
+    capacity: u64,
+    refill: u64,
+    per: u128,
+    /// Tokens held, in units of `1 / per` of a token so that refills of a
+    /// single nanosecond are exact.
+    level: u128,
+    updated: Option<NanoTime>,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket holding `capacity` tokens and gaining `refill`
+    /// tokens every `per`.
+    ///
+    /// Returns `None` if any argument is zero, or if `capacity` times
+    /// `per` in nanoseconds does not fit in a `u128`.
+    pub fn new(capacity: u64, refill: u64, per: Duration) -> Option<Self> {
+        let per = per.as_nanos();
+        if capacity == 0 || refill == 0 || per == 0 {
+            return None;
+        }
+        Some(TokenBucket {
+            capacity,
+            refill,
+            per,
+            level: (capacity as u128).checked_mul(per)?,
+            updated: None,
+        })
+    }
+
+    /// Returns the maximum number of tokens the bucket holds.
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    /// The level at `now`, without recording the refill.
+    fn level_at(&self, now: &NanoTime) -> u128 {
+        let elapsed = match self.updated {
+            Some(last) => now.diff_nanos(&last).max(0) as u128,
+            None => 0,
+        };
+        let full = self.capacity as u128 * self.per;
+        elapsed
+            .saturating_mul(self.refill as u128)
+            .saturating_add(self.level)
+            .min(full)
+    }
+
+    fn update(&mut self, now: NanoTime) {
+        self.level = self.level_at(&now);
+        // Ignore a clock that stepped backwards rather than refilling the
+        // same interval twice.
+        if self.updated.is_none_or(|last| now > last) {
+            self.updated = Some(now);
+        }
+    }
+
+    /// Returns the number of whole tokens available at `now`.
+    pub fn available(&self, now: NanoTime) -> u64 {
+        (self.level_at(&now) / self.per) as u64
+    }
+
+    /// Takes `n` tokens if that many are available at `now`, returning
+    /// whether it did. Nothing is taken on failure.
+    pub fn try_acquire(&mut self, n: u64, now: NanoTime) -> bool {
+        self.update(now);
+        if n > self.capacity {
+            return false;
+        }
+        let cost = n as u128 * self.per;
+        if cost > self.level {
+            return false;
+        }
+        self.level -= cost;
+        true
+    }
+
+    /// Returns how long after `now` until `n` tokens are available, zero if
+    /// they already are, or `None` if `n` exceeds the capacity and never
+    /// will be.
+    pub fn time_until_available(&self, n: u64, now: NanoTime) -> Option<Duration> {
+        if n > self.capacity {
+            return None;
+        }
+        let deficit = (n as u128 * self.per).saturating_sub(self.level_at(&now));
+        let nanos = deficit.div_ceil(self.refill as u128);
+        Some(Duration::from_nanos(nanos.min(u64::MAX as u128) as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: i128 = 1_000_000;
+
+    fn at(ms: i128) -> NanoTime {
+        NanoTime::from_epoch(1_771_770_605).add_nanos(ms * MS)
+    }
+
+    #[test]
+    fn test_debounce_restarts_on_each_event() {
+        let mut d = Debouncer::new(Duration::from_millis(100));
+        assert!(!d.should_fire(at(0)));
+        for ms in [0, 50, 100, 150] {
+            d.trigger(at(ms));
+            assert!(!d.should_fire(at(ms + 99)));
+        }
+        assert!(d.is_pending());
+        assert_eq!(d.call(at(250), || 7), Some(7));
+        assert!(!d.is_pending());
+        assert_eq!(d.call(at(1000), || 7), None);
+    }
+
+    #[test]
+    fn test_debounce_max_wait() {
+        let mut d =
+            Debouncer::new(Duration::from_millis(100)).with_max_wait(Duration::from_millis(300));
+        for ms in (0..=300).step_by(50) {
+            d.trigger(at(ms));
+            if ms < 300 {
+                assert!(!d.should_fire(at(ms)));
+            }
+        }
+        assert!(d.should_fire(at(300)));
+        // The next burst starts a fresh max-wait.
+        d.trigger(at(350));
+        assert!(!d.should_fire(at(400)));
+        d.cancel();
+        assert!(!d.should_fire(at(10_000)));
+    }
+
+    #[test]
+    fn test_throttle() {
+        let mut t = Throttle::new(Duration::from_millis(100));
+        assert_eq!(t.next_allowed(), None);
+        assert!(t.should_fire(at(0)));
+        assert!(!t.should_fire(at(99)));
+        assert_eq!(t.next_allowed(), Some(at(100)));
+        assert!(t.should_fire(at(150)));
+        assert!(!t.should_fire(at(200)));
+        t.reset();
+        assert!(t.should_fire(at(200)));
+    }
+
+    #[test]
+    fn test_token_bucket_refills_exactly() {
+        // 3 tokens every 1ms: one token per 333,333.33ns.
+        let mut b = TokenBucket::new(3, 3, Duration::from_millis(1)).unwrap();
+        assert!(b.try_acquire(3, at(0)));
+        assert_eq!(b.available(at(0)), 0);
+        let one = b.time_until_available(1, at(0)).unwrap();
+        assert_eq!(one, Duration::from_nanos(333_334));
+        let t = at(0).add_nanos(333_333);
+        assert!(!b.try_acquire(1, t));
+        assert!(b.try_acquire(1, t.add_nanos(1)));
+        assert_eq!(b.available(at(1)), 2);
+        assert_eq!(b.available(at(100)), 3);
+    }
+
+    #[test]
+    fn test_token_bucket_limits() {
+        assert!(TokenBucket::new(0, 1, Duration::from_secs(1)).is_none());
+        assert!(TokenBucket::new(1, 1, Duration::ZERO).is_none());
+        assert!(TokenBucket::new(u64::MAX, 1, Duration::MAX).is_none());
+        let mut huge = TokenBucket::new(1, 1, Duration::MAX).unwrap();
+        assert!(!huge.try_acquire(u64::MAX, at(0)));
+        assert!(huge.try_acquire(1, at(0)));
+        let mut b = TokenBucket::new(5, 1, Duration::from_secs(1)).unwrap();
+        assert_eq!(b.capacity(), 5);
+        assert_eq!(b.time_until_available(6, at(0)), None);
+        assert_eq!(b.time_until_available(5, at(0)), Some(Duration::ZERO));
+        assert!(!b.try_acquire(6, at(0)));
+        assert!(b.try_acquire(2, at(0)));
+        // A clock step backwards neither refills nor drains.
+        assert_eq!(b.available(at(-5000)), 3);
+        assert!(b.try_acquire(3, at(-5000)));
+        assert_eq!(b.available(at(999)), 0);
+        assert_eq!(b.available(at(1000)), 1);
+    }
+}
@@ -0,0 +1,150 @@
+//! Replaying recorded timestamps as the current time.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{Clock, NanoTime};
+
+/// How a [`ReplayClock`] moves between recorded timestamps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplayMode {
+    /// Jump straight to each timestamp without waiting.
+    AsFastAsPossible,
+    /// Wait out the recorded gap between timestamps, divided by `speed`, so
+    /// `1.0` reproduces the original timing and `10.0` runs ten times
+    /// faster.
+    Paced { speed: f64 },
+}
+
+/// A [`Clock`] that reads as the timestamp of the recorded event being
+/// processed, for deterministic reprocessing and backtesting.
+///
+/// Feed it each recorded timestamp with [`advance_to`](Self::advance_to)
+/// before handling the event, and hand the clock to the code under test.
+/// Anything that reads the time through the clock sees the recorded times,
+/// so results do not depend on when the replay runs.
+///
+/// In [`ReplayMode::Paced`], events are released on a schedule anchored at
+/// the first call to `advance_to`, so handling time does not accumulate as
+/// drift. If handling falls behind, later events are released without
+/// waiting until the replay catches up.
+///
+/// ```rust
+/// use nanotime::{Clock, NanoTime, ReplayClock, ReplayMode};
+///
+/// let log = [
+///     NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap(),
+///     NanoTime::new(2026, 2, 22, 14, 30, 9, 0).unwrap(),
+/// ];
+/// let mut clock = ReplayClock::new(log[0]).with_mode(ReplayMode::AsFastAsPossible);
+/// for &at in &log {
+///     clock.advance_to(at);
+///     assert_eq!(clock.now(), at);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplayClock {
+    mode: ReplayMode,
+    now: NanoTime,
+    /// The recorded start and the instant replay began, set on the first
+    /// [`advance_to`](Self::advance_to).
+    anchor: Option<(NanoTime, Instant)>,
+}
+
+impl ReplayClock {
+    /// Creates a clock reading `start`, the first recorded timestamp,
+    /// replaying with [`ReplayMode::Paced`] at speed `1.0`.
+    pub fn new(start: NanoTime) -> Self {
+        ReplayClock {
+            mode: ReplayMode::Paced { speed: 1.0 },
+            now: start,
+            anchor: None,
+        }
+    }
+
+    /// Sets how the clock moves between timestamps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a paced `speed` is not positive and finite.
+    pub fn with_mode(mut self, mode: ReplayMode) -> Self {
+        if let ReplayMode::Paced { speed } = mode {
+            assert!(
+                speed.is_finite() && speed > 0.0,
+                "replay speed must be positive and finite"
+            );
+        }
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the replay mode.
+    pub fn mode(&self) -> ReplayMode {
+        self.mode
+    }
+
+    /// Moves the clock to the recorded timestamp `t`, first waiting for the
+    /// recorded gap in [`ReplayMode::Paced`].
+    ///
+    /// A timestamp earlier than the previous one, as found in logs merged
+    /// from several sources, is applied without waiting.
+    pub fn advance_to(&mut self, t: NanoTime) {
+        if let ReplayMode::Paced { speed } = self.mode {
+            let (start, real) = *self.anchor.get_or_insert((self.now, Instant::now()));
+            let offset = t.diff_nanos(&start).max(0) as f64 / speed;
+            let due = real + Duration::from_nanos(offset.min(u64::MAX as f64) as u64);
+            if let Some(wait) = due.checked_duration_since(Instant::now()) {
+                thread::sleep(wait);
+            }
+        }
+        self.now = t;
+    }
+}
+
+impl Clock for ReplayClock {
+    fn now(&self) -> NanoTime {
+        self.now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(ms: i128) -> NanoTime {
+        NanoTime::new(2026, 2, 22, 14, 30, 5, 0)
+            .unwrap()
+            .add_nanos(ms * 1_000_000)
+    }
+
+    #[test]
+    fn test_as_fast_as_possible() {
+        let mut clock = ReplayClock::new(at(0)).with_mode(ReplayMode::AsFastAsPossible);
+        assert_eq!(clock.now(), at(0));
+        let start = Instant::now();
+        for ms in [0, 3_600_000, 1_000, 7_200_000] {
+            clock.advance_to(at(ms));
+            assert_eq!(clock.now(), at(ms));
+        }
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_paced() {
+        let mut clock = ReplayClock::new(at(0));
+        assert_eq!(clock.mode(), ReplayMode::Paced { speed: 1.0 });
+        let start = Instant::now();
+        for ms in [0, 10, 30] {
+            clock.advance_to(at(ms));
+        }
+        assert!(start.elapsed() >= Duration::from_millis(30));
+
+        let mut fast = ReplayClock::new(at(0)).with_mode(ReplayMode::Paced { speed: 10.0 });
+        let start = Instant::now();
+        fast.advance_to(at(0));
+        fast.advance_to(at(200));
+        let took = start.elapsed();
+        assert!(took >= Duration::from_millis(20) && took < Duration::from_millis(200));
+        assert_eq!(fast.now(), at(200));
+    }
+}
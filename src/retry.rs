@@ -0,0 +1,173 @@
+//! Backoff policies and absolute retry schedules.
+
+use std::time::Duration;
+
+use crate::{Deadline, NanoTime};
+
+/// How long to wait before each retry.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::Backoff;
+///
+/// let backoff = Backoff::exponential(Duration::from_millis(100), 2)
+///     .with_max_delay(Duration::from_secs(1))
+///     .with_max_retries(5);
+/// assert_eq!(backoff.delay(0), Some(Duration::from_millis(100)));
+/// assert_eq!(backoff.delay(3), Some(Duration::from_millis(800)));
+/// assert_eq!(backoff.delay(4), Some(Duration::from_secs(1)));
+/// assert_eq!(backoff.delay(5), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Backoff {
+    initial: Duration,
+    multiplier: u32,
+    max_delay: Option<Duration>,
+    max_retries: Option<u32>,
+}
+
+impl Backoff {
+    /// Waits `delay` before every retry.
+    pub fn fixed(delay: Duration) -> Self {
+        Self::exponential(delay, 1)
+    }
+
+    /// Waits `initial` before the first retry and multiplies the wait by
+    /// `multiplier` for each retry after that.
+    pub fn exponential(initial: Duration, multiplier: u32) -> Self {
+        Backoff {
+            initial,
+            multiplier,
+            max_delay: None,
+            max_retries: None,
+        }
+    }
+
+    /// Caps each wait at `max_delay`.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = Some(max_delay);
+        self
+    }
+
+    /// Stops after `max_retries` retries. Without a limit, retries go on
+    /// forever.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Returns the wait before retry number `retry`, counting from zero, or
+    /// `None` if the retry limit has been reached.
+    pub fn delay(&self, retry: u32) -> Option<Duration> {
+        if self.max_retries.is_some_and(|max| retry >= max) {
+            return None;
+        }
+        let delay = self
+            .multiplier
+            .checked_pow(retry)
+            .and_then(|factor| self.initial.checked_mul(factor))
+            .unwrap_or(Duration::MAX);
+        Some(self.max_delay.map_or(delay, |max| delay.min(max)))
+    }
+}
+
+/// The absolute times at which to retry an operation, following a
+/// [`Backoff`] from a start time.
+///
+/// Each deadline is the previous one plus the next backoff delay, starting
+/// from `start`. Because the deadlines are absolute, the time of the next
+/// attempt can be logged or persisted and survives a restart. If an
+/// attempt runs past the next deadline, that deadline has already expired
+/// and [`Deadline::sleep_until`] returns at once.
+///
+/// The iterator ends when the backoff's retry limit is reached, or if a
+/// deadline would fall outside the range of [`NanoTime`].
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{Backoff, NanoTime, RetrySchedule};
+///
+/// let start = NanoTime::new(2026, 2, 22, 14, 30, 0, 0).unwrap();
+/// let backoff = Backoff::exponential(Duration::from_secs(1), 2).with_max_retries(3);
+/// let times: Vec<String> = RetrySchedule::new(backoff, start).map(|d| d.to_string()).collect();
+/// assert_eq!(times, ["2026-02-22T14:30:01Z", "2026-02-22T14:30:03Z", "2026-02-22T14:30:07Z"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetrySchedule {
+    backoff: Backoff,
+    last: NanoTime,
+    retry: u32,
+}
+
+impl RetrySchedule {
+    /// Creates a schedule for retries after a first attempt at `start`, a
+    /// UTC time.
+    pub fn new(backoff: Backoff, start: NanoTime) -> Self {
+        RetrySchedule {
+            backoff,
+            last: start,
+            retry: 0,
+        }
+    }
+
+    /// Returns the number of deadlines produced so far.
+    pub fn retries(&self) -> u32 {
+        self.retry
+    }
+}
+
+impl Iterator for RetrySchedule {
+    type Item = Deadline;
+
+    fn next(&mut self) -> Option<Deadline> {
+        let delay = self.backoff.delay(self.retry)?;
+        self.last = self.last.checked_add_nanos(delay.as_nanos() as i128)?;
+        self.retry = self.retry.saturating_add(1);
+        Some(Deadline::new(self.last))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_delays() {
+        let fixed = Backoff::fixed(Duration::from_millis(250));
+        assert_eq!(fixed.delay(0), fixed.delay(1000));
+        let huge = Backoff::exponential(Duration::from_secs(1), 10);
+        assert_eq!(huge.delay(30), Some(Duration::MAX));
+        let capped = huge.with_max_delay(Duration::from_secs(60));
+        assert_eq!(capped.delay(1), Some(Duration::from_secs(10)));
+        assert_eq!(capped.delay(30), Some(Duration::from_secs(60)));
+        assert_eq!(capped.with_max_retries(0).delay(0), None);
+    }
+
+    #[test]
+    fn test_schedule_is_cumulative() {
+        let start = NanoTime::new(2026, 2, 22, 14, 30, 0, 0).unwrap();
+        let backoff = Backoff::exponential(Duration::from_millis(500), 3)
+            .with_max_delay(Duration::from_secs(2));
+        let mut schedule = RetrySchedule::new(backoff, start);
+        let times: Vec<NanoTime> = schedule.by_ref().take(4).map(|d| d.time()).collect();
+        assert_eq!(
+            times,
+            [
+                NanoTime::new(2026, 2, 22, 14, 30, 0, 500_000_000).unwrap(),
+                NanoTime::new(2026, 2, 22, 14, 30, 2, 0).unwrap(),
+                NanoTime::new(2026, 2, 22, 14, 30, 4, 0).unwrap(),
+                NanoTime::new(2026, 2, 22, 14, 30, 6, 0).unwrap(),
+            ]
+        );
+        assert_eq!(schedule.retries(), 4);
+    }
+
+    #[test]
+    fn test_schedule_ends_at_time_range() {
+        let start = NanoTime::new(65535, 12, 31, 0, 0, 0, 0).unwrap();
+        let mut schedule =
+            RetrySchedule::new(Backoff::fixed(Duration::from_secs(3600 * 20)), start);
+        assert!(schedule.next().is_some());
+        assert!(schedule.next().is_none());
+    }
+}
@@ -0,0 +1,334 @@
+//! Rewriting timestamps inside lines of text, such as log files.
+//!
+//! A [`Rewriter`] scans each line for timestamps in the
+//! [`KnownFormat`]s and [`Format`]s it was given, converts every match to
+//! UTC, and writes the line back out with the match replaced. Text that is
+//! not a timestamp passes through byte for byte.
+
+use std::io::{self, BufRead, Write};
+
+use crate::parse::guess::match_prefix;
+use crate::parse::KnownFormat;
+use crate::tz::Resolution;
+use crate::{Format, NanoTime, OffsetNanoTime, Precision, TimeZone, UtcOffset};
+
+/// Finds and converts timestamps in lines of text.
+///
+/// By default every [`KnownFormat`] except bare dates and epoch numbers is
+/// matched, since those also match ordinary numbers and version strings.
+/// Matches are replaced with UTC RFC 3339. Brackets around Common Log
+/// Format times are kept, and a match whose offset would move it outside
+/// years 0-65535 is left as written.
+///
+/// ```rust
+/// use nanotime::rewrite::Rewriter;
+/// use nanotime::{TimeZone, UtcOffset};
+///
+/// let r = Rewriter::new().with_zone(TimeZone::fixed(UtcOffset::from_minutes(60).unwrap()));
+/// assert_eq!(
+///     r.rewrite_line("2026-02-22 14:30:05 GET /index.html 200"),
+///     "2026-02-22T13:30:05Z GET /index.html 200"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rewriter {
+    formats: Vec<KnownFormat>,
+    inputs: Vec<Format>,
+    zone: TimeZone,
+    precision: Precision,
+    output: Option<Format>,
+}
+
+impl Rewriter {
+    /// Matches the default formats, reads timestamps without an offset as
+    /// UTC, and writes RFC 3339 with trailing fractional zeros trimmed.
+    pub fn new() -> Self {
+        let formats = KnownFormat::ALL
+            .into_iter()
+            .filter(|f| {
+                !matches!(
+                    f,
+                    KnownFormat::Date
+                        | KnownFormat::EpochSeconds
+                        | KnownFormat::EpochMillis
+                        | KnownFormat::EpochMicros
+                        | KnownFormat::EpochNanos
+                )
+            })
+            .collect();
+        Rewriter {
+            formats,
+            inputs: Vec::new(),
+            zone: TimeZone::utc(),
+            precision: Precision::Auto,
+            output: None,
+        }
+    }
+
+    /// Sets the formats to look for. Where several match at the same
+    /// position, the longest match wins, then the earliest in `formats`.
+    pub fn with_formats(mut self, formats: &[KnownFormat]) -> Self {
+        self.formats = formats.to_vec();
+        self
+    }
+
+    /// Also looks for timestamps written with `format`, read as
+    /// [`Format::parse`] reads them. Times from `%s` are UTC; others are in
+    /// the zone set by [`with_zone`](Self::with_zone).
+    ///
+    /// Input formats are tried before the known formats and win ties at
+    /// the same length. Pass an empty slice to
+    /// [`with_formats`](Self::with_formats) to match only input formats.
+    ///
+    /// ```rust
+    /// use nanotime::rewrite::Rewriter;
+    /// use nanotime::Format;
+    ///
+    /// let r = Rewriter::new()
+    ///     .with_formats(&[])
+    ///     .with_input_format(Format::new("%d.%m.%Y %H:%M"));
+    /// assert_eq!(r.rewrite_line("at 22.02.2026 14:30 ok"), "at 2026-02-22T14:30:00Z ok");
+    /// ```
+    pub fn with_input_format(mut self, format: Format) -> Self {
+        self.inputs.push(format);
+        self
+    }
+
+    /// Sets the zone that timestamps without an offset were recorded in.
+    /// Ambiguous and skipped local times resolve as [`Resolution::Shift`]
+    /// does.
+    pub fn with_zone(mut self, zone: TimeZone) -> Self {
+        self.zone = zone;
+        self
+    }
+
+    /// Sets the fractional-second precision of RFC 3339 output.
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Writes matches with `format`, applied to the UTC time, instead of
+    /// RFC 3339.
+    pub fn with_output(mut self, format: Format) -> Self {
+        self.output = Some(format);
+        self
+    }
+
+    /// Finds the longest timestamp at the start of `text`, trying input
+    /// formats before known formats.
+    fn match_at(&self, text: &str) -> Option<(usize, NanoTime, Option<UtcOffset>)> {
+        let mut best: Option<(usize, NanoTime, Option<UtcOffset>)> = None;
+        for format in &self.inputs {
+            let Some((len, t, offset)) = format.parse_prefix(text) else {
+                continue;
+            };
+            let at_boundary = text[len..]
+                .chars()
+                .next()
+                .is_none_or(|c| !c.is_alphanumeric());
+            if at_boundary && len > 0 && best.is_none_or(|(best, _, _)| len > best) {
+                best = Some((len, t, offset));
+            }
+        }
+        match match_prefix(text, &self.formats) {
+            Some(known) if best.is_none_or(|(len, _, _)| known.0 > len) => Some(known),
+            _ => best,
+        }
+    }
+
+    /// Converts a match to UTC, or `None` if that falls outside years
+    /// 0-65535.
+    fn to_utc(&self, local: NanoTime, offset: Option<UtcOffset>) -> Option<NanoTime> {
+        let t = match offset {
+            Some(offset) => OffsetNanoTime::new(local, offset),
+            None => self
+                .zone
+                .resolve(&local, Resolution::Shift)
+                .expect("Shift always resolves"),
+        };
+        t.checked_to_utc()
+    }
+
+    fn write_utc(&self, out: &mut String, utc: &NanoTime) {
+        match &self.output {
+            Some(format) => format.write(out, utc),
+            None => out.push_str(&utc.to_rfc3339_opts(self.precision, None)),
+        }
+    }
+
+    /// Appends `line` to `out` with every timestamp replaced, returning the
+    /// number of replacements.
+    ///
+    /// A match must start and end at a word boundary, so digits inside
+    /// identifiers or longer numbers are left alone.
+    pub fn rewrite_into(&self, line: &str, out: &mut String) -> usize {
+        let mut count = 0;
+        let mut copied = 0;
+        let mut i = 0;
+        let mut prev: Option<char> = None;
+        while let Some(c) = line[i..].chars().next() {
+            let word_start = prev.is_none_or(|p| !p.is_alphanumeric());
+            let candidate = c.is_ascii_alphanumeric() || c == '[' || !self.inputs.is_empty();
+            if word_start && candidate {
+                if let Some((len, local, offset)) = self.match_at(&line[i..]) {
+                    if let Some(utc) = self.to_utc(local, offset) {
+                        out.push_str(&line[copied..i]);
+                        // Only Common Log Format matches include brackets.
+                        let bracketed = c == '[' && line[..i + len].ends_with(']');
+                        if bracketed {
+                            out.push('[');
+                        }
+                        self.write_utc(out, &utc);
+                        if bracketed {
+                            out.push(']');
+                        }
+                        count += 1;
+                        copied = i + len;
+                    }
+                    i += len;
+                    prev = line[..i].chars().next_back();
+                    continue;
+                }
+            }
+            prev = Some(c);
+            i += c.len_utf8();
+        }
+        out.push_str(&line[copied..]);
+        count
+    }
+
+    /// Returns `line` with every timestamp replaced.
+    pub fn rewrite_line(&self, line: &str) -> String {
+        let mut out = String::with_capacity(line.len());
+        self.rewrite_into(line, &mut out);
+        out
+    }
+
+    /// Copies `input` to `output` line by line, replacing timestamps, and
+    /// returns the number of replacements.
+    ///
+    /// Line endings are preserved. Lines that are not valid UTF-8 are
+    /// copied unchanged rather than failing the whole stream.
+    pub fn rewrite<R: BufRead, W: Write>(&self, mut input: R, mut output: W) -> io::Result<u64> {
+        let mut count = 0;
+        let mut raw = Vec::new();
+        let mut line = String::new();
+        loop {
+            raw.clear();
+            if input.read_until(b'\n', &mut raw)? == 0 {
+                break;
+            }
+            match std::str::from_utf8(&raw) {
+                Ok(text) => {
+                    line.clear();
+                    count += self.rewrite_into(text, &mut line) as u64;
+                    output.write_all(line.as_bytes())?;
+                }
+                Err(_) => output.write_all(&raw)?,
+            }
+        }
+        output.flush()?;
+        Ok(count)
+    }
+}
+
+impl Default for Rewriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_formats() {
+        let r = Rewriter::new();
+        assert_eq!(
+            r.rewrite_line("127.0.0.1 - - [22/Feb/2026:14:30:05 +0100] \"GET / HTTP/1.1\" 200"),
+            "127.0.0.1 - - [2026-02-22T13:30:05Z] \"GET / HTTP/1.1\" 200"
+        );
+        assert_eq!(
+            r.rewrite_line("start=2026-02-22T14:30:05.250-05:00 end=Sun Feb 22 20:00:00 2026"),
+            "start=2026-02-22T19:30:05.25Z end=2026-02-22T20:00:00Z"
+        );
+        // Dates and numbers are left alone unless asked for.
+        assert_eq!(
+            r.rewrite_line("v2026-02-22 id 1771770605"),
+            "v2026-02-22 id 1771770605"
+        );
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        let r = Rewriter::new().with_formats(&[KnownFormat::EpochSeconds]);
+        assert_eq!(
+            r.rewrite_line("t=1771770605 ok"),
+            "t=2026-02-22T14:30:05Z ok"
+        );
+        assert_eq!(
+            r.rewrite_line("req1771770605 1771770605000"),
+            "req1771770605 1771770605000"
+        );
+        assert_eq!(r.rewrite_line("é1771770605"), "é1771770605");
+    }
+
+    #[test]
+    fn test_zone_and_output() {
+        let r = Rewriter::new()
+            .with_zone(TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap())
+            .with_output(Format::new("%Y%m%d%H%M%S"));
+        assert_eq!(
+            r.rewrite_line("2026/07/04 12:00:00 and 2026-07-04T12:00:00Z"),
+            "20260704160000 and 20260704120000"
+        );
+    }
+
+    #[test]
+    fn test_input_formats() {
+        let r = Rewriter::new()
+            .with_zone(TimeZone::fixed(UtcOffset::from_minutes(60).unwrap()))
+            .with_input_format(Format::new("%d.%m.%Y %H:%M"))
+            .with_input_format(Format::new("@%s"));
+        assert_eq!(
+            r.rewrite_line("22.02.2026 14:30 @1771770605 2026-02-22 14:30:05"),
+            "2026-02-22T13:30:00Z 2026-02-22T14:30:05Z 2026-02-22T13:30:05Z"
+        );
+        // A known format that matches more of the line wins.
+        let r = Rewriter::new().with_input_format(Format::new("%Y-%m-%d %H:%M"));
+        assert_eq!(
+            r.rewrite_line("2026-02-22 14:30:05 and 2026-02-22 14:30"),
+            "2026-02-22T14:30:05Z and 2026-02-22T14:30:00Z"
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_left_alone() {
+        let r = Rewriter::new();
+        let line = "a [01/Jan/0000:00:30:00 +0100] b 0000-01-01T00:00:00+01:00 c";
+        let mut out = String::new();
+        assert_eq!(r.rewrite_into(line, &mut out), 0);
+        assert_eq!(out, line);
+        assert_eq!(
+            r.rewrite_line("[01/Jan/0000:01:30:00 +0100]"),
+            "[0000-01-01T00:30:00Z]"
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        let input: &[u8] = b"a 2026-02-22 14:30:05\r\nno time\n\xff 2026-02-22 14:30:05\nlast 2026-02-22T14:30:05.5";
+        let mut out = Vec::new();
+        let n = Rewriter::new()
+            .with_precision(Precision::Millis)
+            .rewrite(input, &mut out)
+            .unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(
+            out,
+            b"a 2026-02-22T14:30:05.000Z\r\nno time\n\xff 2026-02-22 14:30:05\nlast 2026-02-22T14:30:05.500Z"
+        );
+    }
+}
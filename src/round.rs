@@ -0,0 +1,157 @@
+//! Rounding times and durations to a multiple of a unit.
+
+use std::time::Duration;
+
+use crate::{NanoDuration, NanoTime};
+
+/// How to round a value that falls between two multiples of the unit.
+///
+/// The half modes pick the nearer multiple and differ only on exact ties.
+/// Ties and directions are judged on the signed value, so for times
+/// before 1970 and negative durations, [`HalfUp`](Self::HalfUp) and
+/// [`HalfAwayFromZero`](Self::HalfAwayFromZero) differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Nearest multiple, ties toward positive infinity (later).
+    HalfUp,
+    /// Nearest multiple, ties to the even multiple ("banker's rounding").
+    HalfEven,
+    /// Nearest multiple, ties away from zero.
+    HalfAwayFromZero,
+    /// The multiple at or below, toward negative infinity (earlier).
+    Floor,
+    /// The multiple at or above, toward positive infinity (later).
+    Ceil,
+}
+
+/// Rounds `value` to a multiple of `unit` under `mode`.
+pub(crate) fn round_nanos(value: i128, unit: Duration, mode: RoundMode) -> i128 {
+    let unit = unit.as_nanos() as i128;
+    assert!(unit > 0, "rounding unit must be nonzero");
+    let floor = value.div_euclid(unit) * unit;
+    let rem = value - floor;
+    let up = match mode {
+        _ if rem == 0 => false,
+        RoundMode::Floor => false,
+        RoundMode::Ceil => true,
+        _ if 2 * rem != unit => 2 * rem > unit,
+        RoundMode::HalfUp => true,
+        RoundMode::HalfEven => (floor / unit) % 2 != 0,
+        RoundMode::HalfAwayFromZero => value > 0,
+    };
+    if up {
+        floor + unit
+    } else {
+        floor
+    }
+}
+
+impl NanoTime {
+    /// Rounds to a multiple of `unit` counted from the Unix epoch, which
+    /// for units dividing a day is the same as counting from midnight.
+    /// Returns `None` if the result would pass the end of year 65535.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is zero.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use nanotime::{NanoTime, RoundMode};
+    ///
+    /// // Timesheets in tenths of an hour.
+    /// let six_minutes = Duration::from_secs(360);
+    /// let t = NanoTime::new(2026, 2, 22, 9, 3, 0, 0).unwrap();
+    /// assert_eq!(t.round_to(six_minutes, RoundMode::HalfUp), NanoTime::new(2026, 2, 22, 9, 6, 0, 0));
+    /// assert_eq!(t.round_to(six_minutes, RoundMode::HalfEven), NanoTime::new(2026, 2, 22, 9, 0, 0, 0));
+    /// assert_eq!(t.round_to(six_minutes, RoundMode::Floor), NanoTime::new(2026, 2, 22, 9, 0, 0, 0));
+    /// ```
+    pub fn round_to(&self, unit: Duration, mode: RoundMode) -> Option<NanoTime> {
+        let nanos = self.unix_nanos();
+        let rounded = round_nanos(nanos, unit, mode);
+        self.checked_add_nanos(rounded - nanos)
+    }
+}
+
+impl NanoDuration {
+    /// Rounds to a multiple of `unit`, such as billing increments.
+    /// Returns `None` beyond the `i64` seconds range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is zero.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use nanotime::{NanoDuration, RoundMode};
+    ///
+    /// let call = NanoDuration::from_nanos(61_000_000_000).unwrap();
+    /// let billed = call.round_to(Duration::from_secs(60), RoundMode::Ceil).unwrap();
+    /// assert_eq!(billed.secs_floor(), 120);
+    /// ```
+    pub fn round_to(&self, unit: Duration, mode: RoundMode) -> Option<NanoDuration> {
+        NanoDuration::from_nanos(round_nanos(self.as_nanos(), unit, mode))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [RoundMode; 5] = [
+        RoundMode::HalfUp,
+        RoundMode::HalfEven,
+        RoundMode::HalfAwayFromZero,
+        RoundMode::Floor,
+        RoundMode::Ceil,
+    ];
+
+    fn round(value: i128, mode: RoundMode) -> i128 {
+        round_nanos(value, Duration::from_nanos(10), mode)
+    }
+
+    #[test]
+    fn test_modes_on_ties_and_signs() {
+        // Results for each value in ALL order.
+        let cases = [
+            (15, [20, 20, 20, 10, 20]),
+            (25, [30, 20, 30, 20, 30]),
+            (-15, [-10, -20, -20, -20, -10]),
+            (-25, [-20, -20, -30, -30, -20]),
+            (14, [10, 10, 10, 10, 20]),
+            (-16, [-20, -20, -20, -20, -10]),
+            (30, [30, 30, 30, 30, 30]),
+        ];
+        for (value, expected) in cases {
+            for (mode, want) in ALL.into_iter().zip(expected) {
+                assert_eq!(round(value, mode), want, "{} {:?}", value, mode);
+            }
+        }
+    }
+
+    #[test]
+    fn test_time_before_epoch_and_at_limits() {
+        let t = NanoTime::new(1969, 12, 31, 23, 59, 30, 0).unwrap();
+        let minute = Duration::from_secs(60);
+        assert_eq!(
+            t.round_to(minute, RoundMode::HalfUp),
+            Some(NanoTime::from_epoch(0))
+        );
+        assert_eq!(
+            t.round_to(minute, RoundMode::HalfAwayFromZero),
+            NanoTime::new(1969, 12, 31, 23, 59, 0, 0)
+        );
+        let last = NanoTime::new(65535, 12, 31, 23, 59, 59, 1).unwrap();
+        assert_eq!(last.round_to(Duration::from_secs(1), RoundMode::Ceil), None);
+        assert_eq!(
+            last.round_to(Duration::from_secs(1), RoundMode::Floor),
+            NanoTime::new(65535, 12, 31, 23, 59, 59, 0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "rounding unit must be nonzero")]
+    fn test_zero_unit_panics() {
+        NanoDuration::ZERO.round_to(Duration::ZERO, RoundMode::Floor);
+    }
+}
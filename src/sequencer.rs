@@ -0,0 +1,129 @@
+//! Unique, strictly increasing timestamps.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::atomic::{from_nanos, to_nanos};
+use crate::{system_nanos, NanoTime};
+
+/// Hands out strictly increasing UTC timestamps, for ordering events in
+/// logs and databases.
+///
+/// Each call returns the current time unless that would not be later than
+/// the previous result, in which case it returns the previous result plus
+/// one nanosecond. A coarse clock that returns the same reading twice, or a
+/// clock stepped backwards by NTP, therefore still yields distinct,
+/// ordered values; after a backwards step the sequence runs slightly ahead
+/// of the clock until the clock catches up.
+///
+/// A `Sequencer` is lock-free and can be shared between threads, so
+/// timestamps are unique across all of them.
+///
+/// ```rust
+/// use nanotime::Sequencer;
+///
+/// let seq = Sequencer::new();
+/// let a = seq.next();
+/// let b = seq.next();
+/// assert!(b > a);
+/// ```
+#[derive(Debug, Default)]
+pub struct Sequencer {
+    last: AtomicU64,
+}
+
+impl Sequencer {
+    /// Creates a sequencer with no previous timestamp.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a sequencer whose results will all be later than `last`,
+    /// such as the newest timestamp already stored when resuming after a
+    /// restart.
+    pub fn starting_after(last: NanoTime) -> Self {
+        Sequencer {
+            last: AtomicU64::new(to_nanos(&last)),
+        }
+    }
+
+    /// Returns the current UTC time, bumped forward if needed to be later
+    /// than every previous result.
+    pub fn next(&self) -> NanoTime {
+        from_nanos(self.advance(system_nanos()))
+    }
+
+    /// Like [`next`](Self::next), with `now` as the current time.
+    pub fn next_at(&self, now: NanoTime) -> NanoTime {
+        from_nanos(self.advance(to_nanos(&now)))
+    }
+
+    /// Returns the most recent result, or `None` if nothing has been
+    /// issued yet.
+    pub fn last(&self) -> Option<NanoTime> {
+        match self.last.load(Ordering::Acquire) {
+            0 => None,
+            nanos => Some(from_nanos(nanos)),
+        }
+    }
+
+    fn advance(&self, now: u64) -> u64 {
+        let mut prev = self.last.load(Ordering::Acquire);
+        loop {
+            let next = now.max(prev.saturating_add(1));
+            match self
+                .last
+                .compare_exchange_weak(prev, next, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => return next,
+                Err(actual) => prev = actual,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_repeated_and_backwards_clock() {
+        let seq = Sequencer::new();
+        assert_eq!(seq.last(), None);
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+        assert_eq!(seq.next_at(t), t);
+        assert_eq!(seq.next_at(t).nanosecond(), 1);
+        let earlier = NanoTime::new(2026, 2, 22, 14, 29, 0, 0).unwrap();
+        assert_eq!(seq.next_at(earlier).nanosecond(), 2);
+        let later = NanoTime::new(2026, 2, 22, 14, 31, 0, 0).unwrap();
+        assert_eq!(seq.next_at(later), later);
+        assert_eq!(seq.last(), Some(later));
+    }
+
+    #[test]
+    fn test_starting_after() {
+        let t = NanoTime::new(2100, 1, 1, 0, 0, 0, 999_999_999).unwrap();
+        let seq = Sequencer::starting_after(t);
+        assert_eq!(seq.next(), NanoTime::new(2100, 1, 1, 0, 0, 1, 0).unwrap());
+    }
+
+    #[test]
+    fn test_unique_across_threads() {
+        let seq = Arc::new(Sequencer::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let seq = Arc::clone(&seq);
+                thread::spawn(move || (0..1000).map(|_| seq.next()).collect::<Vec<_>>())
+            })
+            .collect();
+        let mut seen = HashSet::new();
+        for h in handles {
+            let times = h.join().unwrap();
+            assert!(times.windows(2).all(|w| w[0] < w[1]));
+            seen.extend(times);
+        }
+        assert_eq!(seen.len(), 4000);
+    }
+}
@@ -0,0 +1,300 @@
+//! Serde adapters for timestamp fields. Requires the `serde` feature.
+//!
+//! Use them with `#[serde(with = "...")]`:
+//!
+//! ```rust
+//! use nanotime::NanoTime;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Event {
+//!     #[serde(with = "nanotime::serde::lenient")]
+//!     at: NanoTime,
+//! }
+//!
+//! let a: Event = serde_json::from_str(r#"{"at": 1771770605}"#).unwrap();
+//! let b: Event = serde_json::from_str(r#"{"at": "2026-02-22T14:30:05Z"}"#).unwrap();
+//! assert_eq!(a.at, b.at);
+//! ```
+
+/// Accepts an epoch number or a timestamp string for the same field, and
+/// writes RFC 3339.
+///
+/// Real-world feeds often switch a field between representations across
+/// versions. Deserialization accepts:
+///
+/// - Non-negative integers as Unix time, with the unit inferred from the
+///   digit count the same way as [`NanoTime::parse_guess`]: up to 11 digits
+///   are seconds, 12–14 milliseconds, 15–17 microseconds, and 18–20
+///   nanoseconds.
+/// - Non-negative floats as Unix seconds, whatever their size, so
+///   `1771770605.0` and `1771770605.5` are both seconds.
+/// - Strings in any format [`NanoTime::parse_guess`] recognizes, including
+///   RFC 3339 and ISO 8601. Zoned strings are converted to UTC.
+///
+/// Serialization always writes [`NanoTime::to_rfc3339`].
+///
+/// [`NanoTime::parse_guess`]: crate::NanoTime::parse_guess
+/// [`NanoTime::to_rfc3339`]: crate::NanoTime::to_rfc3339
+pub mod lenient {
+    use std::fmt;
+
+    use ::serde::de::{self, Visitor};
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::NanoTime;
+
+    /// Serializes as an RFC 3339 string in UTC.
+    pub fn serialize<S: Serializer>(t: &NanoTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&t.to_rfc3339())
+    }
+
+    /// Deserializes from an integer epoch, a float epoch in seconds, or a string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NanoTime, D::Error> {
+        deserializer.deserialize_any(LenientVisitor)
+    }
+
+    struct LenientVisitor;
+
+    impl LenientVisitor {
+        fn guess<E: de::Error>(s: &str) -> Result<NanoTime, E> {
+            NanoTime::parse_guess(s).map(|(t, _)| t).map_err(E::custom)
+        }
+    }
+
+    impl<'de> Visitor<'de> for LenientVisitor {
+        type Value = NanoTime;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a Unix epoch number or a timestamp string")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<NanoTime, E> {
+            Self::guess(&v.to_string())
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<NanoTime, E> {
+            Self::guess(&v.to_string())
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<NanoTime, E> {
+            match u64::try_from(v) {
+                Ok(v) => self.visit_u64(v),
+                Err(_) => Err(E::invalid_value(de::Unexpected::Signed(v), &self)),
+            }
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<NanoTime, E> {
+            if !v.is_finite() || v < 0.0 {
+                return Err(E::invalid_value(de::Unexpected::Float(v), &self));
+            }
+            // Display never uses exponent notation and writes the shortest
+            // digits that read back as `v`, so `0.1` gives 100ms, not the
+            // binary value's 99.99...ms.
+            let s = v.to_string();
+            let (secs, frac) = s.split_once('.').unwrap_or((&s, ""));
+            let nanos = (frac.bytes().chain(std::iter::repeat(b'0')))
+                .take(9)
+                .fold(0, |n, d| n * 10 + (d - b'0') as u32);
+            secs.parse()
+                .ok()
+                .and_then(|secs| NanoTime::checked_from_unix(secs, nanos))
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Float(v), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<NanoTime, E> {
+            Self::guess(v)
+        }
+    }
+}
+
+/// Writes and reads Avro timestamp `long`s, for records serialized with
+/// `apache-avro` against a schema using a timestamp logical type.
+///
+/// `apache-avro` serializes through serde's data model, so a field written
+/// as an `i64` matches a `long` with a `timestamp-*` or `local-timestamp-*`
+/// logical type. Pick the module matching the schema's unit; values are
+/// truncated toward the past. See [`NanoTime::to_avro_timestamp`].
+///
+/// ```rust
+/// use nanotime::NanoTime;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "nanotime::serde::avro::timestamp_micros")]
+///     at: NanoTime,
+/// }
+///
+/// let e: Event = serde_json::from_str(r#"{"at": 1771770605123456}"#).unwrap();
+/// assert_eq!(e.at.microsecond(), 123_456);
+/// ```
+///
+/// [`NanoTime::to_avro_timestamp`]: crate::NanoTime::to_avro_timestamp
+pub mod avro {
+    use ::serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+    use crate::{AvroTimestamp, NanoTime};
+
+    fn serialize<S: Serializer>(
+        t: &NanoTime,
+        ty: AvroTimestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match t.to_avro_timestamp(ty) {
+            Some(v) => serializer.serialize_i64(v),
+            None => Err(ser::Error::custom(format_args!(
+                "{} out of range for {}",
+                t.to_rfc3339(),
+                ty.logical_type()
+            ))),
+        }
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(
+        ty: AvroTimestamp,
+        deserializer: D,
+    ) -> Result<NanoTime, D::Error> {
+        let v = i64::deserialize(deserializer)?;
+        NanoTime::from_avro_timestamp(v, ty).ok_or_else(|| {
+            de::Error::custom(format_args!(
+                "{} {} is outside years 0-65535",
+                ty.logical_type(),
+                v
+            ))
+        })
+    }
+
+    macro_rules! unit {
+        ($name:ident, $ty:ident, $doc:literal) => {
+            #[doc = $doc]
+            pub mod $name {
+                use ::serde::{Deserializer, Serializer};
+
+                use crate::{AvroTimestamp, NanoTime};
+
+                /// Serializes as an `i64`.
+                pub fn serialize<S: Serializer>(
+                    t: &NanoTime,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::serialize(t, AvroTimestamp::$ty, serializer)
+                }
+
+                /// Deserializes from an `i64`.
+                pub fn deserialize<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<NanoTime, D::Error> {
+                    super::deserialize(AvroTimestamp::$ty, deserializer)
+                }
+            }
+        };
+    }
+
+    unit!(
+        timestamp_millis,
+        Millis,
+        "Epoch milliseconds, for `timestamp-millis` and `local-timestamp-millis`."
+    );
+    unit!(
+        timestamp_micros,
+        Micros,
+        "Epoch microseconds, for `timestamp-micros` and `local-timestamp-micros`."
+    );
+    unit!(
+        timestamp_nanos,
+        Nanos,
+        "Epoch nanoseconds, for `timestamp-nanos` and `local-timestamp-nanos`. Fails to serialize outside 1677 to 2262."
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NanoTime;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Row {
+        #[serde(with = "crate::serde::lenient")]
+        at: NanoTime,
+    }
+
+    fn at(json: &str) -> Result<NanoTime, serde_json::Error> {
+        serde_json::from_str::<Row>(&format!(r#"{{"at": {}}}"#, json)).map(|r| r.at)
+    }
+
+    #[test]
+    fn test_lenient_integer_units() {
+        let expected = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+        assert_eq!(at("1771770605").unwrap(), expected);
+        assert_eq!(at("1771770605000").unwrap(), expected);
+        assert_eq!(at("1771770605000000").unwrap(), expected);
+        assert_eq!(at("1771770605000000000").unwrap(), expected);
+        assert_eq!(at("0").unwrap(), NanoTime::from_epoch(0));
+        assert!(at("-1").is_err());
+    }
+
+    #[test]
+    fn test_lenient_float_seconds() {
+        let t = at("1771770605.25").unwrap();
+        assert_eq!(t.second(), 5);
+        assert_eq!(t.millisecond(), 250);
+        assert!(at("-1.5").is_err());
+        assert!(at("1e300").is_err());
+        // Floats are seconds at any size, integral or not.
+        let expected = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+        assert_eq!(at("1771770605.0").unwrap(), expected);
+        let far = at("1771770605123.0").unwrap();
+        assert_eq!(far.unix_secs(), 1_771_770_605_123);
+        assert_eq!(at("1771770605123.5").unwrap(), far.add_nanos(500_000_000));
+        assert!(at("3e12").is_err());
+    }
+
+    #[test]
+    fn test_lenient_strings() {
+        let expected = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_000_000).unwrap();
+        assert_eq!(at(r#""2026-02-22T14:30:05.123Z""#).unwrap(), expected);
+        assert_eq!(at(r#""2026-02-22T20:00:05.123+05:30""#).unwrap(), expected);
+        assert_eq!(at(r#""2026-02-22 14:30:05.123""#).unwrap(), expected);
+        assert_eq!(at(r#""1771770605123""#).unwrap(), expected);
+
+        let err = at(r#""not a time""#).unwrap_err().to_string();
+        assert!(err.contains("at offset 0"), "{}", err);
+        assert!(at(r#""0000-01-01T00:00:00+01:00""#).is_err());
+        assert!(at("true").is_err());
+        assert!(at("null").is_err());
+    }
+
+    #[test]
+    fn test_lenient_serializes_rfc3339() {
+        let row = Row {
+            at: NanoTime::new(2026, 2, 22, 14, 30, 5, 120_000_000).unwrap(),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"at":"2026-02-22T14:30:05.12Z"}"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap().at, row.at);
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct AvroRow {
+        #[serde(with = "crate::serde::avro::timestamp_nanos")]
+        at: NanoTime,
+    }
+
+    #[test]
+    fn test_avro_timestamp_nanos() {
+        let row = AvroRow {
+            at: NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap(),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"at":1771770605123456789}"#);
+        assert_eq!(serde_json::from_str::<AvroRow>(&json).unwrap().at, row.at);
+
+        let far = AvroRow {
+            at: NanoTime::new(2300, 1, 1, 0, 0, 0, 0).unwrap(),
+        };
+        let err = serde_json::to_string(&far).unwrap_err().to_string();
+        assert!(err.contains("timestamp-nanos"), "{}", err);
+        assert!(serde_json::from_str::<AvroRow>(r#"{"at":"x"}"#).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+//! Virtual time that runs at an adjustable rate.
+
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::{Clock, NanoTime};
+
+#[derive(Debug)]
+struct SimState {
+    /// The virtual time at `real`.
+    virtual_at: NanoTime,
+    real: Instant,
+    scale: f64,
+    paused: bool,
+}
+
+impl SimState {
+    fn now(&self, real: Instant) -> NanoTime {
+        if self.paused {
+            return self.virtual_at;
+        }
+        let elapsed = real.saturating_duration_since(self.real).as_nanos() as f64;
+        self.virtual_at.add_nanos((elapsed * self.scale) as i128)
+    }
+
+    /// Moves the anchor to the current instant so later changes take effect
+    /// from here.
+    fn rebase(&mut self) {
+        let real = Instant::now();
+        self.virtual_at = self.now(real);
+        self.real = real;
+    }
+}
+
+/// A [`Clock`] running on virtual time, which advances at a multiple of
+/// real time and can be paused or jumped.
+///
+/// Games and discrete-event simulations can run any logic written against
+/// `Clock` in slow motion, fast-forward, or frozen time. Virtual time is
+/// derived from the monotonic clock, so it is never disturbed by changes to
+/// the system clock. All methods take `&self`, so one clock can be shared
+/// between threads.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{Clock, NanoTime, SimClock};
+///
+/// let start = NanoTime::new(2026, 1, 1, 0, 0, 0, 0).unwrap();
+/// let clock = SimClock::paused(start);
+/// clock.advance(Duration::from_secs(90));
+/// assert_eq!(clock.now(), NanoTime::new(2026, 1, 1, 0, 1, 30, 0).unwrap());
+///
+/// clock.set_scale(10.0); // fast-forward once resumed
+/// clock.resume();
+/// ```
+#[derive(Debug)]
+pub struct SimClock {
+    state: Mutex<SimState>,
+}
+
+impl SimClock {
+    /// Creates a clock reading `start` now and running at real speed.
+    pub fn new(start: NanoTime) -> Self {
+        SimClock {
+            state: Mutex::new(SimState {
+                virtual_at: start,
+                real: Instant::now(),
+                scale: 1.0,
+                paused: false,
+            }),
+        }
+    }
+
+    /// Creates a clock frozen at `start`, for simulations that only move
+    /// time with [`advance`](Self::advance) and [`set`](Self::set).
+    pub fn paused(start: NanoTime) -> Self {
+        let clock = Self::new(start);
+        clock.state().paused = true;
+        clock
+    }
+
+    fn state(&self) -> MutexGuard<'_, SimState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns how many virtual seconds pass per real second while running.
+    pub fn scale(&self) -> f64 {
+        self.state().scale
+    }
+
+    /// Sets how many virtual seconds pass per real second, such as `0.5`
+    /// for half speed or `10.0` for ten times faster. Time already passed
+    /// is unaffected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is negative, infinite, or NaN.
+    pub fn set_scale(&self, scale: f64) {
+        assert!(
+            scale.is_finite() && scale >= 0.0,
+            "time scale must be finite and non-negative"
+        );
+        let mut state = self.state();
+        state.rebase();
+        state.scale = scale;
+    }
+
+    /// Stops virtual time until [`resume`](Self::resume). The scale is kept.
+    pub fn pause(&self) {
+        let mut state = self.state();
+        state.rebase();
+        state.paused = true;
+    }
+
+    /// Restarts virtual time at the current scale.
+    pub fn resume(&self) {
+        let mut state = self.state();
+        state.rebase();
+        state.paused = false;
+    }
+
+    /// Returns `true` if virtual time is paused.
+    pub fn is_paused(&self) -> bool {
+        self.state().paused
+    }
+
+    /// Jumps virtual time forward by `by`.
+    pub fn advance(&self, by: Duration) {
+        let mut state = self.state();
+        state.rebase();
+        state.virtual_at = state.virtual_at.add_nanos(by.as_nanos() as i128);
+    }
+
+    /// Jumps virtual time to `to`, which may be in the past.
+    pub fn set(&self, to: NanoTime) {
+        let mut state = self.state();
+        state.rebase();
+        state.virtual_at = to;
+    }
+}
+
+impl Clock for SimClock {
+    fn now(&self) -> NanoTime {
+        self.state().now(Instant::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start() -> NanoTime {
+        NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap()
+    }
+
+    #[test]
+    fn test_pause_and_jump() {
+        let clock = SimClock::paused(start());
+        let frozen = clock.now();
+        assert_eq!(frozen, start());
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(clock.now(), frozen);
+        assert!(clock.is_paused());
+
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(clock.now().diff_nanos(&frozen), 60_000_000_000);
+        clock.set(start());
+        assert_eq!(clock.now(), start());
+    }
+
+    #[test]
+    fn test_scale() {
+        let clock = SimClock::new(start());
+        clock.set_scale(1000.0);
+        assert_eq!(clock.scale(), 1000.0);
+        std::thread::sleep(Duration::from_millis(10));
+        // At least 10s of virtual time in 10ms of real time.
+        assert!(clock.now().diff_nanos(&start()) >= 10_000_000_000);
+
+        clock.pause();
+        clock.set(start());
+        clock.set_scale(0.5);
+        let real = Instant::now();
+        clock.resume();
+        std::thread::sleep(Duration::from_millis(20));
+        let passed = clock.now().diff_nanos(&start());
+        let half = real.elapsed().as_nanos() as i128 / 2;
+        assert!(
+            passed >= 10_000_000 && passed <= half,
+            "{} of {}",
+            passed,
+            half
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "time scale")]
+    fn test_negative_scale_panics() {
+        SimClock::new(start()).set_scale(-1.0);
+    }
+}
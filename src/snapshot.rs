@@ -0,0 +1,238 @@
+//! Plain-data copies of timing measurements, for telemetry payloads.
+//!
+//! Each snapshot has public fields holding durations as whole nanoseconds,
+//! so it can be embedded in a heartbeat or metrics message as is. With the
+//! `serde` feature, every snapshot implements `Serialize` and
+//! `Deserialize`.
+
+use std::time::Duration;
+
+use crate::{CheckpointReport, LatencyHistogram, TimingStats};
+
+fn nanos(d: Option<Duration>) -> u64 {
+    d.map_or(0, |d| d.as_nanos().min(u64::MAX as u128) as u64)
+}
+
+/// A copy of a [`TimingStats`]. Durations are nanoseconds, and zero when
+/// there were no measurements.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TimingSnapshot {
+    pub count: u64,
+    pub total_nanos: u64,
+    pub min_nanos: u64,
+    pub max_nanos: u64,
+    pub mean_nanos: u64,
+    pub std_dev_nanos: u64,
+}
+
+/// A copy of the commonly reported percentiles of a [`LatencyHistogram`].
+/// Durations are nanoseconds, and zero when there were no measurements.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HistogramSnapshot {
+    pub count: u64,
+    pub min_nanos: u64,
+    pub max_nanos: u64,
+    pub p50_nanos: u64,
+    pub p90_nanos: u64,
+    pub p99_nanos: u64,
+    pub p999_nanos: u64,
+}
+
+/// A copy of one checkpoint from a [`CheckpointReport`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CheckpointSnapshot {
+    pub label: String,
+    pub segment_nanos: u64,
+    pub cumulative_nanos: u64,
+}
+
+impl TimingStats {
+    /// Copies the statistics into a [`TimingSnapshot`].
+    pub fn snapshot(&self) -> TimingSnapshot {
+        TimingSnapshot {
+            count: self.count(),
+            total_nanos: nanos(Some(self.total())),
+            min_nanos: nanos(self.min()),
+            max_nanos: nanos(self.max()),
+            mean_nanos: nanos(self.mean()),
+            std_dev_nanos: nanos(self.std_dev()),
+        }
+    }
+}
+
+impl LatencyHistogram {
+    /// Copies the count, extremes, and p50/p90/p99/p99.9 into a
+    /// [`HistogramSnapshot`].
+    pub fn snapshot(&self) -> HistogramSnapshot {
+        HistogramSnapshot {
+            count: self.count(),
+            min_nanos: nanos(self.min()),
+            max_nanos: nanos(self.max()),
+            p50_nanos: nanos(self.percentile(50.0)),
+            p90_nanos: nanos(self.percentile(90.0)),
+            p99_nanos: nanos(self.percentile(99.0)),
+            p999_nanos: nanos(self.percentile(99.9)),
+        }
+    }
+}
+
+impl CheckpointReport {
+    /// Copies each checkpoint into a [`CheckpointSnapshot`].
+    pub fn snapshot(&self) -> Vec<CheckpointSnapshot> {
+        self.checkpoints()
+            .iter()
+            .map(|c| CheckpointSnapshot {
+                label: c.label().to_string(),
+                segment_nanos: nanos(Some(c.segment())),
+                cumulative_nanos: nanos(Some(c.cumulative())),
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod impls {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use ::serde::de::{self, MapAccess, Visitor};
+    use ::serde::ser::SerializeStruct;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{CheckpointSnapshot, HistogramSnapshot, TimingSnapshot};
+
+    /// Implements `Serialize` and `Deserialize` for a snapshot struct as a
+    /// map of its fields. Unknown keys are ignored and missing ones default.
+    macro_rules! plain_struct {
+        ($ty:ident { $($field:ident: $fty:ty),* $(,)? }) => {
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let len = [$(stringify!($field)),*].len();
+                    let mut s = serializer.serialize_struct(stringify!($ty), len)?;
+                    $(s.serialize_field(stringify!($field), &self.$field)?;)*
+                    s.end()
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    struct FieldsVisitor(PhantomData<$ty>);
+
+                    impl<'de> Visitor<'de> for FieldsVisitor {
+                        type Value = $ty;
+
+                        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            f.write_str(concat!("a ", stringify!($ty), " map"))
+                        }
+
+                        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<$ty, A::Error> {
+                            let mut out = $ty::default();
+                            while let Some(key) = map.next_key::<String>()? {
+                                match key.as_str() {
+                                    $(stringify!($field) => out.$field = map.next_value::<$fty>()?,)*
+                                    _ => {
+                                        map.next_value::<de::IgnoredAny>()?;
+                                    }
+                                }
+                            }
+                            Ok(out)
+                        }
+                    }
+
+                    const FIELDS: &[&str] = &[$(stringify!($field)),*];
+                    deserializer.deserialize_struct(stringify!($ty), FIELDS, FieldsVisitor(PhantomData))
+                }
+            }
+        };
+    }
+
+    plain_struct!(TimingSnapshot {
+        count: u64,
+        total_nanos: u64,
+        min_nanos: u64,
+        max_nanos: u64,
+        mean_nanos: u64,
+        std_dev_nanos: u64,
+    });
+
+    plain_struct!(HistogramSnapshot {
+        count: u64,
+        min_nanos: u64,
+        max_nanos: u64,
+        p50_nanos: u64,
+        p90_nanos: u64,
+        p99_nanos: u64,
+        p999_nanos: u64,
+    });
+
+    plain_struct!(CheckpointSnapshot {
+        label: String,
+        segment_nanos: u64,
+        cumulative_nanos: u64,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Elapsed;
+
+    #[test]
+    fn test_snapshots_copy_values() {
+        let mut stats = TimingStats::new();
+        let mut hist = LatencyHistogram::new();
+        assert_eq!(stats.snapshot(), TimingSnapshot::default());
+        assert_eq!(hist.snapshot(), HistogramSnapshot::default());
+        for us in 1..=100 {
+            stats.record(Duration::from_micros(us));
+            hist.record(Duration::from_micros(us));
+        }
+        let s = stats.snapshot();
+        assert_eq!((s.count, s.min_nanos, s.max_nanos), (100, 1_000, 100_000));
+        assert_eq!(s.mean_nanos, 50_500);
+        let h = hist.snapshot();
+        assert_eq!((h.count, h.p999_nanos), (100, 100_000));
+        assert!(h.p50_nanos >= 50_000 && h.p50_nanos < h.p90_nanos);
+
+        let mut timer = Elapsed::start();
+        timer.checkpoint("load");
+        let c = timer.report().snapshot();
+        assert_eq!(c[0].label, "load");
+        assert_eq!(c[0].segment_nanos, c[0].cumulative_nanos);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let s = TimingSnapshot {
+            count: 3,
+            total_nanos: 60,
+            min_nanos: 10,
+            max_nanos: 30,
+            mean_nanos: 20,
+            std_dev_nanos: 8,
+        };
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(
+            json,
+            r#"{"count":3,"total_nanos":60,"min_nanos":10,"max_nanos":30,"mean_nanos":20,"std_dev_nanos":8}"#
+        );
+        assert_eq!(serde_json::from_str::<TimingSnapshot>(&json).unwrap(), s);
+
+        let c: CheckpointSnapshot =
+            serde_json::from_str(r#"{"label":"parse","segment_nanos":5,"extra":[1]}"#).unwrap();
+        assert_eq!(
+            (c.label.as_str(), c.segment_nanos, c.cumulative_nanos),
+            ("parse", 5, 0)
+        );
+
+        let h = HistogramSnapshot {
+            count: 1,
+            p99_nanos: 7,
+            ..Default::default()
+        };
+        let back: HistogramSnapshot =
+            serde_json::from_value(serde_json::to_value(h).unwrap()).unwrap();
+        assert_eq!(back, h);
+    }
+}
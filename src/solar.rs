@@ -0,0 +1,236 @@
+//! Sunrise, sunset, solar noon, and twilight. Requires the `solar`
+//! feature.
+//!
+//! Times follow NOAA's solar calculator, which is good to about a minute
+//! between latitudes 72°N and 72°S. Latitudes are degrees north and
+//! longitudes degrees east, so New York is `(40.71, -74.01)`. Every
+//! result is in UTC and computed for the UTC calendar date of the `date`
+//! argument; far from Greenwich, an event can land on the neighbouring
+//! UTC date.
+//!
+//! ```rust
+//! use nanotime::NanoTime;
+//! use nanotime::solar::{self, Twilight};
+//!
+//! let (lat, lon) = (51.5074, -0.1278); // London
+//! let day = NanoTime::new(2026, 6, 21, 0, 0, 0, 0).unwrap();
+//! let rise = solar::sunrise(&day, lat, lon).unwrap();
+//! assert_eq!((rise.hour(), rise.minute()), (3, 43));
+//! let dusk = solar::dusk(&day, lat, lon, Twilight::Civil).unwrap();
+//! assert_eq!(dusk.hour(), 21);
+//! ```
+
+use crate::{days_from_civil, NanoTime};
+
+/// The Julian Date of 1970-01-01T00:00Z.
+const JULIAN_DATE_OF_EPOCH: f64 = 2_440_587.5;
+/// Zenith angle of the sun's centre at sunrise and sunset, allowing for
+/// atmospheric refraction and the sun's radius.
+const SUNRISE_ZENITH: f64 = 90.833;
+
+/// How far below the horizon the sun is at dawn and dusk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Twilight {
+    /// 6° below the horizon: bright enough to work outdoors without
+    /// lights.
+    Civil,
+    /// 12° below the horizon: the horizon is still visible at sea.
+    Nautical,
+    /// 18° below the horizon: the sky is fully dark.
+    Astronomical,
+}
+
+impl Twilight {
+    /// Returns the sun's depression below the horizon in degrees.
+    pub fn depression(&self) -> f64 {
+        match self {
+            Twilight::Civil => 6.0,
+            Twilight::Nautical => 12.0,
+            Twilight::Astronomical => 18.0,
+        }
+    }
+}
+
+/// The sun's declination in radians and the equation of time in minutes
+/// at Julian Date `jd`.
+fn sun_position(jd: f64) -> (f64, f64) {
+    let t = (jd - 2_451_545.0) / 36_525.0;
+    let mean_long = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let mean_anom = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let ecc = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+    let m = mean_anom.to_radians();
+    let center = m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * m).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * m).sin() * 0.000289;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_long = (mean_long + center - 0.00569 - 0.00478 * omega.sin()).to_radians();
+    let mean_obliq =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliq = (mean_obliq + 0.00256 * omega.cos()).to_radians();
+    let decl = (obliq.sin() * apparent_long.sin()).asin();
+
+    let y = (obliq / 2.0).tan().powi(2);
+    let l0 = mean_long.to_radians();
+    let eq_time = y * (2.0 * l0).sin() - 2.0 * ecc * m.sin()
+        + 4.0 * ecc * y * m.sin() * (2.0 * l0).cos()
+        - 0.5 * y * y * (4.0 * l0).sin()
+        - 1.25 * ecc * ecc * (2.0 * m).sin();
+    (decl, 4.0 * eq_time.to_degrees())
+}
+
+/// Returns midnight UTC on `date` and its Julian Date.
+fn midnight(date: &NanoTime) -> (NanoTime, f64) {
+    let days = days_from_civil(date.year() as i64, date.month(), date.day());
+    let midnight = NanoTime::from_unix(days * 86_400, 0);
+    (midnight, days as f64 + JULIAN_DATE_OF_EPOCH)
+}
+
+/// Minutes after midnight UTC of solar noon at `longitude`.
+fn noon_minutes(jd0: f64, longitude: f64) -> f64 {
+    let mut minutes = 720.0 - 4.0 * longitude;
+    for _ in 0..2 {
+        let (_, eq_time) = sun_position(jd0 + minutes / 1_440.0);
+        minutes = 720.0 - 4.0 * longitude - eq_time;
+    }
+    minutes
+}
+
+fn at_minutes(midnight: &NanoTime, minutes: f64) -> Option<NanoTime> {
+    midnight.checked_add_nanos((minutes * 60e9).round() as i128)
+}
+
+/// Finds when the sun's centre crosses `zenith` degrees, in the morning
+/// or the evening. Returns `None` if it never does that day.
+fn crossing(
+    date: &NanoTime,
+    latitude: f64,
+    longitude: f64,
+    zenith: f64,
+    morning: bool,
+) -> Option<NanoTime> {
+    let (midnight, jd0) = midnight(date);
+    let lat = latitude.to_radians();
+    let mut minutes = noon_minutes(jd0, longitude);
+    // Evaluate at noon, then again at the estimated event time.
+    for _ in 0..2 {
+        let (decl, eq_time) = sun_position(jd0 + minutes / 1_440.0);
+        let cos_ha = zenith.to_radians().cos() / (lat.cos() * decl.cos()) - lat.tan() * decl.tan();
+        if !(-1.0..=1.0).contains(&cos_ha) {
+            return None;
+        }
+        let ha = cos_ha.acos().to_degrees();
+        let noon = 720.0 - 4.0 * longitude - eq_time;
+        minutes = if morning {
+            noon - 4.0 * ha
+        } else {
+            noon + 4.0 * ha
+        };
+    }
+    at_minutes(&midnight, minutes)
+}
+
+/// Returns when the sun is highest on `date` at `longitude`.
+///
+/// ```rust
+/// use nanotime::{solar, NanoTime};
+///
+/// let day = NanoTime::new(2026, 11, 3, 0, 0, 0, 0).unwrap();
+/// let noon = solar::solar_noon(&day, 0.0);
+/// assert_eq!((noon.hour(), noon.minute()), (11, 43));
+/// ```
+pub fn solar_noon(date: &NanoTime, longitude: f64) -> NanoTime {
+    let (midnight, jd0) = midnight(date);
+    let minutes = noon_minutes(jd0, longitude);
+    // Within a day of a valid date, so only the last day of year 65535
+    // can overflow.
+    at_minutes(&midnight, minutes).unwrap_or(midnight)
+}
+
+/// Returns when the top of the sun rises on `date`, or `None` during
+/// polar day or night.
+pub fn sunrise(date: &NanoTime, latitude: f64, longitude: f64) -> Option<NanoTime> {
+    crossing(date, latitude, longitude, SUNRISE_ZENITH, true)
+}
+
+/// Returns when the top of the sun sets on `date`, or `None` during polar
+/// day or night.
+pub fn sunset(date: &NanoTime, latitude: f64, longitude: f64) -> Option<NanoTime> {
+    crossing(date, latitude, longitude, SUNRISE_ZENITH, false)
+}
+
+/// Returns when morning `twilight` begins on `date`, or `None` if the sun
+/// never gets that far below the horizon, or never rises above it.
+pub fn dawn(
+    date: &NanoTime,
+    latitude: f64,
+    longitude: f64,
+    twilight: Twilight,
+) -> Option<NanoTime> {
+    crossing(
+        date,
+        latitude,
+        longitude,
+        90.0 + twilight.depression(),
+        true,
+    )
+}
+
+/// Returns when evening `twilight` ends on `date`, or `None` if the sun
+/// never gets that far below the horizon, or never rises above it.
+pub fn dusk(
+    date: &NanoTime,
+    latitude: f64,
+    longitude: f64,
+    twilight: Twilight,
+) -> Option<NanoTime> {
+    crossing(
+        date,
+        latitude,
+        longitude,
+        90.0 + twilight.depression(),
+        false,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minutes(t: NanoTime) -> i64 {
+        t.hour() as i64 * 60 + t.minute() as i64
+    }
+
+    #[test]
+    fn test_london_midsummer() {
+        let day = NanoTime::new(2026, 6, 21, 0, 0, 0, 0).unwrap();
+        let (lat, lon) = (51.5074, -0.1278);
+        // NOAA: sunrise 03:43, noon 12:02, sunset 20:21 UTC.
+        assert!((minutes(sunrise(&day, lat, lon).unwrap()) - 223).abs() <= 1);
+        assert!((minutes(solar_noon(&day, lon)) - 722).abs() <= 1);
+        assert!((minutes(sunset(&day, lat, lon).unwrap()) - 1221).abs() <= 1);
+        // Astronomical night never comes in London in June.
+        assert_eq!(dusk(&day, lat, lon, Twilight::Astronomical), None);
+        let civil = dawn(&day, lat, lon, Twilight::Civil).unwrap();
+        let nautical = dawn(&day, lat, lon, Twilight::Nautical).unwrap();
+        assert!(nautical < civil && civil < sunrise(&day, lat, lon).unwrap());
+    }
+
+    #[test]
+    fn test_time_of_day_in_date_is_ignored() {
+        let (lat, lon) = (40.71, -74.01);
+        let morning = NanoTime::new(2026, 3, 20, 1, 0, 0, 0).unwrap();
+        let evening = NanoTime::new(2026, 3, 20, 23, 0, 0, 0).unwrap();
+        assert_eq!(sunrise(&morning, lat, lon), sunrise(&evening, lat, lon));
+    }
+
+    #[test]
+    fn test_polar_day_and_night() {
+        let (lat, lon) = (69.65, 18.96); // Tromsø
+        let june = NanoTime::new(2026, 6, 21, 0, 0, 0, 0).unwrap();
+        let december = NanoTime::new(2026, 12, 21, 0, 0, 0, 0).unwrap();
+        assert_eq!(sunrise(&june, lat, lon), None);
+        assert_eq!(sunset(&december, lat, lon), None);
+        // The sun still reaches civil twilight at noon in the polar night.
+        assert!(dawn(&december, lat, lon, Twilight::Civil).is_some());
+    }
+}
@@ -0,0 +1,133 @@
+//! Timestamps in both the wall-clock and monotonic domains.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::time::Instant;
+
+use crate::{NanoDuration, NanoTime};
+
+/// A moment captured on both the monotonic clock and the wall clock.
+///
+/// Wall-clock times are what people and logs want to see, but subtracting
+/// them gives wrong answers whenever the system clock is adjusted in
+/// between. A `Stamp` keeps both: [`wall`](Self::wall) for display and
+/// [`monotonic_since`](Self::monotonic_since) for intervals that are immune
+/// to clock changes.
+///
+/// Stamps compare and order by their monotonic instant. Displays as the
+/// wall time in RFC 3339.
+///
+/// ```rust
+/// use nanotime::Stamp;
+///
+/// let received = Stamp::now();
+/// // ... handle the request ...
+/// let sent = Stamp::now();
+/// let took = sent.monotonic_since(&received);
+/// println!("received at {}, handled in {}ns", received, took.as_nanos());
+/// assert!(!took.is_negative());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stamp {
+    instant: Instant,
+    wall: NanoTime,
+}
+
+impl Stamp {
+    /// Captures the current instant and UTC time.
+    ///
+    /// The wall clock is read between two reads of the monotonic clock, and
+    /// the stamp's instant is their midpoint, so the two halves describe the
+    /// same moment as closely as the clocks allow.
+    pub fn now() -> Self {
+        let before = Instant::now();
+        let wall = NanoTime::now_utc();
+        let after = Instant::now();
+        Stamp {
+            instant: before + (after - before) / 2,
+            wall,
+        }
+    }
+
+    /// Pairs an instant with the UTC time read at it.
+    pub fn from_parts(instant: Instant, wall: NanoTime) -> Self {
+        Stamp { instant, wall }
+    }
+
+    /// Returns the UTC time.
+    pub fn wall(&self) -> NanoTime {
+        self.wall
+    }
+
+    /// Returns the monotonic instant.
+    pub fn instant(&self) -> Instant {
+        self.instant
+    }
+
+    /// Returns the time from `earlier` to this stamp on the monotonic
+    /// clock, negative if `earlier` was actually captured later.
+    pub fn monotonic_since(&self, earlier: &Stamp) -> NanoDuration {
+        let nanos = match self.instant.checked_duration_since(earlier.instant) {
+            Some(d) => d.as_nanos() as i128,
+            None => -((earlier.instant - self.instant).as_nanos() as i128),
+        };
+        NanoDuration::from_nanos(nanos).unwrap_or_default()
+    }
+}
+
+impl PartialOrd for Stamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Stamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.instant
+            .cmp(&other.instant)
+            .then_with(|| self.wall.cmp(&other.wall))
+    }
+}
+
+impl fmt::Display for Stamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.wall.to_rfc3339())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_monotonic_ignores_wall() {
+        let t0 = Instant::now();
+        let wall = NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap();
+        let a = Stamp::from_parts(t0, wall);
+        // The wall clock was stepped back an hour in between.
+        let b = Stamp::from_parts(
+            t0 + Duration::from_millis(250),
+            wall.add_nanos(-3_600_000_000_000),
+        );
+        assert_eq!(b.monotonic_since(&a).as_nanos(), 250_000_000);
+        assert_eq!(a.monotonic_since(&b).as_nanos(), -250_000_000);
+        assert!(a < b);
+        assert_eq!(a.to_string(), "2026-02-22T14:30:05Z");
+        assert_eq!(
+            (b.instant(), b.wall().hour()),
+            (t0 + Duration::from_millis(250), 13)
+        );
+    }
+
+    #[test]
+    fn test_now_halves_agree() {
+        let a = Stamp::now();
+        std::thread::sleep(Duration::from_millis(5));
+        let b = Stamp::now();
+        let mono = b.monotonic_since(&a).as_nanos();
+        assert!(mono >= 5_000_000);
+        // The wall clock advanced about as much, barring an adjustment.
+        assert!((b.wall().diff_nanos(&a.wall()) - mono).abs() < 1_000_000_000);
+    }
+}
@@ -0,0 +1,392 @@
+//! Summary statistics over many measured durations.
+
+use std::time::Duration;
+
+use crate::NanoTime;
+
+/// Saturating conversion of a duration to nanoseconds.
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos().min(u64::MAX as u128) as u64
+}
+
+/// Running count, total, extremes, mean, and standard deviation of a
+/// series of durations, in constant space.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::TimingStats;
+///
+/// let mut stats = TimingStats::new();
+/// for ms in [10, 20, 30] {
+///     stats.record(Duration::from_millis(ms));
+/// }
+/// assert_eq!(stats.mean(), Some(Duration::from_millis(20)));
+/// assert_eq!(stats.max(), Some(Duration::from_millis(30)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimingStats {
+    count: u64,
+    total: u128,
+    min: u64,
+    max: u64,
+    /// Welford's running mean and sum of squared deviations, in
+    /// nanoseconds.
+    mean: f64,
+    m2: f64,
+}
+
+impl TimingStats {
+    /// Creates empty statistics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds one measurement.
+    pub fn record(&mut self, d: Duration) {
+        let x = nanos(d);
+        if self.count == 0 {
+            self.min = x;
+            self.max = x;
+        } else {
+            self.min = self.min.min(x);
+            self.max = self.max.max(x);
+        }
+        self.count += 1;
+        self.total += x as u128;
+        let delta = x as f64 - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x as f64 - self.mean);
+    }
+
+    /// Combines the measurements of `other` into these statistics.
+    pub fn merge(&mut self, other: &TimingStats) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * other.count as f64 / count as f64;
+        self.count = count;
+        self.total += other.total;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Returns the number of measurements.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the sum of all measurements.
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.total.min(u64::MAX as u128) as u64)
+    }
+
+    /// Returns the shortest measurement, or `None` if there are none.
+    pub fn min(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_nanos(self.min))
+    }
+
+    /// Returns the longest measurement, or `None` if there are none.
+    pub fn max(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_nanos(self.max))
+    }
+
+    /// Returns the mean, rounded to the nanosecond, or `None` if there are
+    /// no measurements.
+    pub fn mean(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_nanos(self.mean.round() as u64))
+    }
+
+    /// Returns the population standard deviation, or `None` if there are no
+    /// measurements.
+    pub fn std_dev(&self) -> Option<Duration> {
+        (self.count > 0)
+            .then(|| Duration::from_nanos((self.m2 / self.count as f64).sqrt().round() as u64))
+    }
+}
+
+/// Linear sub-buckets per power of two, as a bit count.
+const SUB_BITS: u32 = 4;
+const SUB: u64 = 1 << SUB_BITS;
+const BUCKETS: usize = ((64 - SUB_BITS + 1) as usize) * SUB as usize;
+
+fn bucket_of(v: u64) -> usize {
+    if v < SUB {
+        v as usize
+    } else {
+        let e = 63 - v.leading_zeros();
+        let sub = (v >> (e - SUB_BITS)) & (SUB - 1);
+        ((e - SUB_BITS + 1) as u64 * SUB + sub) as usize
+    }
+}
+
+/// The largest value that falls in bucket `i`.
+fn bucket_max(i: usize) -> u64 {
+    let i = i as u64;
+    if i < SUB {
+        return i;
+    }
+    let shift = (i / SUB - 1) as u32;
+    let lower = (SUB + i % SUB) << shift;
+    lower + ((1u64 << shift) - 1)
+}
+
+/// A histogram of durations for percentile queries, in fixed memory.
+///
+/// Each power of two is split into 16 buckets, so a reported percentile is
+/// within about 6% of the true value however wide the range of
+/// measurements, from nanoseconds to centuries. Percentiles report the
+/// upper edge of their bucket, clamped to the largest measurement.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::LatencyHistogram;
+///
+/// let mut h = LatencyHistogram::new();
+/// for us in 1..=1000 {
+///     h.record(Duration::from_micros(us));
+/// }
+/// let p99 = h.percentile(99.0).unwrap();
+/// assert!(p99 >= Duration::from_micros(990) && p99 <= Duration::from_micros(1000));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    counts: Vec<u64>,
+    count: u64,
+    min: u64,
+    max: u64,
+}
+
+impl LatencyHistogram {
+    /// Creates an empty histogram.
+    pub fn new() -> Self {
+        LatencyHistogram {
+            counts: vec![0; BUCKETS],
+            count: 0,
+            min: u64::MAX,
+            max: 0,
+        }
+    }
+
+    /// Adds one measurement.
+    pub fn record(&mut self, d: Duration) {
+        let x = nanos(d);
+        self.counts[bucket_of(x)] += 1;
+        self.count += 1;
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+    }
+
+    /// Combines the measurements of `other` into this histogram.
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        for (a, b) in self.counts.iter_mut().zip(&other.counts) {
+            *a += b;
+        }
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Returns the number of measurements.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the shortest measurement, or `None` if there are none.
+    pub fn min(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_nanos(self.min))
+    }
+
+    /// Returns the longest measurement, or `None` if there are none.
+    pub fn max(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_nanos(self.max))
+    }
+
+    /// Returns the duration at or below which `p` percent of measurements
+    /// fall, for `p` in `0.0..=100.0`, or `None` if there are none.
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        let rank = ((p.clamp(0.0, 100.0) / 100.0 * self.count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (i, &n) in self.counts.iter().enumerate() {
+            seen += n;
+            if seen >= rank {
+                let v = bucket_max(i).clamp(self.min, self.max);
+                return Some(Duration::from_nanos(v));
+            }
+        }
+        self.max()
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The distribution of gaps between consecutive events, from
+/// [`inter_arrival_stats`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterArrivalStats {
+    gaps: TimingStats,
+    histogram: LatencyHistogram,
+    jitter: TimingStats,
+}
+
+impl InterArrivalStats {
+    /// Returns the count, total, extremes, mean, and standard deviation of
+    /// the gaps. There is one gap fewer than there are events.
+    pub fn gaps(&self) -> &TimingStats {
+        &self.gaps
+    }
+
+    /// Returns the gaps as a histogram, for percentiles and merging.
+    pub fn histogram(&self) -> &LatencyHistogram {
+        &self.histogram
+    }
+
+    /// Returns the gap at or below which `p` percent of gaps fall, within
+    /// the precision of [`LatencyHistogram`], or `None` if there are none.
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        self.histogram.percentile(p)
+    }
+
+    /// Returns the mean absolute change from one gap to the next, as RTP
+    /// measures jitter, or `None` with fewer than two gaps. Zero for
+    /// perfectly periodic events.
+    pub fn jitter(&self) -> Option<Duration> {
+        self.jitter.mean()
+    }
+}
+
+/// Measures the gaps between consecutive `times`, which should be in
+/// order, to tell steady streams from bursty ones. A time earlier than
+/// the one before it counts as a zero gap.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{inter_arrival_stats, NanoTime};
+///
+/// let ms = |n: u64| NanoTime::from_epoch_ms(1_771_770_600_000 + n);
+/// let events = [ms(0), ms(10), ms(20), ms(30), ms(130)];
+/// let stats = inter_arrival_stats(&events);
+/// assert_eq!(stats.gaps().count(), 4);
+/// assert_eq!(stats.gaps().min(), Some(Duration::from_millis(10)));
+/// assert_eq!(stats.gaps().max(), Some(Duration::from_millis(100)));
+/// assert_eq!(stats.percentile(99.0), Some(Duration::from_millis(100)));
+/// assert_eq!(stats.jitter(), Some(Duration::from_millis(30)));
+/// ```
+pub fn inter_arrival_stats<'a>(times: impl IntoIterator<Item = &'a NanoTime>) -> InterArrivalStats {
+    let mut stats = InterArrivalStats {
+        gaps: TimingStats::new(),
+        histogram: LatencyHistogram::new(),
+        jitter: TimingStats::new(),
+    };
+    let (mut prev, mut prev_gap): (Option<&NanoTime>, Option<Duration>) = (None, None);
+    for t in times {
+        if let Some(p) = prev {
+            let nanos = t.diff_nanos(p).clamp(0, u64::MAX as i128);
+            let gap = Duration::from_nanos(nanos as u64);
+            stats.gaps.record(gap);
+            stats.histogram.record(gap);
+            if let Some(last) = prev_gap {
+                stats.jitter.record(gap.abs_diff(last));
+            }
+            prev_gap = Some(gap);
+        }
+        prev = Some(t);
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_stats() {
+        let mut s = TimingStats::new();
+        assert_eq!((s.count(), s.mean(), s.min()), (0, None, None));
+        for ns in [2, 4, 4, 4, 5, 5, 7, 9] {
+            s.record(Duration::from_nanos(ns));
+        }
+        assert_eq!(s.count(), 8);
+        assert_eq!(s.total(), Duration::from_nanos(40));
+        assert_eq!(s.mean(), Some(Duration::from_nanos(5)));
+        assert_eq!(s.std_dev(), Some(Duration::from_nanos(2)));
+        assert_eq!(s.min(), Some(Duration::from_nanos(2)));
+        assert_eq!(s.max(), Some(Duration::from_nanos(9)));
+    }
+
+    #[test]
+    fn test_timing_stats_merge() {
+        let (mut a, mut b, mut all) = (TimingStats::new(), TimingStats::new(), TimingStats::new());
+        for ms in 1..=10u64 {
+            let d = Duration::from_millis(ms * ms);
+            if ms % 2 == 0 { &mut a } else { &mut b }.record(d);
+            all.record(d);
+        }
+        a.merge(&b);
+        assert_eq!(a.count(), all.count());
+        assert_eq!(a.mean(), all.mean());
+        assert_eq!(a.std_dev(), all.std_dev());
+        assert_eq!((a.min(), a.max()), (all.min(), all.max()));
+        let mut empty = TimingStats::new();
+        empty.merge(&all);
+        assert_eq!(empty, all);
+    }
+
+    #[test]
+    fn test_buckets_are_contiguous() {
+        for i in 0..BUCKETS - 1 {
+            assert_eq!(bucket_of(bucket_max(i)), i);
+            assert_eq!(bucket_of(bucket_max(i) + 1), i + 1);
+        }
+        assert_eq!(bucket_max(BUCKETS - 1), u64::MAX);
+    }
+
+    #[test]
+    fn test_histogram_percentiles() {
+        let mut h = LatencyHistogram::new();
+        assert_eq!(h.percentile(50.0), None);
+        for ns in 1..=10_000u64 {
+            h.record(Duration::from_nanos(ns));
+        }
+        for (p, exact) in [(50.0, 5_000.0), (90.0, 9_000.0), (99.9, 9_990.0)] {
+            let got = h.percentile(p).unwrap().as_nanos() as f64;
+            assert!(got >= exact && got <= exact * 1.0625, "p{}: {}", p, got);
+        }
+        assert_eq!(h.percentile(0.0), Some(Duration::from_nanos(1)));
+        assert_eq!(h.percentile(100.0), Some(Duration::from_nanos(10_000)));
+
+        let mut other = LatencyHistogram::new();
+        other.record(Duration::from_secs(1));
+        h.merge(&other);
+        assert_eq!(h.count(), 10_001);
+        assert_eq!(h.max(), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_inter_arrival() {
+        let t = |s: u64| NanoTime::from_epoch(s);
+        let steady = inter_arrival_stats(&[t(0), t(5), t(10), t(15)]);
+        assert_eq!(steady.gaps().mean(), Some(Duration::from_secs(5)));
+        assert_eq!(steady.jitter(), Some(Duration::ZERO));
+        // Out-of-order times count as zero gaps.
+        let shuffled = inter_arrival_stats(&[t(10), t(0), t(20)]);
+        assert_eq!(shuffled.gaps().min(), Some(Duration::ZERO));
+        assert_eq!(shuffled.gaps().max(), Some(Duration::from_secs(20)));
+        let single = inter_arrival_stats(&[t(0)]);
+        assert_eq!((single.gaps().count(), single.percentile(50.0)), (0, None));
+        assert_eq!(single.jitter(), None);
+    }
+}
@@ -0,0 +1,251 @@
+//! Conversions to and from TOML datetimes. Requires the `toml` feature.
+//!
+//! [`Datetime`] is the type `toml::value::Datetime` re-exports, so values
+//! read by a TOML parser convert directly, without a string round-trip:
+//!
+//! ```rust
+//! use nanotime::NanoTime;
+//!
+//! let config: toml::Table = "started = 2026-02-22T14:30:05+05:30".parse().unwrap();
+//! let dt = config["started"].as_datetime().unwrap();
+//! let t = NanoTime::try_from(*dt).unwrap();
+//! assert_eq!(t.datetime(), "2026-02-22 09:00:05.000");
+//! ```
+
+use std::fmt;
+
+use toml_datetime::{Date, Datetime, Offset, Time};
+
+use crate::{NanoTime, OffsetNanoTime, UtcOffset};
+
+/// Why a TOML [`Datetime`] could not be converted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DatetimeError {
+    /// A local time has no date to attach to.
+    MissingDate,
+    /// An [`OffsetNanoTime`] needs an offset datetime.
+    MissingOffset,
+    /// A field is outside its valid range, or the offset moves the instant
+    /// outside years 0-65535.
+    OutOfRange,
+}
+
+impl fmt::Display for DatetimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DatetimeError::MissingDate => "TOML datetime has no date",
+            DatetimeError::MissingOffset => "TOML datetime has no offset",
+            DatetimeError::OutOfRange => "TOML datetime field out of range",
+        })
+    }
+}
+
+impl std::error::Error for DatetimeError {}
+
+/// Splits a civil time into TOML parts, which only have four-digit years.
+fn to_parts(t: &NanoTime) -> Result<(Date, Time), DatetimeError> {
+    if t.year() > 9999 {
+        return Err(DatetimeError::OutOfRange);
+    }
+    let date = Date {
+        year: t.year(),
+        month: t.month(),
+        day: t.day(),
+    };
+    let time = Time {
+        hour: t.hour(),
+        minute: t.minute(),
+        second: Some(t.second()),
+        nanosecond: Some(t.nanosecond()).filter(|&n| n != 0),
+    };
+    Ok((date, time))
+}
+
+/// Builds the civil time, defaulting a missing time to midnight.
+fn from_parts(dt: &Datetime) -> Result<NanoTime, DatetimeError> {
+    let date = dt.date.ok_or(DatetimeError::MissingDate)?;
+    let time = dt.time.unwrap_or(Time {
+        hour: 0,
+        minute: 0,
+        second: None,
+        nanosecond: None,
+    });
+    NanoTime::new(
+        date.year,
+        date.month,
+        date.day,
+        time.hour,
+        time.minute,
+        time.second.unwrap_or(0),
+        time.nanosecond.unwrap_or(0),
+    )
+    .ok_or(DatetimeError::OutOfRange)
+}
+
+fn to_offset(offset: Offset) -> Result<UtcOffset, DatetimeError> {
+    match offset {
+        Offset::Z => Ok(UtcOffset::UTC),
+        Offset::Custom { minutes } => {
+            UtcOffset::from_minutes(minutes).ok_or(DatetimeError::OutOfRange)
+        }
+    }
+}
+
+/// Writes an offset datetime in UTC (`...Z`). Years past 9999 are
+/// rejected, since TOML has no way to write them.
+impl TryFrom<NanoTime> for Datetime {
+    type Error = DatetimeError;
+
+    fn try_from(t: NanoTime) -> Result<Self, Self::Error> {
+        let (date, time) = to_parts(&t)?;
+        Ok(Datetime {
+            date: Some(date),
+            time: Some(time),
+            offset: Some(Offset::Z),
+        })
+    }
+}
+
+/// Writes an offset datetime with the numeric offset. An offset with a
+/// seconds part is rounded toward zero to whole minutes and the local time
+/// shifted to match, so the instant is kept. Local years past 9999, and
+/// values whose instant is outside years 0-65535, are rejected.
+impl TryFrom<OffsetNanoTime> for Datetime {
+    type Error = DatetimeError;
+
+    fn try_from(t: OffsetNanoTime) -> Result<Self, Self::Error> {
+        t.checked_to_utc().ok_or(DatetimeError::OutOfRange)?;
+        let t = t.with_whole_minute_offset();
+        let (date, time) = to_parts(&t.local())?;
+        Ok(Datetime {
+            date: Some(date),
+            time: Some(time),
+            offset: Some(Offset::Custom {
+                minutes: (t.offset().as_secs() / 60) as i16,
+            }),
+        })
+    }
+}
+
+/// Offset datetimes convert to UTC. Local datetimes and local dates are
+/// taken as written, with a missing time as midnight. Local times without
+/// a date, and offsets that move the instant outside years 0-65535, are
+/// rejected.
+impl TryFrom<Datetime> for NanoTime {
+    type Error = DatetimeError;
+
+    fn try_from(dt: Datetime) -> Result<Self, Self::Error> {
+        let local = from_parts(&dt)?;
+        match dt.offset {
+            Some(offset) => OffsetNanoTime::new(local, to_offset(offset)?)
+                .checked_to_utc()
+                .ok_or(DatetimeError::OutOfRange),
+            None => Ok(local),
+        }
+    }
+}
+
+/// Only offset datetimes convert; the local time and offset are kept.
+impl TryFrom<Datetime> for OffsetNanoTime {
+    type Error = DatetimeError;
+
+    fn try_from(dt: Datetime) -> Result<Self, Self::Error> {
+        let offset = dt.offset.ok_or(DatetimeError::MissingOffset)?;
+        Ok(OffsetNanoTime::new(from_parts(&dt)?, to_offset(offset)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> Datetime {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_offset_datetime_to_utc() {
+        let t = NanoTime::try_from(dt("2026-02-22T14:30:05.123-03:30")).unwrap();
+        assert_eq!(
+            t,
+            NanoTime::new(2026, 2, 22, 18, 0, 5, 123_000_000).unwrap()
+        );
+        let o = OffsetNanoTime::try_from(dt("2026-02-22T14:30:05Z")).unwrap();
+        assert!(o.offset().is_utc());
+    }
+
+    #[test]
+    fn test_local_forms() {
+        assert_eq!(
+            NanoTime::try_from(dt("2026-02-22T14:30:05")).unwrap(),
+            NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap()
+        );
+        assert_eq!(
+            NanoTime::try_from(dt("2026-02-22")).unwrap(),
+            NanoTime::new(2026, 2, 22, 0, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            NanoTime::try_from(dt("14:30:05")),
+            Err(DatetimeError::MissingDate)
+        );
+        assert_eq!(
+            OffsetNanoTime::try_from(dt("2026-02-22T14:30:05")),
+            Err(DatetimeError::MissingOffset)
+        );
+    }
+
+    #[test]
+    fn test_invalid_fields() {
+        let mut bad = dt("2026-02-22T14:30:05Z");
+        bad.date = Some(Date {
+            year: 2026,
+            month: 2,
+            day: 30,
+        });
+        assert_eq!(NanoTime::try_from(bad), Err(DatetimeError::OutOfRange));
+        assert_eq!(
+            NanoTime::try_from(dt("0000-01-01T00:00:00+01:00")),
+            Err(DatetimeError::OutOfRange)
+        );
+        assert!(OffsetNanoTime::try_from(dt("0000-01-01T00:00:00+01:00")).is_ok());
+    }
+
+    #[test]
+    fn test_to_toml() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 120_000_000).unwrap();
+        let d = Datetime::try_from(t).unwrap();
+        assert_eq!(d.to_string(), "2026-02-22T14:30:05.12Z");
+        assert_eq!(NanoTime::try_from(d).unwrap(), t);
+
+        let o = OffsetNanoTime::from_utc(&t, UtcOffset::from_minutes(330).unwrap());
+        let d = Datetime::try_from(o).unwrap();
+        assert_eq!(d.to_string(), "2026-02-22T20:00:05.12+05:30");
+        assert_eq!(OffsetNanoTime::try_from(d).unwrap(), o);
+    }
+
+    #[test]
+    fn test_to_toml_rounds_offset_seconds() {
+        let t = NanoTime::new(1880, 1, 1, 12, 0, 0, 0).unwrap();
+        let lmt = OffsetNanoTime::from_utc(&t, UtcOffset::from_secs(-17_762).unwrap());
+        let d = Datetime::try_from(lmt).unwrap();
+        assert_eq!(d.to_string(), "1880-01-01T07:04:00-04:56");
+        let back = OffsetNanoTime::try_from(d).unwrap();
+        assert!(back.same_instant(&lmt));
+    }
+
+    #[test]
+    fn test_to_toml_four_digit_years() {
+        let last = NanoTime::new(9999, 12, 31, 23, 59, 59, 0).unwrap();
+        assert!(Datetime::try_from(last).is_ok());
+        let t = NanoTime::new(10000, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(Datetime::try_from(t), Err(DatetimeError::OutOfRange));
+        let east = OffsetNanoTime::from_utc(&last, UtcOffset::from_minutes(60).unwrap());
+        assert_eq!(Datetime::try_from(east), Err(DatetimeError::OutOfRange));
+    }
+
+    #[test]
+    fn test_whole_seconds_omit_fraction() {
+        let d = Datetime::try_from(NanoTime::from_epoch(0)).unwrap();
+        assert_eq!(d.to_string(), "1970-01-01T00:00:00Z");
+    }
+}
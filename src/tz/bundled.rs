@@ -0,0 +1,614 @@
+//! Compiled IANA time zone data for the `bundled-tz` feature.
+//!
+//! Generated by `scripts/gen-bundled-tz.sh` from tzdata 2025b. Each zone
+//! is its full TZif file, historical transitions included, stored in
+//! `tzdata.bin`. Do not edit by hand.
+
+/// The IANA tzdata release the bundled zones were generated from.
+pub const TZDATA_VERSION: &str = "2025b";
+
+/// Every bundled zone's TZif file, concatenated.
+pub(super) static TZDATA: &[u8] = include_bytes!("tzdata.bin");
+
+/// Zone name and the byte range of its TZif file in [`TZDATA`], sorted
+/// bytewise by name.
+#[rustfmt::skip]
+pub(super) const ZONES: &[(&str, usize, usize)] = &[
+    ("Africa/Abidjan", 0, 148),
+    ("Africa/Accra", 148, 1208),
+    ("Africa/Addis_Ababa", 1208, 1393),
+    ("Africa/Algiers", 1393, 2128),
+    ("Africa/Asmara", 2128, 2332),
+    ("Africa/Asmera", 2332, 2597),
+    ("Africa/Bamako", 2597, 2805),
+    ("Africa/Bangui", 2805, 2954),
+    ("Africa/Banjul", 2954, 3170),
+    ("Africa/Bissau", 3170, 3364),
+    ("Africa/Blantyre", 3364, 3573),
+    ("Africa/Brazzaville", 3573, 3722),
+    ("Africa/Bujumbura", 3722, 3871),
+    ("Africa/Cairo", 3871, 6270),
+    ("Africa/Casablanca", 6270, 8699),
+    ("Africa/Ceuta", 8699, 10751),
+    ("Africa/Conakry", 10751, 10959),
+    ("Africa/Dakar", 10959, 11141),
+    ("Africa/Dar_es_Salaam", 11141, 11354),
+    ("Africa/Djibouti", 11354, 11503),
+    ("Africa/Douala", 11503, 11652),
+    ("Africa/El_Aaiun", 11652, 13947),
+    ("Africa/Freetown", 13947, 14411),
+    ("Africa/Gaborone", 14411, 14646),
+    ("Africa/Harare", 14646, 14795),
+    ("Africa/Johannesburg", 14795, 15041),
+    ("Africa/Juba", 15041, 15720),
+    ("Africa/Kampala", 15720, 15971),
+    ("Africa/Khartoum", 15971, 16650),
+    ("Africa/Kigali", 16650, 16799),
+    ("Africa/Kinshasa", 16799, 16948),
+    ("Africa/Lagos", 16948, 17183),
+    ("Africa/Libreville", 17183, 17332),
+    ("Africa/Lome", 17332, 17480),
+    ("Africa/Luanda", 17480, 17667),
+    ("Africa/Lubumbashi", 17667, 17850),
+    ("Africa/Lusaka", 17850, 17999),
+    ("Africa/Malabo", 17999, 18182),
+    ("Africa/Maputo", 18182, 18331),
+    ("Africa/Maseru", 18331, 18523),
+    ("Africa/Mbabane", 18523, 18675),
+    ("Africa/Mogadishu", 18675, 18888),
+    ("Africa/Monrovia", 18888, 19096),
+    ("Africa/Nairobi", 2332, 2597),
+    ("Africa/Ndjamena", 19096, 19295),
+    ("Africa/Niamey", 19295, 19512),
+    ("Africa/Nouakchott", 19512, 19720),
+    ("Africa/Ouagadougou", 19720, 19868),
+    ("Africa/Porto-Novo", 19868, 20051),
+    ("Africa/Sao_Tome", 20051, 20305),
+    ("Africa/Timbuktu", 0, 148),
+    ("Africa/Tripoli", 20305, 20930),
+    ("Africa/Tunis", 20930, 21619),
+    ("Africa/Windhoek", 21619, 22574),
+    ("America/Adak", 22574, 24930),
+    ("America/Anchorage", 24930, 27301),
+    ("America/Anguilla", 27301, 27449),
+    ("America/Antigua", 27449, 27631),
+    ("America/Araguaina", 27631, 28515),
+    ("America/Argentina/Buenos_Aires", 28515, 29591),
+    ("America/Argentina/Catamarca", 29591, 30667),
+    ("America/Argentina/ComodRivadavia", 29591, 30667),
+    ("America/Argentina/Cordoba", 30667, 31743),
+    ("America/Argentina/Jujuy", 31743, 32791),
+    ("America/Argentina/La_Rioja", 32791, 33881),
+    ("America/Argentina/Mendoza", 33881, 34957),
+    ("America/Argentina/Rio_Gallegos", 34957, 36033),
+    ("America/Argentina/Salta", 36033, 37081),
+    ("America/Argentina/San_Juan", 37081, 38171),
+    ("America/Argentina/San_Luis", 38171, 39273),
+    ("America/Argentina/Tucuman", 39273, 40377),
+    ("America/Argentina/Ushuaia", 40377, 41453),
+    ("America/Aruba", 41453, 41639),
+    ("America/Asuncion", 41639, 43297),
+    ("America/Atikokan", 43297, 43633),
+    ("America/Atka", 22574, 24930),
+    ("America/Bahia", 43633, 44657),
+    ("America/Bahia_Banderas", 44657, 45757),
+    ("America/Barbados", 45757, 46193),
+    ("America/Belem", 46193, 46769),
+    ("America/Belize", 46769, 48383),
+    ("America/Blanc-Sablon", 48383, 48681),
+    ("America/Boa_Vista", 48681, 49313),
+    ("America/Bogota", 49313, 49559),
+    ("America/Boise", 49559, 51969),
+    ("America/Buenos_Aires", 28515, 29591),
+    ("America/Cambridge_Bay", 51969, 54223),
+    ("America/Campo_Grande", 54223, 55667),
+    ("America/Cancun", 55667, 56531),
+    ("America/Caracas", 56531, 56795),
+    ("America/Catamarca", 29591, 30667),
+    ("America/Cayenne", 56795, 56993),
+    ("America/Cayman", 56993, 57175),
+    ("America/Chicago", 57175, 60767),
+    ("America/Chihuahua", 60767, 61869),
+    ("America/Ciudad_Juarez", 61869, 63407),
+    ("America/Coral_Harbour", 63407, 63589),
+    ("America/Cordoba", 30667, 31743),
+    ("America/Costa_Rica", 63589, 63905),
+    ("America/Coyhaique", 63905, 66045),
+    ("America/Creston", 66045, 66253),
+    ("America/Cuiaba", 66253, 67669),
+    ("America/Curacao", 67669, 67855),
+    ("America/Danmarkshavn", 67855, 68553),
+    ("America/Dawson", 68553, 70167),
+    ("America/Dawson_Creek", 70167, 71217),
+    ("America/Denver", 71217, 73677),
+    ("America/Detroit", 73677, 75907),
+    ("America/Dominica", 75907, 76055),
+    ("America/Edmonton", 76055, 78387),
+    ("America/Eirunepe", 78387, 79043),
+    ("America/El_Salvador", 79043, 79267),
+    ("America/Ensenada", 79267, 81725),
+    ("America/Fort_Nelson", 81725, 83965),
+    ("America/Fort_Wayne", 83965, 85647),
+    ("America/Fortaleza", 85647, 86363),
+    ("America/Glace_Bay", 86363, 88555),
+    ("America/Godthab", 88555, 90458),
+    ("America/Goose_Bay", 90458, 93668),
+    ("America/Grand_Turk", 93668, 95502),
+    ("America/Grenada", 95502, 95650),
+    ("America/Guadeloupe", 95650, 95798),
+    ("America/Guatemala", 95798, 96078),
+    ("America/Guayaquil", 96078, 96324),
+    ("America/Guyana", 96324, 96586),
+    ("America/Halifax", 96586, 100010),
+    ("America/Havana", 100010, 102426),
+    ("America/Hermosillo", 102426, 102814),
+    ("America/Indiana/Indianapolis", 83965, 85647),
+    ("America/Indiana/Knox", 102814, 105258),
+    ("America/Indiana/Marengo", 105258, 106996),
+    ("America/Indiana/Petersburg", 106996, 108916),
+    ("America/Indiana/Tell_City", 108916, 110616),
+    ("America/Indiana/Vevay", 110616, 112046),
+    ("America/Indiana/Vincennes", 112046, 113756),
+    ("America/Indiana/Winamac", 113756, 115550),
+    ("America/Indianapolis", 83965, 85647),
+    ("America/Inuvik", 115550, 117624),
+    ("America/Iqaluit", 117624, 119826),
+    ("America/Jamaica", 119826, 120308),
+    ("America/Jujuy", 31743, 32791),
+    ("America/Juneau", 120308, 122661),
+    ("America/Kentucky/Louisville", 122661, 125449),
+    ("America/Kentucky/Monticello", 125449, 127817),
+    ("America/Knox_IN", 102814, 105258),
+    ("America/Kralendijk", 127817, 128063),
+    ("America/La_Paz", 128063, 128295),
+    ("America/Lima", 128295, 128701),
+    ("America/Los_Angeles", 128701, 131553),
+    ("America/Louisville", 122661, 125449),
+    ("America/Lower_Princes", 127817, 128063),
+    ("America/Maceio", 131553, 132297),
+    ("America/Managua", 132297, 132727),
+    ("America/Manaus", 132727, 133331),
+    ("America/Marigot", 127817, 128063),
+    ("America/Martinique", 133331, 133563),
+    ("America/Matamoros", 133563, 134981),
+    ("America/Mazatlan", 134981, 136041),
+    ("America/Mendoza", 33881, 34957),
+    ("America/Menominee", 136041, 138315),
+    ("America/Merida", 138315, 139319),
+    ("America/Metlakatla", 139319, 140742),
+    ("America/Mexico_City", 140742, 141964),
+    ("America/Miquelon", 141964, 143630),
+    ("America/Moncton", 143630, 146784),
+    ("America/Monterrey", 146784, 147898),
+    ("America/Montevideo", 147898, 149408),
+    ("America/Montreal", 149408, 152902),
+    ("America/Montserrat", 152902, 153050),
+    ("America/Nassau", 153050, 155438),
+    ("America/New_York", 155438, 158990),
+    ("America/Nipigon", 149408, 152902),
+    ("America/Nome", 158990, 161357),
+    ("America/Noronha", 161357, 162073),
+    ("America/North_Dakota/Beulah", 162073, 164469),
+    ("America/North_Dakota/Center", 164469, 166865),
+    ("America/North_Dakota/New_Salem", 166865, 169261),
+    ("America/Nuuk", 88555, 90458),
+    ("America/Ojinaga", 169261, 170785),
+    ("America/Panama", 63407, 63589),
+    ("America/Pangnirtung", 117624, 119826),
+    ("America/Paramaribo", 170785, 171047),
+    ("America/Phoenix", 171047, 171407),
+    ("America/Port-au-Prince", 171407, 172841),
+    ("America/Port_of_Spain", 172841, 172989),
+    ("America/Porto_Acre", 172989, 173617),
+    ("America/Porto_Velho", 173617, 174193),
+    ("America/Puerto_Rico", 127817, 128063),
+    ("America/Punta_Arenas", 174193, 176109),
+    ("America/Rainy_River", 176109, 178977),
+    ("America/Rankin_Inlet", 178977, 181043),
+    ("America/Recife", 181043, 181759),
+    ("America/Regina", 181759, 182739),
+    ("America/Resolute", 182739, 184805),
+    ("America/Rio_Branco", 172989, 173617),
+    ("America/Rosario", 30667, 31743),
+    ("America/Santa_Isabel", 79267, 81725),
+    ("America/Santarem", 184805, 185407),
+    ("America/Santiago", 185407, 187936),
+    ("America/Santo_Domingo", 187936, 188394),
+    ("America/Sao_Paulo", 188394, 189838),
+    ("America/Scoresbysund", 189838, 191787),
+    ("America/Shiprock", 71217, 73677),
+    ("America/Sitka", 191787, 194116),
+    ("America/St_Barthelemy", 127817, 128063),
+    ("America/St_Johns", 194116, 197771),
+    ("America/St_Kitts", 197771, 197919),
+    ("America/St_Lucia", 197919, 198101),
+    ("America/St_Thomas", 198101, 198249),
+    ("America/St_Vincent", 198249, 198431),
+    ("America/Swift_Current", 198431, 198991),
+    ("America/Tegucigalpa", 198991, 199243),
+    ("America/Thule", 199243, 200745),
+    ("America/Thunder_Bay", 149408, 152902),
+    ("America/Tijuana", 79267, 81725),
+    ("America/Toronto", 149408, 152902),
+    ("America/Tortola", 200745, 200893),
+    ("America/Vancouver", 200893, 203785),
+    ("America/Virgin", 127817, 128063),
+    ("America/Whitehorse", 203785, 205399),
+    ("America/Winnipeg", 176109, 178977),
+    ("America/Yakutat", 205399, 207704),
+    ("America/Yellowknife", 76055, 78387),
+    ("Antarctica/Casey", 207704, 208141),
+    ("Antarctica/Davis", 208141, 208438),
+    ("Antarctica/DumontDUrville", 208438, 208632),
+    ("Antarctica/Macquarie", 208632, 210892),
+    ("Antarctica/Mawson", 210892, 211091),
+    ("Antarctica/McMurdo", 211091, 213084),
+    ("Antarctica/Palmer", 213084, 214502),
+    ("Antarctica/Rothera", 214502, 214666),
+    ("Antarctica/South_Pole", 214666, 217103),
+    ("Antarctica/Syowa", 217103, 217268),
+    ("Antarctica/Troll", 217268, 218430),
+    ("Antarctica/Vostok", 218430, 218657),
+    ("Arctic/Longyearbyen", 218657, 220955),
+    ("Asia/Aden", 220955, 221120),
+    ("Asia/Almaty", 221120, 222117),
+    ("Asia/Amman", 222117, 223564),
+    ("Asia/Anadyr", 223564, 224752),
+    ("Asia/Aqtau", 224752, 225735),
+    ("Asia/Aqtobe", 225735, 226746),
+    ("Asia/Ashgabat", 226746, 227365),
+    ("Asia/Ashkhabad", 226746, 227365),
+    ("Asia/Atyrau", 227365, 228356),
+    ("Asia/Baghdad", 228356, 229339),
+    ("Asia/Bahrain", 229339, 229576),
+    ("Asia/Baku", 229576, 230803),
+    ("Asia/Bangkok", 230803, 231002),
+    ("Asia/Barnaul", 231002, 232223),
+    ("Asia/Beirut", 232223, 234377),
+    ("Asia/Bishkek", 234377, 235360),
+    ("Asia/Brunei", 235360, 235563),
+    ("Asia/Calcutta", 235563, 235848),
+    ("Asia/Chita", 235848, 237069),
+    ("Asia/Choibalsan", 237069, 237960),
+    ("Asia/Chongqing", 237960, 238521),
+    ("Asia/Chungking", 237960, 238521),
+    ("Asia/Colombo", 238521, 238893),
+    ("Asia/Dacca", 238893, 239230),
+    ("Asia/Damascus", 239230, 241117),
+    ("Asia/Dhaka", 238893, 239230),
+    ("Asia/Dili", 241117, 241388),
+    ("Asia/Dubai", 241388, 241553),
+    ("Asia/Dushanbe", 241553, 242144),
+    ("Asia/Famagusta", 242144, 244172),
+    ("Asia/Gaza", 244172, 248016),
+    ("Asia/Harbin", 237960, 238521),
+    ("Asia/Hebron", 248016, 251888),
+    ("Asia/Ho_Chi_Minh", 251888, 252239),
+    ("Asia/Hong_Kong", 252239, 253472),
+    ("Asia/Hovd", 253472, 254363),
+    ("Asia/Irkutsk", 254363, 255606),
+    ("Asia/Istanbul", 255606, 257553),
+    ("Asia/Jakarta", 257553, 257936),
+    ("Asia/Jayapura", 257936, 258157),
+    ("Asia/Jerusalem", 258157, 260545),
+    ("Asia/Kabul", 260545, 260753),
+    ("Asia/Kamchatka", 260753, 261919),
+    ("Asia/Karachi", 261919, 262298),
+    ("Asia/Kashgar", 262298, 262463),
+    ("Asia/Kathmandu", 262463, 262675),
+    ("Asia/Katmandu", 262463, 262675),
+    ("Asia/Khandyga", 262675, 263946),
+    ("Asia/Kolkata", 235563, 235848),
+    ("Asia/Krasnoyarsk", 263946, 265153),
+    ("Asia/Kuala_Lumpur", 265153, 265568),
+    ("Asia/Kuching", 265568, 266051),
+    ("Asia/Kuwait", 266051, 266216),
+    ("Asia/Macao", 266216, 267443),
+    ("Asia/Macau", 266216, 267443),
+    ("Asia/Magadan", 267443, 268665),
+    ("Asia/Makassar", 268665, 268919),
+    ("Asia/Manila", 268919, 269341),
+    ("Asia/Muscat", 269341, 269506),
+    ("Asia/Nicosia", 269506, 271508),
+    ("Asia/Novokuznetsk", 271508, 272673),
+    ("Asia/Novosibirsk", 272673, 273894),
+    ("Asia/Omsk", 273894, 275101),
+    ("Asia/Oral", 275101, 276106),
+    ("Asia/Phnom_Penh", 276106, 276401),
+    ("Asia/Pontianak", 276401, 276754),
+    ("Asia/Pyongyang", 276754, 276991),
+    ("Asia/Qatar", 276991, 277190),
+    ("Asia/Qostanay", 277190, 278229),
+    ("Asia/Qyzylorda", 278229, 279254),
+    ("Asia/Rangoon", 279254, 279522),
+    ("Asia/Riyadh", 279522, 279687),
+    ("Asia/Saigon", 251888, 252239),
+    ("Asia/Sakhalin", 279687, 280889),
+    ("Asia/Samarkand", 280889, 281466),
+    ("Asia/Seoul", 281466, 282083),
+    ("Asia/Shanghai", 237960, 238521),
+    ("Asia/Singapore", 282083, 282498),
+    ("Asia/Srednekolymsk", 282498, 283706),
+    ("Asia/Taipei", 283706, 284467),
+    ("Asia/Tashkent", 284467, 285058),
+    ("Asia/Tbilisi", 285058, 286093),
+    ("Asia/Tehran", 286093, 287355),
+    ("Asia/Tel_Aviv", 258157, 260545),
+    ("Asia/Thimbu", 287355, 287558),
+    ("Asia/Thimphu", 287355, 287558),
+    ("Asia/Tokyo", 287558, 287867),
+    ("Asia/Tomsk", 287867, 289088),
+    ("Asia/Ujung_Pandang", 268665, 268919),
+    ("Asia/Ulaanbaatar", 237069, 237960),
+    ("Asia/Ulan_Bator", 237069, 237960),
+    ("Asia/Urumqi", 262298, 262463),
+    ("Asia/Ust-Nera", 289088, 290340),
+    ("Asia/Vientiane", 290340, 290663),
+    ("Asia/Vladivostok", 290663, 291871),
+    ("Asia/Yakutsk", 291871, 293078),
+    ("Asia/Yangon", 279254, 279522),
+    ("Asia/Yekaterinburg", 293078, 294321),
+    ("Asia/Yerevan", 294321, 295472),
+    ("Atlantic/Azores", 295472, 298928),
+    ("Atlantic/Bermuda", 298928, 301324),
+    ("Atlantic/Canary", 301324, 303221),
+    ("Atlantic/Cape_Verde", 303221, 303491),
+    ("Atlantic/Faeroe", 303491, 305306),
+    ("Atlantic/Faroe", 303491, 305306),
+    ("Atlantic/Jan_Mayen", 218657, 220955),
+    ("Atlantic/Madeira", 305306, 308683),
+    ("Atlantic/Reykjavik", 308683, 309845),
+    ("Atlantic/South_Georgia", 309845, 310009),
+    ("Atlantic/St_Helena", 310009, 310191),
+    ("Atlantic/Stanley", 310191, 311405),
+    ("Australia/ACT", 311405, 313595),
+    ("Australia/Adelaide", 313595, 315803),
+    ("Australia/Brisbane", 315803, 316222),
+    ("Australia/Broken_Hill", 316222, 318451),
+    ("Australia/Canberra", 311405, 313595),
+    ("Australia/Currie", 318451, 320809),
+    ("Australia/Darwin", 320809, 321134),
+    ("Australia/Eucla", 321134, 321604),
+    ("Australia/Hobart", 318451, 320809),
+    ("Australia/LHI", 321604, 323464),
+    ("Australia/Lindeman", 323464, 323939),
+    ("Australia/Lord_Howe", 321604, 323464),
+    ("Australia/Melbourne", 323939, 326129),
+    ("Australia/NSW", 311405, 313595),
+    ("Australia/North", 320809, 321134),
+    ("Australia/Perth", 326129, 326575),
+    ("Australia/Queensland", 315803, 316222),
+    ("Australia/South", 313595, 315803),
+    ("Australia/Sydney", 311405, 313595),
+    ("Australia/Tasmania", 318451, 320809),
+    ("Australia/Victoria", 323939, 326129),
+    ("Australia/West", 326129, 326575),
+    ("Australia/Yancowinna", 316222, 318451),
+    ("Brazil/Acre", 172989, 173617),
+    ("Brazil/DeNoronha", 161357, 162073),
+    ("Brazil/East", 188394, 189838),
+    ("Brazil/West", 132727, 133331),
+    ("CET", 326575, 328669),
+    ("CST6CDT", 328669, 330979),
+    ("Canada/Atlantic", 96586, 100010),
+    ("Canada/Central", 176109, 178977),
+    ("Canada/Eastern", 149408, 152902),
+    ("Canada/Mountain", 76055, 78387),
+    ("Canada/Newfoundland", 194116, 197771),
+    ("Canada/Pacific", 200893, 203785),
+    ("Canada/Saskatchewan", 181759, 182739),
+    ("Canada/Yukon", 203785, 205399),
+    ("Chile/Continental", 185407, 187936),
+    ("Chile/EasterIsland", 330979, 333212),
+    ("Cuba", 100010, 102426),
+    ("EET", 333212, 335120),
+    ("EST", 335120, 335234),
+    ("EST5EDT", 335234, 337544),
+    ("Egypt", 3871, 6270),
+    ("Eire", 337544, 341036),
+    ("Etc/GMT", 341036, 341150),
+    ("Etc/GMT+0", 341036, 341150),
+    ("Etc/GMT+1", 341150, 341266),
+    ("Etc/GMT+10", 341266, 341383),
+    ("Etc/GMT+11", 341383, 341500),
+    ("Etc/GMT+12", 341500, 341617),
+    ("Etc/GMT+2", 341617, 341733),
+    ("Etc/GMT+3", 341733, 341849),
+    ("Etc/GMT+4", 341849, 341965),
+    ("Etc/GMT+5", 341965, 342081),
+    ("Etc/GMT+6", 342081, 342197),
+    ("Etc/GMT+7", 342197, 342313),
+    ("Etc/GMT+8", 342313, 342429),
+    ("Etc/GMT+9", 342429, 342545),
+    ("Etc/GMT-0", 341036, 341150),
+    ("Etc/GMT-1", 342545, 342662),
+    ("Etc/GMT-10", 342662, 342780),
+    ("Etc/GMT-11", 342780, 342898),
+    ("Etc/GMT-12", 342898, 343016),
+    ("Etc/GMT-13", 343016, 343134),
+    ("Etc/GMT-14", 343134, 343252),
+    ("Etc/GMT-2", 343252, 343369),
+    ("Etc/GMT-3", 343369, 343486),
+    ("Etc/GMT-4", 343486, 343603),
+    ("Etc/GMT-5", 343603, 343720),
+    ("Etc/GMT-6", 343720, 343837),
+    ("Etc/GMT-7", 343837, 343954),
+    ("Etc/GMT-8", 343954, 344071),
+    ("Etc/GMT-9", 344071, 344188),
+    ("Etc/GMT0", 341036, 341150),
+    ("Etc/Greenwich", 341036, 341150),
+    ("Etc/UCT", 344188, 344302),
+    ("Etc/UTC", 344188, 344302),
+    ("Etc/Universal", 344188, 344302),
+    ("Etc/Zulu", 344188, 344302),
+    ("Europe/Amsterdam", 344302, 347212),
+    ("Europe/Andorra", 347212, 348954),
+    ("Europe/Astrakhan", 348954, 350119),
+    ("Europe/Athens", 350119, 352381),
+    ("Europe/Belfast", 352381, 356045),
+    ("Europe/Belgrade", 356045, 357965),
+    ("Europe/Berlin", 218657, 220955),
+    ("Europe/Bratislava", 357965, 360266),
+    ("Europe/Brussels", 360266, 363199),
+    ("Europe/Bucharest", 363199, 365383),
+    ("Europe/Budapest", 365383, 367751),
+    ("Europe/Busingen", 367751, 369660),
+    ("Europe/Chisinau", 369660, 372050),
+    ("Europe/Copenhagen", 372050, 374187),
+    ("Europe/Dublin", 337544, 341036),
+    ("Europe/Gibraltar", 374187, 377255),
+    ("Europe/Guernsey", 377255, 380987),
+    ("Europe/Helsinki", 380987, 382887),
+    ("Europe/Isle_of_Man", 382887, 386535),
+    ("Europe/Istanbul", 255606, 257553),
+    ("Europe/Jersey", 386535, 390267),
+    ("Europe/Kaliningrad", 390267, 391760),
+    ("Europe/Kiev", 391760, 393880),
+    ("Europe/Kirov", 393880, 395065),
+    ("Europe/Kyiv", 391760, 393880),
+    ("Europe/Lisbon", 395065, 398592),
+    ("Europe/Ljubljana", 398592, 400512),
+    ("Europe/London", 352381, 356045),
+    ("Europe/Luxembourg", 400512, 403458),
+    ("Europe/Madrid", 403458, 406072),
+    ("Europe/Malta", 406072, 408692),
+    ("Europe/Mariehamn", 380987, 382887),
+    ("Europe/Minsk", 408692, 410013),
+    ("Europe/Monaco", 410013, 412957),
+    ("Europe/Moscow", 412957, 414492),
+    ("Europe/Nicosia", 269506, 271508),
+    ("Europe/Oslo", 414492, 416720),
+    ("Europe/Paris", 416720, 419682),
+    ("Europe/Podgorica", 356045, 357965),
+    ("Europe/Prague", 357965, 360266),
+    ("Europe/Riga", 419682, 421880),
+    ("Europe/Rome", 421880, 424521),
+    ("Europe/Samara", 424521, 425736),
+    ("Europe/San_Marino", 421880, 424521),
+    ("Europe/Sarajevo", 425736, 427656),
+    ("Europe/Saratov", 427656, 428839),
+    ("Europe/Simferopol", 428839, 430308),
+    ("Europe/Skopje", 430308, 432228),
+    ("Europe/Sofia", 432228, 434305),
+    ("Europe/Stockholm", 434305, 436214),
+    ("Europe/Tallinn", 436214, 438362),
+    ("Europe/Tirane", 438362, 440446),
+    ("Europe/Tiraspol", 369660, 372050),
+    ("Europe/Ulyanovsk", 440446, 441713),
+    ("Europe/Uzhgorod", 391760, 393880),
+    ("Europe/Vaduz", 441713, 443601),
+    ("Europe/Vatican", 421880, 424521),
+    ("Europe/Vienna", 443601, 445801),
+    ("Europe/Vilnius", 445801, 447963),
+    ("Europe/Volgograd", 447963, 449156),
+    ("Europe/Warsaw", 449156, 451810),
+    ("Europe/Zagreb", 451810, 453730),
+    ("Europe/Zaporozhye", 391760, 393880),
+    ("Europe/Zurich", 367751, 369660),
+    ("GB", 352381, 356045),
+    ("GB-Eire", 352381, 356045),
+    ("GMT", 341036, 341150),
+    ("GMT+0", 341036, 341150),
+    ("GMT-0", 341036, 341150),
+    ("GMT0", 341036, 341150),
+    ("Greenwich", 341036, 341150),
+    ("HST", 453730, 453845),
+    ("Hongkong", 252239, 253472),
+    ("Iceland", 0, 148),
+    ("Indian/Antananarivo", 453845, 454064),
+    ("Indian/Chagos", 454064, 454263),
+    ("Indian/Christmas", 454263, 454428),
+    ("Indian/Cocos", 454428, 454602),
+    ("Indian/Comoro", 454602, 454751),
+    ("Indian/Kerguelen", 454751, 454916),
+    ("Indian/Mahe", 454916, 455081),
+    ("Indian/Maldives", 455081, 455280),
+    ("Indian/Mauritius", 455280, 455521),
+    ("Indian/Mayotte", 455521, 455670),
+    ("Indian/Reunion", 455670, 455835),
+    ("Iran", 286093, 287355),
+    ("Israel", 258157, 260545),
+    ("Jamaica", 119826, 120308),
+    ("Japan", 287558, 287867),
+    ("Kwajalein", 455835, 456151),
+    ("Libya", 20305, 20930),
+    ("MET", 456151, 458245),
+    ("MST", 458245, 458359),
+    ("MST7MDT", 458359, 460669),
+    ("Mexico/BajaNorte", 79267, 81725),
+    ("Mexico/BajaSur", 134981, 136041),
+    ("Mexico/General", 140742, 141964),
+    ("NZ", 214666, 217103),
+    ("NZ-CHAT", 460669, 462737),
+    ("Navajo", 71217, 73677),
+    ("PRC", 237960, 238521),
+    ("PST8PDT", 462737, 465047),
+    ("Pacific/Apia", 465047, 465659),
+    ("Pacific/Auckland", 214666, 217103),
+    ("Pacific/Bougainville", 465659, 465927),
+    ("Pacific/Chatham", 460669, 462737),
+    ("Pacific/Chuuk", 465927, 466196),
+    ("Pacific/Easter", 330979, 333212),
+    ("Pacific/Efate", 466196, 466734),
+    ("Pacific/Enderbury", 466734, 466968),
+    ("Pacific/Fakaofo", 466968, 467168),
+    ("Pacific/Fiji", 467168, 467746),
+    ("Pacific/Funafuti", 467746, 467912),
+    ("Pacific/Galapagos", 467912, 468150),
+    ("Pacific/Gambier", 468150, 468314),
+    ("Pacific/Guadalcanal", 468314, 468480),
+    ("Pacific/Guam", 468480, 468974),
+    ("Pacific/Honolulu", 468974, 469303),
+    ("Pacific/Johnston", 468974, 469303),
+    ("Pacific/Kanton", 466734, 466968),
+    ("Pacific/Kiritimati", 469303, 469541),
+    ("Pacific/Kosrae", 469541, 469892),
+    ("Pacific/Kwajalein", 455835, 456151),
+    ("Pacific/Majuro", 469892, 470202),
+    ("Pacific/Marquesas", 470202, 470375),
+    ("Pacific/Midway", 470375, 470592),
+    ("Pacific/Nauru", 470592, 470844),
+    ("Pacific/Niue", 470844, 471047),
+    ("Pacific/Norfolk", 471047, 471927),
+    ("Pacific/Noumea", 471927, 472231),
+    ("Pacific/Pago_Pago", 472231, 472406),
+    ("Pacific/Palau", 472406, 472586),
+    ("Pacific/Pitcairn", 472586, 472788),
+    ("Pacific/Pohnpei", 472788, 473091),
+    ("Pacific/Ponape", 468314, 468480),
+    ("Pacific/Port_Moresby", 473091, 473277),
+    ("Pacific/Rarotonga", 473277, 473880),
+    ("Pacific/Saipan", 473880, 474360),
+    ("Pacific/Samoa", 472231, 472406),
+    ("Pacific/Tahiti", 474360, 474525),
+    ("Pacific/Tarawa", 474525, 474691),
+    ("Pacific/Tongatapu", 474691, 475063),
+    ("Pacific/Truk", 473091, 473277),
+    ("Pacific/Wake", 475063, 475229),
+    ("Pacific/Wallis", 475229, 475395),
+    ("Pacific/Yap", 473091, 473277),
+    ("Poland", 449156, 451810),
+    ("Portugal", 395065, 398592),
+    ("ROC", 283706, 284467),
+    ("ROK", 281466, 282083),
+    ("Singapore", 282083, 282498),
+    ("Turkey", 255606, 257553),
+    ("UCT", 344188, 344302),
+    ("US/Alaska", 24930, 27301),
+    ("US/Aleutian", 22574, 24930),
+    ("US/Arizona", 171047, 171407),
+    ("US/Central", 57175, 60767),
+    ("US/East-Indiana", 83965, 85647),
+    ("US/Eastern", 155438, 158990),
+    ("US/Hawaii", 468974, 469303),
+    ("US/Indiana-Starke", 102814, 105258),
+    ("US/Michigan", 73677, 75907),
+    ("US/Mountain", 71217, 73677),
+    ("US/Pacific", 128701, 131553),
+    ("US/Samoa", 472231, 472406),
+    ("UTC", 344188, 344302),
+    ("Universal", 344188, 344302),
+    ("W-SU", 412957, 414492),
+    ("WET", 475395, 477300),
+    ("Zulu", 344188, 344302),
+];
@@ -0,0 +1,198 @@
+//! Memoized offset lookups for converting many timestamps in one zone.
+
+use super::{OffsetNanoTime, TimeZone, UtcOffset};
+use crate::NanoTime;
+
+/// Days remembered at once, indexed by day number modulo this.
+const SLOTS: usize = 64;
+
+/// The offsets in effect over one UTC day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Day {
+    /// One offset all day.
+    Fixed(UtcOffset),
+    /// `first` until the transition at Unix second `at`, then `then`.
+    Switch {
+        first: UtcOffset,
+        at: i64,
+        then: UtcOffset,
+    },
+    /// More than one transition; looked up uncached.
+    Mixed,
+}
+
+/// A [`TimeZone`] that remembers the offsets of recently seen UTC days.
+///
+/// Looking up an offset evaluates the zone's DST rule for the year. Most
+/// days have a single offset, so after the first timestamp of a day, the
+/// rest convert with a comparison, which speeds up bulk conversion of
+/// logs and columns where timestamps cluster by day. Days holding a DST
+/// transition cache where it falls, so results always match
+/// [`TimeZone::offset_at`].
+///
+/// ```rust
+/// use nanotime::tz::{TimeZone, TzCache};
+/// use nanotime::NanoTime;
+///
+/// let zone = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+/// let mut cache = TzCache::new(zone.clone());
+/// let t = NanoTime::new(2026, 7, 4, 16, 0, 0, 0).unwrap();
+/// assert_eq!(cache.to_local(&t), zone.to_local(&t));
+/// assert_eq!(cache.to_local(&t).to_string(), "2026-07-04 12:00:00.000 -04:00");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TzCache {
+    zone: TimeZone,
+    slots: Vec<Option<(i64, Day)>>,
+}
+
+impl TzCache {
+    /// Creates an empty cache for `zone`.
+    pub fn new(zone: TimeZone) -> Self {
+        TzCache {
+            zone,
+            slots: vec![None; SLOTS],
+        }
+    }
+
+    /// Returns the zone.
+    pub fn zone(&self) -> &TimeZone {
+        &self.zone
+    }
+
+    /// Returns the UTC offset in effect at the instant `utc`.
+    pub fn offset_at(&mut self, utc: &NanoTime) -> UtcOffset {
+        let secs = utc.unix_secs();
+        let day = secs.div_euclid(86_400);
+        let slot = &mut self.slots[day.rem_euclid(SLOTS as i64) as usize];
+        let entry = match *slot {
+            Some((cached, entry)) if cached == day => entry,
+            _ => {
+                let entry = day_offsets(&self.zone, day);
+                *slot = Some((day, entry));
+                entry
+            }
+        };
+        match entry {
+            Day::Fixed(offset) => offset,
+            Day::Switch { first, at, then } => {
+                if secs < at {
+                    first
+                } else {
+                    then
+                }
+            }
+            Day::Mixed => self.zone.offset_at(utc),
+        }
+    }
+
+    /// Converts a UTC instant to local time in the zone.
+    pub fn to_local(&mut self, utc: &NanoTime) -> OffsetNanoTime {
+        OffsetNanoTime::from_utc(utc, self.offset_at(utc))
+    }
+}
+
+/// Works out the offsets over UTC day number `day`.
+fn day_offsets(zone: &TimeZone, day: i64) -> Day {
+    let (start, end) = (day * 86_400, (day + 1) * 86_400);
+    let offset = |secs: i64| zone.type_at(secs).offset;
+    // Offsets can only change at a listed historical transition, at the
+    // rule's transitions, or where the local year turns over, since the
+    // rule is evaluated per local year.
+    let from = zone.history.partition_point(|&(at, _)| at <= start);
+    let mut changes: Vec<i64> = zone.history[from..]
+        .iter()
+        .map(|&(at, _)| at)
+        .take_while(|&at| at < end)
+        .collect();
+    if let Some(rule) = &zone.dst {
+        let std_off = zone.std.offset.secs as i64;
+        let year = crate::civil_from_days(day).0;
+        changes.extend((year - 1..=year + 1).flat_map(|y| {
+            let [a, b] = zone.transitions(rule, y);
+            let new_year = crate::days_from_civil(y, 1, 1) * 86_400 - std_off;
+            [a, b, new_year]
+        }));
+    }
+    changes.retain(|&t| start < t && t < end && offset(t - 1) != offset(t));
+    changes.sort_unstable();
+    changes.dedup();
+    match changes[..] {
+        [] => Day::Fixed(offset(start)),
+        [at] => Day::Switch {
+            first: offset(start),
+            at,
+            then: offset(at),
+        },
+        _ => Day::Mixed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_zone_across_transitions() {
+        let zones = [
+            "EST5EDT,M3.2.0,M11.1.0",
+            "AEST-10AEDT,M10.1.0,M4.1.0/3",
+            "<+0530>-5:30",
+            "XXX3YYY,0/0,J365/25",
+        ];
+        for rule in zones {
+            let zone = TimeZone::from_posix(rule).unwrap();
+            let mut cache = TzCache::new(zone.clone());
+            let start = NanoTime::new(2025, 12, 20, 0, 0, 0, 0).unwrap().unix_secs();
+            // Every 15 minutes for over a year, twice, to hit cached days.
+            for pass in 0..2 {
+                for i in 0..(400 * 96) {
+                    let t = NanoTime::from_unix(start + i * 900 + pass, 0);
+                    assert_eq!(cache.offset_at(&t), zone.offset_at(&t), "{} {}", rule, t);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_zone_with_history() {
+        let day = |y, m, d| NanoTime::new(y, m, d, 0, 0, 0, 0).unwrap().unix_secs();
+        let data = crate::tz::tzif::tests::encode(
+            &[
+                (day(2024, 6, 1) + 3_600, 1),
+                (day(2024, 6, 1) + 7_200, 0),
+                (day(2025, 1, 1) - 1, 1),
+            ],
+            &[(0, false, "AAA"), (5_400, true, "BBB")],
+            "EST5EDT,M3.2.0,M11.1.0",
+        );
+        let zone = TimeZone::from_tzif(&data).unwrap();
+        let mut cache = TzCache::new(zone.clone());
+        for i in 0..(600 * 96) {
+            let t = NanoTime::from_unix(day(2024, 3, 1) + i * 900 + 1, 0);
+            assert_eq!(cache.offset_at(&t), zone.offset_at(&t), "{}", t);
+        }
+        assert_eq!(day_offsets(&zone, day(2024, 6, 1) / 86_400), Day::Mixed);
+    }
+
+    #[test]
+    fn test_switch_day() {
+        let zone = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        // 2026-03-08 07:00 UTC is 02:00 EST, when DST starts.
+        let day = NanoTime::new(2026, 3, 8, 0, 0, 0, 0).unwrap().unix_secs() / 86_400;
+        let at = NanoTime::new(2026, 3, 8, 7, 0, 0, 0).unwrap().unix_secs();
+        let (est, edt) = (
+            UtcOffset::from_minutes(-300).unwrap(),
+            UtcOffset::from_minutes(-240).unwrap(),
+        );
+        assert_eq!(
+            day_offsets(&zone, day),
+            Day::Switch {
+                first: est,
+                at,
+                then: edt
+            }
+        );
+        assert_eq!(day_offsets(&zone, day + 1), Day::Fixed(edt));
+    }
+}
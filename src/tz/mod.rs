@@ -0,0 +1,1224 @@
+//! Time zones, UTC offsets, and offset-aware timestamps.
+//!
+//! A [`TimeZone`] maps UTC instants to local wall-clock time. Zones are
+//! described by POSIX `TZ` rules (e.g. `"EST5EDT,M3.2.0,M11.1.0"`), which
+//! cover fixed offsets as well as yearly daylight saving transitions.
+//!
+//! Going from an instant to local time is always unambiguous. Going the other
+//! way is not: during a fall-back transition a local time occurs twice, and
+//! during a spring-forward transition it never occurs at all. [`TimeZone::from_local`]
+//! reports which case applies via [`LocalResult`], and [`TimeZone::resolve`]
+//! applies an explicit [`Resolution`] policy instead of silently guessing.
+//!
+//! ```rust
+//! use nanotime::NanoTime;
+//! use nanotime::tz::{Resolution, TimeZone};
+//!
+//! let zone = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+//!
+//! // 02:30 on 2026-03-08 is skipped by the spring-forward transition.
+//! let skipped = NanoTime::new(2026, 3, 8, 2, 30, 0, 0).unwrap();
+//! assert!(zone.resolve(&skipped, Resolution::Reject).is_err());
+//!
+//! let shifted = zone.resolve(&skipped, Resolution::Shift).unwrap();
+//! assert_eq!(shifted.to_string(), "2026-03-08 03:30:00.000 -04:00");
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::NanoTime;
+
+#[cfg(feature = "bundled-tz")]
+mod bundled;
+mod cache;
+mod tzif;
+mod windows;
+mod world;
+#[cfg(unix)]
+mod zoneinfo;
+
+#[cfg(windows)]
+use windows as sys;
+#[cfg(unix)]
+use zoneinfo as sys;
+
+#[cfg(feature = "bundled-tz")]
+pub use bundled::TZDATA_VERSION;
+pub use cache::TzCache;
+pub use windows::{iana_to_windows, windows_to_iana};
+pub use world::{world_clock, WorldClock};
+
+/// A fixed offset from UTC, stored as seconds east of Greenwich.
+///
+/// Displays as `+HH:MM`, or `+HH:MM:SS` when the offset has a seconds part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcOffset {
+    secs: i32,
+}
+
+impl UtcOffset {
+    /// The zero offset.
+    pub const UTC: UtcOffset = UtcOffset { secs: 0 };
+
+    /// Creates an offset from seconds east of UTC.
+    ///
+    /// Returns `None` unless the offset is strictly within ±24 hours.
+    pub fn from_secs(secs: i32) -> Option<Self> {
+        if secs.abs() >= 86_400 {
+            return None;
+        }
+        Some(Self { secs })
+    }
+
+    /// Creates an offset from minutes east of UTC (e.g. `330` for `+05:30`).
+    pub fn from_minutes(minutes: i16) -> Option<Self> {
+        Self::from_secs(minutes as i32 * 60)
+    }
+
+    /// Returns the offset in seconds east of UTC.
+    pub fn as_secs(&self) -> i32 {
+        self.secs
+    }
+
+    /// Returns the offset in whole minutes east of UTC, truncating any
+    /// seconds part toward zero.
+    pub fn as_minutes(&self) -> i16 {
+        (self.secs / 60) as i16
+    }
+
+    /// Returns `true` for the zero offset.
+    pub fn is_utc(&self) -> bool {
+        self.secs == 0
+    }
+}
+
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.secs < 0 { '-' } else { '+' };
+        let abs = self.secs.unsigned_abs();
+        write!(f, "{}{:02}:{:02}", sign, abs / 3600, (abs % 3600) / 60)?;
+        if !abs.is_multiple_of(60) {
+            write!(f, ":{:02}", abs % 60)?;
+        }
+        Ok(())
+    }
+}
+
+/// A local wall-clock time paired with the UTC offset in effect.
+///
+/// Equality and hashing are structural: two values describing the same
+/// instant with different offsets are not equal. Use
+/// [`same_instant`](Self::same_instant) and
+/// [`cmp_instant`](Self::cmp_instant) to compare instants.
+///
+/// ```rust
+/// use nanotime::{NanoTime, OffsetNanoTime, UtcOffset};
+///
+/// let utc = NanoTime::new(2026, 2, 22, 14, 30, 0, 0).unwrap();
+/// let india = OffsetNanoTime::from_utc(&utc, UtcOffset::from_minutes(330).unwrap());
+/// let london = india.to_offset(UtcOffset::UTC);
+/// assert_eq!(india.local().datetime(), "2026-02-22 20:00:00.000");
+/// assert!(india != london && india.same_instant(&london));
+/// ```
+///
+/// Displays as `YYYY-MM-DD HH:MM:SS.mmm +HH:MM`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OffsetNanoTime {
+    local: NanoTime,
+    offset: UtcOffset,
+}
+
+impl OffsetNanoTime {
+    /// Pairs a local wall-clock time with its offset from UTC.
+    pub fn new(local: NanoTime, offset: UtcOffset) -> Self {
+        Self { local, offset }
+    }
+
+    /// Converts a UTC time to local time at the given offset.
+    ///
+    /// A local time past either end of years 0-65535 is clamped to the
+    /// nearest representable one.
+    pub fn from_utc(utc: &NanoTime, offset: UtcOffset) -> Self {
+        let local =
+            NanoTime::saturating_from_unix(utc.unix_secs() + offset.secs as i64, utc.nanosecond());
+        Self { local, offset }
+    }
+
+    /// Returns the current local time with the host's offset from UTC, the
+    /// zoned form of [`NanoTime::now`].
+    ///
+    /// ```rust
+    /// use nanotime::OffsetNanoTime;
+    ///
+    /// let now = OffsetNanoTime::now();
+    /// println!("{} is {} minutes from UTC", now, now.offset().as_minutes());
+    /// ```
+    pub fn now() -> Self {
+        let (local, utc) = NanoTime::now_pair();
+        let secs = local.unix_secs() - utc.unix_secs();
+        let offset = i32::try_from(secs)
+            .ok()
+            .and_then(UtcOffset::from_secs)
+            .unwrap_or(UtcOffset::UTC);
+        Self { local, offset }
+    }
+
+    /// Returns the current time in `zone`, with the offset in effect now.
+    pub fn now_in(zone: &TimeZone) -> Self {
+        zone.to_local(&NanoTime::now_utc())
+    }
+
+    /// Returns the local wall-clock time.
+    pub fn local(&self) -> NanoTime {
+        self.local
+    }
+
+    /// Returns the UTC offset.
+    pub fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Returns the same instant expressed in UTC.
+    ///
+    /// Near the ends of the supported range the instant can fall outside
+    /// years 0-65535, e.g. `0000-01-01 00:00 +01:00`; it is then clamped to
+    /// the nearest representable time. Use
+    /// [`checked_to_utc`](Self::checked_to_utc) to detect this.
+    pub fn to_utc(&self) -> NanoTime {
+        NanoTime::saturating_from_unix(
+            self.local.unix_secs() - self.offset.secs as i64,
+            self.local.nanosecond(),
+        )
+    }
+
+    /// Returns the same instant expressed in UTC, or `None` if it falls
+    /// outside years 0-65535.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, OffsetNanoTime, UtcOffset};
+    ///
+    /// let first = NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap();
+    /// let east = UtcOffset::from_minutes(60).unwrap();
+    /// assert_eq!(OffsetNanoTime::new(first, east).checked_to_utc(), None);
+    /// assert_eq!(OffsetNanoTime::new(first, east).to_utc(), first);
+    /// ```
+    pub fn checked_to_utc(&self) -> Option<NanoTime> {
+        NanoTime::checked_from_unix(
+            self.local.unix_secs() - self.offset.secs as i64,
+            self.local.nanosecond(),
+        )
+    }
+
+    /// Returns the same instant at another offset.
+    pub fn to_offset(&self, offset: UtcOffset) -> Self {
+        Self::from_utc(&self.to_utc(), offset)
+    }
+
+    /// Rounds an offset with a seconds part toward zero to whole minutes,
+    /// shifting the local time to keep the instant. Used by writers whose
+    /// formats cannot express offset seconds.
+    pub(crate) fn with_whole_minute_offset(&self) -> Self {
+        match UtcOffset::from_secs(self.offset.secs / 60 * 60) {
+            Some(offset) if offset != self.offset => self.to_offset(offset),
+            _ => *self,
+        }
+    }
+
+    /// Returns `true` if both denote the same instant, whatever their
+    /// offsets.
+    pub fn same_instant(&self, other: &OffsetNanoTime) -> bool {
+        self.cmp_instant(other) == Ordering::Equal
+    }
+
+    /// Orders by instant, ignoring offsets: `14:00 +01:00` is before
+    /// `13:30 +00:00`.
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use nanotime::{NanoTime, OffsetNanoTime, UtcOffset};
+    ///
+    /// let paris = OffsetNanoTime::new(
+    ///     NanoTime::new(2026, 2, 22, 14, 0, 0, 0).unwrap(),
+    ///     UtcOffset::from_minutes(60).unwrap(),
+    /// );
+    /// let london = OffsetNanoTime::new(NanoTime::new(2026, 2, 22, 13, 30, 0, 0).unwrap(), UtcOffset::UTC);
+    /// assert_eq!(paris.cmp_instant(&london), Ordering::Less);
+    /// ```
+    pub fn cmp_instant(&self, other: &OffsetNanoTime) -> Ordering {
+        let key = |t: &OffsetNanoTime| {
+            (
+                t.local.unix_secs() - t.offset.secs as i64,
+                t.local.nanosecond(),
+            )
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
+impl NanoTime {
+    /// Converts this UTC time to local time in `zone`.
+    ///
+    /// ```rust
+    /// use nanotime::tz::{Resolution, TimeZone};
+    /// use nanotime::NanoTime;
+    ///
+    /// let ny = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    /// let utc = NanoTime::new(2026, 7, 4, 16, 0, 0, 0).unwrap();
+    /// let local = utc.to_zone(&ny);
+    /// assert_eq!(local.to_string(), "2026-07-04 12:00:00.000 -04:00");
+    /// assert_eq!(local.local().from_zone(&ny, Resolution::Reject), Ok(utc));
+    /// ```
+    pub fn to_zone(&self, zone: &TimeZone) -> OffsetNanoTime {
+        zone.to_local(self)
+    }
+
+    /// Reads this as a wall-clock time in `zone` and returns the instant
+    /// in UTC, settling times that DST skips or repeats by `policy`.
+    ///
+    /// As with [`OffsetNanoTime::to_utc`], a wall-clock time whose instant
+    /// falls outside years 0-65535 is clamped to the nearest representable
+    /// time. Resolve it with [`TimeZone::resolve`] and call
+    /// [`OffsetNanoTime::checked_to_utc`] to detect this.
+    pub fn from_zone(
+        &self,
+        zone: &TimeZone,
+        policy: Resolution,
+    ) -> Result<NanoTime, LocalTimeError> {
+        zone.resolve(self, policy).map(|t| t.to_utc())
+    }
+}
+
+impl fmt::Display for OffsetNanoTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.local.datetime(), self.offset)
+    }
+}
+
+/// How to turn an ambiguous or skipped local time into a single instant.
+///
+/// On a spring-forward day in New York, 02:30 does not exist:
+///
+/// ```rust
+/// use nanotime::tz::{Resolution, TimeZone};
+/// use nanotime::NanoTime;
+///
+/// let ny = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+/// let skipped = NanoTime::new(2026, 3, 8, 2, 30, 0, 0).unwrap();
+/// let earliest = ny.resolve(&skipped, Resolution::Earliest).unwrap();
+/// assert_eq!(earliest.to_string(), "2026-03-08 01:30:00.000 -05:00");
+/// let latest = ny.resolve(&skipped, Resolution::Latest).unwrap();
+/// assert_eq!(latest.to_string(), "2026-03-08 03:30:00.000 -04:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resolution {
+    /// Ambiguous: the earlier instant. Skipped: the local time read with the
+    /// offset in force after the gap, which lands before it by the gap's
+    /// length (02:30 becomes 01:30).
+    Earliest,
+    /// Ambiguous: the later instant. Skipped: the local time read with the
+    /// offset in force before the gap, which lands after it by the gap's
+    /// length (02:30 becomes 03:30).
+    Latest,
+    /// Fail with a [`LocalTimeError`] for any local time that is not unique.
+    Reject,
+    /// Ambiguous: the earlier instant. Skipped: move forward by the length of
+    /// the gap (02:30 becomes 03:30). Matches RFC 5545 and `java.time`.
+    Shift,
+}
+
+/// The outcome of mapping a local wall-clock time onto a time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalResult {
+    /// The local time occurs exactly once.
+    Single(OffsetNanoTime),
+    /// The local time occurs twice, typically during a fall-back transition.
+    Ambiguous {
+        earliest: OffsetNanoTime,
+        latest: OffsetNanoTime,
+    },
+    /// The local time was skipped, typically by a spring-forward transition.
+    /// `before` and `after` are the valid times on either side of the gap
+    /// that the local time maps to under each offset.
+    Gap {
+        before: OffsetNanoTime,
+        after: OffsetNanoTime,
+    },
+}
+
+impl LocalResult {
+    /// Returns the time if it is unique, `None` otherwise.
+    pub fn single(self) -> Option<OffsetNanoTime> {
+        match self {
+            LocalResult::Single(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Picks a single time according to `policy`.
+    pub fn resolve(self, policy: Resolution) -> Result<OffsetNanoTime, LocalTimeError> {
+        match (self, policy) {
+            (LocalResult::Single(t), _) => Ok(t),
+            (LocalResult::Ambiguous { earliest, .. }, Resolution::Earliest | Resolution::Shift) => {
+                Ok(earliest)
+            }
+            (LocalResult::Ambiguous { latest, .. }, Resolution::Latest) => Ok(latest),
+            (LocalResult::Ambiguous { earliest, .. }, Resolution::Reject) => {
+                Err(LocalTimeError::Ambiguous(earliest.local))
+            }
+            (LocalResult::Gap { before, .. }, Resolution::Earliest) => Ok(before),
+            (LocalResult::Gap { after, .. }, Resolution::Latest | Resolution::Shift) => Ok(after),
+            (LocalResult::Gap { before, after }, Resolution::Reject) => {
+                // `after` is the requested time pushed forward by the gap.
+                let skipped = NanoTime::saturating_from_unix(
+                    after.local.unix_secs() - (after.offset.secs - before.offset.secs) as i64,
+                    after.local.nanosecond(),
+                );
+                Err(LocalTimeError::Skipped(skipped))
+            }
+        }
+    }
+}
+
+/// A local time that could not be resolved under [`Resolution::Reject`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalTimeError {
+    /// The local time occurs twice in the zone.
+    Ambiguous(NanoTime),
+    /// The local time does not occur in the zone.
+    Skipped(NanoTime),
+}
+
+impl fmt::Display for LocalTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalTimeError::Ambiguous(t) => {
+                write!(f, "local time {} is ambiguous", t.datetime())
+            }
+            LocalTimeError::Skipped(t) => {
+                write!(f, "local time {} does not exist", t.datetime())
+            }
+        }
+    }
+}
+
+impl std::error::Error for LocalTimeError {}
+
+/// An offset together with its abbreviation and DST flag.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct LocalType {
+    offset: UtcOffset,
+    is_dst: bool,
+    abbr: String,
+}
+
+/// The day a POSIX rule transition falls on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RuleDay {
+    /// `Jn`: day 1–365, never counting February 29.
+    Julian1(u16),
+    /// `n`: day 0–365, counting February 29 in leap years.
+    Julian0(u16),
+    /// `Mm.w.d`: weekday `d` (0 = Sunday) of week `w` (5 = last) of month `m`.
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+impl RuleDay {
+    /// Returns the day of this rule in `year`, as days since 1970-01-01.
+    fn days_in(&self, year: i64) -> i64 {
+        let jan1 = crate::days_from_civil(year, 1, 1);
+        let leap = crate::is_leap_year(year as u16);
+        match *self {
+            RuleDay::Julian1(n) => {
+                let n = n as i64;
+                jan1 + n - 1 + if leap && n >= 60 { 1 } else { 0 }
+            }
+            RuleDay::Julian0(n) => jan1 + n as i64,
+            RuleDay::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = crate::days_from_civil(year, month, 1);
+                let first_wd = (first + 4).rem_euclid(7); // 1970-01-01 was a Thursday
+                let mut day = first + (weekday as i64 - first_wd).rem_euclid(7);
+                day += (week as i64 - 1) * 7;
+                let len = crate::days_in_month(year as u16, month) as i64;
+                while day >= first + len {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+/// Daylight saving rules: the DST type and when it starts and ends.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DstRule {
+    dst: LocalType,
+    start: RuleDay,
+    start_time: i32,
+    end: RuleDay,
+    end_time: i32,
+}
+
+/// A time zone: a rule for mapping UTC instants to local time.
+///
+/// Construct one with [`TimeZone::utc`], [`TimeZone::fixed`], or
+/// [`TimeZone::from_posix`]. The host's zones are available through
+/// [`TimeZone::named`] and [`TimeZone::local`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimeZone {
+    name: Option<String>,
+    std: LocalType,
+    dst: Option<DstRule>,
+    /// Past transitions from a TZif file, each with the type in effect
+    /// from then on, starting at `i64::MIN`. `std` and `dst` apply from
+    /// the last one.
+    history: Vec<(i64, LocalType)>,
+}
+
+impl TimeZone {
+    /// The UTC zone.
+    pub fn utc() -> Self {
+        Self::fixed(UtcOffset::UTC)
+    }
+
+    /// A zone that is always at `offset`.
+    pub fn fixed(offset: UtcOffset) -> Self {
+        Self {
+            name: None,
+            std: LocalType {
+                offset,
+                is_dst: false,
+                abbr: numeric_abbr(offset),
+            },
+            dst: None,
+            history: Vec::new(),
+        }
+    }
+
+    /// Parses a POSIX `TZ` rule such as `"CET-1CEST,M3.5.0,M10.5.0/3"`.
+    ///
+    /// Supports quoted `<+05>` abbreviations, `Jn`, `n`, and `Mm.w.d` rule
+    /// days, and the RFC 8536 extension allowing transition times from
+    /// -167 to 167 hours. A DST abbreviation without rules uses the US
+    /// default `M3.2.0,M11.1.0`. Returns `None` for malformed rules.
+    pub fn from_posix(rule: &str) -> Option<Self> {
+        let mut p = PosixParser {
+            s: rule.as_bytes(),
+            pos: 0,
+        };
+        let std_abbr = p.abbr()?;
+        let std_offset = UtcOffset::from_secs(-p.hms(24)?)?;
+        let std = LocalType {
+            offset: std_offset,
+            is_dst: false,
+            abbr: std_abbr,
+        };
+        if p.done() {
+            return Some(Self {
+                name: None,
+                std,
+                dst: None,
+                history: Vec::new(),
+            });
+        }
+
+        let dst_abbr = p.abbr()?;
+        let dst_offset = if p.peek().is_some_and(|c| c != b',') {
+            UtcOffset::from_secs(-p.hms(24)?)?
+        } else {
+            UtcOffset::from_secs(std_offset.secs + 3600)?
+        };
+        let dst = LocalType {
+            offset: dst_offset,
+            is_dst: true,
+            abbr: dst_abbr,
+        };
+
+        let (start, start_time, end, end_time) = if p.done() {
+            (
+                RuleDay::MonthWeekDay {
+                    month: 3,
+                    week: 2,
+                    weekday: 0,
+                },
+                7200,
+                RuleDay::MonthWeekDay {
+                    month: 11,
+                    week: 1,
+                    weekday: 0,
+                },
+                7200,
+            )
+        } else {
+            p.expect(b',')?;
+            let (start, start_time) = p.rule_day_time()?;
+            p.expect(b',')?;
+            let (end, end_time) = p.rule_day_time()?;
+            (start, start_time, end, end_time)
+        };
+        if !p.done() {
+            return None;
+        }
+
+        Some(Self {
+            name: None,
+            std,
+            dst: Some(DstRule {
+                dst,
+                start,
+                start_time,
+                end,
+                end_time,
+            }),
+            history: Vec::new(),
+        })
+    }
+
+    /// Parses a zone file in the TZif format (RFC 8536), as found under
+    /// `/usr/share/zoneinfo` and at `/etc/localtime`.
+    ///
+    /// The zone keeps every transition the file lists, so offsets and
+    /// abbreviations are historically accurate, and follows the file's
+    /// POSIX rule after the last one. Leap second records are ignored.
+    /// Returns `None` for malformed data.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, TimeZone};
+    ///
+    /// if let Ok(data) = std::fs::read("/usr/share/zoneinfo/Europe/London") {
+    ///     let london = TimeZone::from_tzif(&data).unwrap();
+    ///     // British Standard Time kept clocks at +01:00 all year from 1968 to 1971.
+    ///     let winter = NanoTime::new(1970, 1, 15, 12, 0, 0, 0).unwrap();
+    ///     assert_eq!(london.offset_at(&winter).to_string(), "+01:00");
+    /// }
+    /// ```
+    pub fn from_tzif(data: &[u8]) -> Option<Self> {
+        tzif::parse(data)
+    }
+
+    /// Looks up a zone in the host's time zone database.
+    ///
+    /// On Unix this reads `$TZDIR` or the standard zoneinfo directories,
+    /// keeping the zone file's historical transitions as
+    /// [`TimeZone::from_tzif`] does. On Windows the name
+    /// may be an IANA name or a Windows key name (`"Eastern Standard Time"`),
+    /// and the current year's rules come from the Windows API. Returns `None`
+    /// if the zone is unknown.
+    ///
+    /// With the `bundled-tz` feature (or its alias `tzdb`), names the host
+    /// does not know fall back to [`TimeZone::bundled`], which carries the
+    /// same historical transitions.
+    pub fn named(name: &str) -> Option<Self> {
+        let zone = sys::named(name);
+        #[cfg(feature = "bundled-tz")]
+        let zone = zone.or_else(|| Self::bundled(name));
+        zone
+    }
+
+    /// Looks up a zone in the tzdata compiled into the crate, ignoring the
+    /// host's database.
+    ///
+    /// Bundled zones come from tzdata [`TZDATA_VERSION`] and keep their
+    /// historical transitions, as a zone read by [`TimeZone::from_tzif`]
+    /// does.
+    #[cfg(feature = "bundled-tz")]
+    pub fn bundled(name: &str) -> Option<Self> {
+        let idx = bundled::ZONES
+            .binary_search_by(|&(zone, _, _)| zone.cmp(name))
+            .ok()?;
+        let (_, start, end) = bundled::ZONES[idx];
+        let mut tz = Self::from_tzif(&bundled::TZDATA[start..end])?;
+        tz.name = Some(name.to_string());
+        Some(tz)
+    }
+
+    /// Returns the host's configured local zone.
+    ///
+    /// On Unix this honors `$TZ` (a zone name, a `:`-prefixed path, or a
+    /// POSIX rule) and falls back to `/etc/localtime`. On Windows it uses
+    /// the zone selected in system settings.
+    pub fn local() -> Option<Self> {
+        sys::local().map(|(zone, _)| zone)
+    }
+
+    /// Like [`local`](Self::local), but also reports where the zone came
+    /// from.
+    pub(crate) fn local_with_source() -> Option<(Self, crate::LocalZoneSource)> {
+        sys::local()
+    }
+
+    /// Returns the IANA identifier (e.g. `"America/New_York"`), if known.
+    ///
+    /// Zones built from a rule or offset have no identifier.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the abbreviation in effect at the instant `utc`, e.g. `"EST"`
+    /// or `"EDT"`.
+    ///
+    /// Zones without a customary abbreviation use a numeric one such as
+    /// `"+0530"`, as in the IANA database. Zones loaded from the Windows API
+    /// use Windows' display names (`"Eastern Daylight Time"`).
+    pub fn abbreviation_at(&self, utc: &NanoTime) -> &str {
+        &self.type_at(utc.unix_secs()).abbr
+    }
+
+    /// Returns `true` if daylight saving time is in effect at the instant `utc`.
+    pub fn is_dst_at(&self, utc: &NanoTime) -> bool {
+        self.type_at(utc.unix_secs()).is_dst
+    }
+
+    /// Returns the UTC offset in effect at the instant `utc`.
+    pub fn offset_at(&self, utc: &NanoTime) -> UtcOffset {
+        self.type_at(utc.unix_secs()).offset
+    }
+
+    /// Converts a UTC instant to local time in this zone.
+    pub fn to_local(&self, utc: &NanoTime) -> OffsetNanoTime {
+        OffsetNanoTime::from_utc(utc, self.offset_at(utc))
+    }
+
+    /// Maps a local wall-clock time onto this zone, reporting whether it is
+    /// unique, ambiguous, or skipped.
+    pub fn from_local(&self, local: &NanoTime) -> LocalResult {
+        let secs = local.unix_secs();
+        let nanos = local.nanosecond();
+        // Build the local side directly: the instant itself may lie outside
+        // the representable years even when the local time does not.
+        let at = |instant: i64| {
+            let offset = self.type_at(instant).offset;
+            let local = NanoTime::saturating_from_unix(instant + offset.secs as i64, nanos);
+            OffsetNanoTime { local, offset }
+        };
+
+        // Any instant whose local time is `local` lies within a day of it,
+        // so the offsets on either side of that window are the candidates.
+        let before = self.type_at(secs - 86_400).offset.secs as i64;
+        let after = self.type_at(secs + 86_400).offset.secs as i64;
+        let mut valid = [before, after]
+            .into_iter()
+            .filter(|&off| self.type_at(secs - off).offset.secs as i64 == off)
+            .map(|off| secs - off)
+            .collect::<Vec<_>>();
+        valid.sort_unstable();
+        valid.dedup();
+
+        match valid[..] {
+            [instant] => LocalResult::Single(at(instant)),
+            [earliest, latest] => LocalResult::Ambiguous {
+                earliest: at(earliest),
+                latest: at(latest),
+            },
+            _ => LocalResult::Gap {
+                before: at(secs - after),
+                after: at(secs - before),
+            },
+        }
+    }
+
+    /// Maps a local wall-clock time onto this zone, settling ambiguous and
+    /// skipped times according to `policy`.
+    pub fn resolve(
+        &self,
+        local: &NanoTime,
+        policy: Resolution,
+    ) -> Result<OffsetNanoTime, LocalTimeError> {
+        self.from_local(local).resolve(policy)
+    }
+
+    /// Unix seconds at which DST starts and ends under `rule` in `year`.
+    fn transitions(&self, rule: &DstRule, year: i64) -> [i64; 2] {
+        let std_off = self.std.offset.secs as i64;
+        let start = rule.start.days_in(year) * 86_400 + rule.start_time as i64 - std_off;
+        let end =
+            rule.end.days_in(year) * 86_400 + rule.end_time as i64 - rule.dst.offset.secs as i64;
+        [start, end]
+    }
+
+    /// Returns the local time type in effect at Unix second `secs`.
+    fn type_at(&self, secs: i64) -> &LocalType {
+        if self.history.last().is_some_and(|&(last, _)| secs < last) {
+            let i = self.history.partition_point(|&(at, _)| at <= secs);
+            return &self.history[i - 1].1;
+        }
+        let Some(rule) = &self.dst else {
+            return &self.std;
+        };
+        let std_off = self.std.offset.secs as i64;
+        let year = crate::civil_from_days((secs + std_off).div_euclid(86_400)).0;
+        let [start, end] = self.transitions(rule, year);
+        let in_dst = if start <= end {
+            start <= secs && secs < end
+        } else {
+            !(end <= secs && secs < start)
+        };
+        if in_dst {
+            &rule.dst
+        } else {
+            &self.std
+        }
+    }
+}
+
+/// IANA-style numeric abbreviation: `"UTC"`, `"+05"`, `"+0530"`, `"-0330"`.
+fn numeric_abbr(offset: UtcOffset) -> String {
+    if offset.is_utc() {
+        return "UTC".to_string();
+    }
+    let sign = if offset.secs < 0 { '-' } else { '+' };
+    let abs = offset.secs.unsigned_abs();
+    let (h, m) = (abs / 3600, (abs % 3600) / 60);
+    if m == 0 {
+        format!("{}{:02}", sign, h)
+    } else {
+        format!("{}{:02}{:02}", sign, h, m)
+    }
+}
+
+/// Cursor over a POSIX `TZ` string.
+struct PosixParser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl PosixParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn done(&self) -> bool {
+        self.pos == self.s.len()
+    }
+
+    fn expect(&mut self, c: u8) -> Option<()> {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Parses an abbreviation: three or more letters, or `<...>` quoted.
+    fn abbr(&mut self) -> Option<String> {
+        let start;
+        let end;
+        if self.expect(b'<').is_some() {
+            start = self.pos;
+            while self
+                .peek()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == b'+' || c == b'-')
+            {
+                self.pos += 1;
+            }
+            end = self.pos;
+            self.expect(b'>')?;
+        } else {
+            start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+                self.pos += 1;
+            }
+            end = self.pos;
+        }
+        if end - start < 3 {
+            return None;
+        }
+        Some(String::from_utf8_lossy(&self.s[start..end]).into_owned())
+    }
+
+    fn number(&mut self, max: u32) -> Option<u32> {
+        let start = self.pos;
+        let mut n: u32 = 0;
+        while let Some(c) = self.peek().filter(u8::is_ascii_digit) {
+            n = n.checked_mul(10)?.checked_add((c - b'0') as u32)?;
+            self.pos += 1;
+        }
+        if self.pos == start || n > max {
+            return None;
+        }
+        Some(n)
+    }
+
+    /// Parses `[+|-]hh[:mm[:ss]]` into signed seconds.
+    fn hms(&mut self, max_hours: u32) -> Option<i32> {
+        let sign = match self.peek() {
+            Some(b'-') => {
+                self.pos += 1;
+                -1
+            }
+            Some(b'+') => {
+                self.pos += 1;
+                1
+            }
+            _ => 1,
+        };
+        let mut secs = self.number(max_hours)? * 3600;
+        if self.expect(b':').is_some() {
+            secs += self.number(59)? * 60;
+            if self.expect(b':').is_some() {
+                secs += self.number(59)?;
+            }
+        }
+        Some(sign * secs as i32)
+    }
+
+    /// Parses `date[/time]`, with time defaulting to 02:00:00.
+    fn rule_day_time(&mut self) -> Option<(RuleDay, i32)> {
+        let day = match self.peek()? {
+            b'J' => {
+                self.pos += 1;
+                let n = self.number(365)?;
+                if n == 0 {
+                    return None;
+                }
+                RuleDay::Julian1(n as u16)
+            }
+            b'M' => {
+                self.pos += 1;
+                let month = self.number(12)? as u8;
+                self.expect(b'.')?;
+                let week = self.number(5)? as u8;
+                self.expect(b'.')?;
+                let weekday = self.number(6)? as u8;
+                if month == 0 || week == 0 {
+                    return None;
+                }
+                RuleDay::MonthWeekDay {
+                    month,
+                    week,
+                    weekday,
+                }
+            }
+            _ => RuleDay::Julian0(self.number(365)? as u16),
+        };
+        let time = if self.expect(b'/').is_some() {
+            self.hms(167)?
+        } else {
+            7200
+        };
+        Some((day, time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_york() -> TimeZone {
+        TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap()
+    }
+
+    #[test]
+    fn test_to_and_from_zone() {
+        let ny = new_york();
+        let utc = NanoTime::new(2026, 11, 1, 5, 30, 0, 0).unwrap();
+        let local = utc.to_zone(&ny);
+        assert_eq!(local.local().datetime(), "2026-11-01 01:30:00.000");
+        // 01:30 happens twice on the fall-back day.
+        let wall = local.local();
+        assert!(wall.from_zone(&ny, Resolution::Reject).is_err());
+        assert_eq!(wall.from_zone(&ny, Resolution::Earliest), Ok(utc));
+        assert_eq!(
+            wall.from_zone(&ny, Resolution::Latest),
+            Ok(utc.add_nanos(3_600_000_000_000))
+        );
+    }
+
+    #[test]
+    fn test_from_zone_clamps_at_year_edges() {
+        let east = TimeZone::fixed(UtcOffset::from_minutes(60).unwrap());
+        let first = NanoTime::new(0, 1, 1, 0, 30, 0, 0).unwrap();
+        let utc = first.from_zone(&east, Resolution::Reject).unwrap();
+        assert_eq!(utc, NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap());
+        let west = TimeZone::fixed(UtcOffset::from_minutes(-60).unwrap());
+        let last = NanoTime::new(65535, 12, 31, 23, 30, 0, 0).unwrap();
+        let utc = last.from_zone(&west, Resolution::Reject).unwrap();
+        assert_eq!(utc.datetime(), "65535-12-31 23:59:59.999");
+        let resolved = west.resolve(&last, Resolution::Reject).unwrap();
+        assert_eq!(resolved.checked_to_utc(), None);
+    }
+
+    #[cfg(feature = "tzdb")]
+    #[test]
+    fn test_zone_conversions_use_bundled_history() {
+        let ny = TimeZone::bundled("America/New_York").unwrap();
+        // The first Sunday in April 2006, under the pre-2007 US rule.
+        let skipped = nt(2006, 4, 2, 2, 30);
+        assert_eq!(
+            skipped.from_zone(&ny, Resolution::Reject),
+            Err(LocalTimeError::Skipped(skipped))
+        );
+        let utc = nt(2006, 3, 20, 12, 0);
+        assert_eq!(
+            utc.to_zone(&ny).to_string(),
+            "2006-03-20 07:00:00.000 -05:00"
+        );
+        assert_eq!(
+            utc.to_zone(&ny).local().from_zone(&ny, Resolution::Reject),
+            Ok(utc)
+        );
+    }
+
+    #[test]
+    fn test_offset_conversion_and_instant_order() {
+        let utc = NanoTime::new(2026, 1, 1, 0, 30, 0, 5).unwrap();
+        let minus = OffsetNanoTime::from_utc(&utc, UtcOffset::from_minutes(-150).unwrap());
+        assert_eq!(minus.local().datetime(), "2025-12-31 22:00:00.000");
+        assert_eq!(minus.to_offset(UtcOffset::UTC).local(), utc);
+        assert_eq!(minus.to_offset(UtcOffset::UTC).to_utc(), utc);
+        let plus = minus.to_offset(UtcOffset::from_secs(5 * 3600 + 45 * 60 + 7).unwrap());
+        assert!(plus.same_instant(&minus));
+        assert_ne!(plus, minus);
+        let later = OffsetNanoTime::from_utc(&utc.add_nanos(1), UtcOffset::UTC);
+        assert_eq!(plus.cmp_instant(&later), Ordering::Less);
+        assert_eq!(later.cmp_instant(&minus), Ordering::Greater);
+        assert_eq!(plus.offset().as_minutes(), 345);
+        assert_eq!(UtcOffset::from_secs(-90).unwrap().as_minutes(), -1);
+    }
+
+    #[test]
+    fn test_now_offset_matches_local() {
+        let now = OffsetNanoTime::now();
+        let utc = NanoTime::now_utc();
+        assert!((utc.unix_secs() - now.to_utc().unix_secs()).abs() <= 1);
+    }
+
+    fn nt(y: u16, mo: u8, d: u8, h: u8, mi: u8) -> NanoTime {
+        NanoTime::new(y, mo, d, h, mi, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_utc_offset_display() {
+        assert_eq!(UtcOffset::UTC.to_string(), "+00:00");
+        assert_eq!(UtcOffset::from_minutes(330).unwrap().to_string(), "+05:30");
+        assert_eq!(UtcOffset::from_minutes(-210).unwrap().to_string(), "-03:30");
+        assert_eq!(
+            UtcOffset::from_secs(-1172).unwrap().to_string(),
+            "-00:19:32"
+        );
+    }
+
+    #[test]
+    fn test_utc_offset_range() {
+        assert!(UtcOffset::from_secs(86_399).is_some());
+        assert!(UtcOffset::from_secs(86_400).is_none());
+        assert!(UtcOffset::from_secs(-86_400).is_none());
+    }
+
+    #[test]
+    fn test_offset_nanotime_round_trip() {
+        let utc = NanoTime::new(2026, 1, 1, 2, 0, 0, 5).unwrap();
+        let local = OffsetNanoTime::from_utc(&utc, UtcOffset::from_minutes(-300).unwrap());
+        assert_eq!(
+            local.local(),
+            NanoTime::new(2025, 12, 31, 21, 0, 0, 5).unwrap()
+        );
+        assert_eq!(local.to_utc(), utc);
+        assert_eq!(local.to_string(), "2025-12-31 21:00:00.000 -05:00");
+    }
+
+    #[test]
+    fn test_offset_nanotime_now_in() {
+        let kolkata = TimeZone::fixed(UtcOffset::from_minutes(330).unwrap());
+        let now = OffsetNanoTime::now_in(&kolkata);
+        assert_eq!(now.offset().as_secs(), 19_800);
+        assert_eq!(now.local().diff_secs(&now.to_utc()), 19_800);
+    }
+
+    #[test]
+    fn test_from_posix_rejects_malformed() {
+        assert!(TimeZone::from_posix("").is_none());
+        assert!(TimeZone::from_posix("ES5").is_none());
+        assert!(TimeZone::from_posix("EST").is_none());
+        assert!(TimeZone::from_posix("EST5EDT,M3.2.0").is_none());
+        assert!(TimeZone::from_posix("EST5EDT,M13.2.0,M11.1.0").is_none());
+        assert!(TimeZone::from_posix("EST5EDT,J0,J365").is_none());
+        assert!(TimeZone::from_posix("EST5 trailing").is_none());
+    }
+
+    #[test]
+    fn test_offset_at_transitions() {
+        let ny = new_york();
+        let est = UtcOffset::from_minutes(-300).unwrap();
+        let edt = UtcOffset::from_minutes(-240).unwrap();
+        // 2026-03-08 07:00Z is 02:00 EST, the spring-forward instant.
+        assert_eq!(
+            ny.offset_at(&NanoTime::new(2026, 3, 8, 6, 59, 59, 0).unwrap()),
+            est
+        );
+        assert_eq!(ny.offset_at(&nt(2026, 3, 8, 7, 0)), edt);
+        // 2026-11-01 06:00Z is 02:00 EDT, the fall-back instant.
+        assert_eq!(
+            ny.offset_at(&NanoTime::new(2026, 11, 1, 5, 59, 59, 0).unwrap()),
+            edt
+        );
+        assert_eq!(ny.offset_at(&nt(2026, 11, 1, 6, 0)), est);
+    }
+
+    #[test]
+    fn test_southern_hemisphere_rule() {
+        let sydney = TimeZone::from_posix("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        assert_eq!(
+            sydney.offset_at(&nt(2026, 1, 15, 0, 0)).as_secs(),
+            11 * 3600
+        );
+        assert_eq!(
+            sydney.offset_at(&nt(2026, 7, 15, 0, 0)).as_secs(),
+            10 * 3600
+        );
+    }
+
+    #[test]
+    fn test_quoted_abbreviations_and_defaults() {
+        let tz = TimeZone::from_posix("<+0330>-3:30").unwrap();
+        assert_eq!(tz.offset_at(&nt(2026, 1, 1, 0, 0)).to_string(), "+03:30");
+        // DST without explicit rules uses the US default.
+        let tz = TimeZone::from_posix("CST6CDT").unwrap();
+        assert_eq!(tz.offset_at(&nt(2026, 7, 1, 0, 0)).as_secs(), -5 * 3600);
+    }
+
+    #[test]
+    fn test_abbreviation_and_name() {
+        let ny = new_york();
+        assert_eq!(ny.name(), None);
+        assert_eq!(ny.abbreviation_at(&nt(2026, 1, 15, 12, 0)), "EST");
+        assert_eq!(ny.abbreviation_at(&nt(2026, 7, 15, 12, 0)), "EDT");
+        assert!(!ny.is_dst_at(&nt(2026, 1, 15, 12, 0)));
+        assert!(ny.is_dst_at(&nt(2026, 7, 15, 12, 0)));
+
+        let quoted = TimeZone::from_posix("<+0530>-5:30").unwrap();
+        assert_eq!(quoted.abbreviation_at(&nt(2026, 1, 1, 0, 0)), "+0530");
+        assert_eq!(
+            TimeZone::utc().abbreviation_at(&nt(2026, 1, 1, 0, 0)),
+            "UTC"
+        );
+    }
+
+    #[cfg(feature = "bundled-tz")]
+    #[test]
+    fn test_bundled_zones() {
+        for &(name, _, _) in bundled::ZONES {
+            assert!(TimeZone::bundled(name).is_some(), "{}", name);
+        }
+        let ny = TimeZone::bundled("America/New_York").unwrap();
+        assert_eq!(ny.name(), Some("America/New_York"));
+        assert_eq!(ny.offset_at(&nt(2026, 7, 1, 12, 0)).as_secs(), -4 * 3600);
+        // History predates the current rule: the 2006 US rule and war time.
+        assert_eq!(ny.offset_at(&nt(2006, 3, 20, 12, 0)).as_secs(), -5 * 3600);
+        assert_eq!(ny.abbreviation_at(&nt(1944, 7, 1, 12, 0)), "EWT");
+        // Morocco's Ramadan shifts have no POSIX rule; the transitions are listed.
+        let casablanca = TimeZone::bundled("Africa/Casablanca").unwrap();
+        assert_eq!(casablanca.offset_at(&nt(2026, 3, 1, 12, 0)).as_secs(), 0);
+        assert_eq!(casablanca.offset_at(&nt(2026, 4, 1, 12, 0)).as_secs(), 3600);
+        assert!(TimeZone::bundled("Mars/Olympus_Mons").is_none());
+    }
+
+    #[test]
+    fn test_from_local_single() {
+        let ny = new_york();
+        let result = ny.from_local(&nt(2026, 7, 4, 12, 0));
+        let t = result.single().unwrap();
+        assert_eq!(t.to_utc(), nt(2026, 7, 4, 16, 0));
+    }
+
+    #[test]
+    fn test_from_local_gap() {
+        let ny = new_york();
+        let skipped = nt(2026, 3, 8, 2, 30);
+        let LocalResult::Gap { before, after } = ny.from_local(&skipped) else {
+            panic!("expected a gap");
+        };
+        assert_eq!(before.to_string(), "2026-03-08 01:30:00.000 -05:00");
+        assert_eq!(after.to_string(), "2026-03-08 03:30:00.000 -04:00");
+    }
+
+    #[test]
+    fn test_range_edges_clamp() {
+        let first = NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap();
+        let last = NanoTime::new(65535, 12, 31, 23, 59, 59, 999_999_999).unwrap();
+        let east = UtcOffset::from_minutes(60).unwrap();
+        let west = UtcOffset::from_minutes(-60).unwrap();
+
+        let t = OffsetNanoTime::new(first, east);
+        assert_eq!(t.checked_to_utc(), None);
+        assert_eq!(t.to_utc(), first);
+        assert_eq!(OffsetNanoTime::new(last, west).to_utc(), last);
+        assert_eq!(OffsetNanoTime::from_utc(&last, east).local(), last);
+        assert_eq!(OffsetNanoTime::from_utc(&first, west).local(), first);
+
+        let paris = TimeZone::fixed(east);
+        let t = paris.from_local(&first).single().unwrap();
+        assert_eq!((t.local(), t.offset()), (first, east));
+        assert_eq!(t.checked_to_utc(), None);
+    }
+
+    #[test]
+    fn test_from_local_ambiguous() {
+        let ny = new_york();
+        let LocalResult::Ambiguous { earliest, latest } = ny.from_local(&nt(2026, 11, 1, 1, 30))
+        else {
+            panic!("expected an ambiguous time");
+        };
+        assert_eq!(earliest.to_string(), "2026-11-01 01:30:00.000 -04:00");
+        assert_eq!(latest.to_string(), "2026-11-01 01:30:00.000 -05:00");
+        assert_eq!(latest.to_utc().diff_secs(&earliest.to_utc()), 3600);
+    }
+
+    #[test]
+    fn test_resolution_policies() {
+        let ny = new_york();
+        let gap = nt(2026, 3, 8, 2, 30);
+        let fold = nt(2026, 11, 1, 1, 30);
+
+        assert_eq!(
+            ny.resolve(&gap, Resolution::Earliest).unwrap().local(),
+            nt(2026, 3, 8, 1, 30)
+        );
+        assert_eq!(
+            ny.resolve(&gap, Resolution::Latest).unwrap().local(),
+            nt(2026, 3, 8, 3, 30)
+        );
+        assert_eq!(
+            ny.resolve(&gap, Resolution::Shift).unwrap().local(),
+            nt(2026, 3, 8, 3, 30)
+        );
+        assert_eq!(
+            ny.resolve(&gap, Resolution::Reject),
+            Err(LocalTimeError::Skipped(gap))
+        );
+
+        let edt = UtcOffset::from_minutes(-240).unwrap();
+        let est = UtcOffset::from_minutes(-300).unwrap();
+        assert_eq!(
+            ny.resolve(&fold, Resolution::Earliest).unwrap().offset(),
+            edt
+        );
+        assert_eq!(ny.resolve(&fold, Resolution::Latest).unwrap().offset(), est);
+        assert_eq!(ny.resolve(&fold, Resolution::Shift).unwrap().offset(), edt);
+        assert_eq!(
+            ny.resolve(&fold, Resolution::Reject),
+            Err(LocalTimeError::Ambiguous(fold))
+        );
+    }
+
+    #[test]
+    fn test_local_time_error_display() {
+        let err = LocalTimeError::Skipped(nt(2026, 3, 8, 2, 30));
+        assert_eq!(
+            err.to_string(),
+            "local time 2026-03-08 02:30:00.000 does not exist"
+        );
+    }
+
+    #[test]
+    fn test_fixed_zone_is_always_single() {
+        let tz = TimeZone::fixed(UtcOffset::from_minutes(330).unwrap());
+        let t = tz.from_local(&nt(2026, 3, 8, 2, 30)).single().unwrap();
+        assert_eq!(t.to_utc(), nt(2026, 3, 7, 21, 0));
+    }
+}
@@ -0,0 +1,268 @@
+//! The TZif binary zone format (RFC 8536).
+//!
+//! A TZif file lists every UTC offset change a zone has had, each pointing
+//! at a local time type (offset, DST flag, abbreviation). Version 2 and
+//! later files repeat the data with 64-bit times and end with a POSIX `TZ`
+//! rule for instants after the last listed transition.
+
+use super::{LocalType, TimeZone, UtcOffset};
+
+/// Size of a TZif header.
+const HEADER_LEN: usize = 44;
+
+/// The six counts from a TZif header.
+struct Counts {
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Counts {
+    fn read(header: &[u8]) -> Option<Counts> {
+        let count = |i: usize| {
+            let bytes = header.get(20 + i * 4..24 + i * 4)?;
+            Some(u32::from_be_bytes(bytes.try_into().ok()?) as usize)
+        };
+        Some(Counts {
+            isutcnt: count(0)?,
+            isstdcnt: count(1)?,
+            leapcnt: count(2)?,
+            timecnt: count(3)?,
+            typecnt: count(4)?,
+            charcnt: count(5)?,
+        })
+    }
+
+    /// Length of the data block that follows the header, with `time_len`
+    /// bytes per transition time.
+    fn data_len(&self, time_len: usize) -> usize {
+        self.timecnt * (time_len + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_len + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+/// Cursor over a TZif data block.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(n)?)?;
+        self.pos += n;
+        Some(bytes)
+    }
+
+    fn time(&mut self, len: usize) -> Option<i64> {
+        let bytes = self.take(len)?;
+        Some(match len {
+            4 => i32::from_be_bytes(bytes.try_into().ok()?) as i64,
+            _ => i64::from_be_bytes(bytes.try_into().ok()?),
+        })
+    }
+}
+
+/// Parses a TZif file into its transitions and the zone that applies after
+/// the last one. Returns `None` for malformed data.
+pub(super) fn parse(data: &[u8]) -> Option<TimeZone> {
+    if data.len() < HEADER_LEN || &data[..4] != b"TZif" {
+        return None;
+    }
+    let version = data[4];
+    let mut counts = Counts::read(data)?;
+    let mut block = HEADER_LEN;
+    let mut time_len = 4;
+    if version >= b'2' {
+        // Skip the 32-bit block in favor of the 64-bit one after it.
+        block += counts.data_len(4);
+        let header = data.get(block..block + HEADER_LEN)?;
+        if &header[..4] != b"TZif" {
+            return None;
+        }
+        counts = Counts::read(header)?;
+        block += HEADER_LEN;
+        time_len = 8;
+    }
+    if counts.typecnt == 0 || counts.typecnt > 256 || counts.charcnt == 0 {
+        return None;
+    }
+
+    let mut r = Reader { data, pos: block };
+    let times = (0..counts.timecnt)
+        .map(|_| r.time(time_len))
+        .collect::<Option<Vec<_>>>()?;
+    if times.windows(2).any(|w| w[0] >= w[1]) {
+        return None;
+    }
+    let indices = r.take(counts.timecnt)?;
+    let raw_types = r.take(counts.typecnt * 6)?;
+    let chars = r.take(counts.charcnt)?;
+    r.take(counts.data_len(time_len) - (r.pos - block))?;
+
+    let types = raw_types
+        .chunks_exact(6)
+        .map(|t| {
+            let utoff = i32::from_be_bytes(t[..4].try_into().ok()?);
+            let abbr = chars.get(t[5] as usize..)?;
+            let end = abbr.iter().position(|&c| c == 0)?;
+            Some(LocalType {
+                offset: UtcOffset::from_secs(utoff)?,
+                is_dst: t[4] == 1,
+                abbr: String::from_utf8_lossy(&abbr[..end]).into_owned(),
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let mut history = Vec::with_capacity(times.len() + 1);
+    if !times.is_empty() {
+        // Type 0 applies before the first transition.
+        history.push((i64::MIN, types[0].clone()));
+    }
+    for (&at, &idx) in times.iter().zip(indices) {
+        history.push((at, types.get(idx as usize)?.clone()));
+    }
+
+    let footer = if version >= b'2' {
+        let rest = data.get(r.pos..)?;
+        let rule = rest.strip_prefix(b"\n")?;
+        let end = rule.iter().position(|&c| c == b'\n')?;
+        Some(std::str::from_utf8(&rule[..end]).ok()?)
+    } else {
+        None
+    };
+    let mut zone = match footer {
+        Some(rule) if !rule.is_empty() => TimeZone::from_posix(rule)?,
+        _ => {
+            let last = history.last().map_or(&types[0], |(_, ty)| ty);
+            let mut zone = TimeZone::fixed(last.offset);
+            zone.std = last.clone();
+            zone
+        }
+    };
+    zone.history = history;
+    Some(zone)
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use crate::NanoTime;
+
+    /// Encodes a version 2 TZif file from transitions, types of
+    /// `(offset, is_dst, abbr)`, and a footer rule.
+    pub(in crate::tz) fn encode(
+        transitions: &[(i64, u8)],
+        types: &[(i32, bool, &str)],
+        footer: &str,
+    ) -> Vec<u8> {
+        let mut chars = Vec::new();
+        let mut raw_types = Vec::new();
+        for &(offset, is_dst, abbr) in types {
+            raw_types.extend_from_slice(&offset.to_be_bytes());
+            raw_types.push(is_dst as u8);
+            raw_types.push(chars.len() as u8);
+            chars.extend_from_slice(abbr.as_bytes());
+            chars.push(0);
+        }
+        let header = |version: u8| {
+            let mut h = b"TZif".to_vec();
+            h.push(version);
+            h.extend_from_slice(&[0; 15]);
+            for n in [0, 0, 0, transitions.len(), types.len(), chars.len()] {
+                h.extend_from_slice(&(n as u32).to_be_bytes());
+            }
+            h
+        };
+        let mut data = header(b'2');
+        for &(at, _) in transitions {
+            data.extend_from_slice(
+                &(at.clamp(i32::MIN as i64, i32::MAX as i64) as i32).to_be_bytes(),
+            );
+        }
+        data.extend(transitions.iter().map(|&(_, idx)| idx));
+        data.extend_from_slice(&raw_types);
+        data.extend_from_slice(&chars);
+        data.extend(header(b'2'));
+        for &(at, _) in transitions {
+            data.extend_from_slice(&at.to_be_bytes());
+        }
+        data.extend(transitions.iter().map(|&(_, idx)| idx));
+        data.extend_from_slice(&raw_types);
+        data.extend_from_slice(&chars);
+        data.push(b'\n');
+        data.extend_from_slice(footer.as_bytes());
+        data.push(b'\n');
+        data
+    }
+
+    fn utc(y: u16, mo: u8, d: u8, h: u8) -> NanoTime {
+        NanoTime::new(y, mo, d, h, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_history_then_footer() {
+        // A zone on +01:00 until 2000, +02:00 with DST-flagged type until
+        // 2010, then a rule.
+        let data = encode(
+            &[
+                (utc(2000, 1, 1, 0).unix_secs(), 1),
+                (utc(2010, 1, 1, 0).unix_secs(), 2),
+            ],
+            &[
+                (3600, false, "AAA"),
+                (7200, true, "BBB"),
+                (-3600, false, "CCC"),
+            ],
+            "<-01>1",
+        );
+        let zone = parse(&data).unwrap();
+        let at = |t: NanoTime| {
+            (
+                zone.offset_at(&t).as_secs(),
+                zone.abbreviation_at(&t).to_string(),
+            )
+        };
+        assert_eq!(at(utc(1900, 6, 1, 0)), (3600, "AAA".into()));
+        assert_eq!(at(utc(2005, 6, 1, 0)), (7200, "BBB".into()));
+        assert!(zone.is_dst_at(&utc(2005, 6, 1, 0)));
+        assert_eq!(at(utc(2030, 6, 1, 0)), (-3600, "-01".into()));
+        let edge = utc(2010, 1, 1, 0);
+        assert_eq!(zone.offset_at(&edge).as_secs(), -3600);
+        assert_eq!(zone.offset_at(&edge.add_nanos(-1)).as_secs(), 7200);
+    }
+
+    #[test]
+    fn test_without_footer_keeps_last_type() {
+        let mut data = encode(&[(0, 1)], &[(0, false, "UTC"), (19800, false, "IST")], "");
+        let zone = parse(&data).unwrap();
+        assert_eq!(zone.offset_at(&utc(2100, 1, 1, 0)).as_secs(), 19800);
+        assert_eq!(zone.offset_at(&utc(1960, 1, 1, 0)).as_secs(), 0);
+
+        // Truncated files and bad type indices are rejected.
+        assert!(parse(&data[..data.len() - 3]).is_none());
+        data = encode(&[(0, 2)], &[(0, false, "UTC"), (19800, false, "IST")], "");
+        assert!(parse(&data).is_none());
+        assert!(parse(b"TZif2").is_none());
+    }
+
+    #[test]
+    fn test_system_files_match_footer_rules() {
+        // Hosts without tzdata installed have nothing to check.
+        let Ok(data) = std::fs::read("/usr/share/zoneinfo/America/New_York") else {
+            return;
+        };
+        let zone = parse(&data).unwrap();
+        // 2006 used the old US rule: DST from the first Sunday in April.
+        assert_eq!(zone.offset_at(&utc(2006, 3, 20, 12)).as_secs(), -5 * 3600);
+        assert_eq!(zone.offset_at(&utc(2007, 3, 20, 12)).as_secs(), -4 * 3600);
+        assert_eq!(zone.abbreviation_at(&utc(1944, 7, 1, 12)), "EWT");
+        assert_eq!(zone.offset_at(&utc(2040, 7, 1, 12)).as_secs(), -4 * 3600);
+    }
+}
@@ -0,0 +1,391 @@
+//! Windows time zone support.
+//!
+//! Windows identifies zones by registry key names such as
+//! `"Eastern Standard Time"` rather than IANA names. The mapping table below
+//! follows CLDR's `windowsZones.xml`, using the primary (territory `001`)
+//! IANA zone for each Windows zone. On Windows hosts the zone rules come from
+//! `GetDynamicTimeZoneInformation` and `GetTimeZoneInformationForYear`, so no
+//! zoneinfo directory is needed.
+
+/// Windows zone key name to primary IANA zone, per CLDR.
+const WINDOWS_ZONES: &[(&str, &str)] = &[
+    ("Dateline Standard Time", "Etc/GMT+12"),
+    ("UTC-11", "Etc/GMT+11"),
+    ("Aleutian Standard Time", "America/Adak"),
+    ("Hawaiian Standard Time", "Pacific/Honolulu"),
+    ("Marquesas Standard Time", "Pacific/Marquesas"),
+    ("Alaskan Standard Time", "America/Anchorage"),
+    ("UTC-09", "Etc/GMT+9"),
+    ("Pacific Standard Time (Mexico)", "America/Tijuana"),
+    ("UTC-08", "Etc/GMT+8"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("US Mountain Standard Time", "America/Phoenix"),
+    ("Mountain Standard Time (Mexico)", "America/Mazatlan"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("Yukon Standard Time", "America/Whitehorse"),
+    ("Central America Standard Time", "America/Guatemala"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Easter Island Standard Time", "Pacific/Easter"),
+    ("Central Standard Time (Mexico)", "America/Mexico_City"),
+    ("Canada Central Standard Time", "America/Regina"),
+    ("SA Pacific Standard Time", "America/Bogota"),
+    ("Eastern Standard Time (Mexico)", "America/Cancun"),
+    ("Eastern Standard Time", "America/New_York"),
+    ("Haiti Standard Time", "America/Port-au-Prince"),
+    ("Cuba Standard Time", "America/Havana"),
+    ("US Eastern Standard Time", "America/Indiana/Indianapolis"),
+    ("Turks And Caicos Standard Time", "America/Grand_Turk"),
+    ("Paraguay Standard Time", "America/Asuncion"),
+    ("Atlantic Standard Time", "America/Halifax"),
+    ("Venezuela Standard Time", "America/Caracas"),
+    ("Central Brazilian Standard Time", "America/Cuiaba"),
+    ("SA Western Standard Time", "America/La_Paz"),
+    ("Pacific SA Standard Time", "America/Santiago"),
+    ("Newfoundland Standard Time", "America/St_Johns"),
+    ("Tocantins Standard Time", "America/Araguaina"),
+    ("E. South America Standard Time", "America/Sao_Paulo"),
+    ("SA Eastern Standard Time", "America/Cayenne"),
+    ("Argentina Standard Time", "America/Argentina/Buenos_Aires"),
+    ("Greenland Standard Time", "America/Nuuk"),
+    ("Montevideo Standard Time", "America/Montevideo"),
+    ("Magallanes Standard Time", "America/Punta_Arenas"),
+    ("Saint Pierre Standard Time", "America/Miquelon"),
+    ("Bahia Standard Time", "America/Bahia"),
+    ("UTC-02", "Etc/GMT+2"),
+    ("Azores Standard Time", "Atlantic/Azores"),
+    ("Cape Verde Standard Time", "Atlantic/Cape_Verde"),
+    ("UTC", "Etc/UTC"),
+    ("GMT Standard Time", "Europe/London"),
+    ("Greenwich Standard Time", "Atlantic/Reykjavik"),
+    ("Sao Tome Standard Time", "Africa/Sao_Tome"),
+    ("Morocco Standard Time", "Africa/Casablanca"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Central Europe Standard Time", "Europe/Budapest"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("Central European Standard Time", "Europe/Warsaw"),
+    ("W. Central Africa Standard Time", "Africa/Lagos"),
+    ("Jordan Standard Time", "Asia/Amman"),
+    ("GTB Standard Time", "Europe/Bucharest"),
+    ("Middle East Standard Time", "Asia/Beirut"),
+    ("Egypt Standard Time", "Africa/Cairo"),
+    ("E. Europe Standard Time", "Europe/Chisinau"),
+    ("Syria Standard Time", "Asia/Damascus"),
+    ("West Bank Standard Time", "Asia/Hebron"),
+    ("South Africa Standard Time", "Africa/Johannesburg"),
+    ("FLE Standard Time", "Europe/Kyiv"),
+    ("Israel Standard Time", "Asia/Jerusalem"),
+    ("South Sudan Standard Time", "Africa/Juba"),
+    ("Kaliningrad Standard Time", "Europe/Kaliningrad"),
+    ("Sudan Standard Time", "Africa/Khartoum"),
+    ("Libya Standard Time", "Africa/Tripoli"),
+    ("Namibia Standard Time", "Africa/Windhoek"),
+    ("Arabic Standard Time", "Asia/Baghdad"),
+    ("Turkey Standard Time", "Europe/Istanbul"),
+    ("Arab Standard Time", "Asia/Riyadh"),
+    ("Belarus Standard Time", "Europe/Minsk"),
+    ("Russian Standard Time", "Europe/Moscow"),
+    ("E. Africa Standard Time", "Africa/Nairobi"),
+    ("Volgograd Standard Time", "Europe/Volgograd"),
+    ("Iran Standard Time", "Asia/Tehran"),
+    ("Arabian Standard Time", "Asia/Dubai"),
+    ("Astrakhan Standard Time", "Europe/Astrakhan"),
+    ("Azerbaijan Standard Time", "Asia/Baku"),
+    ("Russia Time Zone 3", "Europe/Samara"),
+    ("Mauritius Standard Time", "Indian/Mauritius"),
+    ("Saratov Standard Time", "Europe/Saratov"),
+    ("Georgian Standard Time", "Asia/Tbilisi"),
+    ("Caucasus Standard Time", "Asia/Yerevan"),
+    ("Afghanistan Standard Time", "Asia/Kabul"),
+    ("West Asia Standard Time", "Asia/Tashkent"),
+    ("Qyzylorda Standard Time", "Asia/Qyzylorda"),
+    ("Ekaterinburg Standard Time", "Asia/Yekaterinburg"),
+    ("Pakistan Standard Time", "Asia/Karachi"),
+    ("India Standard Time", "Asia/Kolkata"),
+    ("Sri Lanka Standard Time", "Asia/Colombo"),
+    ("Nepal Standard Time", "Asia/Kathmandu"),
+    ("Central Asia Standard Time", "Asia/Bishkek"),
+    ("Bangladesh Standard Time", "Asia/Dhaka"),
+    ("Omsk Standard Time", "Asia/Omsk"),
+    ("Myanmar Standard Time", "Asia/Yangon"),
+    ("SE Asia Standard Time", "Asia/Bangkok"),
+    ("Altai Standard Time", "Asia/Barnaul"),
+    ("W. Mongolia Standard Time", "Asia/Hovd"),
+    ("North Asia Standard Time", "Asia/Krasnoyarsk"),
+    ("N. Central Asia Standard Time", "Asia/Novosibirsk"),
+    ("Tomsk Standard Time", "Asia/Tomsk"),
+    ("China Standard Time", "Asia/Shanghai"),
+    ("North Asia East Standard Time", "Asia/Irkutsk"),
+    ("Singapore Standard Time", "Asia/Singapore"),
+    ("W. Australia Standard Time", "Australia/Perth"),
+    ("Taipei Standard Time", "Asia/Taipei"),
+    ("Ulaanbaatar Standard Time", "Asia/Ulaanbaatar"),
+    ("Aus Central W. Standard Time", "Australia/Eucla"),
+    ("Transbaikal Standard Time", "Asia/Chita"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("North Korea Standard Time", "Asia/Pyongyang"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("Yakutsk Standard Time", "Asia/Yakutsk"),
+    ("Cen. Australia Standard Time", "Australia/Adelaide"),
+    ("AUS Central Standard Time", "Australia/Darwin"),
+    ("E. Australia Standard Time", "Australia/Brisbane"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("West Pacific Standard Time", "Pacific/Port_Moresby"),
+    ("Tasmania Standard Time", "Australia/Hobart"),
+    ("Vladivostok Standard Time", "Asia/Vladivostok"),
+    ("Lord Howe Standard Time", "Australia/Lord_Howe"),
+    ("Bougainville Standard Time", "Pacific/Bougainville"),
+    ("Russia Time Zone 10", "Asia/Srednekolymsk"),
+    ("Magadan Standard Time", "Asia/Magadan"),
+    ("Norfolk Standard Time", "Pacific/Norfolk"),
+    ("Sakhalin Standard Time", "Asia/Sakhalin"),
+    ("Central Pacific Standard Time", "Pacific/Guadalcanal"),
+    ("Russia Time Zone 11", "Asia/Kamchatka"),
+    ("New Zealand Standard Time", "Pacific/Auckland"),
+    ("UTC+12", "Etc/GMT-12"),
+    ("Fiji Standard Time", "Pacific/Fiji"),
+    ("Chatham Islands Standard Time", "Pacific/Chatham"),
+    ("UTC+13", "Etc/GMT-13"),
+    ("Tonga Standard Time", "Pacific/Tongatapu"),
+    ("Samoa Standard Time", "Pacific/Apia"),
+    ("Line Islands Standard Time", "Pacific/Kiritimati"),
+];
+
+/// Returns the primary IANA zone for a Windows zone key name.
+///
+/// Matching is case-insensitive. Returns `None` for unknown names.
+pub fn windows_to_iana(windows_name: &str) -> Option<&'static str> {
+    WINDOWS_ZONES
+        .iter()
+        .find(|(win, _)| win.eq_ignore_ascii_case(windows_name))
+        .map(|&(_, iana)| iana)
+}
+
+/// Returns the Windows zone key name for a primary IANA zone.
+///
+/// Only the primary zone of each Windows zone is mapped, so e.g.
+/// `"America/New_York"` resolves but `"America/Toronto"` does not.
+pub fn iana_to_windows(iana_name: &str) -> Option<&'static str> {
+    WINDOWS_ZONES
+        .iter()
+        .find(|(_, iana)| *iana == iana_name)
+        .map(|&(win, _)| win)
+}
+
+#[cfg(windows)]
+mod ffi {
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct SYSTEMTIME {
+        pub w_year: u16,
+        pub w_month: u16,
+        pub w_day_of_week: u16,
+        pub w_day: u16,
+        pub w_hour: u16,
+        pub w_minute: u16,
+        pub w_second: u16,
+        pub w_milliseconds: u16,
+    }
+
+    #[repr(C)]
+    pub struct TIME_ZONE_INFORMATION {
+        pub bias: i32,
+        pub standard_name: [u16; 32],
+        pub standard_date: SYSTEMTIME,
+        pub standard_bias: i32,
+        pub daylight_name: [u16; 32],
+        pub daylight_date: SYSTEMTIME,
+        pub daylight_bias: i32,
+    }
+
+    #[repr(C)]
+    pub struct DYNAMIC_TIME_ZONE_INFORMATION {
+        pub bias: i32,
+        pub standard_name: [u16; 32],
+        pub standard_date: SYSTEMTIME,
+        pub standard_bias: i32,
+        pub daylight_name: [u16; 32],
+        pub daylight_date: SYSTEMTIME,
+        pub daylight_bias: i32,
+        pub time_zone_key_name: [u16; 128],
+        pub dynamic_daylight_time_disabled: u8,
+    }
+
+    pub const TIME_ZONE_ID_INVALID: u32 = 0xFFFF_FFFF;
+    pub const ERROR_SUCCESS: u32 = 0;
+
+    extern "system" {
+        pub fn GetDynamicTimeZoneInformation(
+            p_time_zone_information: *mut DYNAMIC_TIME_ZONE_INFORMATION,
+        ) -> u32;
+        pub fn GetTimeZoneInformationForYear(
+            w_year: u16,
+            pdtzi: *const DYNAMIC_TIME_ZONE_INFORMATION,
+            ptzi: *mut TIME_ZONE_INFORMATION,
+        ) -> i32;
+    }
+
+    #[link(name = "advapi32")]
+    extern "system" {
+        pub fn EnumDynamicTimeZoneInformation(
+            dw_index: u32,
+            l_time_zone_information: *mut DYNAMIC_TIME_ZONE_INFORMATION,
+        ) -> u32;
+    }
+}
+
+#[cfg(windows)]
+use super::{DstRule, LocalType, RuleDay, TimeZone, UtcOffset};
+
+/// Loads a zone by IANA name or Windows key name for the current year.
+#[cfg(windows)]
+pub(super) fn named(name: &str) -> Option<TimeZone> {
+    let key = iana_to_windows(name).unwrap_or(name);
+    let mut index = 0;
+    loop {
+        // SAFETY: the struct is plain data and the API fills it on success.
+        let mut dtzi = unsafe { std::mem::zeroed::<ffi::DYNAMIC_TIME_ZONE_INFORMATION>() };
+        if unsafe { ffi::EnumDynamicTimeZoneInformation(index, &mut dtzi) } != ffi::ERROR_SUCCESS {
+            return None;
+        }
+        if wide_to_string(&dtzi.time_zone_key_name).eq_ignore_ascii_case(key) {
+            let mut tz = from_dynamic(&dtzi)?;
+            tz.name = Some(
+                windows_to_iana(key)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| name.to_string()),
+            );
+            return Some(tz);
+        }
+        index += 1;
+    }
+}
+
+/// Resolves the zone configured in Windows settings.
+#[cfg(windows)]
+pub(super) fn local() -> Option<(TimeZone, crate::LocalZoneSource)> {
+    // SAFETY: the struct is plain data and the API fills it.
+    let mut dtzi = unsafe { std::mem::zeroed::<ffi::DYNAMIC_TIME_ZONE_INFORMATION>() };
+    if unsafe { ffi::GetDynamicTimeZoneInformation(&mut dtzi) } == ffi::TIME_ZONE_ID_INVALID {
+        return None;
+    }
+    let mut tz = from_dynamic(&dtzi)?;
+    tz.name = windows_to_iana(&wide_to_string(&dtzi.time_zone_key_name)).map(str::to_string);
+    Some((tz, crate::LocalZoneSource::WindowsApi))
+}
+
+/// Builds a zone from this year's rules for a dynamic zone.
+#[cfg(windows)]
+fn from_dynamic(dtzi: &ffi::DYNAMIC_TIME_ZONE_INFORMATION) -> Option<TimeZone> {
+    let year = crate::NanoTime::now_utc().year();
+    // SAFETY: both pointers reference live, correctly sized structs.
+    let mut tzi = unsafe { std::mem::zeroed::<ffi::TIME_ZONE_INFORMATION>() };
+    if unsafe { ffi::GetTimeZoneInformationForYear(year, dtzi, &mut tzi) } == 0 {
+        return None;
+    }
+
+    let std = LocalType {
+        offset: UtcOffset::from_secs(-(tzi.bias + tzi.standard_bias) * 60)?,
+        is_dst: false,
+        abbr: wide_to_string(&tzi.standard_name),
+    };
+    if dtzi.dynamic_daylight_time_disabled != 0 || tzi.standard_date.w_month == 0 {
+        return Some(TimeZone {
+            name: None,
+            std,
+            dst: None,
+            history: Vec::new(),
+        });
+    }
+
+    let dst = LocalType {
+        offset: UtcOffset::from_secs(-(tzi.bias + tzi.daylight_bias) * 60)?,
+        is_dst: true,
+        abbr: wide_to_string(&tzi.daylight_name),
+    };
+    let (start, start_time) = rule_day(&tzi.daylight_date)?;
+    let (end, end_time) = rule_day(&tzi.standard_date)?;
+    Some(TimeZone {
+        name: None,
+        std,
+        dst: Some(DstRule {
+            dst,
+            start,
+            start_time,
+            end,
+            end_time,
+        }),
+        history: Vec::new(),
+    })
+}
+
+/// Converts a `SYSTEMTIME` transition date into a rule day and local time.
+///
+/// With `wYear == 0` the date is recurring: `wDay` is the week (5 = last)
+/// and `wDayOfWeek` the weekday, the same shape as POSIX `Mm.w.d`. Otherwise
+/// it is an absolute date for that year only.
+#[cfg(windows)]
+fn rule_day(st: &ffi::SYSTEMTIME) -> Option<(RuleDay, i32)> {
+    let month = st.w_month as u8;
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    let day = if st.w_year == 0 {
+        RuleDay::MonthWeekDay {
+            month,
+            week: st.w_day as u8,
+            weekday: st.w_day_of_week as u8,
+        }
+    } else {
+        let year = st.w_year as i64;
+        let doy = crate::days_from_civil(year, month, st.w_day as u8)
+            - crate::days_from_civil(year, 1, 1);
+        RuleDay::Julian0(doy as u16)
+    };
+    let time = st.w_hour as i32 * 3600 + st.w_minute as i32 * 60 + st.w_second as i32;
+    Some((day, time))
+}
+
+#[cfg(windows)]
+fn wide_to_string(wide: &[u16]) -> String {
+    let len = wide.iter().position(|&c| c == 0).unwrap_or(wide.len());
+    String::from_utf16_lossy(&wide[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_to_iana() {
+        assert_eq!(
+            windows_to_iana("Eastern Standard Time"),
+            Some("America/New_York")
+        );
+        assert_eq!(
+            windows_to_iana("eastern standard time"),
+            Some("America/New_York")
+        );
+        assert_eq!(windows_to_iana("India Standard Time"), Some("Asia/Kolkata"));
+        assert_eq!(windows_to_iana("Nowhere Standard Time"), None);
+    }
+
+    #[test]
+    fn test_iana_to_windows() {
+        assert_eq!(
+            iana_to_windows("Europe/Paris"),
+            Some("Romance Standard Time")
+        );
+        assert_eq!(iana_to_windows("Etc/UTC"), Some("UTC"));
+        assert_eq!(iana_to_windows("Mars/Olympus_Mons"), None);
+    }
+
+    #[test]
+    fn test_mapping_is_one_to_one() {
+        for (i, (win, iana)) in WINDOWS_ZONES.iter().enumerate() {
+            for (other_win, other_iana) in &WINDOWS_ZONES[i + 1..] {
+                assert_ne!(win, other_win);
+                assert_ne!(iana, other_iana);
+            }
+        }
+    }
+}
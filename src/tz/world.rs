@@ -0,0 +1,106 @@
+//! One instant shown across several zones, for dashboards.
+
+use std::fmt;
+
+use super::{OffsetNanoTime, TimeZone};
+use crate::NanoTime;
+
+/// An instant in several zones, from [`world_clock`].
+///
+/// Displays as a table with one zone per line, labels padded to line up:
+///
+/// ```text
+/// America/New_York  2026-02-22 09:30:00  -05:00
+/// UTC               2026-02-22 14:30:00  +00:00
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WorldClock {
+    rows: Vec<(String, OffsetNanoTime)>,
+}
+
+impl WorldClock {
+    /// Returns each zone's label and local time, in the order given.
+    pub fn rows(&self) -> &[(String, OffsetNanoTime)] {
+        &self.rows
+    }
+
+    /// Returns the rows, consuming the clock.
+    pub fn into_rows(self) -> Vec<(String, OffsetNanoTime)> {
+        self.rows
+    }
+}
+
+impl fmt::Display for WorldClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .rows
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .max();
+        for (i, (label, t)) in self.rows.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            write!(
+                f,
+                "{:<w$}  {}  {}",
+                label,
+                t.local().datetime_fmt(0),
+                t.offset(),
+                w = width.unwrap_or(0)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts `instant` to local time in each of `zones`.
+///
+/// Each row is labeled with the zone's IANA name, or for zones built from
+/// a rule or offset, the abbreviation in effect at `instant`.
+///
+/// ```rust
+/// use nanotime::NanoTime;
+/// use nanotime::tz::{world_clock, TimeZone};
+///
+/// let zones = [
+///     TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap(),
+///     TimeZone::from_posix("IST-5:30").unwrap(),
+///     TimeZone::utc(),
+/// ];
+/// let t = NanoTime::new(2026, 2, 22, 14, 30, 0, 0).unwrap();
+/// let clock = world_clock(&t, &zones);
+/// assert_eq!(clock.rows()[1].0, "IST");
+/// assert_eq!(
+///     clock.to_string(),
+///     "EST  2026-02-22 09:30:00  -05:00\n\
+///      IST  2026-02-22 20:00:00  +05:30\n\
+///      UTC  2026-02-22 14:30:00  +00:00"
+/// );
+/// ```
+pub fn world_clock(instant: &NanoTime, zones: &[TimeZone]) -> WorldClock {
+    let rows = zones
+        .iter()
+        .map(|zone| {
+            let label = zone.name().unwrap_or(zone.abbreviation_at(instant));
+            (label.to_string(), zone.to_local(instant))
+        })
+        .collect();
+    WorldClock { rows }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels_follow_dst() {
+        let zones = [TimeZone::from_posix("CET-1CEST,M3.5.0,M10.5.0/3").unwrap()];
+        let summer = NanoTime::new(2026, 7, 1, 12, 0, 0, 0).unwrap();
+        let clock = world_clock(&summer, &zones);
+        assert_eq!(clock.rows()[0].0, "CEST");
+        assert_eq!(clock.rows()[0].1.to_utc(), summer);
+        assert_eq!(clock.to_string(), "CEST  2026-07-01 14:00:00  +02:00");
+        assert_eq!(world_clock(&summer, &[]).to_string(), "");
+    }
+}
@@ -0,0 +1,133 @@
+//! System time zone lookup on Unix via the zoneinfo directory.
+//!
+//! Zone files are in TZif format (RFC 8536) and are loaded with their
+//! full transition history by [`TimeZone::from_tzif`].
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::TimeZone;
+use crate::LocalZoneSource;
+
+const ZONEINFO_DIRS: &[&str] = &[
+    "/usr/share/zoneinfo",
+    "/usr/lib/zoneinfo",
+    "/usr/share/lib/zoneinfo",
+    "/etc/zoneinfo",
+];
+
+/// Loads a zone by IANA name from `$TZDIR` or the standard zoneinfo directories.
+pub(super) fn named(name: &str) -> Option<TimeZone> {
+    if !is_valid_name(name) {
+        return None;
+    }
+    let dirs = env::var_os("TZDIR")
+        .map(PathBuf::from)
+        .into_iter()
+        .chain(ZONEINFO_DIRS.iter().map(PathBuf::from));
+    for dir in dirs {
+        if let Some(mut tz) = load(&dir.join(name)) {
+            tz.name = Some(name.to_string());
+            return Some(tz);
+        }
+    }
+    None
+}
+
+/// Resolves the local zone from `$TZ`, falling back to `/etc/localtime`,
+/// and reports which one it came from. An empty `$TZ` means UTC.
+pub(super) fn local() -> Option<(TimeZone, LocalZoneSource)> {
+    match env::var("TZ") {
+        Ok(tz) if tz.is_empty() => Some((TimeZone::utc(), LocalZoneSource::Unavailable)),
+        Ok(tz) => {
+            let spec = tz.strip_prefix(':').unwrap_or(&tz);
+            let zone = if spec.starts_with('/') {
+                let mut zone = load(Path::new(spec))?;
+                zone.name = name_from_path(Path::new(spec));
+                zone
+            } else {
+                named(spec).or_else(|| TimeZone::from_posix(spec))?
+            };
+            Some((zone, LocalZoneSource::TzVariable))
+        }
+        Err(_) => {
+            let path = Path::new("/etc/localtime");
+            let mut zone = load(path)?;
+            zone.name = fs::read_link(path)
+                .ok()
+                .and_then(|target| name_from_path(&target));
+            Some((zone, LocalZoneSource::LocaltimeFile))
+        }
+    }
+}
+
+/// Rejects names that could escape the zoneinfo directory.
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('/')
+        && name
+            .split('/')
+            .all(|part| !part.is_empty() && part != "." && part != "..")
+}
+
+/// Extracts an IANA name from a path like `/usr/share/zoneinfo/Europe/Paris`.
+fn name_from_path(path: &Path) -> Option<String> {
+    let s = path.to_str()?;
+    let idx = s.rfind("zoneinfo/")?;
+    Some(s[idx + "zoneinfo/".len()..].to_string())
+}
+
+fn load(path: &Path) -> Option<TimeZone> {
+    TimeZone::from_tzif(&fs::read(path).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_name() {
+        assert!(is_valid_name("America/New_York"));
+        assert!(is_valid_name("UTC"));
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name("/etc/passwd"));
+        assert!(!is_valid_name("../../etc/passwd"));
+        assert!(!is_valid_name("America//New_York"));
+    }
+
+    #[test]
+    fn test_name_from_path() {
+        assert_eq!(
+            name_from_path(Path::new("/usr/share/zoneinfo/Europe/Paris")),
+            Some("Europe/Paris".to_string())
+        );
+        assert_eq!(name_from_path(Path::new("/etc/localtime")), None);
+    }
+
+    #[test]
+    fn test_load_reads_tzif() {
+        let data = crate::tz::tzif::tests::encode(
+            &[],
+            &[(3600, false, "CET")],
+            "CET-1CEST,M3.5.0,M10.5.0/3",
+        );
+        let path = env::temp_dir().join(format!("nanotime-tzif-{}", std::process::id()));
+        fs::write(&path, data).unwrap();
+        let tz = load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(tz, TimeZone::from_posix("CET-1CEST,M3.5.0,M10.5.0/3"));
+        assert!(load(Path::new("/nonexistent/zone")).is_none());
+    }
+
+    #[test]
+    fn test_named_system_zone() {
+        assert!(named("../../etc/passwd").is_none());
+        assert!(named("Not/A_Zone").is_none());
+        // Hosts without tzdata installed have nothing to look up.
+        if let Some(tz) = named("America/New_York") {
+            let july = crate::NanoTime::new(2026, 7, 1, 12, 0, 0, 0).unwrap();
+            assert_eq!(tz.offset_at(&july).as_secs(), -4 * 3600);
+        }
+    }
+}
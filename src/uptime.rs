@@ -0,0 +1,268 @@
+//! How long the system has been running.
+
+use crate::{NanoDuration, NanoTime};
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod sys {
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: i64,
+        tv_nsec: i64,
+    }
+
+    /// Like `CLOCK_MONOTONIC`, but also counts time spent suspended.
+    const CLOCK_BOOTTIME: i32 = 7;
+
+    extern "C" {
+        fn clock_gettime(clk_id: i32, tp: *mut Timespec) -> i32;
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    pub fn uptime_nanos() -> Option<i128> {
+        unsafe {
+            let mut ts = std::mem::zeroed::<Timespec>();
+            if clock_gettime(CLOCK_BOOTTIME, &mut ts) != 0 {
+                return None;
+            }
+            Some(ts.tv_sec as i128 * 1_000_000_000 + ts.tv_nsec as i128)
+        }
+    }
+}
+
+#[cfg(target_vendor = "apple")]
+mod sys {
+    use std::ffi::{c_char, c_void};
+
+    use crate::NanoTime;
+
+    #[repr(C)]
+    struct Timeval {
+        tv_sec: i64,
+        tv_usec: i32,
+    }
+
+    extern "C" {
+        fn sysctlbyname(
+            name: *const c_char,
+            oldp: *mut c_void,
+            oldlenp: *mut usize,
+            newp: *mut c_void,
+            newlen: usize,
+        ) -> i32;
+    }
+
+    /// Reads `kern.boottime`, the UTC time the system booted.
+    #[cfg(not(tarpaulin_include))]
+    pub fn boot_time() -> Option<NanoTime> {
+        unsafe {
+            let mut tv = std::mem::zeroed::<Timeval>();
+            let mut len = std::mem::size_of::<Timeval>();
+            let rc = sysctlbyname(
+                c"kern.boottime".as_ptr(),
+                &mut tv as *mut Timeval as *mut c_void,
+                &mut len,
+                std::ptr::null_mut(),
+                0,
+            );
+            if rc != 0 || tv.tv_sec < 0 {
+                return None;
+            }
+            NanoTime::checked_from_unix(tv.tv_sec, tv.tv_usec.max(0) as u32 * 1_000)
+        }
+    }
+
+    pub fn uptime_nanos() -> Option<i128> {
+        Some(NanoTime::now_utc().diff_nanos(&boot_time()?))
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    extern "system" {
+        fn GetTickCount64() -> u64;
+    }
+
+    pub fn uptime_nanos() -> Option<i128> {
+        Some(unsafe { GetTickCount64() } as i128 * 1_000_000)
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_vendor = "apple",
+    windows
+)))]
+mod sys {
+    pub fn uptime_nanos() -> Option<i128> {
+        None
+    }
+}
+
+/// Returns how long the system has been running since boot, including time
+/// spent suspended.
+///
+/// Uses `CLOCK_BOOTTIME` on Linux, `kern.boottime` on macOS, and
+/// `GetTickCount64` on Windows, which has millisecond resolution. Returns
+/// zero if the platform provides none of these.
+///
+/// ```rust
+/// let up = nanotime::uptime();
+/// assert!(!up.is_negative());
+/// println!("up {}s", up.secs_floor());
+/// ```
+pub fn uptime() -> NanoDuration {
+    sys::uptime_nanos()
+        .and_then(|nanos| NanoDuration::from_nanos(nanos.max(0)))
+        .unwrap_or_default()
+}
+
+/// Returns the UTC time the system booted.
+///
+/// Kernel timestamps relative to boot, such as those in `dmesg` output,
+/// become wall-clock times by adding them to this. Outside macOS it is
+/// derived from the current time and [`uptime`], so it moves if the wall
+/// clock is stepped. Returns the Unix epoch if the platform provides no
+/// uptime.
+///
+/// ```rust
+/// let booted = nanotime::boot_time();
+/// assert!(booted <= nanotime::NanoTime::now_utc());
+/// ```
+pub fn boot_time() -> NanoTime {
+    #[cfg(target_vendor = "apple")]
+    if let Some(t) = sys::boot_time() {
+        return t;
+    }
+    match sys::uptime_nanos() {
+        Some(nanos) => NanoTime::now_utc()
+            .checked_add_nanos(-nanos)
+            .unwrap_or_else(|| NanoTime::from_epoch(0)),
+        None => NanoTime::from_epoch(0),
+    }
+}
+
+/// Formats the time since `since` the way `uptime(1)` does, such as
+/// `"up 12 days, 3:42"`, `"up 3:42"`, or `"up 42 min"`. Pass
+/// [`boot_time`] for the system's own uptime, or a process start time
+/// for a daemon's. A `since` in the future counts as zero.
+///
+/// ```rust
+/// use nanotime::{boot_time, format_uptime};
+///
+/// let motd = format_uptime(&boot_time());
+/// assert!(motd.starts_with("up "));
+/// ```
+pub fn format_uptime(since: &NanoTime) -> String {
+    human(up_secs(since))
+}
+
+fn human(secs: i128) -> String {
+    let (days, hours, mins) = (secs / 86_400, secs / 3600 % 24, secs / 60 % 60);
+    let mut out = String::from("up ");
+    if days > 0 {
+        let plural = if days == 1 { "" } else { "s" };
+        out.push_str(&format!("{} day{}, ", days, plural));
+    }
+    if hours > 0 {
+        out.push_str(&format!("{}:{:02}", hours, mins));
+    } else {
+        out.push_str(&format!("{} min", mins));
+    }
+    out
+}
+
+/// Formats the time since `since` as an ISO 8601 duration in whole
+/// seconds, such as `"P12DT3H42M7S"`, for status endpoints and other
+/// machine readers. A `since` in the future gives `"PT0S"`.
+///
+/// ```rust
+/// use nanotime::{boot_time, format_uptime_iso, NanoTime};
+///
+/// assert!(format_uptime_iso(&boot_time()).starts_with('P'));
+/// let later = NanoTime::new(9999, 1, 1, 0, 0, 0, 0).unwrap();
+/// assert_eq!(format_uptime_iso(&later), "PT0S");
+/// ```
+pub fn format_uptime_iso(since: &NanoTime) -> String {
+    iso(up_secs(since))
+}
+
+fn iso(secs: i128) -> String {
+    let days = secs / 86_400;
+    let mut out = String::from("P");
+    if days > 0 {
+        out.push_str(&format!("{}D", days));
+    }
+    let parts = [
+        (secs / 3600 % 24, 'H'),
+        (secs / 60 % 60, 'M'),
+        (secs % 60, 'S'),
+    ];
+    if parts.iter().any(|p| p.0 != 0) || days == 0 {
+        out.push('T');
+        for (n, unit) in parts {
+            if n != 0 {
+                out.push_str(&format!("{}{}", n, unit));
+            }
+        }
+        if secs == 0 {
+            out.push_str("0S");
+        }
+    }
+    out
+}
+
+/// Whole seconds from `since` to now, or zero if `since` is later.
+fn up_secs(since: &NanoTime) -> i128 {
+    NanoTime::now_utc().diff_nanos(since).max(0) / 1_000_000_000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uptime_advances() {
+        if sys::uptime_nanos().is_none() {
+            assert_eq!(uptime(), NanoDuration::default());
+            assert_eq!(boot_time(), NanoTime::from_epoch(0));
+            return;
+        }
+        let a = uptime();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let b = uptime();
+        assert!(a.as_nanos() > 0);
+        assert!(b.as_nanos() - a.as_nanos() >= 10_000_000);
+    }
+
+    #[test]
+    fn test_boot_time_matches_uptime() {
+        let booted = boot_time();
+        let implied = NanoTime::now_utc().diff_nanos(&booted);
+        assert!((implied - uptime().as_nanos()).abs() < 1_000_000_000);
+    }
+
+    #[test]
+    fn test_uptime_text() {
+        let cases = [
+            (0, "up 0 min", "PT0S"),
+            (59, "up 0 min", "PT59S"),
+            (3600 + 5 * 60, "up 1:05", "PT1H5M"),
+            (86_400, "up 1 day, 0 min", "P1D"),
+            (86_400 + 42 * 60, "up 1 day, 42 min", "P1DT42M"),
+            (
+                12 * 86_400 + 3 * 3600 + 42 * 60 + 7,
+                "up 12 days, 3:42",
+                "P12DT3H42M7S",
+            ),
+        ];
+        for (secs, text, machine) in cases {
+            assert_eq!(human(secs), text);
+            assert_eq!(iso(secs), machine);
+        }
+        let future = NanoTime::now_utc()
+            .checked_add_nanos(60_000_000_000)
+            .unwrap();
+        assert_eq!(format_uptime_iso(&future), "PT0S");
+    }
+}
@@ -0,0 +1,212 @@
+//! Conversions between video frame numbers, durations, and MPEG PTS.
+//!
+//! Broadcast rates such as 29.97 fps are really 30000/1001, so a frame
+//! time is never a whole number of nanoseconds and `f64` fps math drifts
+//! by a frame every few hours. [`FrameRate`] keeps the exact fraction and
+//! computes every position from the frame number, rounding once.
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::{samples_to_duration, NANOS_PER_SEC};
+
+/// The 90 kHz clock that MPEG transport streams, RTP video, and most
+/// containers use for presentation timestamps.
+pub const PTS_CLOCK_RATE: u32 = 90_000;
+
+/// An exact frame rate of `num / den` frames per second.
+///
+/// The fraction is kept in lowest terms, so `60/2` and `30/1` compare
+/// equal.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::FrameRate;
+///
+/// let rate = FrameRate::NTSC; // 30000/1001, "29.97"
+/// assert_eq!(rate.frame_to_time(30_000), Duration::from_secs(1001));
+/// assert_eq!(rate.time_to_frame(Duration::from_secs(1001)), 30_000);
+/// assert_eq!(rate.frame_to_pts(1), 3003);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameRate {
+    num: u32,
+    den: u32,
+}
+
+impl FrameRate {
+    /// 24000/1001, film telecined to NTSC ("23.976").
+    pub const FILM_NTSC: FrameRate = FrameRate {
+        num: 24_000,
+        den: 1_001,
+    };
+    /// 24 fps film.
+    pub const FILM: FrameRate = FrameRate { num: 24, den: 1 };
+    /// 25 fps PAL.
+    pub const PAL: FrameRate = FrameRate { num: 25, den: 1 };
+    /// 30000/1001 NTSC ("29.97").
+    pub const NTSC: FrameRate = FrameRate {
+        num: 30_000,
+        den: 1_001,
+    };
+    /// 60000/1001 ("59.94").
+    pub const NTSC_60: FrameRate = FrameRate {
+        num: 60_000,
+        den: 1_001,
+    };
+
+    /// Creates a rate of `num / den` frames per second. Returns `None` if
+    /// either is zero.
+    pub fn new(num: u32, den: u32) -> Option<Self> {
+        if num == 0 || den == 0 {
+            return None;
+        }
+        let g = gcd(num, den);
+        Some(FrameRate {
+            num: num / g,
+            den: den / g,
+        })
+    }
+
+    /// Creates a whole-number rate of `fps` frames per second. Returns
+    /// `None` if it is zero.
+    pub fn from_fps(fps: u32) -> Option<Self> {
+        Self::new(fps, 1)
+    }
+
+    /// Returns the numerator in lowest terms.
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+
+    /// Returns the denominator in lowest terms.
+    pub fn den(&self) -> u32 {
+        self.den
+    }
+
+    /// Returns the rate as an approximate frames-per-second value, for
+    /// display.
+    pub fn as_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    /// Returns when frame `frame` starts, measured from frame 0 and
+    /// rounded down to the nanosecond.
+    pub fn frame_to_time(&self, frame: u64) -> Duration {
+        let nanos = frame as u128 * NANOS_PER_SEC as u128 * self.den as u128 / self.num as u128;
+        Duration::new(
+            (nanos / NANOS_PER_SEC as u128) as u64,
+            (nanos % NANOS_PER_SEC as u128) as u32,
+        )
+    }
+
+    /// Returns the frame showing at `d` after frame 0: the last one that
+    /// starts at or before it, saturating at `u64::MAX`.
+    pub fn time_to_frame(&self, d: Duration) -> u64 {
+        // Frame starts are rounded down, so frame n starts at or before d
+        // exactly when n * 1e9 * den < (d + 1) * num.
+        let frame = ((d.as_nanos() + 1) * self.num as u128 - 1)
+            / (NANOS_PER_SEC as u128 * self.den as u128);
+        u64::try_from(frame).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the 90 kHz PTS of frame `frame`, with frame 0 at PTS 0,
+    /// rounded down to a whole tick.
+    ///
+    /// The result is not wrapped to the 33 bits a transport stream
+    /// carries; mask it with `& 0x1_FFFF_FFFF` when writing one.
+    pub fn frame_to_pts(&self, frame: u64) -> u64 {
+        let pts = frame as u128 * PTS_CLOCK_RATE as u128 * self.den as u128 / self.num as u128;
+        u64::try_from(pts).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the frame showing at 90 kHz timestamp `pts`, with frame 0
+    /// at PTS 0.
+    pub fn pts_to_frame(&self, pts: u64) -> u64 {
+        let frame = ((pts as u128 + 1) * self.num as u128 - 1)
+            / (PTS_CLOCK_RATE as u128 * self.den as u128);
+        u64::try_from(frame).unwrap_or(u64::MAX)
+    }
+}
+
+impl fmt::Display for FrameRate {
+    /// Formats as `num/den`, or just `num` for whole rates.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Converts a 90 kHz timestamp to a duration, rounded down to the
+/// nanosecond.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{duration_to_pts, pts_to_duration};
+///
+/// assert_eq!(pts_to_duration(90_000), Duration::from_secs(1));
+/// assert_eq!(duration_to_pts(Duration::from_millis(40)), 3_600);
+/// ```
+pub fn pts_to_duration(pts: u64) -> Duration {
+    samples_to_duration(pts, PTS_CLOCK_RATE)
+}
+
+/// Returns the 90 kHz tick in progress at `d`: the last one whose start,
+/// as [`pts_to_duration`] rounds it, is at or before `d`. Saturates at
+/// `u64::MAX`.
+pub fn duration_to_pts(d: Duration) -> u64 {
+    let rate = PTS_CLOCK_RATE as u128;
+    let pts = ((d.as_nanos() + 1) * rate - 1) / NANOS_PER_SEC as u128;
+    u64::try_from(pts).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_reduces() {
+        assert_eq!(FrameRate::new(60, 2), FrameRate::from_fps(30));
+        assert_eq!(FrameRate::new(60_000, 2_002), Some(FrameRate::NTSC));
+        assert_eq!(FrameRate::new(0, 1), None);
+        assert_eq!(FrameRate::new(25, 0), None);
+        assert_eq!(FrameRate::NTSC.to_string(), "30000/1001");
+        assert_eq!(FrameRate::PAL.to_string(), "25");
+    }
+
+    #[test]
+    fn test_no_drift_over_a_day() {
+        let rate = FrameRate::NTSC;
+        // 24 hours of wall time is 2589410.58... frames at 29.97.
+        let day = Duration::from_secs(86_400);
+        assert_eq!(rate.time_to_frame(day), 2_589_410);
+        for frame in [1, 1_000, 2_589_410, u32::MAX as u64] {
+            let t = rate.frame_to_time(frame);
+            assert_eq!(rate.time_to_frame(t), frame);
+            assert_eq!(rate.time_to_frame(t - Duration::from_nanos(1)), frame - 1);
+        }
+    }
+
+    #[test]
+    fn test_pts() {
+        assert_eq!(FrameRate::NTSC.frame_to_pts(30_000), 90_090_000);
+        assert_eq!(FrameRate::PAL.frame_to_pts(25), 90_000);
+        assert_eq!(FrameRate::NTSC_60.frame_to_pts(1), 1_501);
+        for rate in [FrameRate::FILM_NTSC, FrameRate::NTSC_60, FrameRate::PAL] {
+            for frame in [0, 1, 7, 123_457] {
+                assert_eq!(rate.pts_to_frame(rate.frame_to_pts(frame)), frame);
+            }
+        }
+        assert_eq!(duration_to_pts(pts_to_duration(12_345)), 12_345);
+    }
+}
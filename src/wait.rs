@@ -0,0 +1,184 @@
+//! Blocking until a wall-clock time.
+//!
+//! The standard library only waits for durations, measured on the
+//! monotonic clock. Converting a wall-clock target to a duration once and
+//! waiting that long goes wrong if the system clock is stepped while
+//! waiting, for example by NTP or a suspended laptop resuming. These
+//! helpers instead wait in slices of at most [`MAX_SLICE`] and re-read the
+//! system clock after each, so a step is noticed within one slice.
+
+use std::sync::{Condvar, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::NanoTime;
+
+/// The longest single wait before the system clock is read again.
+const MAX_SLICE: Duration = Duration::from_secs(1);
+
+/// The time left until `target` by the system clock, or `None` once it has
+/// been reached, capped at [`MAX_SLICE`].
+fn next_slice(target: &NanoTime) -> Option<Duration> {
+    let remaining = target.diff_nanos(&NanoTime::now_utc());
+    (remaining > 0)
+        .then(|| MAX_SLICE.min(Duration::from_nanos(remaining.min(u64::MAX as i128) as u64)))
+}
+
+/// Sleeps until the system clock reaches `target`, a UTC time.
+///
+/// Returns immediately if `target` has passed.
+pub fn sleep_until(target: NanoTime) {
+    while let Some(slice) = next_slice(&target) {
+        thread::sleep(slice);
+    }
+}
+
+/// Parks the current thread until it is unparked or the system clock
+/// reaches `target`, a UTC time.
+///
+/// Like [`thread::park_timeout`], this may also return spuriously, so
+/// callers should re-check whatever they were waiting for.
+pub fn park_until(target: NanoTime) {
+    while let Some(slice) = next_slice(&target) {
+        let start = Instant::now();
+        thread::park_timeout(slice);
+        if start.elapsed() < slice {
+            // Unparked, or a spurious wakeup; either way the caller decides.
+            return;
+        }
+    }
+}
+
+/// Waits on `condvar` until notified or until the system clock reaches
+/// `target`, a UTC time.
+///
+/// Returns the reacquired guard and whether the wait ended because
+/// `target` was reached. As with [`Condvar::wait_timeout`], a return
+/// without timing out may be spurious; prefer [`wait_until_while`] when
+/// waiting for a condition.
+pub fn wait_until<'a, T>(
+    condvar: &Condvar,
+    mut guard: MutexGuard<'a, T>,
+    target: NanoTime,
+) -> (MutexGuard<'a, T>, bool) {
+    while let Some(slice) = next_slice(&target) {
+        let (g, result) = condvar
+            .wait_timeout(guard, slice)
+            .unwrap_or_else(|e| e.into_inner());
+        guard = g;
+        if !result.timed_out() {
+            return (guard, false);
+        }
+    }
+    (guard, true)
+}
+
+/// Waits on `condvar` while `condition` holds, until the system clock
+/// reaches `target`, a UTC time.
+///
+/// `condition` is re-checked after every wakeup, spurious or not. Returns
+/// the reacquired guard and whether the wait ended because `target` was
+/// reached with `condition` still true.
+///
+/// ```rust
+/// use std::sync::{Arc, Condvar, Mutex};
+/// use std::thread;
+/// use std::time::Duration;
+/// use nanotime::{wait_until_while, Deadline};
+///
+/// let pair = Arc::new((Mutex::new(false), Condvar::new()));
+/// let worker = Arc::clone(&pair);
+/// thread::spawn(move || {
+///     *worker.0.lock().unwrap() = true;
+///     worker.1.notify_one();
+/// });
+///
+/// let deadline = Deadline::after(Duration::from_secs(5));
+/// let (lock, cvar) = &*pair;
+/// let (ready, timed_out) = wait_until_while(cvar, lock.lock().unwrap(), deadline.time(), |ready| !*ready);
+/// assert!(*ready && !timed_out);
+/// ```
+pub fn wait_until_while<'a, T, F>(
+    condvar: &Condvar,
+    mut guard: MutexGuard<'a, T>,
+    target: NanoTime,
+    mut condition: F,
+) -> (MutexGuard<'a, T>, bool)
+where
+    F: FnMut(&mut T) -> bool,
+{
+    while condition(&mut *guard) {
+        let Some(slice) = next_slice(&target) else {
+            return (guard, true);
+        };
+        guard = condvar
+            .wait_timeout(guard, slice)
+            .unwrap_or_else(|e| e.into_inner())
+            .0;
+    }
+    (guard, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn in_ms(ms: i128) -> NanoTime {
+        NanoTime::now_utc().add_nanos(ms * 1_000_000)
+    }
+
+    #[test]
+    fn test_sleep_until() {
+        let start = Instant::now();
+        sleep_until(in_ms(20));
+        assert!(start.elapsed() >= Duration::from_millis(15));
+        // A target in the past returns at once.
+        sleep_until(NanoTime::from_epoch(0));
+    }
+
+    #[test]
+    fn test_wait_until_times_out() {
+        let m = Mutex::new(());
+        let cv = Condvar::new();
+        let target = in_ms(20);
+        let (_guard, timed_out) = wait_until(&cv, m.lock().unwrap(), target);
+        assert!(timed_out);
+        assert!(NanoTime::now_utc() >= target);
+    }
+
+    #[test]
+    fn test_wait_until_while_sees_notification() {
+        let pair = Arc::new((Mutex::new(0), Condvar::new()));
+        let worker = Arc::clone(&pair);
+        let handle = thread::spawn(move || {
+            for _ in 0..3 {
+                thread::sleep(Duration::from_millis(5));
+                *worker.0.lock().unwrap() += 1;
+                worker.1.notify_all();
+            }
+        });
+        let (lock, cv) = &*pair;
+        let (count, timed_out) =
+            wait_until_while(cv, lock.lock().unwrap(), in_ms(10_000), |n| *n < 3);
+        assert_eq!((*count, timed_out), (3, false));
+        drop(count);
+        handle.join().unwrap();
+
+        let (count, timed_out) = wait_until_while(cv, lock.lock().unwrap(), in_ms(10), |_| true);
+        assert_eq!((*count, timed_out), (3, true));
+    }
+
+    #[test]
+    fn test_park_until() {
+        let start = Instant::now();
+        park_until(in_ms(20));
+        // Parking may wake spuriously, but never after a long delay.
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        let parked = thread::spawn(|| park_until(in_ms(10_000)));
+        thread::sleep(Duration::from_millis(10));
+        parked.thread().unpark();
+        parked.join().unwrap();
+    }
+}
@@ -0,0 +1,226 @@
+//! Detecting changes to the system clock.
+
+use std::fmt;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::checkpoint::human;
+use crate::{NanoDuration, NanoTime};
+
+/// A change to the wall clock reported by [`ClockWatch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClockJump {
+    expected: NanoTime,
+    actual: NanoTime,
+}
+
+impl ClockJump {
+    /// Returns the UTC time the wall clock would read had it only advanced
+    /// with the monotonic clock.
+    pub fn expected(&self) -> NanoTime {
+        self.expected
+    }
+
+    /// Returns the UTC time the wall clock actually read.
+    pub fn actual(&self) -> NanoTime {
+        self.actual
+    }
+
+    /// Returns how far the wall clock moved relative to the monotonic
+    /// clock: positive if it jumped forward, negative if backward.
+    pub fn offset(&self) -> NanoDuration {
+        NanoDuration::from_nanos(self.actual.diff_nanos(&self.expected)).unwrap_or_default()
+    }
+
+    /// Returns `true` if the wall clock moved forward.
+    pub fn is_forward(&self) -> bool {
+        self.actual > self.expected
+    }
+}
+
+impl fmt::Display for ClockJump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.actual.diff_nanos(&self.expected);
+        let by = Duration::from_nanos(nanos.unsigned_abs().min(u64::MAX as u128) as u64);
+        let direction = if nanos > 0 { "forward" } else { "backward" };
+        write!(
+            f,
+            "wall clock moved {} by {} at {}",
+            direction,
+            human(by),
+            self.actual.to_rfc3339()
+        )
+    }
+}
+
+/// Watches the wall clock against the monotonic clock to detect steps and
+/// slews.
+///
+/// NTP steps, manual changes, suspend and resume, and VM migration can all
+/// move the wall clock without the monotonic clock following. A service
+/// that caches values derived from the wall time, such as "today's" date or
+/// the next scheduled run, can use the reported [`ClockJump`]s to
+/// invalidate them.
+///
+/// Each check compares how far the wall clock advanced since the last
+/// baseline with how far the monotonic clock did. Once they disagree by
+/// more than the threshold, a jump is reported and the baseline resets, so
+/// gradual slewing is reported once it adds up to the threshold.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::ClockWatch;
+///
+/// let mut watch = ClockWatch::new(Duration::from_secs(1));
+/// // ... later, e.g. once per loop iteration ...
+/// if let Some(jump) = watch.check() {
+///     eprintln!("{}", jump); // wall clock moved forward by 3.20s at ...
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClockWatch {
+    threshold: Duration,
+    wall: NanoTime,
+    mono: Instant,
+}
+
+impl ClockWatch {
+    /// Creates a watch reporting disagreements larger than `threshold`,
+    /// with the current time as the baseline.
+    pub fn new(threshold: Duration) -> Self {
+        Self::starting_at(threshold, NanoTime::now_utc(), Instant::now())
+    }
+
+    /// Creates a watch whose baseline is the UTC time `wall` read at the
+    /// instant `mono`.
+    pub fn starting_at(threshold: Duration, wall: NanoTime, mono: Instant) -> Self {
+        ClockWatch {
+            threshold,
+            wall,
+            mono,
+        }
+    }
+
+    /// Returns the threshold.
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// Compares the wall clock with the monotonic clock now.
+    pub fn check(&mut self) -> Option<ClockJump> {
+        let mono = Instant::now();
+        let wall = NanoTime::now_utc();
+        self.check_at(wall, mono)
+    }
+
+    /// Compares the UTC time `wall`, read at the instant `mono`, with the
+    /// baseline, and returns the jump if they disagree by more than the
+    /// threshold.
+    pub fn check_at(&mut self, wall: NanoTime, mono: Instant) -> Option<ClockJump> {
+        let elapsed = mono.saturating_duration_since(self.mono);
+        let expected = self.wall.add_nanos(elapsed.as_nanos() as i128);
+        let drift = wall.diff_nanos(&expected).unsigned_abs();
+        if drift <= self.threshold.as_nanos() {
+            return None;
+        }
+        self.wall = wall;
+        self.mono = mono;
+        Some(ClockJump {
+            expected,
+            actual: wall,
+        })
+    }
+
+    /// Starts a thread that checks every `interval` and sends each jump to
+    /// the returned receiver. The thread exits once the receiver is
+    /// dropped and the next jump cannot be delivered.
+    pub fn spawn(threshold: Duration, interval: Duration) -> Receiver<ClockJump> {
+        let (tx, rx) = mpsc::channel();
+        let mut watch = Self::new(threshold);
+        thread::Builder::new()
+            .name("nanotime-clock-watch".into())
+            .spawn(move || loop {
+                thread::sleep(interval);
+                if let Some(jump) = watch.check() {
+                    if tx.send(jump).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("failed to spawn clock watch thread");
+        rx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> NanoTime {
+        NanoTime::new(2026, 2, 22, 14, 30, 5, 0).unwrap()
+    }
+
+    #[test]
+    fn test_detects_step() {
+        let t0 = Instant::now();
+        let mut watch = ClockWatch::starting_at(Duration::from_millis(500), base(), t0);
+        let secs = |s: u64| t0 + Duration::from_secs(s);
+        // In step with the monotonic clock, plus jitter under the threshold.
+        assert_eq!(
+            watch.check_at(base().add_nanos(10_000_000_200), secs(10)),
+            None
+        );
+
+        // Stepped forward by 3 seconds.
+        let jump = watch
+            .check_at(base().add_nanos(23_000_000_000), secs(20))
+            .unwrap();
+        assert!(jump.is_forward());
+        assert_eq!(jump.offset().as_nanos(), 3_000_000_000);
+        assert_eq!(jump.expected(), base().add_nanos(20_000_000_000));
+        assert_eq!(
+            jump.to_string(),
+            "wall clock moved forward by 3.00s at 2026-02-22T14:30:28Z"
+        );
+
+        // The baseline moved with the jump.
+        assert_eq!(
+            watch.check_at(base().add_nanos(33_000_000_000), secs(30)),
+            None
+        );
+        let back = watch
+            .check_at(base().add_nanos(32_000_000_000), secs(40))
+            .unwrap();
+        assert!(!back.is_forward());
+        assert_eq!(back.offset().as_nanos(), -11_000_000_000);
+    }
+
+    #[test]
+    fn test_slew_accumulates() {
+        let t0 = Instant::now();
+        let mut watch = ClockWatch::starting_at(Duration::from_millis(500), base(), t0);
+        // Running 1% fast is reported once 500ms has built up.
+        let mut reported = None;
+        for s in 1..=100u64 {
+            let wall = base().add_nanos(s as i128 * 1_010_000_000);
+            if let Some(jump) = watch.check_at(wall, t0 + Duration::from_secs(s)) {
+                reported = Some((s, jump));
+                break;
+            }
+        }
+        let (s, jump) = reported.unwrap();
+        assert_eq!(s, 51);
+        assert_eq!(jump.offset().as_nanos(), 510_000_000);
+    }
+
+    #[test]
+    fn test_live_check() {
+        let mut watch = ClockWatch::new(Duration::from_secs(5));
+        assert_eq!(watch.threshold(), Duration::from_secs(5));
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(watch.check(), None);
+        let rx = ClockWatch::spawn(Duration::from_secs(5), Duration::from_millis(1));
+        assert!(rx.recv_timeout(Duration::from_millis(10)).is_err());
+    }
+}
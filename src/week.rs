@@ -0,0 +1,282 @@
+//! Weekdays, week boundaries, and week numbers under the schemes
+//! different countries and reports use.
+
+use crate::parse::WEEKDAYS;
+use crate::{NanoTime, NANOS_PER_DAY};
+
+/// A day of the week.
+///
+/// Which day starts a week varies: Monday under ISO 8601 and in most of
+/// Europe, Sunday in the US, Canada, and Japan, and Saturday in much of
+/// the Middle East. Functions that need a week boundary take one as a
+/// parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Weekday::Sunday,
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+    ];
+
+    /// Returns the English name, such as `"Monday"`.
+    pub fn name(&self) -> &'static str {
+        WEEKDAYS[*self as usize]
+    }
+
+    /// Returns 0 for Sunday through 6 for Saturday.
+    pub fn number_from_sunday(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns 1 for Monday through 7 for Sunday, as ISO 8601 does.
+    pub fn number_from_monday(&self) -> u8 {
+        (*self as u8 + 6) % 7 + 1
+    }
+
+    /// Returns how many days after `start` this day falls, 0 to 6.
+    ///
+    /// ```rust
+    /// use nanotime::Weekday;
+    ///
+    /// assert_eq!(Weekday::Sunday.days_since(Weekday::Monday), 6);
+    /// assert_eq!(Weekday::Sunday.days_since(Weekday::Saturday), 1);
+    /// ```
+    pub fn days_since(&self, start: Weekday) -> u8 {
+        (*self as u8 + 7 - start as u8) % 7
+    }
+}
+
+/// A rule for numbering the weeks of a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeekScheme {
+    /// ISO 8601: weeks start on Monday, and week 1 is the one holding the
+    /// year's first Thursday. Days near January 1st can fall in the last
+    /// week of the previous week-based year, and days near December 31st
+    /// in week 1 of the next. Weeks run 1 to 52 or 53.
+    Iso,
+    /// US and Canadian calendars: weeks start on Sunday, and week 1 is the
+    /// one holding January 1st, however short. Weeks run 1 to 53, or 54
+    /// in a leap year starting on a Saturday.
+    Us,
+    /// Like [`Us`](Self::Us), with weeks starting on the given day: week 1
+    /// holds January 1st, and a new week begins on each of that weekday.
+    StartingOn(Weekday),
+    /// Seven-day blocks counted from January 1st, whatever the weekday:
+    /// days 1 to 7 are week 1. Weeks run 1 to 53.
+    Simple,
+}
+
+/// Number of ISO 8601 weeks in `year`: 53 when it starts on a Thursday,
+/// or on a Wednesday in a leap year.
+pub(crate) fn iso_weeks_in(year: i64) -> i64 {
+    let dec31_weekday = |y: i64| (y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)) % 7;
+    if dec31_weekday(year) == 4 || dec31_weekday(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+/// Returns the ISO 8601 week-based year and week number.
+pub(crate) fn iso_week(t: &NanoTime) -> (i64, i64) {
+    let year = t.year() as i64;
+    let weekday = (t.weekday_index() as i64 + 6) % 7 + 1; // Monday = 1
+    let week = (t.day_of_year() as i64 - weekday + 10) / 7;
+    if week < 1 {
+        (year - 1, iso_weeks_in(year - 1))
+    } else if week > iso_weeks_in(year) {
+        (year + 1, 1)
+    } else {
+        (year, week)
+    }
+}
+
+impl NanoTime {
+    /// Returns the week of the year under `scheme`, starting from 1.
+    ///
+    /// Under [`WeekScheme::Iso`] the week can belong to the previous or
+    /// next year; [`week_year`](Self::week_year) says which.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, WeekScheme};
+    ///
+    /// // Friday, January 1st 2027.
+    /// let t = NanoTime::new(2027, 1, 1, 0, 0, 0, 0).unwrap();
+    /// assert_eq!(t.week_number(WeekScheme::Iso), 53);
+    /// assert_eq!(t.week_year(WeekScheme::Iso), 2026);
+    /// assert_eq!(t.week_number(WeekScheme::Us), 1);
+    ///
+    /// // Sunday, January 3rd starts US week 2 but is still ISO week 53.
+    /// let t = NanoTime::new(2027, 1, 3, 0, 0, 0, 0).unwrap();
+    /// assert_eq!(t.week_number(WeekScheme::Us), 2);
+    /// assert_eq!(t.week_number(WeekScheme::Simple), 1);
+    /// ```
+    pub fn week_number(&self, scheme: WeekScheme) -> u8 {
+        let yday = self.day_of_year() as usize - 1;
+        let week = match scheme {
+            WeekScheme::Iso => iso_week(self).1 as usize,
+            WeekScheme::Us => self.week_number(WeekScheme::StartingOn(Weekday::Sunday)) as usize,
+            WeekScheme::StartingOn(first) => {
+                let jan1 = (self.weekday().days_since(first) as usize + 7 * 53 - yday) % 7;
+                (yday + jan1) / 7 + 1
+            }
+            WeekScheme::Simple => yday / 7 + 1,
+        };
+        week as u8
+    }
+
+    /// Returns the year that [`week_number`](Self::week_number) counts
+    /// within. Only [`WeekScheme::Iso`] can differ from the calendar
+    /// year, by one either way, which is why this is an `i32`.
+    pub fn week_year(&self, scheme: WeekScheme) -> i32 {
+        match scheme {
+            WeekScheme::Iso => iso_week(self).0 as i32,
+            _ => self.year() as i32,
+        }
+    }
+
+    /// Returns the day of the week.
+    pub fn weekday(&self) -> Weekday {
+        Weekday::ALL[self.weekday_index()]
+    }
+
+    /// Returns midnight at the start of the week holding this time, for
+    /// weeks starting on `first`.
+    ///
+    /// Near the start of year 0, where that midnight would fall before the
+    /// earliest representable time, returns 0000-01-01T00:00 instead.
+    ///
+    /// ```rust
+    /// use nanotime::{NanoTime, Weekday};
+    ///
+    /// // Wednesday afternoon.
+    /// let t = NanoTime::new(2026, 2, 25, 15, 0, 0, 0).unwrap();
+    /// assert_eq!(t.start_of_week(Weekday::Monday), NanoTime::new(2026, 2, 23, 0, 0, 0, 0).unwrap());
+    /// assert_eq!(t.start_of_week(Weekday::Sunday), NanoTime::new(2026, 2, 22, 0, 0, 0, 0).unwrap());
+    /// assert_eq!(t.start_of_week(Weekday::Saturday), NanoTime::new(2026, 2, 21, 0, 0, 0, 0).unwrap());
+    /// ```
+    pub fn start_of_week(&self, first: Weekday) -> NanoTime {
+        let back = self.weekday().days_since(first) as i64;
+        let midnight = self.unix_secs().div_euclid(86_400) * 86_400;
+        NanoTime::saturating_from_unix(midnight - back * 86_400, 0)
+    }
+}
+
+/// Returns the start of each week, for weeks starting on `first`, that
+/// overlaps `start` up to but not including `end`. The first item is the
+/// start of the week holding `start`, which can fall before it.
+///
+/// ```rust
+/// use nanotime::{week_starts, NanoTime, Weekday};
+///
+/// let start = NanoTime::new(2026, 2, 1, 0, 0, 0, 0).unwrap(); // a Sunday
+/// let end = NanoTime::new(2026, 3, 1, 0, 0, 0, 0).unwrap();
+/// let weeks: Vec<_> = week_starts(&start, &end, Weekday::Monday).collect();
+/// assert_eq!(weeks.len(), 5);
+/// assert_eq!(weeks[0], NanoTime::new(2026, 1, 26, 0, 0, 0, 0).unwrap());
+/// ```
+pub fn week_starts(
+    start: &NanoTime,
+    end: &NanoTime,
+    first: Weekday,
+) -> impl Iterator<Item = NanoTime> {
+    let end = *end;
+    std::iter::successors(Some(start.start_of_week(first)), move |t| {
+        // From a start clamped in year 0, a week later lands in the
+        // second week, whose start is a true boundary.
+        t.checked_add_nanos(7 * NANOS_PER_DAY)
+            .map(|next| next.start_of_week(first))
+    })
+    .take_while(move |t| *t < end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso_weeks_in() {
+        assert_eq!(iso_weeks_in(2020), 53);
+        assert_eq!(iso_weeks_in(2026), 53);
+        assert_eq!(iso_weeks_in(2024), 52);
+        assert_eq!(iso_weeks_in(2015), 53);
+    }
+
+    #[test]
+    fn test_us_weeks_match_excel_weeknum() {
+        // (date, WEEKNUM(date, 1)) from a spreadsheet.
+        let cases = [
+            ((2026, 1, 1), 1),
+            ((2026, 1, 3), 1),
+            ((2026, 1, 4), 2),
+            ((2026, 12, 31), 53),
+            ((2028, 12, 31), 54), // 2028 starts on a Saturday.
+            ((2023, 12, 31), 53),
+        ];
+        for ((y, m, d), week) in cases {
+            let t = NanoTime::new(y, m, d, 12, 0, 0, 0).unwrap();
+            assert_eq!(t.week_number(WeekScheme::Us), week, "{}", t.date());
+        }
+    }
+
+    #[test]
+    fn test_iso_and_simple_boundaries() {
+        let dec31 = NanoTime::new(2024, 12, 31, 0, 0, 0, 0).unwrap();
+        assert_eq!(dec31.week_number(WeekScheme::Iso), 1);
+        assert_eq!(dec31.week_year(WeekScheme::Iso), 2025);
+        assert_eq!(dec31.week_number(WeekScheme::Simple), 53);
+        assert_eq!(dec31.week_year(WeekScheme::Simple), 2024);
+        let first = NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(first.week_year(WeekScheme::Iso), -1);
+    }
+
+    #[test]
+    fn test_week_start_on_each_day() {
+        // Wednesday 2026-02-25.
+        let t = NanoTime::new(2026, 2, 25, 15, 0, 0, 0).unwrap();
+        assert_eq!(t.weekday(), Weekday::Wednesday);
+        for first in Weekday::ALL {
+            let start = t.start_of_week(first);
+            assert_eq!(start.weekday(), first);
+            assert!(start <= t && t.diff_secs(&start) < 7 * 86_400);
+        }
+        let saturday_weeks = WeekScheme::StartingOn(Weekday::Saturday);
+        let jan1 = NanoTime::new(2026, 1, 1, 0, 0, 0, 0).unwrap(); // Thursday
+        assert_eq!(jan1.week_number(saturday_weeks), 1);
+        let jan3 = NanoTime::new(2026, 1, 3, 0, 0, 0, 0).unwrap(); // Saturday
+        assert_eq!(jan3.week_number(saturday_weeks), 2);
+    }
+
+    #[test]
+    fn test_week_starts_clamped_in_year_zero() {
+        let first = NanoTime::new(0, 1, 1, 12, 0, 0, 0).unwrap(); // Saturday
+        assert_eq!(
+            first.start_of_week(Weekday::Monday),
+            NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+        let end = NanoTime::new(0, 1, 11, 0, 0, 0, 0).unwrap();
+        let weeks: Vec<_> = week_starts(&first, &end, Weekday::Monday).collect();
+        assert_eq!(
+            weeks,
+            [
+                NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap(),
+                NanoTime::new(0, 1, 3, 0, 0, 0, 0).unwrap(),
+                NanoTime::new(0, 1, 10, 0, 0, 0, 0).unwrap(),
+            ]
+        );
+    }
+}
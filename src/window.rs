@@ -0,0 +1,241 @@
+//! Items from a trailing time window, such as errors in the last minute.
+
+use std::time::Duration;
+
+use crate::NanoTime;
+
+#[derive(Debug, Clone)]
+struct Bucket<T> {
+    /// Which slice of time, counted in bucket widths from the epoch, the
+    /// items belong to. `i128::MIN` when the bucket has never been used.
+    index: i128,
+    items: Vec<(NanoTime, T)>,
+}
+
+/// Keeps the items recorded within a trailing window of time and forgets
+/// older ones.
+///
+/// The window is divided into a ring of buckets. Inserting touches a
+/// single bucket, and a bucket's items expire together once the window has
+/// moved past it, so neither insertion nor expiry depends on how many
+/// items are held. Counting and iteration still filter by exact time, so
+/// results cover precisely `(now - window, now]`.
+///
+/// Timestamps are UTC instants.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use nanotime::{NanoTime, RecentWindow};
+///
+/// let mut errors = RecentWindow::new(Duration::from_secs(60));
+/// let t = NanoTime::from_epoch(1_771_770_600);
+/// errors.insert(t, "timeout");
+/// errors.insert(NanoTime::from_epoch(1_771_770_630), "refused");
+///
+/// assert_eq!(errors.count_at(NanoTime::from_epoch(1_771_770_640)), 2);
+/// assert_eq!(errors.count_at(NanoTime::from_epoch(1_771_770_670)), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecentWindow<T> {
+    window: i128,
+    width: i128,
+    buckets: Vec<Bucket<T>>,
+}
+
+impl<T> RecentWindow<T> {
+    /// Creates a window of the given length, divided into 60 buckets.
+    pub fn new(window: Duration) -> Self {
+        Self::with_buckets(window, 60)
+    }
+
+    /// Creates a window divided into `buckets` buckets (at least one).
+    ///
+    /// More buckets release expired items sooner at the cost of a longer
+    /// ring to walk when counting.
+    pub fn with_buckets(window: Duration, buckets: usize) -> Self {
+        let window = window.as_nanos().max(1) as i128;
+        let n = buckets.max(1);
+        // One extra slot so the partly expired oldest bucket and the
+        // newest bucket never share a slot.
+        RecentWindow {
+            window,
+            width: (window + n as i128 - 1) / n as i128,
+            buckets: (0..=n)
+                .map(|_| Bucket {
+                    index: i128::MIN,
+                    items: Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the length of the window.
+    pub fn window(&self) -> Duration {
+        Duration::from_nanos(self.window as u64)
+    }
+
+    /// Returns the window ending at `now` as exclusive-start, inclusive-end
+    /// nanoseconds, and the indices of the buckets holding its two ends.
+    fn span(&self, now: &NanoTime) -> (i128, i128, i128, i128) {
+        let end = now.unix_nanos();
+        let start = end - self.window;
+        (
+            start,
+            end,
+            start.div_euclid(self.width),
+            end.div_euclid(self.width),
+        )
+    }
+
+    /// Records `item` at time `t`.
+    ///
+    /// Returns `false`, dropping the item, if `t` is already older than the
+    /// window ending at the newest time seen by its bucket's slot.
+    pub fn insert(&mut self, t: NanoTime, item: T) -> bool {
+        let index = t.unix_nanos().div_euclid(self.width);
+        let slot = index.rem_euclid(self.buckets.len() as i128) as usize;
+        let bucket = &mut self.buckets[slot];
+        if bucket.index < index {
+            bucket.index = index;
+            bucket.items.clear();
+        } else if bucket.index > index {
+            return false;
+        }
+        bucket.items.push((t, item));
+        true
+    }
+
+    /// Records `item` at the current UTC time.
+    pub fn insert_now(&mut self, item: T) -> bool {
+        self.insert(NanoTime::now_utc(), item)
+    }
+
+    /// Returns the items in the window ending at `now`, oldest bucket
+    /// first.
+    pub fn iter_at(&self, now: NanoTime) -> impl Iterator<Item = (&NanoTime, &T)> {
+        let (start, end, first, last) = self.span(&now);
+        let n = self.buckets.len() as i128;
+        (first..=last)
+            .filter_map(move |index| {
+                let bucket = &self.buckets[index.rem_euclid(n) as usize];
+                (bucket.index == index).then_some(bucket)
+            })
+            .flat_map(|bucket| bucket.items.iter())
+            .filter(move |(t, _)| (start + 1..=end).contains(&t.unix_nanos()))
+            .map(|(t, item)| (t, item))
+    }
+
+    /// Returns the items in the window ending at the current UTC time.
+    pub fn iter(&self) -> impl Iterator<Item = (&NanoTime, &T)> {
+        self.iter_at(NanoTime::now_utc())
+    }
+
+    /// Counts the items in the window ending at `now`.
+    ///
+    /// Only the buckets at either edge of the window are filtered item by
+    /// item; the rest are counted by length.
+    pub fn count_at(&self, now: NanoTime) -> usize {
+        let (start, end, first, last) = self.span(&now);
+        self.buckets
+            .iter()
+            .filter(|b| (first..=last).contains(&b.index))
+            .map(|b| {
+                if b.index == first || b.index == last {
+                    b.items
+                        .iter()
+                        .filter(|(t, _)| (start + 1..=end).contains(&t.unix_nanos()))
+                        .count()
+                } else {
+                    b.items.len()
+                }
+            })
+            .sum()
+    }
+
+    /// Counts the items in the window ending at the current UTC time.
+    pub fn count(&self) -> usize {
+        self.count_at(NanoTime::now_utc())
+    }
+
+    /// Frees the buckets that fall entirely before the window ending at
+    /// `now`. Counting and iteration already ignore them; this only
+    /// releases the items they hold.
+    pub fn expire_at(&mut self, now: NanoTime) {
+        let (_, _, first, _) = self.span(&now);
+        for bucket in &mut self.buckets {
+            if bucket.index < first {
+                bucket.index = i128::MIN;
+                bucket.items.clear();
+            }
+        }
+    }
+
+    /// Removes every item.
+    pub fn clear(&mut self) {
+        for bucket in &mut self.buckets {
+            bucket.index = i128::MIN;
+            bucket.items.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: u64) -> NanoTime {
+        NanoTime::from_epoch(1_771_770_000 + secs)
+    }
+
+    #[test]
+    fn test_count_is_exact_at_edges() {
+        let mut w = RecentWindow::with_buckets(Duration::from_secs(60), 4);
+        for s in [0, 10, 20, 30, 59, 60] {
+            assert!(w.insert(at(s), s));
+        }
+        assert_eq!(w.count_at(at(60)), 5);
+        assert_eq!(w.count_at(at(69)), 5);
+        assert_eq!(w.count_at(at(70)), 4);
+        assert_eq!(w.count_at(at(118)), 2);
+        assert_eq!(w.count_at(at(119)), 1);
+        assert_eq!(w.count_at(at(120)), 0);
+        // Items after `now` are not counted.
+        assert_eq!(w.count_at(at(30)), 4);
+    }
+
+    #[test]
+    fn test_iter_matches_count() {
+        let mut w = RecentWindow::with_buckets(Duration::from_secs(10), 5);
+        for s in 0..30 {
+            w.insert(at(s), s);
+        }
+        for now in 0..40 {
+            let items: Vec<u64> = w.iter_at(at(now)).map(|(_, &s)| s).collect();
+            assert_eq!(items.len(), w.count_at(at(now)), "now={}", now);
+            assert!(items.iter().all(|&s| s + 10 > now && s <= now));
+        }
+    }
+
+    #[test]
+    fn test_slot_reuse_and_stale_inserts() {
+        let mut w = RecentWindow::with_buckets(Duration::from_secs(4), 2);
+        assert!(w.insert(at(0), 'a'));
+        assert!(w.insert(at(6), 'b'));
+        // at(0) shares a slot with at(6) and has been overwritten.
+        assert!(!w.insert(at(1), 'c'));
+        let items: Vec<char> = w.iter_at(at(6)).map(|(_, &c)| c).collect();
+        assert_eq!(items, ['b']);
+    }
+
+    #[test]
+    fn test_expire_and_clear() {
+        let mut w = RecentWindow::with_buckets(Duration::from_secs(60), 6);
+        w.insert(at(0), ());
+        w.insert(at(100), ());
+        w.expire_at(at(100));
+        assert!(w.buckets.iter().map(|b| b.items.len()).sum::<usize>() == 1);
+        w.clear();
+        assert_eq!(w.count_at(at(100)), 0);
+        assert_eq!(w.window(), Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,119 @@
+//! A fixed 16-byte binary encoding for exchanging timestamps with other
+//! languages.
+//!
+//! The layout is documented on [`NanoTime::encode_wire`]. In C it is:
+//!
+//! ```c
+//! struct nanotime_wire {
+//!     uint8_t  version;      /* 1 */
+//!     uint8_t  reserved[3];
+//!     int64_t  seconds;      /* big-endian */
+//!     uint32_t nanos;        /* big-endian */
+//! };
+//! ```
+
+use crate::NanoTime;
+
+/// The layout version written by [`NanoTime::encode_wire`].
+const WIRE_VERSION: u8 = 1;
+
+impl NanoTime {
+    /// Writes this time in a fixed 16-byte binary format, independent of
+    /// serde, for exchange with systems written in other languages.
+    ///
+    /// All integers are big-endian:
+    ///
+    /// ```text
+    /// byte  0      version, currently 1
+    /// bytes 1-3    reserved, zero
+    /// bytes 4-11   seconds since 1970-01-01T00:00:00Z, signed 64-bit
+    /// bytes 12-15  nanoseconds within the second (0-999999999), unsigned 32-bit
+    /// ```
+    ///
+    /// The value is always UTC. Times before the epoch have negative
+    /// seconds and a nonnegative nanosecond part, so
+    /// `1969-12-31T23:59:59.5Z` is `-1` seconds and `500000000`
+    /// nanoseconds. Decoders should reject versions they do not know;
+    /// version 1 decoders also reject nonzero reserved bytes so that later
+    /// versions can use them.
+    ///
+    /// ```rust
+    /// use nanotime::NanoTime;
+    ///
+    /// let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+    /// let mut buf = [0u8; 16];
+    /// t.encode_wire(&mut buf);
+    /// assert_eq!(buf[0], 1);
+    /// assert_eq!(NanoTime::decode_wire(&buf), Some(t));
+    /// ```
+    pub fn encode_wire(&self, out: &mut [u8; 16]) {
+        out[0] = WIRE_VERSION;
+        out[1..4].fill(0);
+        out[4..12].copy_from_slice(&self.unix_secs().to_be_bytes());
+        out[12..16].copy_from_slice(&self.nanosecond().to_be_bytes());
+    }
+
+    /// Reads a time in the 16-byte wire format. Returns `None` for an
+    /// unknown version, nonzero reserved bytes, nanoseconds of a second or
+    /// more, or a year outside `0..=65535`.
+    pub fn decode_wire(bytes: &[u8; 16]) -> Option<Self> {
+        if bytes[0] != WIRE_VERSION || bytes[1..4] != [0; 3] {
+            return None;
+        }
+        let secs = i64::from_be_bytes(bytes[4..12].try_into().ok()?);
+        let nanos = u32::from_be_bytes(bytes[12..16].try_into().ok()?);
+        if nanos >= 1_000_000_000 {
+            return None;
+        }
+        Self::checked_from_unix(secs, nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(t: NanoTime) -> [u8; 16] {
+        let mut buf = [0xAA; 16];
+        t.encode_wire(&mut buf);
+        buf
+    }
+
+    #[test]
+    fn test_known_bytes() {
+        let t = NanoTime::new(2026, 2, 22, 14, 30, 5, 123_456_789).unwrap();
+        assert_eq!(
+            encode(t),
+            [1, 0, 0, 0, 0, 0, 0, 0, 0x69, 0x9B, 0x12, 0xED, 0x07, 0x5B, 0xCD, 0x15]
+        );
+    }
+
+    #[test]
+    fn test_before_epoch() {
+        let t = NanoTime::new(1969, 12, 31, 23, 59, 59, 500_000_000).unwrap();
+        let buf = encode(t);
+        assert_eq!(buf[4..12], [0xFF; 8]);
+        assert_eq!(buf[12..16], 500_000_000u32.to_be_bytes());
+        assert_eq!(NanoTime::decode_wire(&buf), Some(t));
+
+        let first = NanoTime::new(0, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(NanoTime::decode_wire(&encode(first)), Some(first));
+    }
+
+    #[test]
+    fn test_rejects_invalid() {
+        let good = encode(NanoTime::from_epoch(0));
+        let mut bad = good;
+        bad[0] = 2;
+        assert_eq!(NanoTime::decode_wire(&bad), None);
+        let mut bad = good;
+        bad[2] = 1;
+        assert_eq!(NanoTime::decode_wire(&bad), None);
+        let mut bad = good;
+        bad[12..16].copy_from_slice(&1_000_000_000u32.to_be_bytes());
+        assert_eq!(NanoTime::decode_wire(&bad), None);
+        let mut bad = good;
+        bad[4..12].copy_from_slice(&i64::MAX.to_be_bytes());
+        assert_eq!(NanoTime::decode_wire(&bad), None);
+    }
+}
@@ -1,4 +1,4 @@
-use nanotime::{Elapsed, NanoTime};
+use nanotime::{Elapsed, NanoTime, Precision, TimeZone, UtcOffset};
 use proptest::prelude::*;
 
 fn arb_nanotime() -> impl Strategy<Value = NanoTime> {
@@ -319,3 +319,55 @@ proptest! {
         prop_assert!(NanoTime::new(year, month, day, hour, minute, second, nanosecond).is_none());
     }
 }
+
+proptest! {
+    /// Converting an instant to local time and back always recovers the instant.
+    #[test]
+    fn tz_local_round_trip(secs in 0u64..=4_102_444_800) {
+        use nanotime::tz::LocalResult;
+
+        let zone = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        let utc = NanoTime::from_epoch(secs);
+        let local = zone.to_local(&utc);
+        match zone.from_local(&local.local()) {
+            LocalResult::Single(t) => prop_assert_eq!(t, local),
+            LocalResult::Ambiguous { earliest, latest } => {
+                prop_assert!(earliest == local || latest == local);
+            }
+            LocalResult::Gap { .. } => prop_assert!(false, "local time of an instant is never skipped"),
+        }
+    }
+}
+
+proptest! {
+    /// RFC 3339 output at full precision parses back to the same instant.
+    #[test]
+    fn rfc3339_round_trip(t in arb_nanotime(), minutes in -1439i16..=1439) {
+        let offset = UtcOffset::from_minutes(minutes);
+        let s = t.to_rfc3339_opts(Precision::Nanos, offset);
+        prop_assert_eq!(NanoTime::parse_rfc3339(&s).unwrap(), t);
+        prop_assert_eq!(NanoTime::parse_rfc3339(&t.to_rfc3339()).unwrap(), t);
+    }
+}
+
+proptest! {
+    /// The allocation-free validator agrees with the parser, including on
+    /// near-misses with out-of-range fields and wrong separators.
+    #[test]
+    fn rfc3339_validator_matches_parser(
+        s in "[0-9]{4}-[0-3][0-9]-[0-3][0-9][Tt _][0-2][0-9]:[0-6][0-9]:[0-6][0-9](\\.[0-9]{0,3})?([Zz]|[+-][0-2][0-9]:?[0-6][0-9])?"
+    ) {
+        prop_assert_eq!(
+            nanotime::parse::is_valid_rfc3339(&s),
+            NanoTime::parse_rfc3339(&s).is_ok()
+        );
+    }
+
+    #[test]
+    fn rfc3339_validator_matches_parser_on_noise(s in "[0-9:Tt.Zz +-]{0,32}") {
+        prop_assert_eq!(
+            nanotime::parse::is_valid_rfc3339(&s),
+            NanoTime::parse_rfc3339(&s).is_ok()
+        );
+    }
+}